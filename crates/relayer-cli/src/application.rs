@@ -14,6 +14,7 @@ use ibc_relayer::config::Config;
 
 use crate::{
     components::{JsonTracing, PrettyTracing},
+    conclude::ErrorCode,
     config::validate_config,
     entry::EntryPoint,
 };
@@ -163,7 +164,7 @@ impl Application for CliApp {
                     supported configuration options: \
                     https://github.com/informalsystems/hermes/blob/master/config.toml"
                 );
-                std::process::exit(1);
+                std::process::exit(ErrorCode::Config.exit_code());
             })
             .expect("invalid config")
             .unwrap_or_default();
@@ -171,6 +172,10 @@ impl Application for CliApp {
         // Update the `json_output` flag used by `conclude::Output`
         self.json_output = command.json;
 
+        if command.profile {
+            ibc_relayer::util::profile::enable();
+        }
+
         if command.json {
             // Enable JSON by using the crate-level `Tracing`
             let tracing = JsonTracing::new(config.global)?;
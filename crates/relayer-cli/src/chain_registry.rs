@@ -109,6 +109,7 @@ where
         rpc_timeout: default::rpc_timeout(),
         account_prefix: chain_data.bech32_prefix,
         key_name: String::new(),
+        handshake_key_name: None,
         key_store_type: Store::default(),
         store_prefix: "ibc".to_string(),
         default_gas: Some(100000),
@@ -116,12 +117,21 @@ where
         gas_adjustment: None,
         gas_multiplier: Some(GasMultiplier::new(1.1).unwrap()),
         fee_granter: None,
+        max_daily_fee_spend: None,
         max_msg_num: MaxMsgNum::default(),
         max_tx_size: MaxTxSize::default(),
         clock_drift: default::clock_drift(),
         max_block_time: default::max_block_time(),
         trusting_period: None,
+        unbonding_period: None,
         memo_prefix: Memo::default(),
+        verify_query_proofs: false,
+        proof_query_concurrency: default::proof_query_concurrency(),
+        event_batch_delay: None,
+        priority_channels: Default::default(),
+        recv_only_channels: Default::default(),
+        ack_only_channels: Default::default(),
+        transfer_limits: Default::default(),
         proof_specs: Default::default(),
         trust_threshold: TrustThreshold::default(),
         gas_price: GasPrice {
@@ -132,6 +142,7 @@ where
         address_type: AddressType::default(),
         sequential_batch_tx: false,
         extension_options: Vec::new(),
+        compat_mode: None,
     })
 }
 
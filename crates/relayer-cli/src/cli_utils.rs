@@ -15,6 +15,7 @@ use ibc_relayer::{
     },
     config::Config,
     spawn,
+    util::profile::{self, Phase},
 };
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
@@ -73,6 +74,8 @@ pub fn spawn_chain_runtime_generic<Handle: ChainHandle>(
     config: &Config,
     chain_id: &ChainId,
 ) -> Result<Handle, Error> {
+    let _timing = profile::measure(Phase::SpawnChains);
+
     let rt = Arc::new(TokioRuntime::new().unwrap());
     spawn::spawn_chain_runtime(config, chain_id, rt).map_err(Error::spawn)
 }
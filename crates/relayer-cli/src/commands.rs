@@ -1,5 +1,6 @@
 //! Definition of all the Hermes subcommands
 
+mod bootstrap;
 mod clear;
 mod completions;
 mod config;
@@ -14,13 +15,14 @@ mod start;
 mod tx;
 mod update;
 mod upgrade;
+mod util;
 mod version;
 
 use self::{
-    clear::ClearCmds, completions::CompletionsCmd, config::ConfigCmd, create::CreateCmds,
-    fee::FeeCmd, health::HealthCheckCmd, keys::KeysCmd, listen::ListenCmd,
+    bootstrap::BootstrapCmd, clear::ClearCmds, completions::CompletionsCmd, config::ConfigCmd,
+    create::CreateCmds, fee::FeeCmd, health::HealthCheckCmd, keys::KeysCmd, listen::ListenCmd,
     misbehaviour::MisbehaviourCmd, query::QueryCmd, start::StartCmd, tx::TxCmd, update::UpdateCmds,
-    upgrade::UpgradeCmds, version::VersionCmd,
+    upgrade::UpgradeCmds, util::UtilCmd, version::VersionCmd,
 };
 
 use core::time::Duration;
@@ -70,6 +72,12 @@ pub enum CliCmd {
     /// Relays packets and open handshake messages between all chains in the config.
     Start(StartCmd),
 
+    /// Set up a path between two chains in a single step.
+    ///
+    /// Creates or reuses the clients, connection and channel between the two chains,
+    /// allows packets on the channel in the configuration file, and optionally starts relaying.
+    Bootstrap(BootstrapCmd),
+
     /// Query objects from the chain
     #[clap(subcommand)]
     Query(QueryCmd),
@@ -85,6 +93,10 @@ pub enum CliCmd {
     /// Listen to and display IBC events emitted by a chain
     Listen(ListenCmd),
 
+    /// Utilities to inspect IBC objects without relaying
+    #[clap(subcommand)]
+    Util(UtilCmd),
+
     /// Listen to client update IBC events and handles misbehaviour
     Misbehaviour(MisbehaviourCmd),
 
@@ -0,0 +1,518 @@
+use std::collections::hash_map::{Entry, HashMap};
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use serde::Serialize;
+
+use ibc_relayer::chain::handle::{BaseChainHandle, ChainHandle};
+use ibc_relayer::chain::requests::{
+    IncludeProof, QueryClientStateRequest, QueryConnectionChannelsRequest, QueryConnectionsRequest,
+    QueryHeight,
+};
+use ibc_relayer::channel::Channel;
+use ibc_relayer::config::default::connection_delay;
+use ibc_relayer::config::filter::Error as FilterError;
+use ibc_relayer::config::{load, store, Config};
+use ibc_relayer::connection::Connection;
+use ibc_relayer::foreign_client::ForeignClient;
+use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics03_connection::connection::IdentifiedConnectionEnd;
+use ibc_relayer_types::core::ics04_channel::channel::{IdentifiedChannelEnd, Order};
+use ibc_relayer_types::core::ics04_channel::version::Version;
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+
+use crate::cli_utils::ChainHandlePair;
+use crate::conclude::{ErrorCode, Output};
+use crate::error::Error;
+use crate::prelude::*;
+
+/// The data structure that represents the arguments when invoking the `bootstrap` CLI command.
+///
+/// `bootstrap --chain-a <CHAIN_A_ID> --chain-b <CHAIN_B_ID> --port <PORT_ID>`
+///
+/// Sets up a path between the two chains in a single step:
+/// 1. reuses an open channel between the two chains on the given ports if there is one,
+///    otherwise creates a new channel, on top of an open connection between the two chains
+///    if there is one, or on top of a new connection and new clients otherwise;
+/// 2. updates the packet filter of both chains in the configuration file so that
+///    packets on the channel are relayed;
+/// 3. if `--start` is given, starts relaying.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct BootstrapCmd {
+    #[clap(
+        long = "chain-a",
+        required = true,
+        value_name = "CHAIN_A_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the side `a` chain"
+    )]
+    chain_a: ChainId,
+
+    #[clap(
+        long = "chain-b",
+        required = true,
+        value_name = "CHAIN_B_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the side `b` chain"
+    )]
+    chain_b: ChainId,
+
+    #[clap(
+        long = "port",
+        value_name = "PORT_ID",
+        default_value = "transfer",
+        help = "Identifier of the port on chain `a`, and on chain `b` unless --b-port is given"
+    )]
+    port_a: PortId,
+
+    #[clap(
+        long = "b-port",
+        value_name = "B_PORT_ID",
+        help = "Identifier of the port on chain `b`, if different from the one on chain `a`"
+    )]
+    port_b: Option<PortId>,
+
+    #[clap(
+        long = "order",
+        value_name = "ORDER",
+        help = "The channel ordering, valid options 'unordered' (default) and 'ordered'",
+        default_value_t
+    )]
+    order: Order,
+
+    #[clap(
+        long = "channel-version",
+        visible_alias = "chan-version",
+        value_name = "VERSION",
+        help = "The version for the channel, if a new one is created"
+    )]
+    version: Option<Version>,
+
+    #[clap(
+        long = "start",
+        help = "Start relaying between the two chains once the path is set up"
+    )]
+    start: bool,
+}
+
+/// The path between the two chains set up by the `bootstrap` command.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+struct BootstrapPath {
+    a_chain: ChainId,
+    a_client: ClientId,
+    a_connection: ConnectionId,
+    a_port: PortId,
+    a_channel: ChannelId,
+    b_chain: ChainId,
+    b_client: ClientId,
+    b_connection: ConnectionId,
+    b_port: PortId,
+    b_channel: ChannelId,
+    /// Whether an existing channel was reused
+    reused_channel: bool,
+}
+
+impl BootstrapCmd {
+    fn port_b(&self) -> &PortId {
+        self.port_b.as_ref().unwrap_or(&self.port_a)
+    }
+
+    fn execute(&self, config: &Config) -> Result<BootstrapPath, Error> {
+        let chains = ChainHandlePair::spawn(config, &self.chain_a, &self.chain_b)?;
+
+        if let Some(path) = self.find_channel(&chains)? {
+            info!(
+                "reusing open channel {}/{} on chain {}",
+                path.a_port, path.a_channel, path.a_chain
+            );
+
+            return Ok(path);
+        }
+
+        let connection = match self.find_connection(&chains)? {
+            Some(connection) => {
+                info!(
+                    "reusing open connection {} on chain {}",
+                    connection.connection_id, self.chain_a
+                );
+
+                let client_a = ForeignClient::find(
+                    chains.dst.clone(),
+                    chains.src.clone(),
+                    connection.connection_end.client_id(),
+                )
+                .map_err(Error::foreign_client)?;
+
+                let client_b = ForeignClient::find(
+                    chains.src.clone(),
+                    chains.dst.clone(),
+                    connection.connection_end.counterparty().client_id(),
+                )
+                .map_err(Error::foreign_client)?;
+
+                Connection::find(client_a, client_b, &connection).map_err(Error::connection)?
+            }
+            None => {
+                info!(
+                    "creating new clients and a new connection between chains {} and {}",
+                    self.chain_a, self.chain_b
+                );
+
+                let client_a = ForeignClient::new(chains.src.clone(), chains.dst.clone())
+                    .map_err(Error::foreign_client)?;
+                let client_b = ForeignClient::new(chains.dst.clone(), chains.src.clone())
+                    .map_err(Error::foreign_client)?;
+
+                Connection::new(client_a, client_b, connection_delay())
+                    .map_err(Error::connection)?
+            }
+        };
+
+        info!("creating a new channel with order {}", self.order);
+
+        let channel = Channel::new(
+            connection,
+            self.order,
+            self.port_a.clone(),
+            self.port_b().clone(),
+            self.version.clone(),
+        )
+        .map_err(Error::channel)?;
+
+        let (a_channel, b_channel) = match (channel.a_channel_id(), channel.b_channel_id()) {
+            (Some(a_channel), Some(b_channel)) => (a_channel.clone(), b_channel.clone()),
+            _ => {
+                return Err(Error::cli_arg(
+                    "the channel handshake completed without channel identifiers".to_string(),
+                ))
+            }
+        };
+
+        Ok(BootstrapPath {
+            a_chain: self.chain_a.clone(),
+            a_client: channel.a_side.client_id().clone(),
+            a_connection: channel.a_side.connection_id().clone(),
+            a_port: self.port_a.clone(),
+            a_channel,
+            b_chain: self.chain_b.clone(),
+            b_client: channel.b_side.client_id().clone(),
+            b_connection: channel.b_side.connection_id().clone(),
+            b_port: self.port_b().clone(),
+            b_channel,
+            reused_channel: false,
+        })
+    }
+
+    /// Looks for an open channel on chain `a` on the given ports, whose
+    /// underlying connection is to chain `b`.
+    fn find_channel(
+        &self,
+        chains: &ChainHandlePair<BaseChainHandle>,
+    ) -> Result<Option<BootstrapPath>, Error> {
+        for connection in self.connections_to_b(chains)? {
+            let b_connection = match connection.connection_end.counterparty().connection_id() {
+                Some(b_connection) => b_connection.clone(),
+                None => continue,
+            };
+
+            let channels = chains
+                .src
+                .query_connection_channels(QueryConnectionChannelsRequest {
+                    connection_id: connection.connection_id.clone(),
+                    pagination: None,
+                })
+                .map_err(Error::relayer)?;
+
+            for channel in channels {
+                if !self.is_candidate_channel(&channel) {
+                    continue;
+                }
+
+                let b_channel = match channel.channel_end.counterparty().channel_id() {
+                    Some(b_channel) => b_channel.clone(),
+                    None => continue,
+                };
+
+                return Ok(Some(BootstrapPath {
+                    a_chain: self.chain_a.clone(),
+                    a_client: connection.connection_end.client_id().clone(),
+                    a_connection: connection.connection_id,
+                    a_port: channel.port_id,
+                    a_channel: channel.channel_id,
+                    b_chain: self.chain_b.clone(),
+                    b_client: connection.connection_end.counterparty().client_id().clone(),
+                    b_connection,
+                    b_port: self.port_b().clone(),
+                    b_channel,
+                    reused_channel: true,
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Whether the given channel end on chain `a` is an open channel on the
+    /// given ports and with the given ordering, which could be reused.
+    fn is_candidate_channel(&self, channel: &IdentifiedChannelEnd) -> bool {
+        let end = &channel.channel_end;
+
+        end.is_open()
+            && channel.port_id == self.port_a
+            && end.counterparty().port_id() == self.port_b()
+            && end.order_matches(&self.order)
+            && end.connection_hops().len() == 1
+            && self
+                .version
+                .as_ref()
+                .map_or(true, |version| end.version_matches(version))
+    }
+
+    /// Looks for an open connection on chain `a` to chain `b`.
+    fn find_connection(
+        &self,
+        chains: &ChainHandlePair<BaseChainHandle>,
+    ) -> Result<Option<IdentifiedConnectionEnd>, Error> {
+        Ok(self.connections_to_b(chains)?.into_iter().next())
+    }
+
+    /// The open connections on chain `a` to chain `b`.
+    ///
+    /// The client state of each client of chain `a` is only queried once,
+    /// however many connections are built on top of it.
+    fn connections_to_b(
+        &self,
+        chains: &ChainHandlePair<BaseChainHandle>,
+    ) -> Result<Vec<IdentifiedConnectionEnd>, Error> {
+        let connections = chains
+            .src
+            .query_connections(QueryConnectionsRequest { pagination: None })
+            .map_err(Error::relayer)?;
+
+        let mut client_chains = HashMap::new();
+        let mut connections_to_b = Vec::new();
+
+        for connection in connections {
+            if !connection.connection_end.is_open() {
+                continue;
+            }
+
+            let client_id = connection.connection_end.client_id();
+
+            let client_chain = match client_chains.entry(client_id.clone()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(counterparty_chain(&chains.src, client_id)?),
+            };
+
+            if *client_chain == self.chain_b {
+                connections_to_b.push(connection);
+            }
+        }
+
+        Ok(connections_to_b)
+    }
+}
+
+/// The identifier of the chain tracked by the given client.
+fn counterparty_chain(chain: &impl ChainHandle, client_id: &ClientId) -> Result<ChainId, Error> {
+    chain
+        .query_client_state(
+            QueryClientStateRequest {
+                client_id: client_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map(|(client_state, _)| client_state.chain_id())
+        .map_err(Error::relayer)
+}
+
+/// Allows packets on the given path to be relayed in the packet filters of both chains.
+///
+/// Returns true if any of the filters was modified, and an error if the channel
+/// is denied by a wildcard pattern of the packet filter of either chain.
+fn allow_path(config: &mut Config, path: &BootstrapPath) -> Result<bool, FilterError> {
+    let sides = [
+        (&path.a_chain, &path.a_port, &path.a_channel),
+        (&path.b_chain, &path.b_port, &path.b_channel),
+    ];
+
+    let mut modified = false;
+
+    for (chain_id, port_id, channel_id) in sides {
+        if let Some(chain_config) = config.find_chain_mut(chain_id) {
+            modified |= chain_config
+                .packet_filter
+                .allow_channel(port_id.clone(), channel_id.clone())?;
+        }
+    }
+
+    Ok(modified)
+}
+
+impl Runnable for BootstrapCmd {
+    fn run(&self) {
+        let mut config = (*app_config()).clone();
+
+        if self.chain_a == self.chain_b {
+            Output::error("the two chains must be different".to_string())
+                .with_code(ErrorCode::User)
+                .exit();
+        }
+
+        let path = self
+            .execute(&config)
+            .unwrap_or_else(|e| Output::error(format!("{}", e)).with_code(e.code()).exit());
+
+        let modified = allow_path(&mut config, &path).unwrap_or_else(|e| {
+            Output::error(format!(
+                "channel {}/{} was set up, but the packet filters could not be updated: {}",
+                path.a_port, path.a_channel, e
+            ))
+            .with_code(ErrorCode::Config)
+            .exit()
+        });
+
+        if modified {
+            match crate::config::config_path() {
+                Some(config_path) => {
+                    // Update the configuration file as written, rather than the
+                    // configuration loaded by this command, which is altered by
+                    // the command line options and the defaults applied by Hermes.
+                    warn!(
+                        "rewriting configuration file '{}' to update the packet filters, \
+                        its comments and formatting will not be preserved",
+                        config_path.display()
+                    );
+
+                    let stored =
+                        load(&config_path)
+                            .map_err(|e| e.to_string())
+                            .and_then(|mut stored| {
+                                allow_path(&mut stored, &path).map_err(|e| e.to_string())?;
+                                store(&stored, &config_path).map_err(|e| e.to_string())
+                            });
+
+                    match stored {
+                        Ok(()) => info!(
+                            "updated the packet filters in configuration file '{}'",
+                            config_path.display()
+                        ),
+                        Err(e) => Output::error(format!(
+                            "failed to update the packet filters in configuration file '{}': {}",
+                            config_path.display(),
+                            e
+                        ))
+                        .with_code(ErrorCode::Config)
+                        .exit(),
+                    }
+                }
+                None => warn!(
+                    "cannot figure out configuration path, the packet filters were not updated"
+                ),
+            }
+        }
+
+        if !self.start {
+            Output::success(path).exit();
+        }
+
+        info!(
+            "relaying on channel {}/{} between chains {} and {}",
+            path.a_port, path.a_channel, path.a_chain, path.b_chain
+        );
+
+        super::start::start(config, false, false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    use abscissa_core::clap::Parser;
+
+    #[test]
+    fn test_bootstrap_defaults() {
+        assert_eq!(
+            BootstrapCmd {
+                chain_a: ChainId::from_string("chain_a"),
+                chain_b: ChainId::from_string("chain_b"),
+                port_a: PortId::from_str("transfer").unwrap(),
+                port_b: None,
+                order: Order::Unordered,
+                version: None,
+                start: false,
+            },
+            BootstrapCmd::parse_from(["test", "--chain-a", "chain_a", "--chain-b", "chain_b"])
+        )
+    }
+
+    #[test]
+    fn test_bootstrap_all_options() {
+        assert_eq!(
+            BootstrapCmd {
+                chain_a: ChainId::from_string("chain_a"),
+                chain_b: ChainId::from_string("chain_b"),
+                port_a: PortId::from_str("port_a").unwrap(),
+                port_b: Some(PortId::from_str("port_b").unwrap()),
+                order: Order::Ordered,
+                version: Some(Version::new("v1".to_owned())),
+                start: true,
+            },
+            BootstrapCmd::parse_from([
+                "test",
+                "--chain-a",
+                "chain_a",
+                "--chain-b",
+                "chain_b",
+                "--port",
+                "port_a",
+                "--b-port",
+                "port_b",
+                "--order",
+                "ordered",
+                "--chan-version",
+                "v1",
+                "--start"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_bootstrap_no_chain_b() {
+        assert!(BootstrapCmd::try_parse_from(["test", "--chain-a", "chain_a"]).is_err())
+    }
+
+    #[test]
+    fn test_allow_path() {
+        let mut config = load(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../relayer/tests/config/fixtures/relayer_conf_example.toml"
+        ))
+        .unwrap();
+
+        let path = BootstrapPath {
+            a_chain: ChainId::from_string("chain_A"),
+            a_client: ClientId::from_str("07-tendermint-0").unwrap(),
+            a_connection: ConnectionId::from_str("connection-0").unwrap(),
+            a_port: PortId::transfer(),
+            a_channel: ChannelId::new(5),
+            b_chain: ChainId::from_string("chain_B"),
+            b_client: ClientId::from_str("07-tendermint-1").unwrap(),
+            b_connection: ConnectionId::from_str("connection-1").unwrap(),
+            b_port: PortId::transfer(),
+            b_channel: ChannelId::new(1),
+            reused_channel: false,
+        };
+
+        assert!(allow_path(&mut config, &path).unwrap());
+        assert!(!allow_path(&mut config, &path).unwrap());
+
+        assert!(config.packets_on_channel_allowed(&path.a_chain, &path.a_port, &path.a_channel));
+    }
+}
@@ -11,7 +11,7 @@ use ibc_relayer_types::events::IbcEvent;
 
 use crate::application::app_config;
 use crate::cli_utils::spawn_chain_counterparty;
-use crate::conclude::Output;
+use crate::conclude::{ErrorCode, Output};
 use crate::error::Error;
 
 /// `clear` subcommands
@@ -94,7 +94,7 @@ impl Runnable for ClearPacketsCmd {
             &self.channel_id,
         ) {
             Ok((chains, _)) => chains,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         };
 
         // If `counterparty_key_name` is provided, fetch the counterparty chain's
@@ -104,7 +104,9 @@ impl Runnable for ClearPacketsCmd {
                 Ok(mut dst_chain_cfg) => {
                     dst_chain_cfg.key_name = counterparty_key_name.to_string();
                 }
-                Err(e) => Output::error(format!("{}", e)).exit(),
+                Err(e) => Output::error(format!("{}", e))
+                    .with_code(ErrorCode::Connectivity)
+                    .exit(),
             }
         }
 
@@ -118,37 +120,55 @@ impl Runnable for ClearPacketsCmd {
         let fwd_link = match Link::new_from_opts(chains.src.clone(), chains.dst, opts, false, false)
         {
             Ok(link) => link,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Tx)
+                .exit(),
         };
         let rev_link = match fwd_link.reverse(false, false) {
             Ok(link) => link,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Tx)
+                .exit(),
         };
 
         // Schedule RecvPacket messages for pending packets in both directions.
         // This may produce pending acks which will be processed in the next phase.
         run_and_collect_events(&mut ev_list, || {
-            fwd_link.relay_recv_packet_and_timeout_messages()
+            fwd_link.relay_recv_packet_and_timeout_messages_with_progress(&mut report_batch)
         });
         run_and_collect_events(&mut ev_list, || {
-            rev_link.relay_recv_packet_and_timeout_messages()
+            rev_link.relay_recv_packet_and_timeout_messages_with_progress(&mut report_batch)
         });
 
         // Schedule AckPacket messages in both directions.
-        run_and_collect_events(&mut ev_list, || fwd_link.relay_ack_packet_messages());
-        run_and_collect_events(&mut ev_list, || rev_link.relay_ack_packet_messages());
+        run_and_collect_events(&mut ev_list, || {
+            fwd_link.relay_ack_packet_messages_with_progress(&mut report_batch)
+        });
+        run_and_collect_events(&mut ev_list, || {
+            rev_link.relay_ack_packet_messages_with_progress(&mut report_batch)
+        });
 
         Output::success(ev_list).exit()
     }
 }
 
+/// Report the events of a batch of messages as soon as it is committed,
+/// since clearing a channel with many pending packets may take a while.
+fn report_batch(events: &[IbcEvent]) {
+    if !events.is_empty() {
+        Output::progress(events.to_vec()).print();
+    }
+}
+
 fn run_and_collect_events<F>(ev_list: &mut Vec<IbcEvent>, f: F)
 where
     F: FnOnce() -> Result<Vec<IbcEvent>, LinkError>,
 {
     match f() {
         Ok(mut ev) => ev_list.append(&mut ev),
-        Err(e) => Output::error(Error::link(e)).exit(),
+        Err(e) => Output::error(Error::link(e))
+            .with_code(ErrorCode::Tx)
+            .exit(),
     };
 }
 
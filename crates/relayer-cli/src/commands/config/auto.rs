@@ -4,7 +4,7 @@ use abscissa_core::{
     {Command, Runnable},
 };
 
-use crate::conclude::Output;
+use crate::conclude::{ErrorCode, Output};
 
 use ibc_relayer::{
     config::{store, ChainConfig, Config},
@@ -132,11 +132,15 @@ impl Runnable for AutoCmd {
                         ))
                         .exit()
                     }
-                    Err(e) => Output::error(e.to_string()).exit(),
+                    Err(e) => Output::error(e.to_string())
+                        .with_code(ErrorCode::Config)
+                        .exit(),
                 }
             }
             Err(e) => {
-                Output::error(e.to_string()).exit();
+                Output::error(e.to_string())
+                    .with_code(ErrorCode::Connectivity)
+                    .exit();
             }
         }
     }
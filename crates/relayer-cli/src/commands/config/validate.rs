@@ -3,7 +3,7 @@ use std::fs;
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 
-use crate::conclude::Output;
+use crate::conclude::{ErrorCode, Output};
 use crate::config;
 use crate::prelude::*;
 
@@ -26,24 +26,31 @@ impl Runnable for ValidateCmd {
                 match fs::read_to_string(p.clone()) {
                     Ok(content) => {
                         if content.is_empty() {
-                            Output::error("the configuration file is empty").exit();
+                            Output::error("the configuration file is empty")
+                                .with_code(ErrorCode::Config)
+                                .exit();
                         }
                     }
                     Err(e) => Output::error(format!(
                         "error reading the configuration file {:?}: {}",
                         p, e
                     ))
+                    .with_code(ErrorCode::Config)
                     .exit(),
                 }
             }
-            None => Output::error("no configuration file found").exit(),
+            None => Output::error("no configuration file found")
+                .with_code(ErrorCode::Config)
+                .exit(),
         }
 
         // No need to output the underlying error, this is done already when the application boots.
         // See `application::CliApp::after_config`.
         match config::validate_config(&config) {
             Ok(_) => Output::success("configuration is valid").exit(),
-            Err(_) => Output::error("configuration is invalid").exit(),
+            Err(_) => Output::error("configuration is invalid")
+                .with_code(ErrorCode::Config)
+                .exit(),
         }
     }
 }
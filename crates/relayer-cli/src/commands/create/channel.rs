@@ -4,11 +4,12 @@ use abscissa_core::{Command, Runnable};
 use console::style;
 use dialoguer::Confirm;
 
+use ibc_relayer::chain::cosmos::compatibility::ChainVersions;
 use ibc_relayer::chain::handle::ChainHandle;
 use ibc_relayer::chain::requests::{
     IncludeProof, QueryClientStateRequest, QueryConnectionRequest, QueryHeight,
 };
-use ibc_relayer::channel::Channel;
+use ibc_relayer::channel::{version, Channel};
 use ibc_relayer::connection::Connection;
 use ibc_relayer::foreign_client::ForeignClient;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
@@ -18,7 +19,7 @@ use ibc_relayer_types::core::ics04_channel::version::Version;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId, PortId};
 
 use crate::cli_utils::{spawn_chain_runtime, ChainHandlePair};
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::conclude::{exit_with_error, exit_with_unrecoverable_error, ErrorCode, Output};
 use crate::prelude::*;
 use ibc_relayer::config::default::connection_delay;
 
@@ -116,6 +117,12 @@ pub struct CreateChannelCommand {
     )]
     version: Option<Version>,
 
+    #[clap(
+        long = "with-fee",
+        help = "Enable the ICS29 fee middleware on the new channel, by negotiating the fee-enabled version of the channel version (defaults to the version of the port for well-known ports)"
+    )]
+    with_fee: bool,
+
     #[clap(
         long = "new-client-connection",
         visible_alias = "new-client-conn",
@@ -157,14 +164,16 @@ impl Runnable for CreateChannelCommand {
                                     if confirm {
                                         self.run_using_new_connection(chain_b);
                                     } else {
-                                        Output::error("You elected not to create new clients and connections. Please re-invoke `create channel` with a pre-existing connection ID".to_string()).exit();
+                                        Output::error("You elected not to create new clients and connections. Please re-invoke `create channel` with a pre-existing connection ID".to_string()).with_code(ErrorCode::User).exit();
                                     }
                                 }
                                 Err(e) => {
                                     Output::error(format!(
                                         "An error occurred while waiting for user input: {}",
                                         e
-                                    ));
+                                    ))
+                                    .with_code(ErrorCode::Unknown)
+                                    .exit();
                                 }
                             }
                         }
@@ -176,12 +185,105 @@ impl Runnable for CreateChannelCommand {
 }
 
 impl CreateChannelCommand {
+    /// The version to propose for the new channel, wrapped into the
+    /// ICS29 composite version if the fee middleware is to be enabled.
+    fn channel_version(&self) -> Result<Option<Version>, String> {
+        if !self.with_fee {
+            return Ok(self.version.clone());
+        }
+
+        let app_version = self
+            .version
+            .clone()
+            .or_else(|| version::default_by_port(&self.port_a))
+            .ok_or_else(|| {
+                format!(
+                    "no default version for port {}, use --channel-version to give the application version to enable fees for",
+                    self.port_a
+                )
+            })?;
+
+        app_version
+            .fee_metadata()
+            .map_err(|e| format!("invalid channel version '{}': {}", app_version, e))?;
+
+        Ok(Some(app_version.with_fee()))
+    }
+
+    /// Aborts if fees are to be enabled on the new channel
+    /// but the given chain does not include the fee middleware.
+    fn check_fee_support(&self, chain: &impl ChainHandle) {
+        if !self.with_fee {
+            return;
+        }
+
+        let ibc_go = chain
+            .ibc_version()
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Connectivity));
+
+        match ibc_go {
+            None => warn!(
+                "could not determine the IBC-Go version of chain {}, cannot check that it supports the fee middleware",
+                chain.id()
+            ),
+            Some(ibc_go) => {
+                let versions = ChainVersions {
+                    cosmos_sdk: None,
+                    ibc_go: Some(ibc_go.clone()),
+                };
+
+                if !versions.supports_fees() {
+                    Output::error(format!(
+                        "chain {} does not support the ICS29 fee middleware (IBC-Go {}), cannot create a fee-enabled channel",
+                        chain.id(),
+                        ibc_go
+                    )).with_code(ErrorCode::User)
+                    .exit();
+                }
+            }
+        }
+    }
+
+    /// Aborts if fees were to be enabled on the new channel but
+    /// either end settled on a version without the fee middleware.
+    fn check_negotiated_fee<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        channel: &Channel<ChainA, ChainB>,
+    ) {
+        if !self.with_fee {
+            return;
+        }
+
+        let sides = [
+            (channel.src_chain().id(), channel.src_version()),
+            (channel.dst_chain().id(), channel.dst_version()),
+        ];
+
+        for (chain_id, version) in sides {
+            if !version.map_or(false, Version::supports_fee) {
+                Output::error(format!(
+                    "the channel was created but the fee middleware was not enabled on chain {}, which settled on version '{}'",
+                    chain_id,
+                    version.cloned().unwrap_or_default()
+                )).with_code(ErrorCode::Tx)
+                .exit();
+            }
+        }
+    }
+
     /// Creates a new channel, as well as a new underlying connection and clients.
     fn run_using_new_connection(&self, chain_b: &ChainId) {
         let config = app_config();
 
-        let chains = ChainHandlePair::spawn(&config, &self.chain_a, chain_b)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let version = self
+            .channel_version()
+            .unwrap_or_else(|e| Output::error(e).with_code(ErrorCode::User).exit());
+
+        let chains =
+            ChainHandlePair::spawn(&config, &self.chain_a, chain_b).unwrap_or_else(exit_with_error);
+
+        self.check_fee_support(&chains.src);
+        self.check_fee_support(&chains.dst);
 
         info!(
             "Creating new clients, new connection, and a new channel with order {}",
@@ -189,13 +291,19 @@ impl CreateChannelCommand {
         );
 
         let client_a = ForeignClient::new(chains.src.clone(), chains.dst.clone())
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
+        report_client(&client_a);
+
         let client_b = ForeignClient::new(chains.dst.clone(), chains.src)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
+        report_client(&client_b);
 
         // Create the connection.
         let con = Connection::new(client_a, client_b, connection_delay())
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
+
+        // Report the connection ahead of the channel handshake, which may take a while.
+        Output::progress(con.clone()).print();
 
         // Finally create the channel.
         let channel = Channel::new(
@@ -203,9 +311,12 @@ impl CreateChannelCommand {
             self.order,
             self.port_a.clone(),
             self.port_b.clone(),
-            self.version.clone(),
+            version,
         )
-        .unwrap_or_else(exit_with_unrecoverable_error);
+        .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
+
+        report_negotiated_versions(&channel);
+        self.check_negotiated_fee(&channel);
 
         Output::success(channel).exit();
     }
@@ -214,9 +325,12 @@ impl CreateChannelCommand {
     fn run_reusing_connection(&self, connection_a: &ConnectionId) {
         let config = app_config();
 
+        let version = self
+            .channel_version()
+            .unwrap_or_else(|e| Output::error(e).with_code(ErrorCode::User).exit());
+
         // Validate & spawn runtime for side a.
-        let chain_a = spawn_chain_runtime(&config, &self.chain_a)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain_a = spawn_chain_runtime(&config, &self.chain_a).unwrap_or_else(exit_with_error);
 
         // Query the connection end.
         let (conn_end, _) = chain_a
@@ -227,7 +341,7 @@ impl CreateChannelCommand {
                 },
                 IncludeProof::No,
             )
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Connectivity));
 
         // Query the client state, obtain the identifier of chain b.
         let chain_b = chain_a
@@ -239,36 +353,88 @@ impl CreateChannelCommand {
                 IncludeProof::No,
             )
             .map(|(cs, _)| cs.chain_id())
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Connectivity));
 
         // Spawn the runtime for side b.
-        let chain_b =
-            spawn_chain_runtime(&config, &chain_b).unwrap_or_else(exit_with_unrecoverable_error);
+        let chain_b = spawn_chain_runtime(&config, &chain_b).unwrap_or_else(exit_with_error);
+
+        self.check_fee_support(&chain_a);
+        self.check_fee_support(&chain_b);
 
         // Create the foreign client handles.
         let client_a = ForeignClient::find(chain_b.clone(), chain_a.clone(), conn_end.client_id())
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
         let client_b = ForeignClient::find(chain_a, chain_b, conn_end.counterparty().client_id())
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
 
         let identified_end = IdentifiedConnectionEnd::new(connection_a.clone(), conn_end);
 
         let connection = Connection::find(client_a, client_b, &identified_end)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
 
         let channel = Channel::new(
             connection,
             self.order,
             self.port_a.clone(),
             self.port_b.clone(),
-            self.version.clone(),
+            version,
         )
-        .unwrap_or_else(exit_with_unrecoverable_error);
+        .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
+
+        report_negotiated_versions(&channel);
+        self.check_negotiated_fee(&channel);
 
         Output::success(channel).exit();
     }
 }
 
+/// Report a client created ahead of the connection and channel handshakes.
+fn report_client<DstChain: ChainHandle, SrcChain: ChainHandle>(
+    client: &ForeignClient<DstChain, SrcChain>,
+) {
+    Output::progress_msg(format!(
+        "created client {} on chain {} tracking chain {}",
+        client.id(),
+        client.dst_chain().id(),
+        client.src_chain().id()
+    ))
+    .print();
+}
+
+/// Report the version settled on at each end of the channel, along with the
+/// version which was proposed for it if the application modified it.
+fn report_negotiated_versions<ChainA: ChainHandle, ChainB: ChainHandle>(
+    channel: &Channel<ChainA, ChainB>,
+) {
+    let sides = [
+        (
+            channel.src_chain().id(),
+            channel.a_side.version(),
+            channel.a_side.proposed_version(),
+        ),
+        (
+            channel.dst_chain().id(),
+            channel.b_side.version(),
+            channel.b_side.proposed_version(),
+        ),
+    ];
+
+    for (chain_id, version, proposed_version) in sides {
+        let version = version.cloned().unwrap_or_default();
+
+        match proposed_version {
+            Some(proposed_version) => info!(
+                "chain {} settled on channel version '{}' instead of the proposed '{}'",
+                chain_id, version, proposed_version
+            ),
+            None => info!(
+                "chain {} settled on channel version '{}'",
+                chain_id, version
+            ),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -291,6 +457,7 @@ mod tests {
                 port_b: PortId::from_str("port_id_b").unwrap(),
                 order: Order::Unordered,
                 version: None,
+                with_fee: false,
                 new_client_connection: false,
                 yes: false
             },
@@ -319,6 +486,7 @@ mod tests {
                 port_b: PortId::from_str("port_id_b").unwrap(),
                 order: Order::Unordered,
                 version: Some(Version::new("v1".to_owned())),
+                with_fee: false,
                 new_client_connection: false,
                 yes: false
             },
@@ -349,6 +517,7 @@ mod tests {
                 port_b: PortId::from_str("port_id_b").unwrap(),
                 order: Order::Ordered,
                 version: None,
+                with_fee: false,
                 new_client_connection: false,
                 yes: false
             },
@@ -379,6 +548,7 @@ mod tests {
                 port_b: PortId::from_str("port_id_b").unwrap(),
                 order: Order::Unordered,
                 version: None,
+                with_fee: false,
                 new_client_connection: false,
                 yes: false
             },
@@ -465,6 +635,7 @@ mod tests {
                 port_b: PortId::from_str("port_id_b").unwrap(),
                 order: Order::Unordered,
                 version: None,
+                with_fee: false,
                 new_client_connection: true,
                 yes: false
             },
@@ -494,6 +665,7 @@ mod tests {
                 port_b: PortId::from_str("port_id_b").unwrap(),
                 order: Order::Unordered,
                 version: None,
+                with_fee: false,
                 new_client_connection: true,
                 yes: true
             },
@@ -524,6 +696,7 @@ mod tests {
                 port_b: PortId::from_str("port_id_b").unwrap(),
                 order: Order::Unordered,
                 version: None,
+                with_fee: false,
                 new_client_connection: true,
                 yes: false
             },
@@ -590,6 +763,52 @@ mod tests {
         .is_err())
     }
 
+    #[test]
+    fn test_create_channel_with_fee() {
+        let cmd = CreateChannelCommand::parse_from([
+            "test",
+            "--a-chain",
+            "chain_a",
+            "--a-connection",
+            "connection_a",
+            "--a-port",
+            "transfer",
+            "--b-port",
+            "transfer",
+            "--with-fee",
+        ]);
+
+        assert!(cmd.with_fee);
+        assert_eq!(cmd.channel_version(), Ok(Some(Version::ics20_with_fee())));
+    }
+
+    #[test]
+    fn test_create_channel_with_fee_custom_port() {
+        let mut cmd = CreateChannelCommand::parse_from([
+            "test",
+            "--a-chain",
+            "chain_a",
+            "--a-connection",
+            "connection_a",
+            "--a-port",
+            "port_id_a",
+            "--b-port",
+            "port_id_b",
+            "--with-fee",
+        ]);
+
+        // No default version to wrap for a custom port
+        assert!(cmd.channel_version().is_err());
+
+        cmd.version = Some(Version::new("v1".to_owned()));
+        assert_eq!(
+            cmd.channel_version(),
+            Ok(Some(Version::new(
+                r#"{"fee_version":"ics29-1","app_version":"v1"}"#.to_owned()
+            )))
+        );
+    }
+
     #[test]
     fn test_create_channel_no_b_chain_nor_a_conn() {
         assert!(CreateChannelCommand::try_parse_from([
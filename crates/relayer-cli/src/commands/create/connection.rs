@@ -11,7 +11,7 @@ use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 
 use crate::cli_utils::{spawn_chain_runtime, ChainHandlePair};
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::conclude::{exit_with_error, exit_with_unrecoverable_error, ErrorCode, Output};
 use crate::prelude::*;
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
@@ -85,7 +85,7 @@ impl CreateConnectionCommand {
         let config = app_config();
 
         let chains = ChainHandlePair::spawn(&config, &self.chain_a_id, chain_b_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_error);
 
         info!(
             "Creating new clients hosted on chains {} and {}",
@@ -93,15 +93,17 @@ impl CreateConnectionCommand {
         );
 
         let client_a = ForeignClient::new(chains.src.clone(), chains.dst.clone())
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
         let client_b = ForeignClient::new(chains.dst.clone(), chains.src)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
 
         // Finally, execute the connection handshake.
         let delay = Duration::from_secs(self.delay);
         match Connection::new(client_a, client_b, delay) {
             Ok(conn) => Output::success(conn).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Tx)
+                .exit(),
         }
     }
 
@@ -112,7 +114,7 @@ impl CreateConnectionCommand {
         // Validate & spawn runtime for chain_a.
         let chain_a = match spawn_chain_runtime(&config, &self.chain_a_id) {
             Ok(handle) => handle,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         };
 
         // Unwrap the identifier of the client on chain_a.
@@ -121,6 +123,7 @@ impl CreateConnectionCommand {
             None => Output::error(
                 "Option `--a-client` is necessary when <B_CHAIN_ID> is missing".to_string(),
             )
+            .with_code(ErrorCode::User)
             .exit(),
         };
 
@@ -137,13 +140,14 @@ impl CreateConnectionCommand {
                 "failed while querying client '{}' on chain '{}' with error: {}",
                 client_a_id, self.chain_a_id, e
             ))
+            .with_code(ErrorCode::Connectivity)
             .exit(),
         };
 
         // Validate & spawn runtime for chain_b.
         let chain_b = match spawn_chain_runtime(&config, &chain_b_id) {
             Ok(handle) => handle,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         };
 
         // Unwrap the identifier of the client on chain_b.
@@ -152,6 +156,7 @@ impl CreateConnectionCommand {
             None => Output::error(
                 "Option `--b-client` is necessary when <B_CHAIN_ID> is missing".to_string(),
             )
+            .with_code(ErrorCode::User)
             .exit(),
         };
 
@@ -162,15 +167,17 @@ impl CreateConnectionCommand {
 
         // Get the two ForeignClient objects.
         let client_a = ForeignClient::find(chain_b.clone(), chain_a.clone(), client_a_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
         let client_b = ForeignClient::find(chain_a, chain_b, client_b_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
 
         // All verification passed. Create the Connection object & do the handshake.
         let delay = Duration::from_secs(self.delay);
         match Connection::new(client_a, client_b, delay) {
             Ok(conn) => Output::success(conn).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Tx)
+                .exit(),
         }
     }
 }
@@ -9,7 +9,7 @@ use ibc_relayer_types::signer::Signer;
 
 use crate::application::app_config;
 use crate::cli_utils::spawn_chain_runtime;
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::conclude::{exit_with_error, Output};
 use crate::error::Error;
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
@@ -60,7 +60,7 @@ impl Runnable for RegisterPayeeCmd {
             &self.port_id,
             &self.payee_address,
         )
-        .unwrap_or_else(exit_with_unrecoverable_error);
+        .unwrap_or_else(exit_with_error);
 
         Output::success_msg("Successfully registered payee").exit()
     }
@@ -20,7 +20,7 @@ use ibc_relayer_types::{
 
 use crate::{
     cli_utils::{check_can_send_on_channel, ChainHandlePair},
-    conclude::{exit_with_unrecoverable_error, Output},
+    conclude::{exit_with_error, exit_with_unrecoverable_error, ErrorCode, Output},
     prelude::*,
 };
 
@@ -207,14 +207,14 @@ impl Runnable for FeeTransferCmd {
         let config = app_config();
 
         let opts = match self.validate_options(&config) {
-            Err(err) => Output::error(err).exit(),
+            Err(err) => Output::error(err).with_code(ErrorCode::Config).exit(),
             Ok(result) => result,
         };
 
         let chains = ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_error);
 
-        fee_transfer(chains, opts).unwrap_or_else(exit_with_unrecoverable_error);
+        fee_transfer(chains, opts).unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
     }
 }
 
@@ -245,6 +245,9 @@ impl From<FeeTransferOptions> for TransferOptions {
             receiver: f.receiver,
             timeout_height_offset: f.timeout_height_offset,
             timeout_duration: f.timeout_duration,
+            timeout_height: None,
+            timeout_timestamp: None,
+            memo: None,
             number_msgs: f.number_msgs,
         }
     }
@@ -288,7 +291,9 @@ fn fee_transfer(chains: ChainHandlePair, opts: FeeTransferOptions) -> Result<(),
 
     match res {
         Ok(ev) => Output::success(ev).exit(),
-        Err(e) => Output::error(format!("{}", e)).exit(),
+        Err(e) => Output::error(format!("{}", e))
+            .with_code(ErrorCode::Tx)
+            .exit(),
     }
 }
 
@@ -5,23 +5,30 @@ use ibc_relayer::chain::endpoint::HealthCheck::*;
 use ibc_relayer::chain::handle::ChainHandle;
 
 use crate::cli_utils::spawn_chain_runtime;
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::conclude::{exit_with_error, ErrorCode, Output};
 use crate::prelude::*;
 
 #[derive(Clone, Command, Debug, Parser)]
-pub struct HealthCheckCmd {}
+pub struct HealthCheckCmd {
+    #[clap(
+        long = "allow-incompatible-versions",
+        help = "Only warn about the chains running unsupported versions of their modules, instead of failing"
+    )]
+    allow_incompatible_versions: bool,
+}
 
 impl Runnable for HealthCheckCmd {
     fn run(&self) {
         let config = (*app_config()).clone();
 
+        let mut incompatible_chains = vec![];
+
         for ch in &config.chains {
             let _span = tracing::error_span!("health_check", chain = %ch.id).entered();
 
             info!("performing health check...");
 
-            let chain =
-                spawn_chain_runtime(&config, &ch.id).unwrap_or_else(exit_with_unrecoverable_error);
+            let chain = spawn_chain_runtime(&config, &ch.id).unwrap_or_else(exit_with_error);
 
             match chain.health_check() {
                 Ok(Healthy) => info!("chain is healthy"),
@@ -30,10 +37,24 @@ impl Runnable for HealthCheckCmd {
                     // TODO(romac): Move the printing code here and in the supervisor/registry
                     warn!("chain is not healthy")
                 }
+                Ok(Incompatible(_)) => {
+                    warn!("chain runs unsupported versions");
+                    incompatible_chains.push(ch.id.to_string());
+                }
                 Err(e) => error!("failed to perform health check, reason: {}", e.detail()),
             }
         }
 
+        if !incompatible_chains.is_empty() && !self.allow_incompatible_versions {
+            Output::error(format!(
+                "chains {} run unsupported versions of their modules, \
+                 pass `--allow-incompatible-versions` to relay on them anyway",
+                incompatible_chains.join(", ")
+            ))
+            .with_code(ErrorCode::Config)
+            .exit()
+        }
+
         Output::success_msg("performed health check for all chains in the config").exit()
     }
 }
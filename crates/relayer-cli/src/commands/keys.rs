@@ -3,9 +3,12 @@ use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 
 mod add;
+mod add_multisig;
 mod balance;
+mod chains;
 mod delete;
 mod list;
+mod rename;
 
 /// `keys` subcommand
 #[derive(Command, Debug, Parser, Runnable)]
@@ -13,9 +16,15 @@ pub enum KeysCmd {
     /// Adds key to a configured chain or restores a key to a configured chain using a mnemonic
     Add(add::KeysAddCmd),
 
-    /// Delete key(s) from a configured chain
+    /// Adds a multisig key to a configured chain, from the public keys of the members of the multisig account
+    AddMultisig(add_multisig::KeysAddMultisigCmd),
+
+    /// Delete key(s) from one or more configured chains
     Delete(delete::KeysDeleteCmd),
 
+    /// Rename a key on one or more configured chains
+    Rename(rename::KeysRenameCmd),
+
     /// List keys configured on a chain
     List(list::KeysListCmd),
 
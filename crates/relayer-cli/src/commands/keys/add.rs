@@ -14,9 +14,10 @@ use ibc_relayer::{
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tracing::warn;
+use zeroize::Zeroizing;
 
 use crate::application::app_config;
-use crate::conclude::Output;
+use crate::conclude::{ErrorCode, Output};
 
 /// The data structure that represents the arguments when invoking the `keys add` CLI command.
 ///
@@ -82,6 +83,20 @@ pub struct KeysAddCmd {
     )]
     hd_path: String,
 
+    #[clap(
+        long = "account",
+        value_name = "ACCOUNT",
+        help = "Account number to derive the key at, overriding the one of the derivation path"
+    )]
+    account: Option<u32>,
+
+    #[clap(
+        long = "address-index",
+        value_name = "ADDRESS_INDEX",
+        help = "Address index to derive the key at, overriding the one of the derivation path"
+    )]
+    address_index: Option<u32>,
+
     #[clap(
         long = "overwrite",
         help = "Overwrite the key if there is already one with the same key name"
@@ -95,14 +110,24 @@ impl KeysAddCmd {
             .find_chain(&self.chain_id)
             .ok_or_else(|| eyre!("chain '{}' not found in configuration file", self.chain_id))?;
 
-        let name = self
-            .key_name
-            .clone()
-            .unwrap_or_else(|| chain_config.key_name.clone());
-
         let hd_path = HDPath::from_str(&self.hd_path)
             .map_err(|_| eyre!("invalid derivation path: {}", self.hd_path))?;
 
+        let hd_path = with_indexes(&hd_path, self.account, self.address_index)?;
+
+        // Keys derived at a given account or address index are named after it by default,
+        // so that several keys derived from the same mnemonic can be told apart.
+        let name = match &self.key_name {
+            Some(name) => name.clone(),
+            None if self.account.is_some() || self.address_index.is_some() => format!(
+                "{}-{}-{}",
+                chain_config.key_name,
+                hd_path.account(),
+                hd_path.index()
+            ),
+            None => chain_config.key_name.clone(),
+        };
+
         Ok(KeysAddOptions {
             config: chain_config.clone(),
             name,
@@ -123,7 +148,7 @@ impl Runnable for KeysAddCmd {
         let config = app_config();
 
         let opts = match self.options(&config) {
-            Err(err) => Output::error(err).exit(),
+            Err(err) => Output::error(err).with_code(ErrorCode::Config).exit(),
             Ok(result) => result,
         };
 
@@ -147,6 +172,7 @@ impl Runnable for KeysAddCmd {
                         "An error occurred adding the key on chain {} from file {:?}: {}",
                         self.chain_id, key_file, e
                     ))
+                    .with_code(ErrorCode::User)
                     .exit(),
                 }
             }
@@ -169,6 +195,7 @@ impl Runnable for KeysAddCmd {
                         "An error occurred restoring the key on chain {} from file {:?}: {}",
                         self.chain_id, mnemonic_file, e
                     ))
+                    .with_code(ErrorCode::User)
                     .exit(),
                 }
             }
@@ -178,6 +205,7 @@ impl Runnable for KeysAddCmd {
             _ => Output::error(
                 "--mnemonic-file and --key-file can't both be set or both None".to_string(),
             )
+            .with_code(ErrorCode::User)
             .exit(),
         }
     }
@@ -194,7 +222,9 @@ pub fn add_key(
 
     check_key_exists(&keyring, key_name, overwrite);
 
-    let key_contents = fs::read_to_string(file).map_err(|_| eyre!("error reading the key file"))?;
+    let key_contents = fs::read_to_string(file)
+        .map(Zeroizing::new)
+        .map_err(|_| eyre!("error reading the key file"))?;
     let key = keyring.key_from_seed_file(&key_contents, hd_path)?;
 
     keyring.add_key(key_name, key.clone())?;
@@ -208,8 +238,9 @@ pub fn restore_key(
     config: &ChainConfig,
     overwrite: bool,
 ) -> eyre::Result<KeyEntry> {
-    let mnemonic_content =
-        fs::read_to_string(mnemonic).map_err(|_| eyre!("error reading the mnemonic file"))?;
+    let mnemonic_content = fs::read_to_string(mnemonic)
+        .map(Zeroizing::new)
+        .map_err(|_| eyre!("error reading the mnemonic file"))?;
 
     let mut keyring = KeyRing::new(Store::Test, &config.account_prefix, &config.id)?;
 
@@ -221,6 +252,25 @@ pub fn restore_key(
     Ok(key_entry)
 }
 
+/// Replace the account and the address index of the given derivation path
+/// with the given ones, if any, keeping its purpose, coin type and change.
+fn with_indexes(
+    hd_path: &HDPath,
+    account: Option<u32>,
+    address_index: Option<u32>,
+) -> eyre::Result<HDPath> {
+    let path = format!(
+        "m/{}'/{}'/{}'/{}/{}",
+        hd_path.purpose().as_value().as_number(),
+        hd_path.coin_type(),
+        account.unwrap_or_else(|| hd_path.account()),
+        hd_path.change(),
+        address_index.unwrap_or_else(|| hd_path.index()),
+    );
+
+    HDPath::from_str(&path).map_err(|_| eyre!("invalid derivation path: {}", path))
+}
+
 /// Check if the key with the given key name already exists.
 /// If it already exists and overwrite is false, abort the command with an error.
 /// If overwrite is true, output a warning message informing the key will be overwritten.
@@ -229,7 +279,9 @@ fn check_key_exists(keyring: &KeyRing, key_name: &str, overwrite: bool) {
         if overwrite {
             warn!("key {} will be overwritten", key_name);
         } else {
-            Output::error(format!("A key with name '{}' already exists", key_name)).exit();
+            Output::error(format!("A key with name '{}' already exists", key_name))
+                .with_code(ErrorCode::User)
+                .exit();
         }
     }
 }
@@ -237,7 +289,9 @@ fn check_key_exists(keyring: &KeyRing, key_name: &str, overwrite: bool) {
 #[cfg(test)]
 mod tests {
 
-    use super::KeysAddCmd;
+    use super::{with_indexes, KeysAddCmd};
+    use core::str::FromStr;
+    use ibc_relayer::keyring::HDPath;
     use std::path::PathBuf;
 
     use abscissa_core::clap::Parser;
@@ -252,6 +306,8 @@ mod tests {
                 mnemonic_file: None,
                 key_name: None,
                 hd_path: "m/44'/118'/0'/0/0".to_string(),
+                account: None,
+                address_index: None,
                 overwrite: false,
             },
             KeysAddCmd::parse_from(["test", "--chain", "chain_id", "--key-file", "key_file"])
@@ -267,6 +323,8 @@ mod tests {
                 mnemonic_file: Some(PathBuf::from("mnemonic_file")),
                 key_name: None,
                 hd_path: "m/44'/118'/0'/0/0".to_string(),
+                account: None,
+                address_index: None,
                 overwrite: false
             },
             KeysAddCmd::parse_from([
@@ -288,6 +346,8 @@ mod tests {
                 mnemonic_file: None,
                 key_name: None,
                 hd_path: "m/44'/118'/0'/0/0".to_string(),
+                account: None,
+                address_index: None,
                 overwrite: true,
             },
             KeysAddCmd::parse_from([
@@ -310,6 +370,8 @@ mod tests {
                 mnemonic_file: Some(PathBuf::from("mnemonic_file")),
                 key_name: None,
                 hd_path: "m/44'/118'/0'/0/0".to_string(),
+                account: None,
+                address_index: None,
                 overwrite: true,
             },
             KeysAddCmd::parse_from([
@@ -323,6 +385,48 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_keys_add_mnemonic_file_indexes() {
+        assert_eq!(
+            KeysAddCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                key_file: None,
+                mnemonic_file: Some(PathBuf::from("mnemonic_file")),
+                key_name: None,
+                hd_path: "m/44'/118'/0'/0/0".to_string(),
+                account: Some(1),
+                address_index: Some(2),
+                overwrite: false,
+            },
+            KeysAddCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--mnemonic-file",
+                "mnemonic_file",
+                "--account",
+                "1",
+                "--address-index",
+                "2"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_with_indexes() {
+        let hd_path = HDPath::from_str("m/44'/60'/0'/0/0").unwrap();
+
+        assert_eq!(
+            with_indexes(&hd_path, Some(1), Some(2)).unwrap(),
+            HDPath::from_str("m/44'/60'/1'/0/2").unwrap()
+        );
+        assert_eq!(
+            with_indexes(&hd_path, None, Some(3)).unwrap(),
+            HDPath::from_str("m/44'/60'/0'/0/3").unwrap()
+        );
+        assert_eq!(with_indexes(&hd_path, None, None).unwrap(), hd_path);
+    }
+
     #[test]
     fn test_keys_add_no_file_nor_mnemonic() {
         assert!(KeysAddCmd::try_parse_from(["test", "--chain", "chain_id"]).is_err());
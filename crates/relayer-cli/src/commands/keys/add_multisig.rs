@@ -0,0 +1,201 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use eyre::eyre;
+use ibc_relayer::config::ChainConfig;
+use ibc_relayer::keyring::{EncodedPubKey, KeyRing, MultisigKey, Store};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::application::app_config;
+use crate::conclude::{ErrorCode, Output};
+
+/// Length of a compressed secp256k1 public key.
+const PUB_KEY_LEN: usize = 33;
+
+/// The data structure that represents the arguments when invoking the `keys add-multisig` CLI command.
+///
+/// `keys add-multisig --chain <CHAIN_ID> --key-name <KEY_NAME> --threshold <THRESHOLD> --pubkey <PUBKEY>...`
+///
+/// The public keys are those of the members of the multisig account, in the order
+/// in which they were given when creating the account. Only the public keys are
+/// stored, the multisig key can therefore only be used to build the transactions
+/// which the members of the account then sign, see `hermes tx multisig`.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct KeysAddMultisigCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "key-name",
+        required = true,
+        value_name = "KEY_NAME",
+        help_heading = "REQUIRED",
+        help = "Name of the multisig key"
+    )]
+    key_name: String,
+
+    #[clap(
+        long = "threshold",
+        required = true,
+        value_name = "THRESHOLD",
+        help_heading = "REQUIRED",
+        help = "Number of signatures required to sign a transaction"
+    )]
+    threshold: u32,
+
+    #[clap(
+        long = "pubkey",
+        required = true,
+        multiple_occurrences = true,
+        value_name = "PUBKEY",
+        help_heading = "REQUIRED",
+        help = "Public key of a member of the multisig account, either Bech32 or JSON encoded as output by `<chain-binary> keys show --pubkey`. Must be given once per member, in the order used to create the account"
+    )]
+    pubkeys: Vec<String>,
+
+    #[clap(
+        long = "overwrite",
+        help = "Overwrite the key if there is already one with the same key name"
+    )]
+    overwrite: bool,
+}
+
+impl Runnable for KeysAddMultisigCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chain_config = match config.find_chain(&self.chain_id) {
+            Some(chain_config) => chain_config,
+            None => Output::error(format!(
+                "chain '{}' not found in configuration file",
+                self.chain_id
+            ))
+            .with_code(ErrorCode::Config)
+            .exit(),
+        };
+
+        match add_multisig_key(
+            chain_config,
+            &self.key_name,
+            self.threshold,
+            &self.pubkeys,
+            self.overwrite,
+        ) {
+            Ok(key) => Output::success_msg(format!(
+                "Added {}-of-{} multisig key '{}' ({}) on chain {}",
+                key.threshold,
+                key.public_keys.len(),
+                self.key_name,
+                key.account,
+                self.chain_id
+            ))
+            .exit(),
+            Err(e) => Output::error(format!(
+                "An error occurred adding the multisig key on chain {}: {}",
+                self.chain_id, e
+            ))
+            .with_code(ErrorCode::User)
+            .exit(),
+        }
+    }
+}
+
+pub fn add_multisig_key(
+    config: &ChainConfig,
+    key_name: &str,
+    threshold: u32,
+    pubkeys: &[String],
+    overwrite: bool,
+) -> eyre::Result<MultisigKey> {
+    let mut keyring = KeyRing::new(Store::Test, &config.account_prefix, &config.id)?;
+
+    if keyring.get_multisig_key(key_name).is_ok() && !overwrite {
+        return Err(eyre!(
+            "a multisig key with name '{}' already exists, use --overwrite to replace it",
+            key_name
+        ));
+    }
+
+    let public_keys = pubkeys
+        .iter()
+        .map(|pubkey| decode_pubkey(pubkey))
+        .collect::<eyre::Result<Vec<_>>>()?;
+
+    let key = MultisigKey::new(threshold, public_keys, &config.account_prefix)?;
+
+    keyring.add_multisig_key(key_name, key.clone())?;
+
+    Ok(key)
+}
+
+/// Decode a public key into its compressed secp256k1 bytes.
+fn decode_pubkey(pubkey: &str) -> eyre::Result<Vec<u8>> {
+    let encoded: EncodedPubKey = pubkey
+        .parse()
+        .map_err(|e| eyre!("invalid public key '{}': {}", pubkey, e))?;
+
+    let mut bytes = encoded.into_bytes();
+
+    // Bech32-encoded public keys are prefixed with their Amino type prefix
+    if bytes.len() > PUB_KEY_LEN {
+        bytes = bytes.split_off(bytes.len() - PUB_KEY_LEN);
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeysAddMultisigCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_keys_add_multisig() {
+        assert_eq!(
+            KeysAddMultisigCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                key_name: "multisig".to_owned(),
+                threshold: 2,
+                pubkeys: vec!["pk_a".to_owned(), "pk_b".to_owned(), "pk_c".to_owned()],
+                overwrite: false,
+            },
+            KeysAddMultisigCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--key-name",
+                "multisig",
+                "--threshold",
+                "2",
+                "--pubkey",
+                "pk_a",
+                "--pubkey",
+                "pk_b",
+                "--pubkey",
+                "pk_c"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_keys_add_multisig_no_pubkey() {
+        assert!(KeysAddMultisigCmd::try_parse_from([
+            "test",
+            "--chain",
+            "chain_id",
+            "--key-name",
+            "multisig",
+            "--threshold",
+            "2"
+        ])
+        .is_err())
+    }
+}
@@ -8,7 +8,7 @@ use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
 use crate::application::app_config;
 use crate::cli_utils::spawn_chain_runtime;
-use crate::conclude::{exit_with_unrecoverable_error, json, Output};
+use crate::conclude::{exit_with_error, exit_with_unrecoverable_error, json, ErrorCode, Output};
 
 /// The data structure that represents the arguments when invoking the `keys balance` CLI command.
 ///
@@ -55,8 +55,7 @@ impl Runnable for KeyBalanceCmd {
     fn run(&self) {
         let config = app_config();
 
-        let chain = spawn_chain_runtime(&config, &self.chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
 
         let key_name = self.key_name.clone();
 
@@ -74,7 +73,9 @@ fn get_balance(chain: impl ChainHandle, key_name: Option<String>, denom: Option<
         Ok(balance) => {
             // Retrieve the key name string to output.
             let key_name = key_name.unwrap_or_else(|| {
-                let chain_config = chain.config().unwrap_or_else(exit_with_unrecoverable_error);
+                let chain_config = chain
+                    .config()
+                    .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Connectivity));
                 chain_config.key_name
             });
 
@@ -84,7 +85,9 @@ fn get_balance(chain: impl ChainHandle, key_name: Option<String>, denom: Option<
             ))
             .exit()
         }
-        Err(e) => Output::error(format!("there was a problem querying the balance: {}", e)).exit(),
+        Err(e) => Output::error(format!("there was a problem querying the balance: {}", e))
+            .with_code(ErrorCode::Connectivity)
+            .exit(),
     }
 }
 
@@ -94,19 +97,23 @@ fn get_balances(chain: impl ChainHandle, key_name: Option<String>) {
         Ok(balances) => {
             // Retrieve the key name string to output.
             let key_name = key_name.unwrap_or_else(|| {
-                let chain_config = chain.config().unwrap_or_else(exit_with_unrecoverable_error);
+                let chain_config = chain
+                    .config()
+                    .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Connectivity));
                 chain_config.key_name
             });
 
             let mut pretty_output = format!("Balances for key `{}`:", key_name);
             for balance in balances {
                 write!(pretty_output, "\n\t{} {}", balance.amount, balance.denom)
-                    .unwrap_or_else(exit_with_unrecoverable_error);
+                    .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Unknown));
             }
 
             Output::success_msg(pretty_output).exit()
         }
-        Err(e) => Output::error(format!("there was a problem querying the balance: {}", e)).exit(),
+        Err(e) => Output::error(format!("there was a problem querying the balance: {}", e))
+            .with_code(ErrorCode::Connectivity)
+            .exit(),
     }
 }
 
@@ -0,0 +1,68 @@
+//! Helpers for the `keys` subcommands which operate on several chains at once.
+
+use console::style;
+use dialoguer::Confirm;
+use eyre::eyre;
+
+use ibc_relayer::config::filter::Wildcard;
+use ibc_relayer::config::{ChainConfig, Config};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+/// The configurations of the chains targeted by a command, ie. either the chain
+/// given with `--chain`, or all the chains matching any of the patterns given
+/// with `--chains`, in the order in which they appear in the configuration file.
+pub fn select_chains(
+    config: &Config,
+    chain_id: Option<&ChainId>,
+    patterns: &[Wildcard],
+) -> eyre::Result<Vec<ChainConfig>> {
+    if let Some(chain_id) = chain_id {
+        let chain_config = config
+            .find_chain(chain_id)
+            .ok_or_else(|| eyre!("chain '{}' not found in configuration file", chain_id))?;
+
+        return Ok(vec![chain_config.clone()]);
+    }
+
+    let chains: Vec<ChainConfig> = config
+        .chains
+        .iter()
+        .filter(|chain| {
+            patterns
+                .iter()
+                .any(|pattern| pattern.is_match(chain.id.as_str()))
+        })
+        .cloned()
+        .collect();
+
+    if chains.is_empty() {
+        let patterns: Vec<String> = patterns.iter().map(ToString::to_string).collect();
+
+        return Err(eyre!(
+            "no chain in configuration file matches '{}'",
+            patterns.join(",")
+        ));
+    }
+
+    Ok(chains)
+}
+
+/// Ask the user to confirm the given operation on the given chains,
+/// unless `yes` is set, in which case the operation is confirmed.
+pub fn confirm(operation: &str, chains: &[ChainConfig], yes: bool) -> eyre::Result<bool> {
+    if yes {
+        return Ok(true);
+    }
+
+    let chain_ids: Vec<&str> = chains.iter().map(|chain| chain.id.as_str()).collect();
+
+    Confirm::new()
+        .with_prompt(format!(
+            "{}: {} on chain(s) {}?",
+            style("WARN").yellow(),
+            operation,
+            chain_ids.join(", ")
+        ))
+        .interact()
+        .map_err(|e| eyre!("an error occurred while waiting for user input: {}", e))
+}
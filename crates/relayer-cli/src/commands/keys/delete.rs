@@ -1,31 +1,47 @@
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 
-use eyre::eyre;
 use ibc_relayer::{
-    config::{ChainConfig, Config},
+    config::{filter::Wildcard, ChainConfig, Config},
     keyring::{KeyRing, Store},
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
+use super::chains::{confirm, select_chains};
 use crate::application::app_config;
-use crate::conclude::Output;
+use crate::conclude::{ErrorCode, Output};
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
 #[clap(
     override_usage = "hermes keys delete --chain <CHAIN_ID> --key-name <KEY_NAME>
 
-    hermes keys delete --chain <CHAIN_ID> --all"
+    hermes keys delete --chain <CHAIN_ID> --all
+
+    hermes keys delete --chains <CHAIN_ID_PATTERNS> --key-name <KEY_NAME> [--yes]
+
+    hermes keys delete --chains <CHAIN_ID_PATTERNS> --all [--yes]"
 )]
 pub struct KeysDeleteCmd {
     #[clap(
         long = "chain",
         required = true,
         value_name = "CHAIN_ID",
+        group = "chain_selection",
         help_heading = "FLAGS",
         help = "Identifier of the chain"
     )]
-    chain_id: ChainId,
+    chain_id: Option<ChainId>,
+
+    #[clap(
+        long = "chains",
+        required = true,
+        value_name = "CHAIN_ID_PATTERNS",
+        value_delimiter = ',',
+        group = "chain_selection",
+        help_heading = "FLAGS",
+        help = "Comma-separated patterns matching the identifiers of the chains, eg. 'osmosis*,cosmoshub*'"
+    )]
+    chains: Vec<Wildcard>,
 
     #[clap(
         long = "key-name",
@@ -45,13 +61,18 @@ pub struct KeysDeleteCmd {
         help = "Delete all keys"
     )]
     all: bool,
+
+    #[clap(
+        long = "yes",
+        help_heading = "FLAGS",
+        help = "Skip the confirmation prompt when deleting all keys or deleting keys on several chains"
+    )]
+    yes: bool,
 }
 
 impl KeysDeleteCmd {
     fn options(&self, config: &Config) -> eyre::Result<KeysDeleteOptions<'_>> {
-        let chain_config = config
-            .find_chain(&self.chain_id)
-            .ok_or_else(|| eyre!("chain '{}' not found in configuration file", self.chain_id))?;
+        let chain_configs = select_chains(config, self.chain_id.as_ref(), &self.chains)?;
 
         let id = match (self.all, &self.key_name) {
             (true, None) => KeysDeleteId::All,
@@ -60,20 +81,27 @@ impl KeysDeleteCmd {
             // The 'required' parameter for the flags will trigger an error if both flags have not been given.
             // And the 'group' parameter for the flags will trigger an error if both flags are given.
             _ => Output::error("--key-name and --all can't both be set or both None".to_string())
+                .with_code(ErrorCode::User)
                 .exit(),
         };
 
         Ok(KeysDeleteOptions {
-            config: chain_config.clone(),
+            configs: chain_configs,
             id,
         })
     }
+
+    /// Whether the deletion must be confirmed, ie. if all keys
+    /// are deleted or if keys are deleted on several chains.
+    fn needs_confirmation(&self) -> bool {
+        self.all || self.chain_id.is_none()
+    }
 }
 
 #[derive(Clone, Debug)]
 struct KeysDeleteOptions<'a> {
     id: KeysDeleteId<'a>,
-    config: ChainConfig,
+    configs: Vec<ChainConfig>,
 }
 
 #[derive(Clone, Debug)]
@@ -87,27 +115,44 @@ impl Runnable for KeysDeleteCmd {
         let config = app_config();
 
         let opts = match self.options(&config) {
-            Err(err) => Output::error(err).exit(),
+            Err(err) => Output::error(err).with_code(ErrorCode::Config).exit(),
             Ok(result) => result,
         };
 
-        match opts.id {
-            KeysDeleteId::All => match delete_all_keys(&opts.config) {
-                Ok(_) => {
-                    Output::success_msg(format!("Removed all keys on chain {}", opts.config.id))
-                        .exit()
-                }
-                Err(e) => Output::error(format!("{}", e)).exit(),
-            },
-            KeysDeleteId::Named(key_name) => match delete_key(&opts.config, key_name) {
-                Ok(_) => Output::success_msg(format!(
-                    "Removed key ({}) on chain {}",
-                    key_name, opts.config.id
-                ))
-                .exit(),
-                Err(e) => Output::error(format!("{}", e)).exit(),
-            },
-        };
+        if self.needs_confirmation() {
+            let operation = match opts.id {
+                KeysDeleteId::All => "Delete all keys".to_string(),
+                KeysDeleteId::Named(key_name) => format!("Delete key ({})", key_name),
+            };
+
+            match confirm(&operation, &opts.configs, self.yes) {
+                Ok(true) => {}
+                Ok(false) => Output::error("You elected not to delete any key".to_string())
+                    .with_code(ErrorCode::User)
+                    .exit(),
+                Err(e) => Output::error(e).with_code(ErrorCode::Unknown).exit(),
+            }
+        }
+
+        let mut messages = Vec::new();
+
+        for chain_config in opts.configs.iter() {
+            let result = match opts.id {
+                KeysDeleteId::All => delete_all_keys(chain_config)
+                    .map(|_| format!("Removed all keys on chain {}", chain_config.id)),
+                KeysDeleteId::Named(key_name) => delete_key(chain_config, key_name)
+                    .map(|_| format!("Removed key ({}) on chain {}", key_name, chain_config.id)),
+            };
+
+            match result {
+                Ok(message) => messages.push(message),
+                Err(e) => Output::error(format!("{}", e))
+                    .with_code(ErrorCode::User)
+                    .exit(),
+            }
+        }
+
+        Output::success_msg(messages.join("\n")).exit()
     }
 }
 
@@ -137,9 +182,11 @@ mod tests {
     fn test_keys_delete_key_name() {
         assert_eq!(
             KeysDeleteCmd {
-                chain_id: ChainId::from_string("chain_id"),
+                chain_id: Some(ChainId::from_string("chain_id")),
+                chains: vec![],
                 key_name: Some("to_delete".to_owned()),
-                all: false
+                all: false,
+                yes: false,
             },
             KeysDeleteCmd::parse_from(["test", "--chain", "chain_id", "--key-name", "to_delete"])
         )
@@ -149,9 +196,11 @@ mod tests {
     fn test_keys_delete_all() {
         assert_eq!(
             KeysDeleteCmd {
-                chain_id: ChainId::from_string("chain_id"),
+                chain_id: Some(ChainId::from_string("chain_id")),
+                chains: vec![],
                 key_name: None,
-                all: true
+                all: true,
+                yes: false,
             },
             KeysDeleteCmd::parse_from(["test", "--chain", "chain_id", "--all"])
         )
@@ -175,6 +224,34 @@ mod tests {
         .is_err())
     }
 
+    #[test]
+    fn test_keys_delete_chains() {
+        assert_eq!(
+            KeysDeleteCmd {
+                chain_id: None,
+                chains: vec!["osmosis*".parse().unwrap(), "cosmoshub*".parse().unwrap()],
+                key_name: None,
+                all: true,
+                yes: true,
+            },
+            KeysDeleteCmd::parse_from([
+                "test",
+                "--chains",
+                "osmosis*,cosmoshub*",
+                "--all",
+                "--yes"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_keys_delete_chain_or_chains() {
+        assert!(KeysDeleteCmd::try_parse_from([
+            "test", "--chain", "chain_id", "--chains", "chain*", "--all"
+        ])
+        .is_err())
+    }
+
     #[test]
     fn test_keys_delete_no_chain() {
         assert!(KeysDeleteCmd::try_parse_from(["test", "--all"]).is_err())
@@ -4,7 +4,7 @@ use core::fmt::Write;
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 
-use crate::conclude::Output;
+use crate::conclude::{ErrorCode, Output};
 use crate::{application::app_config, conclude::json};
 use ibc_relayer::{
     config::{ChainConfig, Config},
@@ -41,7 +41,7 @@ impl Runnable for KeysListCmd {
         let config = app_config();
 
         let opts = match self.options(&config) {
-            Err(err) => Output::error(err).exit(),
+            Err(err) => Output::error(err).with_code(ErrorCode::Config).exit(),
             Ok(result) => result,
         };
 
@@ -57,7 +57,7 @@ impl Runnable for KeysListCmd {
                 }
                 Output::success_msg(msg).exit()
             }
-            Err(e) => Output::error(e).exit(),
+            Err(e) => Output::error(e).with_code(ErrorCode::User).exit(),
         }
     }
 }
@@ -0,0 +1,208 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use eyre::eyre;
+use ibc_relayer::{
+    config::{filter::Wildcard, ChainConfig, Config},
+    keyring::{KeyRing, Store},
+};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use super::chains::{confirm, select_chains};
+use crate::application::app_config;
+use crate::conclude::{ErrorCode, Output};
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+#[clap(
+    override_usage = "hermes keys rename --chain <CHAIN_ID> --key-name <KEY_NAME> --new-name <NEW_KEY_NAME>
+
+    hermes keys rename --chains <CHAIN_ID_PATTERNS> --key-name <KEY_NAME> --new-name <NEW_KEY_NAME> [--yes]"
+)]
+pub struct KeysRenameCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        group = "chain_selection",
+        help_heading = "FLAGS",
+        help = "Identifier of the chain"
+    )]
+    chain_id: Option<ChainId>,
+
+    #[clap(
+        long = "chains",
+        required = true,
+        value_name = "CHAIN_ID_PATTERNS",
+        value_delimiter = ',',
+        group = "chain_selection",
+        help_heading = "FLAGS",
+        help = "Comma-separated patterns matching the identifiers of the chains, eg. 'osmosis*,cosmoshub*'"
+    )]
+    chains: Vec<Wildcard>,
+
+    #[clap(
+        long = "key-name",
+        required = true,
+        value_name = "KEY_NAME",
+        help_heading = "REQUIRED",
+        help = "Name of the key to rename"
+    )]
+    key_name: String,
+
+    #[clap(
+        long = "new-name",
+        required = true,
+        value_name = "NEW_KEY_NAME",
+        help_heading = "REQUIRED",
+        help = "New name of the key"
+    )]
+    new_name: String,
+
+    #[clap(
+        long = "yes",
+        help_heading = "FLAGS",
+        help = "Skip the confirmation prompt when renaming keys on several chains"
+    )]
+    yes: bool,
+}
+
+impl KeysRenameCmd {
+    fn options(&self, config: &Config) -> eyre::Result<Vec<ChainConfig>> {
+        if self.key_name == self.new_name {
+            return Err(eyre!(
+                "the new name of the key must differ from its current name '{}'",
+                self.key_name
+            ));
+        }
+
+        select_chains(config, self.chain_id.as_ref(), &self.chains)
+    }
+}
+
+impl Runnable for KeysRenameCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chain_configs = match self.options(&config) {
+            Err(err) => Output::error(err).with_code(ErrorCode::Config).exit(),
+            Ok(result) => result,
+        };
+
+        // Renaming a key on a single, explicitly given chain does not need to be confirmed
+        if self.chain_id.is_none() {
+            let operation = format!("Rename key ({}) to ({})", self.key_name, self.new_name);
+
+            match confirm(&operation, &chain_configs, self.yes) {
+                Ok(true) => {}
+                Ok(false) => Output::error("You elected not to rename any key".to_string())
+                    .with_code(ErrorCode::User)
+                    .exit(),
+                Err(e) => Output::error(e).with_code(ErrorCode::Unknown).exit(),
+            }
+        }
+
+        let mut messages = Vec::new();
+
+        for chain_config in chain_configs.iter() {
+            match rename_key(chain_config, &self.key_name, &self.new_name) {
+                Ok(()) => messages.push(format!(
+                    "Renamed key ({}) to ({}) on chain {}",
+                    self.key_name, self.new_name, chain_config.id
+                )),
+                Err(e) => Output::error(format!("{}", e))
+                    .with_code(ErrorCode::User)
+                    .exit(),
+            }
+        }
+
+        Output::success_msg(messages.join("\n")).exit()
+    }
+}
+
+/// Rename the key with the given name, failing if a key already exists with the new name.
+pub fn rename_key(config: &ChainConfig, key_name: &str, new_name: &str) -> eyre::Result<()> {
+    let mut keyring = KeyRing::new(Store::Test, &config.account_prefix, &config.id)?;
+
+    if keyring.get_key(new_name).is_ok() {
+        return Err(eyre!(
+            "a key named '{}' already exists on chain {}",
+            new_name,
+            config.id
+        ));
+    }
+
+    let key_entry = keyring.get_key(key_name)?;
+    keyring.add_key(new_name, key_entry)?;
+    keyring.remove_key(key_name)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeysRenameCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_keys_rename() {
+        assert_eq!(
+            KeysRenameCmd {
+                chain_id: Some(ChainId::from_string("chain_id")),
+                chains: vec![],
+                key_name: "old".to_owned(),
+                new_name: "new".to_owned(),
+                yes: false,
+            },
+            KeysRenameCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--key-name",
+                "old",
+                "--new-name",
+                "new"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_keys_rename_chains() {
+        assert_eq!(
+            KeysRenameCmd {
+                chain_id: None,
+                chains: vec!["osmosis*".parse().unwrap(), "cosmoshub*".parse().unwrap()],
+                key_name: "old".to_owned(),
+                new_name: "new".to_owned(),
+                yes: true,
+            },
+            KeysRenameCmd::parse_from([
+                "test",
+                "--chains",
+                "osmosis*,cosmoshub*",
+                "--key-name",
+                "old",
+                "--new-name",
+                "new",
+                "--yes"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_keys_rename_no_new_name() {
+        assert!(
+            KeysRenameCmd::try_parse_from(["test", "--chain", "chain_id", "--key-name", "old"])
+                .is_err()
+        )
+    }
+
+    #[test]
+    fn test_keys_rename_no_chain() {
+        assert!(
+            KeysRenameCmd::try_parse_from(["test", "--key-name", "old", "--new-name", "new"])
+                .is_err()
+        )
+    }
+}
@@ -17,7 +17,7 @@ use ibc_relayer_types::{core::ics24_host::identifier::ChainId, events::IbcEvent}
 use eyre::eyre;
 use ibc_relayer::{
     config::ChainConfig,
-    event::monitor::{EventMonitor, EventReceiver},
+    event::monitor::{BatchConfig, EventMonitor, EventReceiver},
 };
 
 use crate::prelude::*;
@@ -152,6 +152,7 @@ fn subscribe(
     let (mut event_monitor, rx, _) = EventMonitor::new(
         chain_config.id.clone(),
         chain_config.websocket_addr.clone(),
+        BatchConfig::from_chain_config(chain_config),
         rt,
     )
     .map_err(|e| eyre!("could not initialize event monitor: {}", e))?;
@@ -11,7 +11,7 @@ use ibc_relayer_types::events::IbcEvent;
 use std::ops::Deref;
 
 use crate::cli_utils::{spawn_chain_runtime, spawn_chain_runtime_generic};
-use crate::conclude::Output;
+use crate::conclude::{ErrorCode, Output};
 use crate::prelude::*;
 use eyre::eyre;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
@@ -44,7 +44,9 @@ impl Runnable for MisbehaviourCmd {
         let res = monitor_misbehaviour(&self.chain_id, &self.client_id, &config);
         match res {
             Ok(some_event) => Output::success(some_event).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
         }
     }
 }
@@ -16,9 +16,11 @@ mod client;
 mod clients;
 mod connection;
 mod connections;
+mod height;
 mod packet;
 mod transfer;
 mod tx;
+mod wasm;
 
 /// `query` subcommand
 #[derive(Command, Debug, Parser, Runnable)]
@@ -55,6 +57,10 @@ pub enum QueryCmd {
     /// Query information about token transfers
     #[clap(subcommand)]
     Transfer(transfer::TransferCmd),
+
+    /// Query information about CosmWasm contracts on wasmd-based chains
+    #[clap(subcommand)]
+    Wasm(wasm::WasmCmd),
 }
 
 #[derive(Command, Debug, Parser, Runnable)]
@@ -70,6 +76,9 @@ pub enum QueryClientCmds {
 
     /// Query the client connections
     Connections(client::QueryClientConnectionsCmd),
+
+    /// Query the parameters of a client, such as its trusting period and latest height
+    Parameters(client::QueryClientParametersCmd),
 }
 
 #[derive(Command, Debug, Parser, Runnable)]
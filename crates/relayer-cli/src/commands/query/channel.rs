@@ -2,15 +2,15 @@ use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 use ibc_relayer::chain::handle::ChainHandle;
 
-use ibc_relayer::chain::requests::{IncludeProof, QueryChannelRequest, QueryHeight};
+use ibc_relayer::chain::requests::{IncludeProof, QueryChannelRequest};
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 
 use crate::cli_utils::spawn_chain_runtime;
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::commands::query::height::{HeightArg, ResolvedHeight};
+use crate::conclude::{exit_with_error, exit_with_unrecoverable_error, ErrorCode, Output};
 use crate::prelude::*;
 use ibc_relayer_types::core::ics04_channel::channel::State;
-use ibc_relayer_types::Height;
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
 pub struct QueryChannelEndCmd {
@@ -45,28 +45,25 @@ pub struct QueryChannelEndCmd {
     #[clap(
         long = "height",
         value_name = "HEIGHT",
-        help = "Height of the state to query"
+        help = "Height of the state to query, or 'latest-consistent' to pin the latest height"
     )]
-    height: Option<u64>,
+    height: Option<HeightArg>,
 }
 
 impl Runnable for QueryChannelEndCmd {
     fn run(&self) {
         let config = app_config();
 
-        let chain = spawn_chain_runtime(&config, &self.chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
+
+        let height = ResolvedHeight::resolve(self.height, &chain)
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Connectivity));
 
         let res = chain.query_channel(
             QueryChannelRequest {
                 port_id: self.port_id.clone(),
                 channel_id: self.channel_id.clone(),
-                height: self.height.map_or(QueryHeight::Latest, |revision_height| {
-                    QueryHeight::Specific(
-                        Height::new(chain.id().version(), revision_height)
-                            .unwrap_or_else(exit_with_unrecoverable_error),
-                    )
-                }),
+                height: height.query_height(),
             },
             IncludeProof::No,
         );
@@ -77,12 +74,15 @@ impl Runnable for QueryChannelEndCmd {
                         "port '{}' & channel '{}' does not exist",
                         self.port_id, self.channel_id
                     ))
+                    .with_code(ErrorCode::User)
                     .exit()
                 } else {
-                    Output::success(channel_end).exit()
+                    height.success(channel_end).exit()
                 }
             }
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
         }
     }
 }
@@ -90,6 +90,7 @@ impl Runnable for QueryChannelEndCmd {
 #[cfg(test)]
 mod tests {
     use super::QueryChannelEndCmd;
+    use crate::commands::query::height::HeightArg;
 
     use std::str::FromStr;
 
@@ -145,7 +146,7 @@ mod tests {
                 chain_id: ChainId::from_string("chain_id"),
                 port_id: PortId::from_str("port_id").unwrap(),
                 channel_id: ChannelId::from_str("channel-07").unwrap(),
-                height: Some(42)
+                height: Some(HeightArg::Specific(42))
             },
             QueryChannelEndCmd::parse_from([
                 "test",
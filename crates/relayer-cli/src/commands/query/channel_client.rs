@@ -7,7 +7,7 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId
 
 use crate::application::app_config;
 use crate::cli_utils::spawn_chain_runtime;
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::conclude::{exit_with_error, ErrorCode, Output};
 
 /// The data structure that represents the arguments when invoking the `query channel client` CLI command.
 ///
@@ -49,15 +49,16 @@ impl Runnable for QueryChannelClientCmd {
     fn run(&self) {
         let config = app_config();
 
-        let chain = spawn_chain_runtime(&config, &self.chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
 
         match chain.query_channel_client_state(QueryChannelClientStateRequest {
             port_id: self.port_id.clone(),
             channel_id: self.channel_id.clone(),
         }) {
             Ok(cs) => Output::success(cs).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
         }
     }
 }
@@ -14,9 +14,9 @@ use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
 use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, State};
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc_relayer_types::Height;
 
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::commands::query::height::{HeightArg, ResolvedHeight};
+use crate::conclude::{ErrorCode, Output};
 use crate::prelude::*;
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
@@ -52,9 +52,9 @@ pub struct QueryChannelEndsCmd {
     #[clap(
         long = "height",
         value_name = "HEIGHT",
-        help = "Height of the state to query"
+        help = "Height of the state to query, or 'latest-consistent' to pin the latest height"
     )]
-    height: Option<u64>,
+    height: Option<HeightArg>,
 
     #[clap(
         long = "verbose",
@@ -101,11 +101,12 @@ fn do_run<Chain: ChainHandle>(cmd: &QueryChannelEndsCmd) -> eyre::Result<()> {
     let mut registry = <Registry<Chain>>::new((*config).clone());
     let chain = registry.get_or_spawn(chain_id)?;
 
-    let chain_height = match cmd.height {
-        Some(height) => {
-            Height::new(chain.id().version(), height).unwrap_or_else(exit_with_unrecoverable_error)
-        }
-        None => chain.query_latest_height()?,
+    let height = ResolvedHeight::resolve(cmd.height, &chain)?;
+
+    // All the queries on the chain are performed at the same height
+    let chain_height = match height.query_height() {
+        QueryHeight::Specific(chain_height) => chain_height,
+        QueryHeight::Latest => chain.query_latest_height()?,
     };
 
     let (channel_end, _) = chain.query_channel(
@@ -224,7 +225,7 @@ fn do_run<Chain: ChainHandle>(cmd: &QueryChannelEndsCmd) -> eyre::Result<()> {
             counterparty_client_state,
         };
 
-        Output::success(res).exit();
+        height.success(res).exit();
     } else {
         let res = ChannelEndsSummary {
             chain_id: chain_id.clone(),
@@ -240,7 +241,7 @@ fn do_run<Chain: ChainHandle>(cmd: &QueryChannelEndsCmd) -> eyre::Result<()> {
             counterparty_port_id,
         };
 
-        Output::success(res).exit();
+        height.success(res).exit();
     }
 }
 
@@ -248,7 +249,9 @@ impl Runnable for QueryChannelEndsCmd {
     fn run(&self) {
         match do_run::<BaseChainHandle>(self) {
             Ok(()) => {}
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
         }
     }
 }
@@ -259,6 +262,8 @@ mod tests {
 
     use std::str::FromStr;
 
+    use crate::commands::query::height::HeightArg;
+
     use abscissa_core::clap::Parser;
     use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 
@@ -313,7 +318,7 @@ mod tests {
                 chain_id: ChainId::from_string("chain_id"),
                 port_id: PortId::from_str("port_id").unwrap(),
                 channel_id: ChannelId::from_str("channel-07").unwrap(),
-                height: Some(42),
+                height: Some(HeightArg::Specific(42)),
                 verbose: false
             },
             QueryChannelEndsCmd::parse_from([
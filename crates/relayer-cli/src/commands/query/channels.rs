@@ -1,4 +1,5 @@
 use core::fmt::{Debug, Error, Formatter};
+use std::collections::HashMap;
 
 use abscissa_core::clap::Parser;
 use abscissa_core::Runnable;
@@ -18,7 +19,7 @@ use ibc_relayer_types::core::ics24_host::identifier::{
 };
 
 use crate::commands::query::channel_ends::ChannelEnds;
-use crate::conclude::Output;
+use crate::conclude::{ErrorCode, Output};
 use crate::prelude::*;
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
@@ -35,7 +36,7 @@ pub struct QueryChannelsCmd {
     #[clap(
         long = "counterparty-chain",
         value_name = "COUNTERPARTY_CHAIN_ID",
-        help = "Filter the query response by the this counterparty chain"
+        help = "Filter the query response by the counterparty chain"
     )]
     dst_chain_id: Option<ChainId>,
 
@@ -72,6 +73,10 @@ fn run_query_channels<Chain: ChainHandle>(
         pagination: Some(PageRequest::all()),
     })?;
 
+    // Several channels may share the same connection, so only resolve
+    // the counterparty chain of each connection once.
+    let mut counterparty_chain_ids: HashMap<ConnectionId, ChainId> = HashMap::new();
+
     for identified_channel in identified_channels {
         let port_id = identified_channel.port_id;
         let channel_id = identified_channel.channel_id;
@@ -107,23 +112,28 @@ fn run_query_channels<Chain: ChainHandle>(
         // If a counterparty chain is specified as a filter, check and skip the
         // channel if required.
         if cmd.show_counterparty || cmd.dst_chain_id.is_some() {
-            let (connection_end, _) = chain.query_connection(
-                QueryConnectionRequest {
-                    connection_id: connection_id.clone(),
-                    height: QueryHeight::Specific(chain_height),
-                },
-                IncludeProof::No,
-            )?;
-
-            let client_id = connection_end.client_id().clone();
-            let (client_state, _) = chain.query_client_state(
-                QueryClientStateRequest {
-                    client_id,
-                    height: QueryHeight::Specific(chain_height),
-                },
-                IncludeProof::No,
-            )?;
-            let cid = client_state.chain_id().clone();
+            if !counterparty_chain_ids.contains_key(&connection_id) {
+                let (connection_end, _) = chain.query_connection(
+                    QueryConnectionRequest {
+                        connection_id: connection_id.clone(),
+                        height: QueryHeight::Specific(chain_height),
+                    },
+                    IncludeProof::No,
+                )?;
+
+                let client_id = connection_end.client_id().clone();
+                let (client_state, _) = chain.query_client_state(
+                    QueryClientStateRequest {
+                        client_id,
+                        height: QueryHeight::Specific(chain_height),
+                    },
+                    IncludeProof::No,
+                )?;
+
+                counterparty_chain_ids.insert(connection_id.clone(), client_state.chain_id());
+            }
+
+            let cid = counterparty_chain_ids[&connection_id].clone();
 
             if let Some(dst_chain_id) = &cmd.dst_chain_id {
                 if cid != *dst_chain_id {
@@ -274,7 +284,9 @@ impl Runnable for QueryChannelsCmd {
     fn run(&self) {
         match run_query_channels::<BaseChainHandle>(self) {
             Ok(output) => Output::success(output).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
         }
     }
 }
@@ -315,10 +327,12 @@ impl QueryChannelsOutput {
             Self::Pretty(pes) => pes.push(pe),
             Self::Verbose(_) => {
                 Output::error("PrettyOutput and QueryChannelsOutput::Verbose are incompatible")
+                    .with_code(ErrorCode::Unknown)
                     .exit()
             }
             Self::Summary(_) => {
                 Output::error("PrettyOutput and QueryChannelsOutput::Summary are incompatible")
+                    .with_code(ErrorCode::Unknown)
                     .exit()
             }
         }
@@ -327,11 +341,14 @@ impl QueryChannelsOutput {
     fn push_verbose(&mut self, ce: ChannelEnds) {
         match self {
             Self::Pretty(_) => {
-                Output::error("ChannelEnds and QueryChannelsOutput::Pretty are incompatible").exit()
+                Output::error("ChannelEnds and QueryChannelsOutput::Pretty are incompatible")
+                    .with_code(ErrorCode::Unknown)
+                    .exit()
             }
             Self::Verbose(ces) => ces.push(ce),
             Self::Summary(_) => {
                 Output::error("ChannelEnds and QueryChannelsOutput::Summary are incompatible")
+                    .with_code(ErrorCode::Unknown)
                     .exit()
             }
         }
@@ -341,10 +358,12 @@ impl QueryChannelsOutput {
         match self {
             Self::Pretty(_) => {
                 Output::error("PortChannelId and QueryChannelsOutput::Pretty are incompatible")
+                    .with_code(ErrorCode::Unknown)
                     .exit()
             }
             Self::Verbose(_) => {
                 Output::error("PortChannelId and QueryChannelsOutput::Verbose are incompatible")
+                    .with_code(ErrorCode::Unknown)
                     .exit()
             }
             Self::Summary(pcs) => pcs.push(pc),
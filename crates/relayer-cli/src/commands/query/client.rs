@@ -1,14 +1,21 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::time::Duration;
+
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
+use serde::Serialize;
 
 use ibc_relayer::chain::handle::ChainHandle;
 use ibc_relayer::chain::requests::{
     IncludeProof, PageRequest, QueryClientConnectionsRequest, QueryClientEventRequest,
-    QueryClientStateRequest, QueryConsensusStateRequest, QueryConsensusStatesRequest, QueryHeight,
+    QueryClientStateRequest, QueryConsensusStateRequest, QueryConsensusStatesRequest,
     QueryTxRequest,
 };
+use ibc_relayer::client_state::AnyClientState;
 
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
+use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::core::ics24_host::identifier::ClientId;
 use ibc_relayer_types::events::WithBlockDataType;
@@ -16,7 +23,8 @@ use ibc_relayer_types::Height;
 
 use crate::application::app_config;
 use crate::cli_utils::spawn_chain_runtime;
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::commands::query::height::{HeightArg, ResolvedHeight};
+use crate::conclude::{exit_with_error, exit_with_unrecoverable_error, json, ErrorCode, Output};
 
 /// Query client state command
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
@@ -42,9 +50,15 @@ pub struct QueryClientStateCmd {
     #[clap(
         long = "height",
         value_name = "HEIGHT",
-        help = "The chain height context for the query"
+        help = "The chain height context for the query, or 'latest-consistent' to pin the latest height"
     )]
-    height: Option<u64>,
+    height: Option<HeightArg>,
+
+    #[clap(
+        long = "redact",
+        help = "Show a concise form of the client state, leaving out fields such as the proof specs"
+    )]
+    redact: bool,
 }
 
 /// Command for querying a client's state.
@@ -53,23 +67,24 @@ impl Runnable for QueryClientStateCmd {
     fn run(&self) {
         let config = app_config();
 
-        let chain = spawn_chain_runtime(&config, &self.chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
+
+        let height = ResolvedHeight::resolve(self.height, &chain)
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Connectivity));
 
         match chain.query_client_state(
             QueryClientStateRequest {
                 client_id: self.client_id.clone(),
-                height: self.height.map_or(QueryHeight::Latest, |revision_height| {
-                    QueryHeight::Specific(
-                        Height::new(chain.id().version(), revision_height)
-                            .unwrap_or_else(exit_with_unrecoverable_error),
-                    )
-                }),
+                height: height.query_height(),
             },
             IncludeProof::No,
         ) {
-            Ok((cs, _)) => Output::success(cs).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Ok((cs, _)) if !self.redact => height.success(cs).exit(),
+            Ok((cs, _)) if json() => height.success(cs.redacted()).exit(),
+            Ok((cs, _)) => height.success_msg(cs).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
         }
     }
 }
@@ -108,9 +123,9 @@ pub struct QueryClientConsensusCmd {
     #[clap(
         long = "height",
         value_name = "HEIGHT",
-        help = "The chain height context to be used, applicable only to a specific height"
+        help = "The chain height context to be used, applicable only to a specific height, or 'latest-consistent' to pin the latest height"
     )]
-    height: Option<u64>,
+    height: Option<HeightArg>,
 }
 
 /// Implementation of the query for a client's consensus state at a certain height.
@@ -119,13 +134,15 @@ impl Runnable for QueryClientConsensusCmd {
     fn run(&self) {
         let config = app_config();
 
-        let chain = spawn_chain_runtime(&config, &self.chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
+
+        let height = ResolvedHeight::resolve(self.height, &chain)
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Connectivity));
 
         let counterparty_chain = match chain.query_client_state(
             QueryClientStateRequest {
                 client_id: self.client_id.clone(),
-                height: QueryHeight::Latest,
+                height: height.query_height(),
             },
             IncludeProof::No,
         ) {
@@ -134,36 +151,31 @@ impl Runnable for QueryClientConsensusCmd {
                 "failed while querying client '{}' on chain '{}' with error: {}",
                 self.client_id, self.chain_id, e
             ))
+            .with_code(ErrorCode::Connectivity)
             .exit(),
         };
 
         match self.consensus_height {
             Some(cs_height) => {
                 let consensus_height = Height::new(counterparty_chain.version(), cs_height)
-                    .unwrap_or_else(exit_with_unrecoverable_error);
+                    .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::User));
 
                 let res = chain
                     .query_consensus_state(
                         QueryConsensusStateRequest {
                             client_id: self.client_id.clone(),
                             consensus_height,
-                            query_height: self.height.map_or(
-                                QueryHeight::Latest,
-                                |revision_height| {
-                                    QueryHeight::Specific(
-                                        Height::new(chain.id().version(), revision_height)
-                                            .unwrap_or_else(exit_with_unrecoverable_error),
-                                    )
-                                },
-                            ),
+                            query_height: height.query_height(),
                         },
                         IncludeProof::No,
                     )
                     .map(|(consensus_state, _)| consensus_state);
 
                 match res {
-                    Ok(cs) => Output::success(cs).exit(),
-                    Err(e) => Output::error(format!("{}", e)).exit(),
+                    Ok(cs) => height.success(cs).exit(),
+                    Err(e) => Output::error(format!("{}", e))
+                        .with_code(ErrorCode::Connectivity)
+                        .exit(),
                 }
             }
             None => {
@@ -181,7 +193,9 @@ impl Runnable for QueryClientConsensusCmd {
                             Output::success(states).exit()
                         }
                     }
-                    Err(e) => Output::error(format!("{}", e)).exit(),
+                    Err(e) => Output::error(format!("{}", e))
+                        .with_code(ErrorCode::Connectivity)
+                        .exit(),
                 }
             }
         }
@@ -220,9 +234,9 @@ pub struct QueryClientHeaderCmd {
     #[clap(
         long = "height",
         value_name = "HEIGHT",
-        help = "The chain height context for the query. Leave unspecified for latest height."
+        help = "The chain height context for the query. Leave unspecified for latest height, or use 'latest-consistent' to pin the latest height."
     )]
-    height: Option<u64>,
+    height: Option<HeightArg>,
 }
 
 /// Implementation of the query for the header used in a client update at a certain height.
@@ -231,13 +245,15 @@ impl Runnable for QueryClientHeaderCmd {
     fn run(&self) {
         let config = app_config();
 
-        let chain = spawn_chain_runtime(&config, &self.chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
+
+        let height = ResolvedHeight::resolve(self.height, &chain)
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Connectivity));
 
         let counterparty_chain = match chain.query_client_state(
             QueryClientStateRequest {
                 client_id: self.client_id.clone(),
-                height: QueryHeight::Latest,
+                height: height.query_height(),
             },
             IncludeProof::No,
         ) {
@@ -246,30 +262,25 @@ impl Runnable for QueryClientHeaderCmd {
                 "failed while querying client '{}' on chain '{}' with error: {}",
                 self.client_id, self.chain_id, e
             ))
+            .with_code(ErrorCode::Connectivity)
             .exit(),
         };
 
         let consensus_height = Height::new(counterparty_chain.version(), self.consensus_height)
-            .unwrap_or_else(exit_with_unrecoverable_error);
-
-        let query_height = match self.height {
-            Some(revision_height) => QueryHeight::Specific(
-                Height::new(chain.id().version(), revision_height)
-                    .unwrap_or_else(exit_with_unrecoverable_error),
-            ),
-            None => QueryHeight::Latest,
-        };
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::User));
 
         let res = chain.query_txs(QueryTxRequest::Client(QueryClientEventRequest {
-            query_height,
+            query_height: height.query_height(),
             event_id: WithBlockDataType::UpdateClient,
             client_id: self.client_id.clone(),
             consensus_height,
         }));
 
         match res {
-            Ok(header) => Output::success(header).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Ok(header) => height.success(header).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
         }
     }
 }
@@ -308,8 +319,7 @@ impl Runnable for QueryClientConnectionsCmd {
     fn run(&self) {
         let config = app_config();
 
-        let chain = spawn_chain_runtime(&config, &self.chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
 
         let res = chain.query_client_connections(QueryClientConnectionsRequest {
             client_id: self.client_id.clone(),
@@ -317,7 +327,125 @@ impl Runnable for QueryClientConnectionsCmd {
 
         match res {
             Ok(ce) => Output::success(ce).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
+        }
+    }
+}
+
+/// Query client parameters command
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct QueryClientParametersCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain to query"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "client",
+        required = true,
+        value_name = "CLIENT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the client to query"
+    )]
+    client_id: ClientId,
+
+    #[clap(
+        long = "height",
+        value_name = "HEIGHT",
+        help = "The chain height context for the query, or 'latest-consistent' to pin the latest height"
+    )]
+    height: Option<HeightArg>,
+}
+
+/// The parameters of a client which determine whether and for how long it can
+/// be updated, as shown by `query client parameters`.
+#[derive(Debug, Serialize)]
+pub struct ClientParameters {
+    pub client_type: ClientType,
+    pub chain_id: ChainId,
+    pub trusting_period: Option<Duration>,
+    pub trust_threshold: Option<TrustThreshold>,
+    pub max_clock_drift: Duration,
+    pub frozen_height: Option<Height>,
+    pub latest_height: Height,
+}
+
+impl From<&AnyClientState> for ClientParameters {
+    fn from(client_state: &AnyClientState) -> Self {
+        Self {
+            client_type: client_state.client_type(),
+            chain_id: client_state.chain_id(),
+            trusting_period: client_state.trusting_period(),
+            trust_threshold: client_state.trust_threshold(),
+            max_clock_drift: client_state.max_clock_drift(),
+            frozen_height: client_state.frozen_height(),
+            latest_height: client_state.latest_height(),
+        }
+    }
+}
+
+impl Display for ClientParameters {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        let or_none = |value: Option<String>| value.unwrap_or_else(|| "none".to_string());
+
+        writeln!(f, "client type:     {}", self.client_type.as_str())?;
+        writeln!(f, "chain:           {}", self.chain_id)?;
+        writeln!(
+            f,
+            "trusting period: {}",
+            or_none(
+                self.trusting_period
+                    .map(|period| humantime::format_duration(period).to_string())
+            )
+        )?;
+        writeln!(
+            f,
+            "trust threshold: {}",
+            or_none(self.trust_threshold.map(|threshold| threshold.to_string()))
+        )?;
+        writeln!(
+            f,
+            "max clock drift: {}",
+            humantime::format_duration(self.max_clock_drift)
+        )?;
+        writeln!(
+            f,
+            "frozen height:   {}",
+            or_none(self.frozen_height.map(|height| height.to_string()))
+        )?;
+        write!(f, "latest height:   {}", self.latest_height)
+    }
+}
+
+/// Command for querying the parameters of a client.
+/// hermes query client parameters --chain ibc-1 --client 07-tendermint-0
+impl Runnable for QueryClientParametersCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
+
+        let height = ResolvedHeight::resolve(self.height, &chain)
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Connectivity));
+
+        match chain.query_client_state(
+            QueryClientStateRequest {
+                client_id: self.client_id.clone(),
+                height: height.query_height(),
+            },
+            IncludeProof::No,
+        ) {
+            Ok((cs, _)) if json() => height.success(ClientParameters::from(&cs)).exit(),
+            Ok((cs, _)) => height.success_msg(ClientParameters::from(&cs)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
         }
     }
 }
@@ -326,8 +454,9 @@ impl Runnable for QueryClientConnectionsCmd {
 mod tests {
     use super::{
         QueryClientConnectionsCmd, QueryClientConsensusCmd, QueryClientHeaderCmd,
-        QueryClientStateCmd,
+        QueryClientParametersCmd, QueryClientStateCmd,
     };
+    use crate::commands::query::height::HeightArg;
 
     use std::str::FromStr;
 
@@ -434,7 +563,7 @@ mod tests {
                 client_id: ClientId::from_str("client_id").unwrap(),
                 consensus_height: None,
                 heights_only: false,
-                height: Some(42)
+                height: Some(HeightArg::Specific(42))
             },
             QueryClientConsensusCmd::parse_from([
                 "test",
@@ -448,6 +577,30 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_query_client_consensus_latest_consistent_height() {
+        assert_eq!(
+            QueryClientConsensusCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                client_id: ClientId::from_str("client_id").unwrap(),
+                consensus_height: Some(42),
+                heights_only: false,
+                height: Some(HeightArg::LatestConsistent)
+            },
+            QueryClientConsensusCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--client",
+                "client_id",
+                "--consensus-height",
+                "42",
+                "--height",
+                "latest-consistent"
+            ])
+        )
+    }
+
     #[test]
     fn test_query_client_consensus_heights_only() {
         assert_eq!(
@@ -507,7 +660,7 @@ mod tests {
                 chain_id: ChainId::from_string("chain_id"),
                 client_id: ClientId::from_str("client_id").unwrap(),
                 consensus_height: 42,
-                height: Some(21)
+                height: Some(HeightArg::Specific(21))
             },
             QueryClientHeaderCmd::parse_from([
                 "test",
@@ -565,7 +718,8 @@ mod tests {
             QueryClientStateCmd {
                 chain_id: ChainId::from_string("chain_id"),
                 client_id: ClientId::from_str("client_id").unwrap(),
-                height: None
+                height: None,
+                redact: false
             },
             QueryClientStateCmd::parse_from([
                 "test",
@@ -583,7 +737,8 @@ mod tests {
             QueryClientStateCmd {
                 chain_id: ChainId::from_string("chain_id"),
                 client_id: ClientId::from_str("client_id").unwrap(),
-                height: Some(42)
+                height: Some(HeightArg::Specific(42)),
+                redact: false
             },
             QueryClientStateCmd::parse_from([
                 "test",
@@ -597,6 +752,26 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_query_client_state_redact() {
+        assert_eq!(
+            QueryClientStateCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                client_id: ClientId::from_str("client_id").unwrap(),
+                height: None,
+                redact: true
+            },
+            QueryClientStateCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--client",
+                "client_id",
+                "--redact"
+            ])
+        )
+    }
+
     #[test]
     fn test_query_client_state_no_client() {
         assert!(QueryClientStateCmd::try_parse_from(["test", "--chain", "chain_id"]).is_err())
@@ -606,4 +781,47 @@ mod tests {
     fn test_query_client_state_no_chain() {
         assert!(QueryClientStateCmd::try_parse_from(["test", "--client", "client_id"]).is_err())
     }
+
+    #[test]
+    fn test_query_client_parameters_required_only() {
+        assert_eq!(
+            QueryClientParametersCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                client_id: ClientId::from_str("client_id").unwrap(),
+                height: None,
+            },
+            QueryClientParametersCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--client",
+                "client_id"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_client_parameters_height() {
+        assert_eq!(
+            QueryClientParametersCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                client_id: ClientId::from_str("client_id").unwrap(),
+                height: Some(HeightArg::LatestConsistent),
+            },
+            QueryClientParametersCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--client",
+                "client_id",
+                "--height",
+                "latest-consistent"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_client_parameters_no_client() {
+        assert!(QueryClientParametersCmd::try_parse_from(["test", "--chain", "chain_id"]).is_err())
+    }
 }
@@ -8,7 +8,7 @@ use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 
 use crate::cli_utils::spawn_chain_runtime;
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::conclude::{exit_with_error, Output};
 use crate::error::Error;
 use crate::prelude::*;
 
@@ -50,8 +50,7 @@ impl Runnable for QueryAllClientsCmd {
     fn run(&self) {
         let config = app_config();
 
-        let chain = spawn_chain_runtime(&config, &self.chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
 
         let res: Result<_, Error> = chain
             .query_clients(QueryClientStatesRequest {
@@ -103,7 +102,7 @@ impl Runnable for QueryAllClientsCmd {
                     }
                 }
             }
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         }
     }
 }
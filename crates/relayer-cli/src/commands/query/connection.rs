@@ -2,7 +2,7 @@ use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 use ibc_relayer::chain::handle::ChainHandle;
 use ibc_relayer::chain::requests::{
-    IncludeProof, PageRequest, QueryConnectionChannelsRequest, QueryConnectionRequest, QueryHeight,
+    IncludeProof, PageRequest, QueryConnectionChannelsRequest, QueryConnectionRequest,
 };
 
 use ibc_relayer_types::core::{
@@ -10,10 +10,10 @@ use ibc_relayer_types::core::{
     ics24_host::identifier::ConnectionId,
     ics24_host::identifier::{ChainId, PortChannelId},
 };
-use ibc_relayer_types::Height;
 
 use crate::cli_utils::spawn_chain_runtime;
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::commands::query::height::{HeightArg, ResolvedHeight};
+use crate::conclude::{exit_with_error, exit_with_unrecoverable_error, ErrorCode, Output};
 use crate::error::Error;
 use crate::prelude::*;
 
@@ -41,9 +41,9 @@ pub struct QueryConnectionEndCmd {
     #[clap(
         long = "height",
         value_name = "HEIGHT",
-        help = "Height of the state to query. Leave unspecified for latest height."
+        help = "Height of the state to query. Leave unspecified for latest height, or use 'latest-consistent' to pin the latest height."
     )]
-    height: Option<u64>,
+    height: Option<HeightArg>,
 }
 
 // cargo run --bin hermes -- query connection end --chain ibc-test --connection connectionidone --height 3
@@ -51,18 +51,15 @@ impl Runnable for QueryConnectionEndCmd {
     fn run(&self) {
         let config = app_config();
 
-        let chain = spawn_chain_runtime(&config, &self.chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
+
+        let height = ResolvedHeight::resolve(self.height, &chain)
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Connectivity));
 
         let res = chain.query_connection(
             QueryConnectionRequest {
                 connection_id: self.connection_id.clone(),
-                height: self.height.map_or(QueryHeight::Latest, |revision_height| {
-                    QueryHeight::Specific(
-                        Height::new(chain.id().version(), revision_height)
-                            .unwrap_or_else(exit_with_unrecoverable_error),
-                    )
-                }),
+                height: height.query_height(),
             },
             IncludeProof::No,
         );
@@ -73,12 +70,15 @@ impl Runnable for QueryConnectionEndCmd {
                         "connection '{}' does not exist",
                         self.connection_id
                     ))
+                    .with_code(ErrorCode::User)
                     .exit()
                 } else {
-                    Output::success(connection_end).exit()
+                    height.success(connection_end).exit()
                 }
             }
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
         }
     }
 }
@@ -112,8 +112,7 @@ impl Runnable for QueryConnectionChannelsCmd {
     fn run(&self) {
         let config = app_config();
 
-        let chain = spawn_chain_runtime(&config, &self.chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
 
         let res: Result<_, Error> = chain
             .query_connection_channels(QueryConnectionChannelsRequest {
@@ -133,7 +132,7 @@ impl Runnable for QueryConnectionChannelsCmd {
                     .collect();
                 Output::success(ids).exit()
             }
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         }
     }
 }
@@ -141,6 +140,7 @@ impl Runnable for QueryConnectionChannelsCmd {
 #[cfg(test)]
 mod tests {
     use super::{QueryConnectionChannelsCmd, QueryConnectionEndCmd};
+    use crate::commands::query::height::HeightArg;
 
     use std::str::FromStr;
 
@@ -240,7 +240,7 @@ mod tests {
             QueryConnectionEndCmd {
                 chain_id: ChainId::from_string("chain_id"),
                 connection_id: ConnectionId::from_str("connection_id").unwrap(),
-                height: Some(42)
+                height: Some(HeightArg::Specific(42))
             },
             QueryConnectionEndCmd::parse_from([
                 "test",
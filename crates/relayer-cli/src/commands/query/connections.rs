@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use abscissa_core::clap::Parser;
 use abscissa_core::Runnable;
 
@@ -5,11 +7,13 @@ use ibc_relayer::chain::handle::ChainHandle;
 use ibc_relayer::chain::requests::{
     IncludeProof, PageRequest, QueryClientStateRequest, QueryConnectionsRequest, QueryHeight,
 };
+use ibc_relayer::error::Error as RelayerError;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics03_connection::connection::IdentifiedConnectionEnd;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
 
 use crate::cli_utils::spawn_chain_runtime;
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::conclude::{exit_with_error, exit_with_unrecoverable_error, ErrorCode, Output};
 use crate::prelude::*;
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
@@ -42,49 +46,31 @@ impl Runnable for QueryConnectionsCmd {
     fn run(&self) {
         let config = app_config();
 
-        let chain = spawn_chain_runtime(&config, &self.chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
 
         let res = chain.query_connections(QueryConnectionsRequest {
             pagination: Some(PageRequest::all()),
         });
 
         let connections = match res {
-            Ok(connections) => {
-                // Check the counterparty chain id only if filtering is required.
-                if let Some(counterparty_filter_id) = self.counterparty_chain_id.clone() {
-                    let mut output = connections.clone();
-
-                    for (id, connection) in connections.into_iter().enumerate() {
-                        let client_id = connection.end().client_id().to_owned();
-                        let chain_height = chain.query_latest_height();
-                        let (client_state, _) = chain
-                            .query_client_state(
-                                QueryClientStateRequest {
-                                    client_id,
-                                    height: QueryHeight::Specific(chain_height.unwrap()),
-                                },
-                                IncludeProof::No,
-                            )
-                            .unwrap();
-                        let counterparty_chain_id = client_state.chain_id();
-
-                        if counterparty_chain_id != counterparty_filter_id {
-                            output.remove(id);
-                        }
-                    }
-                    output
-                } else {
-                    connections
-                }
-            }
+            Ok(connections) => connections,
             Err(e) => Output::error(format!(
                 "An error occurred trying to query connections: {}",
                 e
             ))
+            .with_code(ErrorCode::Connectivity)
             .exit(),
         };
 
+        // Check the counterparty chain id only if filtering is required.
+        let connections = match &self.counterparty_chain_id {
+            Some(counterparty_chain_id) => {
+                filter_by_counterparty_chain(&chain, connections, counterparty_chain_id)
+                    .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Connectivity))
+            }
+            None => connections,
+        };
+
         if self.verbose {
             Output::success(connections).exit()
         } else {
@@ -98,6 +84,41 @@ impl Runnable for QueryConnectionsCmd {
     }
 }
 
+/// Retains only the connections whose client tracks the given counterparty chain.
+fn filter_by_counterparty_chain<Chain: ChainHandle>(
+    chain: &Chain,
+    connections: Vec<IdentifiedConnectionEnd>,
+    counterparty_chain_id: &ChainId,
+) -> Result<Vec<IdentifiedConnectionEnd>, RelayerError> {
+    let chain_height = chain.query_latest_height()?;
+
+    // Several connections may share the same client, so only query each client state once.
+    let mut client_chain_ids = HashMap::new();
+    let mut filtered = Vec::new();
+
+    for connection in connections {
+        let client_id = connection.end().client_id();
+
+        if !client_chain_ids.contains_key(client_id) {
+            let (client_state, _) = chain.query_client_state(
+                QueryClientStateRequest {
+                    client_id: client_id.clone(),
+                    height: QueryHeight::Specific(chain_height),
+                },
+                IncludeProof::No,
+            )?;
+
+            client_chain_ids.insert(client_id.clone(), client_state.chain_id());
+        }
+
+        if client_chain_ids[client_id] == *counterparty_chain_id {
+            filtered.push(connection);
+        }
+    }
+
+    Ok(filtered)
+}
+
 #[cfg(test)]
 mod tests {
     use super::QueryConnectionsCmd;
@@ -0,0 +1,129 @@
+//! The `--height` option of the query commands.
+
+use core::fmt::{Debug, Display};
+use core::str::FromStr;
+
+use serde::Serialize;
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::QueryHeight;
+use ibc_relayer_types::Height;
+
+use crate::conclude::Output;
+
+/// The value of `--height` which pins the latest height of the chain.
+const LATEST_CONSISTENT: &str = "latest-consistent";
+
+/// The height at which a query command queries a chain, as given with `--height`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HeightArg {
+    /// The given revision height, in the current revision of the chain.
+    Specific(u64),
+
+    /// The latest height of the chain, resolved once when the command starts,
+    /// so that all the queries performed by the command reflect the same state.
+    LatestConsistent,
+}
+
+impl FromStr for HeightArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == LATEST_CONSISTENT {
+            return Ok(Self::LatestConsistent);
+        }
+
+        s.parse().map(Self::Specific).map_err(|_| {
+            format!(
+                "expected a revision height or '{}', got '{}'",
+                LATEST_CONSISTENT, s
+            )
+        })
+    }
+}
+
+/// The height at which the queries of a command are performed,
+/// resolved from the value of `--height` given to the command.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedHeight {
+    query_height: QueryHeight,
+    pinned: bool,
+}
+
+impl ResolvedHeight {
+    /// Resolve the given height against the given chain, querying
+    /// its latest height if `latest-consistent` was given.
+    pub fn resolve<Chain: ChainHandle>(
+        height: Option<HeightArg>,
+        chain: &Chain,
+    ) -> eyre::Result<Self> {
+        let (query_height, pinned) = match height {
+            None => (QueryHeight::Latest, false),
+            Some(HeightArg::Specific(revision_height)) => {
+                let height = Height::new(chain.id().version(), revision_height)?;
+
+                (QueryHeight::Specific(height), false)
+            }
+            Some(HeightArg::LatestConsistent) => {
+                let height = chain.query_latest_height()?;
+
+                (QueryHeight::Specific(height), true)
+            }
+        };
+
+        Ok(Self {
+            query_height,
+            pinned,
+        })
+    }
+
+    /// The height to perform all the queries of the command at.
+    pub fn query_height(&self) -> QueryHeight {
+        self.query_height
+    }
+
+    /// A successful output with the given result, along with the height
+    /// it was queried at if that height was pinned with `latest-consistent`.
+    pub fn success<R>(&self, result: R) -> Output
+    where
+        R: Serialize + Debug + 'static,
+    {
+        match self.query_height {
+            QueryHeight::Specific(height) if self.pinned => {
+                Output::success(AtHeight { height, result })
+            }
+            _ => Output::success(result),
+        }
+    }
+
+    /// A successful output with the given message, along with the height
+    /// it was queried at if that height was pinned with `latest-consistent`.
+    pub fn success_msg(&self, msg: impl Display) -> Output {
+        match self.query_height {
+            QueryHeight::Specific(height) if self.pinned => {
+                Output::success_msg(format!("{}\n(queried at height {})", msg, height))
+            }
+            _ => Output::success_msg(msg),
+        }
+    }
+}
+
+/// The result of a query, along with the height it was queried at.
+#[derive(Debug, Serialize)]
+pub struct AtHeight<R> {
+    pub height: Height,
+    pub result: R,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeightArg;
+
+    #[test]
+    fn parse_height_arg() {
+        assert_eq!("42".parse(), Ok(HeightArg::Specific(42)));
+        assert_eq!("latest-consistent".parse(), Ok(HeightArg::LatestConsistent));
+        assert!("latest".parse::<HeightArg>().is_err());
+        assert!("-1".parse::<HeightArg>().is_err());
+    }
+}
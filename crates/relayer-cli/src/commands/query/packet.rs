@@ -8,6 +8,7 @@ mod commitments;
 mod pending;
 mod pending_acks;
 mod pending_sends;
+mod stuck;
 
 #[derive(Command, Debug, Parser, Runnable)]
 pub enum QueryPacketCmds {
@@ -31,4 +32,7 @@ pub enum QueryPacketCmds {
 
     /// Output a summary of pending packets in both directions
     Pending(pending::QueryPendingPacketsCmd),
+
+    /// List the pending send packets which are about to time out, most urgent first
+    Stuck(stuck::QueryStuckPacketsCmd),
 }
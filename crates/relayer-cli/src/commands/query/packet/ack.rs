@@ -9,7 +9,7 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId
 use ibc_relayer_types::Height;
 
 use crate::cli_utils::spawn_chain_runtime;
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::conclude::{exit_with_unrecoverable_error, ErrorCode, Output};
 use crate::error::Error;
 use crate::prelude::*;
 
@@ -76,7 +76,7 @@ impl QueryPacketAcknowledgmentCmd {
                     height: self.height.map_or(QueryHeight::Latest, |revision_height| {
                         QueryHeight::Specific(
                             Height::new(chain.id().version(), revision_height)
-                                .unwrap_or_else(exit_with_unrecoverable_error),
+                                .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::User)),
                         )
                     }),
                 },
@@ -95,7 +95,7 @@ impl Runnable for QueryPacketAcknowledgmentCmd {
     fn run(&self) {
         match self.execute() {
             Ok(hex) => Output::success(hex).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         }
     }
 }
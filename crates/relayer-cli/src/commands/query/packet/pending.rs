@@ -98,7 +98,7 @@ impl Runnable for QueryPendingPacketsCmd {
     fn run(&self) {
         match self.execute() {
             Ok(pending) => Output::success(pending).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         }
     }
 }
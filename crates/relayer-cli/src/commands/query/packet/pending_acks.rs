@@ -78,7 +78,7 @@ impl Runnable for QueryPendingAcksCmd {
     fn run(&self) {
         match self.execute() {
             Ok(seqs) => Output::success(seqs).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         }
     }
 }
@@ -0,0 +1,359 @@
+use core::cmp::Ordering;
+use core::time::Duration;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use serde::Serialize;
+
+use ibc_relayer::chain::counterparty::unreceived_packets;
+use ibc_relayer::chain::endpoint::ChainStatus;
+use ibc_relayer::chain::handle::{BaseChainHandle, ChainHandle};
+use ibc_relayer::chain::requests::{Qualified, QueryHeight, QueryPacketEventDataRequest};
+use ibc_relayer::path::PathIdentifiers;
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics04_channel::timeout::TimeoutHeight;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::events::{IbcEvent, WithBlockDataType};
+
+use crate::cli_utils::spawn_chain_counterparty;
+use crate::conclude::Output;
+use crate::error::Error;
+use crate::prelude::*;
+
+/// Maximum number of sequences to query the send packet events for at once.
+const QUERY_BATCH_SIZE: usize = 50;
+
+/// A packet which is not yet received on the counterparty chain,
+/// and how close it is to timing out.
+#[derive(Debug, Serialize)]
+struct StuckPacket {
+    sequence: Sequence,
+    /// Number of counterparty blocks left before the packet times out, if it has a timeout height
+    /// which is not in a later revision of the counterparty chain.
+    blocks_left: Option<u64>,
+    /// Time left before the packet times out, if it has a timeout timestamp.
+    #[serde(serialize_with = "serialize_time_left")]
+    time_left: Option<Duration>,
+    /// Whether the packet has already timed out.
+    expired: bool,
+    /// The estimated time left before the packet times out, by either its height or timestamp,
+    /// converting the blocks left into time using the `max_block_time` of the counterparty chain.
+    #[serde(skip)]
+    urgency: Duration,
+}
+
+impl StuckPacket {
+    fn new(packet: &Packet, status: &ChainStatus, block_time: Duration) -> Self {
+        let blocks_left = match packet.timeout_height {
+            TimeoutHeight::At(height) => {
+                match height
+                    .revision_number()
+                    .cmp(&status.height.revision_number())
+                {
+                    // The counterparty chain has already moved past the revision of the timeout height
+                    Ordering::Less => Some(0),
+                    Ordering::Equal => Some(
+                        height
+                            .revision_height()
+                            .saturating_sub(status.height.revision_height()),
+                    ),
+                    // The packet only times out after the counterparty chain is upgraded
+                    Ordering::Greater => None,
+                }
+            }
+            TimeoutHeight::Never => None,
+        };
+
+        let time_left = if packet.timeout_timestamp.nanoseconds() == 0 {
+            None
+        } else {
+            Some(
+                packet
+                    .timeout_timestamp
+                    .duration_since(&status.timestamp)
+                    .unwrap_or_default(),
+            )
+        };
+
+        let by_height = blocks_left
+            .map(|blocks| block_time.saturating_mul(u32::try_from(blocks).unwrap_or(u32::MAX)));
+        let urgency = by_height
+            .into_iter()
+            .chain(time_left)
+            .min()
+            .unwrap_or(Duration::MAX);
+
+        Self {
+            sequence: packet.sequence,
+            blocks_left,
+            time_left,
+            expired: urgency.is_zero(),
+            urgency,
+        }
+    }
+}
+
+fn serialize_time_left<S>(time_left: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match time_left {
+        Some(time_left) => serializer.collect_str(&humantime::format_duration(
+            Duration::from_secs(time_left.as_secs()),
+        )),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// This command does the following:
+/// 1. queries the chain to get its counterparty chain, channel and port identifiers
+/// 2. queries the counterparty chain for the sequences of the packets sent on the chain
+///    which it has not yet received
+/// 3. queries the chain for the send packet events of these packets, to get their timeouts
+/// 4. compares these timeouts with the latest height and timestamp of the counterparty chain,
+///    and lists the packets which time out within the given window, most urgent first.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct QueryStuckPacketsCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain on which the packets were sent"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "port",
+        required = true,
+        value_name = "PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Port identifier on the chain given by <CHAIN_ID>"
+    )]
+    port_id: PortId,
+
+    #[clap(
+        long = "channel",
+        visible_alias = "chan",
+        required = true,
+        value_name = "CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Channel identifier on the chain given by <CHAIN_ID>"
+    )]
+    channel_id: ChannelId,
+
+    #[clap(
+        long = "window",
+        value_name = "WINDOW",
+        default_value = "1h",
+        help = "List the pending packets which time out within this window, e.g. '30m' or '2h'"
+    )]
+    window: humantime::Duration,
+}
+
+impl QueryStuckPacketsCmd {
+    fn execute(&self) -> Result<Vec<StuckPacket>, Error> {
+        let config = app_config();
+
+        let (chains, chan_conn_cli) = spawn_chain_counterparty::<BaseChainHandle>(
+            &config,
+            &self.chain_id,
+            &self.port_id,
+            &self.channel_id,
+        )?;
+
+        let channel = chan_conn_cli.channel;
+
+        debug!(
+            "fetched from source chain {} the following channel {:?}",
+            self.chain_id, channel
+        );
+
+        // The path as seen from the counterparty chain, which is the one receiving the packets
+        let path = {
+            let path = PathIdentifiers::from_channel_end(channel.clone())
+                .ok_or_else(|| Error::missing_counterparty_channel_id(channel))?;
+
+            PathIdentifiers {
+                port_id: path.counterparty_port_id,
+                channel_id: path.counterparty_channel_id,
+                counterparty_port_id: path.port_id,
+                counterparty_channel_id: path.channel_id,
+            }
+        };
+
+        let (sequences, height) =
+            unreceived_packets(&chains.dst, &chains.src, &path).map_err(Error::supervisor)?;
+
+        let status = chains
+            .dst
+            .query_application_status()
+            .map_err(Error::relayer)?;
+
+        let block_time = config
+            .find_chain(&chains.dst.id())
+            .map(|chain_config| chain_config.max_block_time)
+            .unwrap_or_default();
+
+        let mut stuck = Vec::new();
+
+        for batch in sequences.chunks(QUERY_BATCH_SIZE) {
+            let events = chains
+                .src
+                .query_packet_events(QueryPacketEventDataRequest {
+                    event_id: WithBlockDataType::SendPacket,
+                    source_port_id: path.counterparty_port_id.clone(),
+                    source_channel_id: path.counterparty_channel_id.clone(),
+                    destination_port_id: path.port_id.clone(),
+                    destination_channel_id: path.channel_id.clone(),
+                    sequences: batch.to_vec(),
+                    height: Qualified::SmallerEqual(QueryHeight::Specific(height)),
+                    min_height: None,
+                    pagination: None,
+                })
+                .map_err(Error::relayer)?;
+
+            stuck.extend(events.into_iter().filter_map(|event| match event.event {
+                IbcEvent::SendPacket(send_packet) => {
+                    Some(StuckPacket::new(&send_packet.packet, &status, block_time))
+                }
+                _ => None,
+            }));
+        }
+
+        Ok(select_stuck(stuck, self.window.into()))
+    }
+}
+
+/// Keep the packets which time out within the given window, most urgent first.
+fn select_stuck(mut packets: Vec<StuckPacket>, window: Duration) -> Vec<StuckPacket> {
+    packets.retain(|packet| packet.urgency <= window);
+    packets.sort_by_key(|packet| (packet.urgency, packet.sequence));
+    packets
+}
+
+impl Runnable for QueryStuckPacketsCmd {
+    fn run(&self) {
+        match self.execute() {
+            Ok(stuck) => Output::success(stuck).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::timestamp::Timestamp;
+    use ibc_relayer_types::Height;
+
+    fn packet(sequence: u64, timeout_height: u64, timeout_secs: u64) -> Packet {
+        Packet {
+            sequence: sequence.into(),
+            timeout_height: match timeout_height {
+                0 => TimeoutHeight::Never,
+                h => TimeoutHeight::At(Height::new(0, h).unwrap()),
+            },
+            timeout_timestamp: Timestamp::from_nanoseconds(timeout_secs * 1_000_000_000).unwrap(),
+            ..Packet::default()
+        }
+    }
+
+    #[test]
+    fn test_select_stuck_packets() {
+        let status = ChainStatus {
+            height: Height::new(0, 100).unwrap(),
+            timestamp: Timestamp::from_nanoseconds(1_000 * 1_000_000_000).unwrap(),
+        };
+        let block_time = Duration::from_secs(5);
+
+        let packets = vec![
+            // times out in 50 blocks, ie. ~250s
+            packet(1, 150, 0),
+            // times out in 100s
+            packet(2, 0, 1_100),
+            // timed out by height
+            packet(3, 90, 0),
+            // times out in 2000s, out of the window
+            packet(4, 0, 3_000),
+            // no timeout
+            packet(5, 0, 0),
+        ];
+
+        let stuck = select_stuck(
+            packets
+                .iter()
+                .map(|p| StuckPacket::new(p, &status, block_time))
+                .collect(),
+            Duration::from_secs(600),
+        );
+
+        let sequences: Vec<u64> = stuck.iter().map(|p| p.sequence.into()).collect();
+        assert_eq!(sequences, vec![3, 2, 1]);
+        assert!(stuck[0].expired);
+        assert_eq!(stuck[2].blocks_left, Some(50));
+    }
+
+    #[test]
+    fn test_stuck_packet_timeout_revision() {
+        let status = ChainStatus {
+            height: Height::new(1, 100).unwrap(),
+            timestamp: Timestamp::from_nanoseconds(1_000 * 1_000_000_000).unwrap(),
+        };
+        let block_time = Duration::from_secs(5);
+
+        // The timeout height is in the previous revision, despite its higher revision height
+        let stuck = StuckPacket::new(&packet(1, 150, 0), &status, block_time);
+        assert_eq!(stuck.blocks_left, Some(0));
+        assert!(stuck.expired);
+
+        // The timeout height is in the next revision, despite its lower revision height
+        let mut next_revision = packet(2, 0, 0);
+        next_revision.timeout_height = TimeoutHeight::At(Height::new(2, 10).unwrap());
+        let stuck = StuckPacket::new(&next_revision, &status, block_time);
+        assert_eq!(stuck.blocks_left, None);
+        assert!(!stuck.expired);
+    }
+
+    #[test]
+    fn test_query_packet_stuck() {
+        assert_eq!(
+            QueryStuckPacketsCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                port_id: PortId::from_str("port_id").unwrap(),
+                channel_id: ChannelId::from_str("channel-07").unwrap(),
+                window: "30m".parse().unwrap(),
+            },
+            QueryStuckPacketsCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--port",
+                "port_id",
+                "--channel",
+                "channel-07",
+                "--window",
+                "30m"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_packet_stuck_default_window() {
+        let cmd = QueryStuckPacketsCmd::parse_from([
+            "test",
+            "--chain",
+            "chain_id",
+            "--port",
+            "port_id",
+            "--chan",
+            "channel-07",
+        ]);
+
+        assert_eq!(cmd.window, "1h".parse().unwrap());
+    }
+}
@@ -6,7 +6,7 @@ use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
 use crate::application::app_config;
 use crate::cli_utils::spawn_chain_runtime;
-use crate::conclude::{exit_with_unrecoverable_error, json, Output};
+use crate::conclude::{exit_with_error, json, ErrorCode, Output};
 
 /// The data structure that represents the arguments when invoking the `query transfer denom-trace` CLI command.
 ///
@@ -38,8 +38,7 @@ impl Runnable for DenomTraceCmd {
     fn run(&self) {
         let config = app_config();
 
-        let chain = spawn_chain_runtime(&config, &self.chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
 
         match chain.query_denom_trace(self.hash.clone()) {
             Ok(denom_trace) if json() => Output::success(denom_trace).exit(),
@@ -52,6 +51,7 @@ impl Runnable for DenomTraceCmd {
                 "there was a problem querying the denomination trace: {}",
                 e
             ))
+            .with_code(ErrorCode::Connectivity)
             .exit(),
         }
     }
@@ -3,6 +3,7 @@
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 
+mod decode;
 mod events;
 
 /// `query tx` subcommand
@@ -10,4 +11,7 @@ mod events;
 pub enum QueryTxCmd {
     /// Query the events emitted by transaction
     Events(events::QueryTxEventsCmd),
+
+    /// Query the IBC messages of a transaction and the events they emitted
+    Decode(decode::QueryTxDecodeCmd),
 }
@@ -0,0 +1,202 @@
+use core::fmt::{self, Debug};
+use core::str::FromStr;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use serde::{Serialize, Serializer};
+use tendermint::abci::transaction::Hash;
+
+use ibc_relayer_types::applications::transfer::msgs::transfer;
+use ibc_relayer_types::core::ics02_client::msgs::{create_client, misbehaviour, update_client};
+use ibc_relayer_types::core::ics03_connection::msgs::{
+    conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::{
+    acknowledgement, chan_close_confirm, chan_close_init, chan_open_ack, chan_open_confirm,
+    chan_open_init, chan_open_try, recv_packet, timeout, timeout_on_close,
+};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::{QueryTxHash, QueryTxRequest};
+use ibc_relayer::event::IbcEventWithHeight;
+
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_error, ErrorCode, Output};
+use crate::error::Error;
+use crate::prelude::app_config;
+
+/// Query the IBC messages of a transaction and the events they emitted
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct QueryTxDecodeCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain to query"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "hash",
+        required = true,
+        value_name = "HASH",
+        help_heading = "REQUIRED",
+        help = "Transaction hash to query"
+    )]
+    hash: String,
+}
+
+/// The messages of a transaction, decoded into their domain types
+/// when they are IBC messages, along with the IBC events they emitted.
+#[derive(Debug, Serialize)]
+struct DecodedTx {
+    messages: Vec<DecodedMessage>,
+    events: Vec<IbcEventWithHeight>,
+}
+
+#[derive(Serialize)]
+struct DecodedMessage {
+    type_url: String,
+    #[serde(serialize_with = "serialize_debug")]
+    message: Option<Box<dyn Debug>>,
+}
+
+impl Debug for DecodedMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.message {
+            Some(message) => message.fmt(f),
+            None => f
+                .debug_struct("UnknownMessage")
+                .field("type_url", &self.type_url)
+                .finish(),
+        }
+    }
+}
+
+fn serialize_debug<S: Serializer>(
+    message: &Option<Box<dyn Debug>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match message {
+        Some(message) => serializer.collect_str(&format_args!("{:?}", message)),
+        None => serializer.serialize_none(),
+    }
+}
+
+macro_rules! decode_ibc_message {
+    ($msg:expr, $($module:ident::$ty:ident),* $(,)?) => {
+        match $msg.type_url.as_str() {
+            $(
+                $module::TYPE_URL => <$module::$ty>::decode_vec(&$msg.value)
+                    .ok()
+                    .map(|m| Box::new(m) as Box<dyn Debug>),
+            )*
+            _ => None,
+        }
+    };
+}
+
+/// Decode the given message into its domain type if it is an IBC message.
+/// Messages of other modules, or which fail to decode, are left undecoded.
+fn decode_message(msg: Any) -> DecodedMessage {
+    let message = decode_ibc_message!(
+        msg,
+        create_client::MsgCreateClient,
+        update_client::MsgUpdateClient,
+        misbehaviour::MsgSubmitMisbehaviour,
+        conn_open_init::MsgConnectionOpenInit,
+        conn_open_try::MsgConnectionOpenTry,
+        conn_open_ack::MsgConnectionOpenAck,
+        conn_open_confirm::MsgConnectionOpenConfirm,
+        chan_open_init::MsgChannelOpenInit,
+        chan_open_try::MsgChannelOpenTry,
+        chan_open_ack::MsgChannelOpenAck,
+        chan_open_confirm::MsgChannelOpenConfirm,
+        chan_close_init::MsgChannelCloseInit,
+        chan_close_confirm::MsgChannelCloseConfirm,
+        recv_packet::MsgRecvPacket,
+        acknowledgement::MsgAcknowledgement,
+        timeout::MsgTimeout,
+        timeout_on_close::MsgTimeoutOnClose,
+        transfer::MsgTransfer,
+    );
+
+    DecodedMessage {
+        type_url: msg.type_url,
+        message,
+    }
+}
+
+// cargo run --bin hermes -- query tx decode --chain ibc-0 --hash B8E78AD83810239E21863AC7B5FC4F99396ABB39EB534F721EEF43A4979C2821
+impl Runnable for QueryTxDecodeCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
+
+        let hash = Hash::from_str(self.hash.as_str())
+            .map_err(|e| Error::invalid_hash(self.hash.clone(), e))
+            .unwrap_or_else(exit_with_error);
+
+        let res = chain
+            .query_tx_messages(QueryTxHash(hash))
+            .and_then(|messages| {
+                let events = chain.query_txs(QueryTxRequest::Transaction(QueryTxHash(hash)))?;
+                Ok((messages, events))
+            })
+            .map_err(Error::relayer);
+
+        match res {
+            Ok((messages, _)) if messages.is_empty() => {
+                Output::error(format!("transaction {} not found", self.hash))
+                    .with_code(ErrorCode::User)
+                    .exit()
+            }
+            Ok((messages, events)) => Output::success(DecodedTx {
+                messages: messages.into_iter().map(decode_message).collect(),
+                events,
+            })
+            .exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_message, QueryTxDecodeCmd};
+
+    use abscissa_core::clap::Parser;
+    use ibc_proto::google::protobuf::Any;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_query_tx_decode() {
+        assert_eq!(
+            QueryTxDecodeCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                hash: "abcdefg".to_owned()
+            },
+            QueryTxDecodeCmd::parse_from(["test", "--chain", "chain_id", "--hash", "abcdefg"])
+        )
+    }
+
+    #[test]
+    fn test_query_tx_decode_no_hash() {
+        assert!(QueryTxDecodeCmd::try_parse_from(["test", "--chain", "chain_id"]).is_err())
+    }
+
+    #[test]
+    fn test_decode_non_ibc_message() {
+        let decoded = decode_message(Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_owned(),
+            value: vec![],
+        });
+
+        assert!(decoded.message.is_none());
+    }
+}
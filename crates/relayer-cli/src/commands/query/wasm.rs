@@ -0,0 +1,13 @@
+//! `query wasm` subcommand
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+mod contract_channels;
+
+/// `query wasm` subcommand
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum WasmCmd {
+    /// Query the channels bound to a CosmWasm contract
+    ContractChannels(contract_channels::ContractChannelsCmd),
+}
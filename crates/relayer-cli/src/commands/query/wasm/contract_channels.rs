@@ -0,0 +1,112 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::{PageRequest, QueryChannelsRequest};
+use ibc_relayer_types::applications::wasm::WASM_PORT_PREFIX;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, PortChannelId, PortId};
+use ibc_relayer_types::core::ics24_host::validate::validate_wasm_port_identifier;
+
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_error, exit_with_unrecoverable_error, ErrorCode, Output};
+
+/// The data structure that represents the arguments when invoking the `query wasm contract-channels` CLI command.
+///
+/// The command has the following format:
+///
+/// `query wasm contract-channels --chain <CHAIN_ID> --contract <CONTRACT_ADDRESS>`
+///
+/// If successful the channels bound to the `wasm.<CONTRACT_ADDRESS>` port will be displayed.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct ContractChannelsCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the wasmd-based chain to query"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "contract",
+        required = true,
+        value_name = "CONTRACT_ADDRESS",
+        help_heading = "REQUIRED",
+        help = "Address of the CosmWasm contract"
+    )]
+    contract: String,
+}
+
+impl Runnable for ContractChannelsCmd {
+    fn run(&self) {
+        let port_id = format!("{}{}", WASM_PORT_PREFIX, self.contract);
+
+        validate_wasm_port_identifier(&port_id)
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::User));
+
+        let port_id: PortId = port_id
+            .parse()
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::User));
+
+        let config = app_config();
+
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
+
+        let res = chain.query_channels(QueryChannelsRequest {
+            pagination: Some(PageRequest::all()),
+        });
+
+        match res {
+            Ok(channels) => {
+                let ids: Vec<PortChannelId> = channels
+                    .into_iter()
+                    .filter(|channel| channel.port_id == port_id)
+                    .map(|channel| PortChannelId {
+                        port_id: channel.port_id,
+                        channel_id: channel.channel_id,
+                    })
+                    .collect();
+
+                Output::success(ids).exit()
+            }
+            Err(e) => Output::error(format!(
+                "there was a problem querying the channels of contract {}: {}",
+                self.contract, e
+            ))
+            .with_code(ErrorCode::Connectivity)
+            .exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContractChannelsCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_query_wasm_contract_channels() {
+        assert_eq!(
+            ContractChannelsCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                contract: "wasm1contract".to_owned()
+            },
+            ContractChannelsCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--contract",
+                "wasm1contract"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_wasm_contract_channels_no_contract() {
+        assert!(ContractChannelsCmd::try_parse_from(["test", "--chain", "chain_id"]).is_err())
+    }
+}
@@ -12,8 +12,8 @@ use ibc_relayer::registry::SharedRegistry;
 use ibc_relayer::rest;
 use ibc_relayer::supervisor::{cmd::SupervisorCmd, spawn_supervisor, SupervisorHandle};
 
-use crate::conclude::json;
 use crate::conclude::Output;
+use crate::conclude::{json, ErrorCode};
 use crate::prelude::*;
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
@@ -23,32 +23,46 @@ pub struct StartCmd {
         help = "Force a full scan of the chains for clients, connections and channels"
     )]
     full_scan: bool,
+
+    #[clap(
+        long = "allow-incompatible-versions",
+        help = "Relay on the chains running unsupported versions of their modules, instead of failing to start"
+    )]
+    allow_incompatible_versions: bool,
 }
 
 impl Runnable for StartCmd {
     fn run(&self) {
         let config = (*app_config()).clone();
 
-        let supervisor_handle = make_supervisor::<CachingChainHandle>(config, self.full_scan)
+        start(config, self.full_scan, self.allow_incompatible_versions);
+    }
+}
+
+/// Start the supervisor with the given configuration, and block until it exits.
+pub(crate) fn start(config: Config, full_scan: bool, allow_incompatible_versions: bool) {
+    let supervisor_handle =
+        make_supervisor::<CachingChainHandle>(config, full_scan, allow_incompatible_versions)
             .unwrap_or_else(|e| {
-                Output::error(format!("Hermes failed to start, last error: {}", e)).exit()
+                Output::error(format!("Hermes failed to start, last error: {}", e))
+                    .with_code(ErrorCode::Unknown)
+                    .exit()
             });
 
-        match crate::config::config_path() {
-            Some(_) => {
-                register_signals(supervisor_handle.sender.clone()).unwrap_or_else(|e| {
-                    warn!("failed to install signal handler: {}", e);
-                });
-            }
-            None => {
-                warn!("cannot figure out configuration path, skipping registration of signal handlers");
-            }
-        };
+    match crate::config::config_path() {
+        Some(_) => {
+            register_signals(supervisor_handle.sender.clone()).unwrap_or_else(|e| {
+                warn!("failed to install signal handler: {}", e);
+            });
+        }
+        None => {
+            warn!("cannot figure out configuration path, skipping registration of signal handlers");
+        }
+    };
 
-        info!("Hermes has started");
+    info!("Hermes has started");
 
-        supervisor_handle.wait();
-    }
+    supervisor_handle.wait();
 }
 
 /// Register the SIGHUP and SIGUSR1 signals, and notify the supervisor.
@@ -101,24 +115,27 @@ fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     Ok(())
 }
 
+/// Spawn the REST server if enabled, forwarding its requests to the supervisor
+/// through the given sender. Returns whether the server was spawned.
 #[cfg(feature = "rest-server")]
-fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
+fn spawn_rest_server(config: &Config, sender: Sender<rest::request::Request>) -> bool {
     let _span = tracing::error_span!("rest").entered();
 
     let rest = config.rest.clone();
 
     if rest.enabled {
-        let rest_config = ibc_relayer_rest::Config::new(rest.host, rest.port);
-        let (_, rest_receiver) = ibc_relayer_rest::server::spawn(rest_config);
-        Some(rest_receiver)
+        let rest_config =
+            ibc_relayer_rest::Config::new(rest.host, rest.port).with_websocket(rest.websocket);
+        let _ = ibc_relayer_rest::server::spawn_with_sender(rest_config, sender);
+        true
     } else {
         info!("REST server disabled");
-        None
+        false
     }
 }
 
 #[cfg(not(feature = "rest-server"))]
-fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
+fn spawn_rest_server(config: &Config, _sender: Sender<rest::request::Request>) -> bool {
     let rest = config.rest.clone();
 
     if rest.enabled {
@@ -126,11 +143,45 @@ fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
             "REST server enabled in the config but Hermes was built without REST support, \
              build Hermes with --features=rest-server to enable REST support."
         );
+    }
+
+    false
+}
+
+/// Spawn the gRPC admin server if enabled, forwarding its requests to the supervisor
+/// through the given sender. Returns whether the server was spawned.
+#[cfg(feature = "grpc-server")]
+fn spawn_grpc_server(config: &Config, sender: Sender<rest::request::Request>) -> bool {
+    let _span = tracing::error_span!("grpc").entered();
 
-        None
+    let grpc = config.grpc.clone();
+
+    if grpc.enabled {
+        let grpc_config = ibc_relayer_grpc::Config::new(grpc.host, grpc.port);
+
+        match ibc_relayer_grpc::server::spawn(grpc_config, sender) {
+            Ok(_) => true,
+            Err(e) => {
+                error!("gRPC admin server failed to start: {}", e);
+                false
+            }
+        }
     } else {
-        None
+        info!("gRPC admin server disabled");
+        false
+    }
+}
+
+#[cfg(not(feature = "grpc-server"))]
+fn spawn_grpc_server(config: &Config, _sender: Sender<rest::request::Request>) -> bool {
+    if config.grpc.enabled {
+        warn!(
+            "gRPC admin server enabled in the config but Hermes was built without gRPC support, \
+             build Hermes with --features=grpc-server to enable gRPC support."
+        );
     }
+
+    false
 }
 
 #[cfg(feature = "telemetry")]
@@ -173,19 +224,25 @@ fn spawn_telemetry_server(config: &Config) -> Result<(), Box<dyn Error + Send +
 fn make_supervisor<Chain: ChainHandle>(
     config: Config,
     force_full_scan: bool,
+    allow_incompatible_versions: bool,
 ) -> Result<SupervisorHandle, Box<dyn Error + Send + Sync>> {
     let registry = SharedRegistry::<Chain>::new(config.clone());
     spawn_telemetry_server(&config)?;
 
-    let rest = spawn_rest_server(&config);
+    // The REST and gRPC servers share the same channel to the supervisor
+    let (api_sender, api_receiver) = crossbeam_channel::unbounded();
+    let rest = spawn_rest_server(&config, api_sender.clone());
+    let grpc = spawn_grpc_server(&config, api_sender);
+    let api_receiver = (rest || grpc).then(|| api_receiver);
 
     Ok(spawn_supervisor(
         config,
         registry,
-        rest,
+        api_receiver,
         SupervisorOptions {
             health_check: true,
             force_full_scan,
+            allow_incompatible_versions,
         },
     )?)
 }
@@ -199,7 +256,10 @@ mod tests {
     #[test]
     fn test_start_required_only() {
         assert_eq!(
-            StartCmd { full_scan: false },
+            StartCmd {
+                full_scan: false,
+                allow_incompatible_versions: false,
+            },
             StartCmd::parse_from(["test"])
         )
     }
@@ -207,8 +267,22 @@ mod tests {
     #[test]
     fn test_start_full_scan() {
         assert_eq!(
-            StartCmd { full_scan: true },
+            StartCmd {
+                full_scan: true,
+                allow_incompatible_versions: false,
+            },
             StartCmd::parse_from(["test", "--full-scan"])
         )
     }
+
+    #[test]
+    fn test_start_allow_incompatible_versions() {
+        assert_eq!(
+            StartCmd {
+                full_scan: false,
+                allow_incompatible_versions: true,
+            },
+            StartCmd::parse_from(["test", "--allow-incompatible-versions"])
+        )
+    }
 }
@@ -6,6 +6,7 @@ use ibc_relayer::config::Config;
 mod channel;
 pub(crate) mod client;
 mod connection;
+mod multisig;
 mod packet;
 mod transfer;
 mod upgrade;
@@ -55,6 +56,10 @@ pub enum TxCmd {
 
     /// Send an IBC upgrade plan
     UpgradeChain(upgrade::TxIbcUpgradeChainCmd),
+
+    /// Build, sign and broadcast transactions from a multisig account
+    #[clap(subcommand)]
+    Multisig(multisig::TxMultisigCmd),
 }
 
 impl Override<Config> for TxCmd {
@@ -12,7 +12,7 @@ use ibc_relayer_types::core::ics24_host::identifier::{
 use ibc_relayer_types::events::IbcEvent;
 
 use crate::cli_utils::ChainHandlePair;
-use crate::conclude::Output;
+use crate::conclude::{ErrorCode, Output};
 use crate::error::Error;
 use crate::prelude::*;
 
@@ -23,7 +23,7 @@ macro_rules! tx_chan_cmd {
         let chains = match ChainHandlePair::spawn(&config, &$self.src_chain_id, &$self.dst_chain_id)
         {
             Ok(chains) => chains,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         };
 
         // Retrieve the connection
@@ -35,7 +35,9 @@ macro_rules! tx_chan_cmd {
             IncludeProof::No,
         ) {
             Ok((connection, _)) => connection,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
         };
 
         let channel = $chan(chains, dst_connection);
@@ -46,7 +48,7 @@ macro_rules! tx_chan_cmd {
 
         match res {
             Ok(receipt) => Output::success(receipt).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         }
     };
 }
@@ -114,7 +116,7 @@ impl Runnable for TxChanOpenInitCmd {
 
         let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
             Ok(chains) => chains,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         };
 
         // Retrieve the connection
@@ -126,7 +128,9 @@ impl Runnable for TxChanOpenInitCmd {
             IncludeProof::No,
         ) {
             Ok((connection, _)) => connection,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
         };
 
         let channel = Channel {
@@ -158,7 +162,7 @@ impl Runnable for TxChanOpenInitCmd {
 
         match res {
             Ok(receipt) => Output::success(receipt).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         }
     }
 }
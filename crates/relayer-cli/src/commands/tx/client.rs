@@ -15,6 +15,7 @@ use ibc_relayer::config::Config;
 use ibc_relayer::event::IbcEventWithHeight;
 use ibc_relayer::foreign_client::{CreateOptions, ForeignClient};
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::Height;
@@ -23,7 +24,7 @@ use tracing::debug;
 
 use crate::application::app_config;
 use crate::cli_utils::{spawn_chain_runtime, spawn_chain_runtime_generic, ChainHandlePair};
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::conclude::{exit_with_unrecoverable_error, ErrorCode, Output};
 use crate::error::Error;
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
@@ -71,6 +72,24 @@ pub struct TxCreateClientCmd {
     /// and trusted validator set is sufficient for a commit to be accepted going forward.
     #[clap(long = "trust-threshold", value_name = "TRUST_THRESHOLD", parse(try_from_str = parse_trust_threshold))]
     trust_threshold: Option<TrustThreshold>,
+
+    /// The height of the reference chain at which to create the client.
+    ///
+    /// The client is anchored at the header and validator set of the reference chain
+    /// at this height, which allows the host chain to verify proofs of the state of the
+    /// reference chain from before its latest height. The header at this height must
+    /// still be within the trusting period of the client.
+    /// Leave unspecified for latest height.
+    #[clap(long = "height", value_name = "REFERENCE_HEIGHT")]
+    height: Option<u64>,
+
+    /// Override the ICS-23 proof specs specified in the configuration.
+    ///
+    /// The proof specs, given as a JSON array, define the format of the proofs of the
+    /// reference chain which the client verifies, eg. for chains whose stores use other
+    /// IAVL parameters or a sparse Merkle tree.
+    #[clap(long = "proof-specs", value_name = "PROOF_SPECS", parse(try_from_str = parse_proof_specs))]
+    proof_specs: Option<ProofSpecs>,
 }
 
 /// Sample to run this tx:
@@ -80,20 +99,29 @@ impl Runnable for TxCreateClientCmd {
         let config = app_config();
 
         if self.src_chain_id == self.dst_chain_id {
-            Output::error("source and destination chains must be different".to_string()).exit()
+            Output::error("source and destination chains must be different".to_string())
+                .with_code(ErrorCode::User)
+                .exit()
         }
 
         let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
             Ok(chains) => chains,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         };
 
+        let height = self.height.map(|height| {
+            Height::new(chains.src.id().version(), height)
+                .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::User))
+        });
+
         let client = ForeignClient::restore(ClientId::default(), chains.dst, chains.src);
 
         let options = CreateOptions {
             max_clock_drift: self.clock_drift.map(Into::into),
             trusting_period: self.trusting_period.map(Into::into),
             trust_threshold: self.trust_threshold.map(Into::into),
+            height,
+            proof_specs: self.proof_specs.clone(),
         };
 
         // Trigger client creation via the "build" interface, so that we obtain the resulting event
@@ -103,7 +131,7 @@ impl Runnable for TxCreateClientCmd {
 
         match res {
             Ok(receipt) => Output::success(receipt.event).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         }
     }
 }
@@ -149,7 +177,7 @@ impl Runnable for TxUpdateClientCmd {
 
         let dst_chain = match spawn_chain_runtime(&config, &self.dst_chain_id) {
             Ok(handle) => handle,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         };
 
         let src_chain_id = match dst_chain.query_client_state(
@@ -165,29 +193,30 @@ impl Runnable for TxUpdateClientCmd {
                     "Query of client '{}' on chain '{}' failed with error: {}",
                     self.dst_client_id, self.dst_chain_id, e
                 ))
+                .with_code(ErrorCode::Connectivity)
                 .exit();
             }
         };
 
         let src_chain = match spawn_chain_runtime(&config, &src_chain_id) {
             Ok(handle) => handle,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         };
 
         let target_height = self.target_height.map_or(QueryHeight::Latest, |height| {
             QueryHeight::Specific(
                 Height::new(src_chain.id().version(), height)
-                    .unwrap_or_else(exit_with_unrecoverable_error),
+                    .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::User)),
             )
         });
 
         let trusted_height = self.trusted_height.map(|height| {
             Height::new(src_chain.id().version(), height)
-                .unwrap_or_else(exit_with_unrecoverable_error)
+                .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::User))
         });
 
         let client = ForeignClient::find(src_chain, dst_chain, &self.dst_client_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
 
         let res = client
             .build_update_client_and_send(target_height, trusted_height)
@@ -195,7 +224,7 @@ impl Runnable for TxUpdateClientCmd {
 
         match res {
             Ok(events) => Output::success(events).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         }
     }
 }
@@ -236,7 +265,7 @@ impl Runnable for TxUpgradeClientCmd {
 
         let host_chain = match spawn_chain_runtime(&config, &self.chain_id) {
             Ok(handle) => handle,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         };
 
         let reference_chain_id = match host_chain.query_client_state(
@@ -252,17 +281,18 @@ impl Runnable for TxUpgradeClientCmd {
                     "Query of client '{}' on chain '{}' failed with error: {}",
                     self.client_id, self.chain_id, e
                 ))
+                .with_code(ErrorCode::Connectivity)
                 .exit();
             }
         };
 
         let reference_chain = match spawn_chain_runtime(&config, &reference_chain_id) {
             Ok(handle) => handle,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         };
 
         let client = ForeignClient::find(reference_chain, host_chain, &self.client_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
 
         // In order to perform the client upgrade, the chain is paused at the height specified by
         // the user. When the chain is paused, the application height reports a height of 1 less
@@ -273,7 +303,7 @@ impl Runnable for TxUpgradeClientCmd {
             client.src_chain().id().version(),
             self.reference_upgrade_height,
         )
-        .unwrap_or_else(exit_with_unrecoverable_error);
+        .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::User));
 
         let target_reference_application_height = reference_upgrade_height
             .decrement()
@@ -282,7 +312,9 @@ impl Runnable for TxUpgradeClientCmd {
         let mut reference_application_latest_height = match client.src_chain().query_latest_height()
         {
             Ok(height) => height,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
         };
 
         debug!(
@@ -295,7 +327,9 @@ impl Runnable for TxUpgradeClientCmd {
 
             reference_application_latest_height = match client.src_chain().query_latest_height() {
                 Ok(height) => height,
-                Err(e) => Output::error(format!("{}", e)).exit(),
+                Err(e) => Output::error(format!("{}", e))
+                    .with_code(ErrorCode::Connectivity)
+                    .exit(),
             };
 
             debug!(
@@ -313,7 +347,9 @@ impl Runnable for TxUpgradeClientCmd {
 
         match outcome {
             Ok(receipt) => Output::success(receipt).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Tx)
+                .exit(),
         }
     }
 }
@@ -351,14 +387,14 @@ impl Runnable for TxUpgradeClientsCmd {
         let config = app_config();
         let reference_chain = match spawn_chain_runtime(&config, &self.reference_chain_id) {
             Ok(handle) => handle,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         };
 
         let reference_upgrade_height = Height::new(
             reference_chain.id().version(),
             self.reference_upgrade_height,
         )
-        .unwrap_or_else(exit_with_unrecoverable_error);
+        .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::User));
 
         let target_reference_application_height = reference_upgrade_height
             .decrement()
@@ -366,7 +402,9 @@ impl Runnable for TxUpgradeClientsCmd {
 
         let mut reference_application_latest_height = match reference_chain.query_latest_height() {
             Ok(height) => height,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
         };
 
         debug!(
@@ -379,7 +417,9 @@ impl Runnable for TxUpgradeClientsCmd {
 
             reference_application_latest_height = match reference_chain.query_latest_height() {
                 Ok(height) => height,
-                Err(e) => Output::error(format!("{}", e)).exit(),
+                Err(e) => Output::error(format!("{}", e))
+                    .with_code(ErrorCode::Connectivity)
+                    .exit(),
             };
 
             debug!(
@@ -414,7 +454,7 @@ impl Runnable for TxUpgradeClientsCmd {
         let output = OutputBuffer(results);
         match output.into_result() {
             Ok(events) => Output::success(events).exit(),
-            Err(e) => Output::error(e).exit(),
+            Err(e) => Output::error(e).with_code(ErrorCode::Tx).exit(),
         }
     }
 }
@@ -482,6 +522,17 @@ fn parse_trust_threshold(input: &str) -> Result<TrustThreshold, Error> {
         .map_err(|e| Error::cli_arg(format!("invalid trust threshold fraction: {}", e)))
 }
 
+fn parse_proof_specs(input: &str) -> Result<ProofSpecs, Error> {
+    let proof_specs: ProofSpecs = serde_json::from_str(input)
+        .map_err(|e| Error::cli_arg(format!("invalid proof specs: {}", e)))?;
+
+    proof_specs
+        .validate()
+        .map_err(|e| Error::cli_arg(format!("invalid proof specs: {}", e)))?;
+
+    Ok(proof_specs)
+}
+
 type UpgradeClientResult = Result<Vec<IbcEvent>, Error>;
 type UpgradeClientsForChainResult = Result<Vec<UpgradeClientResult>, Error>;
 
@@ -566,6 +617,7 @@ mod tests {
 
     use abscissa_core::clap::Parser;
     use humantime::Duration;
+    use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
     use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
     use tendermint_light_client_verifier::types::TrustThreshold;
 
@@ -592,7 +644,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                height: None,
+                proof_specs: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -612,7 +666,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("5s".parse::<Duration>().unwrap()),
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                height: None,
+                proof_specs: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -630,7 +686,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("3s".parse::<Duration>().unwrap()),
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                height: None,
+                proof_specs: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -652,7 +710,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: Some("5s".parse::<Duration>().unwrap()),
-                trust_threshold: None
+                trust_threshold: None,
+                height: None,
+                proof_specs: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -670,7 +730,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: Some("3s".parse::<Duration>().unwrap()),
-                trust_threshold: None
+                trust_threshold: None,
+                height: None,
+                proof_specs: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -692,7 +754,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: None,
-                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap())
+                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap()),
+                height: None,
+                proof_specs: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -714,7 +778,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("5s".parse::<Duration>().unwrap()),
                 trusting_period: Some("3s".parse::<Duration>().unwrap()),
-                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap())
+                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap()),
+                height: Some(42),
+                proof_specs: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -727,11 +793,75 @@ mod tests {
                 "--trusting-period",
                 "3s",
                 "--trust-threshold",
-                "1/2"
+                "1/2",
+                "--height",
+                "42"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_create_client_height() {
+        assert_eq!(
+            TxCreateClientCmd {
+                dst_chain_id: ChainId::from_string("host_chain"),
+                src_chain_id: ChainId::from_string("reference_chain"),
+                clock_drift: None,
+                trusting_period: None,
+                trust_threshold: None,
+                height: Some(42),
+                proof_specs: None
+            },
+            TxCreateClientCmd::parse_from([
+                "test",
+                "--host-chain",
+                "host_chain",
+                "--reference-chain",
+                "reference_chain",
+                "--height",
+                "42"
             ])
         )
     }
 
+    #[test]
+    fn test_create_client_proof_specs() {
+        let proof_specs = ProofSpecs::default();
+        let json = serde_json::to_string(&proof_specs).unwrap();
+
+        assert_eq!(
+            TxCreateClientCmd {
+                dst_chain_id: ChainId::from_string("host_chain"),
+                src_chain_id: ChainId::from_string("reference_chain"),
+                clock_drift: None,
+                trusting_period: None,
+                trust_threshold: None,
+                height: None,
+                proof_specs: Some(proof_specs)
+            },
+            TxCreateClientCmd::parse_from([
+                "test",
+                "--host-chain",
+                "host_chain",
+                "--reference-chain",
+                "reference_chain",
+                "--proof-specs",
+                &json
+            ])
+        );
+
+        assert!(TxCreateClientCmd::try_parse_from([
+            "test",
+            "--host-chain",
+            "host_chain",
+            "--reference-chain",
+            "reference_chain",
+            "--proof-specs",
+            "[]"
+        ])
+        .is_err())
+    }
+
     #[test]
     fn test_create_client_no_host_chain() {
         assert!(TxCreateClientCmd::try_parse_from([
@@ -0,0 +1,460 @@
+//! `tx multisig` subcommands, to submit transactions from a multisig account.
+//!
+//! The unsigned transaction built by `tx multisig build` is handed over to the
+//! members of the multisig account, who each sign it with `tx multisig sign`.
+//! `tx multisig broadcast` then collects the partial signatures, either from
+//! files or from signing endpoints, assembles them and broadcasts the transaction.
+//!
+//! The members sign in `SIGN_MODE_DIRECT`, see [`ibc_relayer::chain::cosmos::multisig`],
+//! so their signatures cannot be produced with the multisig commands of the Cosmos SDK.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use eyre::eyre;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use subtle_encoding::base64;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_relayer::chain::cosmos::gas::gas_amount_to_fee;
+use ibc_relayer::chain::cosmos::multisig::{
+    assemble_multisig_tx, broadcast_multisig_tx, build_multisig_tx, sign_multisig_tx, MultisigTx,
+    PartialSignature,
+};
+use ibc_relayer::chain::cosmos::query::account::query_account;
+use ibc_relayer::chain::cosmos::types::account::Account;
+use ibc_relayer::chain::cosmos::types::config::TxConfig;
+use ibc_relayer::config::types::Memo;
+use ibc_relayer::config::{ChainConfig, Config};
+use ibc_relayer::keyring::{KeyRing, Store};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::application::app_config;
+use crate::conclude::{ErrorCode, Output};
+
+/// `tx multisig` subcommands
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum TxMultisigCmd {
+    /// Build an unsigned transaction from a multisig account
+    Build(TxMultisigBuildCmd),
+
+    /// Sign a multisig transaction with the key of a member of the multisig account
+    Sign(TxMultisigSignCmd),
+
+    /// Collect the signatures of a multisig transaction, assemble them and broadcast the transaction
+    Broadcast(TxMultisigBroadcastCmd),
+}
+
+/// A message to include in a multisig transaction, as found in the messages file
+/// given to `tx multisig build`: a protobuf `Any` with a base64-encoded value.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct EncodedMsg {
+    type_url: String,
+    value: String,
+}
+
+impl EncodedMsg {
+    fn decode(self) -> eyre::Result<Any> {
+        let value = base64::decode(&self.value).map_err(|e| {
+            eyre!(
+                "invalid base64 value for message '{}': {}",
+                self.type_url,
+                e
+            )
+        })?;
+
+        Ok(Any {
+            type_url: self.type_url,
+            value,
+        })
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxMultisigBuildCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "key-name",
+        required = true,
+        value_name = "KEY_NAME",
+        help_heading = "REQUIRED",
+        help = "Name of the multisig key sending the transaction, as added with `keys add-multisig`"
+    )]
+    key_name: String,
+
+    #[clap(
+        long = "msgs",
+        required = true,
+        value_name = "MSGS_FILE",
+        help_heading = "REQUIRED",
+        help = "Path to a JSON file containing the list of messages to include in the transaction, each with a `type_url` and a base64-encoded `value`"
+    )]
+    msgs_file: PathBuf,
+
+    #[clap(
+        long = "output",
+        required = true,
+        value_name = "TX_FILE",
+        help_heading = "REQUIRED",
+        help = "Path of the file to write the unsigned transaction to"
+    )]
+    output: PathBuf,
+
+    #[clap(
+        long = "signers",
+        value_name = "SIGNERS",
+        multiple_values = true,
+        help = "Positions of the members of the multisig account which will sign the transaction (defaults to the first members, as many as the threshold)"
+    )]
+    signers: Vec<usize>,
+
+    #[clap(
+        long = "gas",
+        value_name = "GAS",
+        help = "Gas limit of the transaction (defaults to the `default_gas` defined in the config)"
+    )]
+    gas: Option<u64>,
+
+    #[clap(
+        long = "memo",
+        value_name = "MEMO",
+        help = "Memo of the transaction (defaults to the `memo_prefix` defined in the config)"
+    )]
+    memo: Option<String>,
+}
+
+impl TxMultisigBuildCmd {
+    fn execute(&self) -> eyre::Result<MultisigTx> {
+        let config = app_config();
+        let chain_config = find_chain(&config, &self.chain_id)?;
+        let tx_config = TxConfig::try_from(chain_config)?;
+
+        let keyring = KeyRing::new(Store::Test, &chain_config.account_prefix, &self.chain_id)?;
+        let key = keyring.get_multisig_key(&self.key_name)?;
+
+        let messages = read_json::<Vec<EncodedMsg>>(&self.msgs_file)?
+            .into_iter()
+            .map(EncodedMsg::decode)
+            .collect::<eyre::Result<Vec<_>>>()?;
+
+        let signers = if self.signers.is_empty() {
+            (0..key.threshold as usize).collect()
+        } else {
+            self.signers.clone()
+        };
+
+        let rt = tokio::runtime::Runtime::new()?;
+        let account: Account = rt
+            .block_on(query_account(&tx_config.grpc_address, &key.account))?
+            .into();
+
+        let gas = self.gas.unwrap_or(tx_config.gas_config.default_gas);
+        let fee = gas_amount_to_fee(&tx_config.gas_config, gas);
+
+        let memo = match &self.memo {
+            Some(memo) => Memo::new(memo)?,
+            None => chain_config.memo_prefix.clone(),
+        };
+
+        let tx = build_multisig_tx(&tx_config, &key, &signers, &account, &memo, &messages, &fee)?;
+
+        write_json(&self.output, &tx)?;
+
+        Ok(tx)
+    }
+}
+
+impl Runnable for TxMultisigBuildCmd {
+    fn run(&self) {
+        match self.execute() {
+            Ok(tx) => Output::success_msg(format!(
+                "Wrote the unsigned transaction from {} to {}, to be signed by {}",
+                tx.account,
+                self.output.display(),
+                tx.signers.join(", ")
+            ))
+            .exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Connectivity)
+                .exit(),
+        }
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxMultisigSignCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "tx",
+        required = true,
+        value_name = "TX_FILE",
+        help_heading = "REQUIRED",
+        help = "Path to the unsigned transaction, as output by `tx multisig build`"
+    )]
+    tx_file: PathBuf,
+
+    #[clap(
+        long = "output",
+        required = true,
+        value_name = "SIGNATURE_FILE",
+        help_heading = "REQUIRED",
+        help = "Path of the file to write the signature to"
+    )]
+    output: PathBuf,
+
+    #[clap(
+        long = "key-name",
+        value_name = "KEY_NAME",
+        help = "Name of the key of the member of the multisig account signing the transaction (defaults to the `key_name` defined in the config)"
+    )]
+    key_name: Option<String>,
+}
+
+impl TxMultisigSignCmd {
+    fn execute(&self) -> eyre::Result<PartialSignature> {
+        let config = app_config();
+        let chain_config = find_chain(&config, &self.chain_id)?;
+
+        let tx: MultisigTx = read_json(&self.tx_file)?;
+
+        if tx.chain_id != self.chain_id {
+            return Err(eyre!(
+                "the transaction is for chain {}, not {}",
+                tx.chain_id,
+                self.chain_id
+            ));
+        }
+
+        let key_name = self.key_name.as_ref().unwrap_or(&chain_config.key_name);
+
+        let keyring = KeyRing::new(Store::Test, &chain_config.account_prefix, &self.chain_id)?;
+        let key_entry = keyring.get_key(key_name)?;
+
+        let signature = sign_multisig_tx(&tx, &key_entry, &chain_config.address_type)?;
+
+        write_json(&self.output, &signature)?;
+
+        Ok(signature)
+    }
+}
+
+impl Runnable for TxMultisigSignCmd {
+    fn run(&self) {
+        match self.execute() {
+            Ok(signature) => Output::success_msg(format!(
+                "Wrote the signature of {} to {}",
+                signature.public_key,
+                self.output.display()
+            ))
+            .exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::User)
+                .exit(),
+        }
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxMultisigBroadcastCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "tx",
+        required = true,
+        value_name = "TX_FILE",
+        help_heading = "REQUIRED",
+        help = "Path to the unsigned transaction, as output by `tx multisig build`"
+    )]
+    tx_file: PathBuf,
+
+    #[clap(
+        long = "signature",
+        multiple_occurrences = true,
+        value_name = "SIGNATURE_FILE",
+        help = "Path to a signature, as output by `tx multisig sign`"
+    )]
+    signature_files: Vec<PathBuf>,
+
+    #[clap(
+        long = "signer-endpoint",
+        multiple_occurrences = true,
+        value_name = "URL",
+        help = "URL of a signing endpoint, to which the unsigned transaction is POSTed as JSON and which replies with a signature in the format output by `tx multisig sign`"
+    )]
+    signer_endpoints: Vec<String>,
+}
+
+impl TxMultisigBroadcastCmd {
+    fn execute(&self) -> eyre::Result<String> {
+        let config = app_config();
+        let chain_config = find_chain(&config, &self.chain_id)?;
+        let tx_config = TxConfig::try_from(chain_config)?;
+
+        let tx: MultisigTx = read_json(&self.tx_file)?;
+
+        let mut signatures = self
+            .signature_files
+            .iter()
+            .map(|path| read_json::<PartialSignature>(path))
+            .collect::<eyre::Result<Vec<_>>>()?;
+
+        for endpoint in &self.signer_endpoints {
+            let signature = ureq::post(endpoint)
+                .send_json(&tx)
+                .map_err(|e| eyre!("failed to request a signature from {}: {}", endpoint, e))?
+                .into_json::<PartialSignature>()
+                .map_err(|e| eyre!("invalid signature returned by {}: {}", endpoint, e))?;
+
+            signatures.push(signature);
+        }
+
+        let tx_bytes = assemble_multisig_tx(&tx, &signatures)?;
+
+        let rt = tokio::runtime::Runtime::new()?;
+        let events = rt.block_on(broadcast_multisig_tx(&tx_config, tx_bytes))?;
+
+        Ok(format!(
+            "Broadcast the transaction from {} ({} IBC events)",
+            tx.account,
+            events.len()
+        ))
+    }
+}
+
+impl Runnable for TxMultisigBroadcastCmd {
+    fn run(&self) {
+        match self.execute() {
+            Ok(msg) => Output::success_msg(msg).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Tx)
+                .exit(),
+        }
+    }
+}
+
+fn find_chain<'a>(config: &'a Config, chain_id: &ChainId) -> eyre::Result<&'a ChainConfig> {
+    config
+        .find_chain(chain_id)
+        .ok_or_else(|| eyre!("chain '{}' not found in configuration file", chain_id))
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> eyre::Result<T> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| eyre!("failed to read file {}: {}", path.display(), e))?;
+
+    serde_json::from_str(&content)
+        .map_err(|e| eyre!("failed to decode file {}: {}", path.display(), e))
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> eyre::Result<()> {
+    let content = serde_json::to_string_pretty(value)?;
+
+    fs::write(path, content).map_err(|e| eyre!("failed to write file {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TxMultisigBroadcastCmd, TxMultisigBuildCmd};
+
+    use std::path::PathBuf;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_tx_multisig_build() {
+        assert_eq!(
+            TxMultisigBuildCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                key_name: "multisig".to_owned(),
+                msgs_file: PathBuf::from("msgs.json"),
+                output: PathBuf::from("tx.json"),
+                signers: vec![0, 2],
+                gas: Some(300000),
+                memo: None,
+            },
+            TxMultisigBuildCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--key-name",
+                "multisig",
+                "--msgs",
+                "msgs.json",
+                "--output",
+                "tx.json",
+                "--signers",
+                "0",
+                "2",
+                "--gas",
+                "300000"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_tx_multisig_build_default_signers() {
+        let cmd = TxMultisigBuildCmd::parse_from([
+            "test",
+            "--chain",
+            "chain_id",
+            "--key-name",
+            "multisig",
+            "--msgs",
+            "msgs.json",
+            "--output",
+            "tx.json",
+        ]);
+
+        assert!(cmd.signers.is_empty());
+    }
+
+    #[test]
+    fn test_tx_multisig_broadcast() {
+        assert_eq!(
+            TxMultisigBroadcastCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                tx_file: PathBuf::from("tx.json"),
+                signature_files: vec![PathBuf::from("sig_a.json")],
+                signer_endpoints: vec!["http://127.0.0.1:3001/sign".to_owned()],
+            },
+            TxMultisigBroadcastCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--tx",
+                "tx.json",
+                "--signature",
+                "sig_a.json",
+                "--signer-endpoint",
+                "http://127.0.0.1:3001/sign"
+            ])
+        )
+    }
+}
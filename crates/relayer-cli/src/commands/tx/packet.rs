@@ -8,7 +8,7 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId
 use ibc_relayer_types::events::IbcEvent;
 
 use crate::cli_utils::ChainHandlePair;
-use crate::conclude::Output;
+use crate::conclude::{ErrorCode, Output};
 use crate::error::Error;
 use crate::prelude::*;
 
@@ -64,7 +64,7 @@ impl Runnable for TxPacketRecvCmd {
 
         let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
             Ok(chains) => chains,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         };
 
         let opts = LinkParameters {
@@ -73,7 +73,9 @@ impl Runnable for TxPacketRecvCmd {
         };
         let link = match Link::new_from_opts(chains.src, chains.dst, opts, false, false) {
             Ok(link) => link,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Tx)
+                .exit(),
         };
 
         let packet_data_query_height = self
@@ -88,7 +90,7 @@ impl Runnable for TxPacketRecvCmd {
 
         match res {
             Ok(ev) => Output::success(ev).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         }
     }
 }
@@ -145,7 +147,7 @@ impl Runnable for TxPacketAckCmd {
 
         let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
             Ok(chains) => chains,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         };
 
         let opts = LinkParameters {
@@ -154,7 +156,9 @@ impl Runnable for TxPacketAckCmd {
         };
         let link = match Link::new_from_opts(chains.src, chains.dst, opts, false, false) {
             Ok(link) => link,
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e))
+                .with_code(ErrorCode::Tx)
+                .exit(),
         };
 
         let packet_data_query_height = self
@@ -167,7 +171,7 @@ impl Runnable for TxPacketAckCmd {
 
         match res {
             Ok(ev) => Output::success(ev).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         }
     }
 }
@@ -13,10 +13,13 @@ use ibc_relayer::{
 use ibc_relayer_types::{
     applications::transfer::Amount,
     core::ics24_host::identifier::{ChainId, ChannelId, PortId},
+    timestamp::Timestamp,
+    Height,
 };
+use tendermint::Time;
 
 use crate::cli_utils::{check_can_send_on_channel, ChainHandlePair};
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::conclude::{exit_with_error, exit_with_unrecoverable_error, ErrorCode, Output};
 use crate::error::Error;
 use crate::prelude::*;
 
@@ -84,6 +87,22 @@ pub struct TxIcs20MsgTransferCmd {
     )]
     timeout_seconds: u64,
 
+    #[clap(
+        long = "timeout-height",
+        value_name = "TIMEOUT_HEIGHT",
+        conflicts_with = "timeout-height-offset",
+        help = "Timeout at the given absolute height of the destination chain, in its current revision"
+    )]
+    timeout_height: Option<u64>,
+
+    #[clap(
+        long = "timeout-timestamp",
+        value_name = "TIMEOUT_TIMESTAMP",
+        conflicts_with = "timeout-seconds",
+        help = "Timeout at the given absolute time of the destination chain, in RFC 3339 format (e.g. `2022-11-01T12:00:00Z`)"
+    )]
+    timeout_timestamp: Option<Time>,
+
     #[clap(
         long = "receiver",
         value_name = "RECEIVER",
@@ -91,6 +110,13 @@ pub struct TxIcs20MsgTransferCmd {
     )]
     receiver: Option<String>,
 
+    #[clap(
+        long = "memo",
+        value_name = "MEMO",
+        help = "Memo of the transfer, e.g. the instructions of the middlewares of the destination chain. Only understood by the chains running ibc-go v6 or later"
+    )]
+    memo: Option<String>,
+
     #[clap(
         long = "denom",
         value_name = "DENOM",
@@ -154,6 +180,11 @@ impl TxIcs20MsgTransferCmd {
             return Err(eyre!("number of messages should be greater than zero"));
         }
 
+        let timeout_height = self
+            .timeout_height
+            .map(|height| Height::new(self.dst_chain_id.version(), height))
+            .transpose()?;
+
         let opts = TransferOptions {
             src_port_id: self.src_port_id.clone(),
             src_channel_id: self.src_channel_id.clone(),
@@ -162,6 +193,9 @@ impl TxIcs20MsgTransferCmd {
             receiver: self.receiver.clone(),
             timeout_height_offset: self.timeout_height_offset,
             timeout_duration: Duration::from_secs(self.timeout_seconds),
+            timeout_height,
+            timeout_timestamp: self.timeout_timestamp.map(Timestamp::from),
+            memo: self.memo.clone(),
             number_msgs,
         };
 
@@ -174,12 +208,12 @@ impl Runnable for TxIcs20MsgTransferCmd {
         let config = app_config();
 
         let opts = match self.validate_options(&config) {
-            Err(err) => Output::error(err).exit(),
+            Err(err) => Output::error(err).with_code(ErrorCode::Config).exit(),
             Ok(result) => result,
         };
 
         let chains = ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            .unwrap_or_else(exit_with_error);
 
         check_can_send_on_channel(
             &chains.src,
@@ -187,7 +221,7 @@ impl Runnable for TxIcs20MsgTransferCmd {
             &opts.src_port_id,
             &chains.dst.id(),
         )
-        .unwrap_or_else(exit_with_unrecoverable_error);
+        .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::User));
 
         // Checks pass, build and send the tx
         let res: Result<Vec<IbcEventWithHeight>, Error> =
@@ -196,7 +230,7 @@ impl Runnable for TxIcs20MsgTransferCmd {
 
         match res {
             Ok(ev) => Output::success(ev).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         }
     }
 }
@@ -208,6 +242,8 @@ mod tests {
         core::ics24_host::identifier::{ChainId, ChannelId, PortId},
     };
 
+    use tendermint::Time;
+
     use super::TxIcs20MsgTransferCmd;
 
     use abscissa_core::clap::Parser;
@@ -224,7 +260,10 @@ mod tests {
                 amount: Amount::from(42u64),
                 timeout_height_offset: 0,
                 timeout_seconds: 0,
+                timeout_height: None,
+                timeout_timestamp: None,
                 receiver: None,
+                memo: None,
                 denom: "samoleans".to_owned(),
                 number_msgs: None,
                 key_name: None
@@ -256,7 +295,10 @@ mod tests {
                 amount: Amount::from(42u64),
                 timeout_height_offset: 0,
                 timeout_seconds: 0,
+                timeout_height: None,
+                timeout_timestamp: None,
                 receiver: None,
+                memo: None,
                 denom: "samoleans".to_owned(),
                 number_msgs: None,
                 key_name: None
@@ -288,7 +330,10 @@ mod tests {
                 amount: Amount::from(42u64),
                 timeout_height_offset: 0,
                 timeout_seconds: 0,
+                timeout_height: None,
+                timeout_timestamp: None,
                 receiver: None,
+                memo: None,
                 denom: "my_denom".to_owned(),
                 number_msgs: None,
                 key_name: None
@@ -322,7 +367,10 @@ mod tests {
                 amount: Amount::from(42u64),
                 timeout_height_offset: 0,
                 timeout_seconds: 0,
+                timeout_height: None,
+                timeout_timestamp: None,
                 receiver: None,
+                memo: None,
                 denom: "samoleans".to_owned(),
                 number_msgs: None,
                 key_name: Some("key_name".to_owned())
@@ -356,7 +404,10 @@ mod tests {
                 amount: Amount::from(42u64),
                 timeout_height_offset: 0,
                 timeout_seconds: 0,
+                timeout_height: None,
+                timeout_timestamp: None,
                 receiver: None,
+                memo: None,
                 denom: "samoleans".to_owned(),
                 number_msgs: Some(21),
                 key_name: None
@@ -390,7 +441,10 @@ mod tests {
                 amount: Amount::from(42u64),
                 timeout_height_offset: 0,
                 timeout_seconds: 0,
+                timeout_height: None,
+                timeout_timestamp: None,
                 receiver: Some("receiver_addr".to_owned()),
+                memo: None,
                 denom: "samoleans".to_owned(),
                 number_msgs: None,
                 key_name: None
@@ -413,6 +467,43 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_ft_transfer_memo() {
+        assert_eq!(
+            TxIcs20MsgTransferCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                amount: Amount::from(42u64),
+                timeout_height_offset: 0,
+                timeout_seconds: 0,
+                timeout_height: None,
+                timeout_timestamp: None,
+                receiver: None,
+                memo: Some(r#"{"forward":{"port":"transfer","channel":"channel-1"}}"#.to_owned()),
+                denom: "samoleans".to_owned(),
+                number_msgs: None,
+                key_name: None
+            },
+            TxIcs20MsgTransferCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-channel",
+                "channel_sender",
+                "--amount",
+                "42",
+                "--memo",
+                r#"{"forward":{"port":"transfer","channel":"channel-1"}}"#
+            ])
+        )
+    }
+
     #[test]
     fn test_ft_transfer_timeout_height_offset() {
         assert_eq!(
@@ -424,7 +515,10 @@ mod tests {
                 amount: Amount::from(42u64),
                 timeout_height_offset: 21,
                 timeout_seconds: 0,
+                timeout_height: None,
+                timeout_timestamp: None,
                 receiver: None,
+                memo: None,
                 denom: "samoleans".to_owned(),
                 number_msgs: None,
                 key_name: None
@@ -458,7 +552,10 @@ mod tests {
                 amount: Amount::from(42u64),
                 timeout_height_offset: 0,
                 timeout_seconds: 21,
+                timeout_height: None,
+                timeout_timestamp: None,
                 receiver: None,
+                memo: None,
                 denom: "samoleans".to_owned(),
                 number_msgs: None,
                 key_name: None
@@ -481,6 +578,102 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_ft_transfer_timeout_height() {
+        assert_eq!(
+            TxIcs20MsgTransferCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                amount: Amount::from(42u64),
+                timeout_height_offset: 0,
+                timeout_seconds: 0,
+                timeout_height: Some(1000),
+                timeout_timestamp: None,
+                receiver: None,
+                memo: None,
+                denom: "samoleans".to_owned(),
+                number_msgs: None,
+                key_name: None
+            },
+            TxIcs20MsgTransferCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-channel",
+                "channel_sender",
+                "--amount",
+                "42",
+                "--timeout-height",
+                "1000"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ft_transfer_timeout_timestamp() {
+        assert_eq!(
+            TxIcs20MsgTransferCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                amount: Amount::from(42u64),
+                timeout_height_offset: 0,
+                timeout_seconds: 0,
+                timeout_height: None,
+                timeout_timestamp: Some(Time::from_str("2022-11-01T12:00:00Z").unwrap()),
+                receiver: None,
+                memo: None,
+                denom: "samoleans".to_owned(),
+                number_msgs: None,
+                key_name: None
+            },
+            TxIcs20MsgTransferCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-channel",
+                "channel_sender",
+                "--amount",
+                "42",
+                "--timeout-timestamp",
+                "2022-11-01T12:00:00Z"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ft_transfer_timeout_height_and_offset() {
+        assert!(TxIcs20MsgTransferCmd::try_parse_from([
+            "test",
+            "--dst-chain",
+            "chain_receiver",
+            "--src-chain",
+            "chain_sender",
+            "--src-port",
+            "port_sender",
+            "--src-channel",
+            "channel_sender",
+            "--amount",
+            "42",
+            "--timeout-height",
+            "1000",
+            "--timeout-height-offset",
+            "21"
+        ])
+        .is_err())
+    }
+
     #[test]
     fn test_ft_transfer_no_amount() {
         assert!(TxIcs20MsgTransferCmd::try_parse_from([
@@ -8,7 +8,7 @@ use ibc_relayer::upgrade_chain::{build_and_send_ibc_upgrade_proposal, UpgradePla
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 
 use crate::cli_utils::spawn_chain_runtime;
-use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::conclude::{exit_with_error, ErrorCode, Output};
 use crate::error::Error;
 use crate::prelude::*;
 
@@ -132,22 +132,22 @@ impl Runnable for TxIbcUpgradeChainCmd {
         let config = app_config();
 
         let opts = match self.validate_options(&config) {
-            Err(err) => Output::error(err).exit(),
+            Err(err) => Output::error(err).with_code(ErrorCode::Config).exit(),
             Ok(result) => result,
         };
 
-        let host_chain = spawn_chain_runtime(&config, &self.host_chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let host_chain =
+            spawn_chain_runtime(&config, &self.host_chain_id).unwrap_or_else(exit_with_error);
 
-        let reference_chain = spawn_chain_runtime(&config, &self.reference_chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let reference_chain =
+            spawn_chain_runtime(&config, &self.reference_chain_id).unwrap_or_else(exit_with_error);
 
         let res = build_and_send_ibc_upgrade_proposal(reference_chain, host_chain, &opts)
             .map_err(Error::upgrade_chain);
 
         match res {
             Ok(ev) => Output::success(ev).exit(),
-            Err(e) => Output::error(format!("{}", e)).exit(),
+            Err(e) => Output::error(format!("{}", e)).with_code(e.code()).exit(),
         }
     }
 }
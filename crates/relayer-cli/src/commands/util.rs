@@ -0,0 +1,16 @@
+//! `util` subcommand
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use self::denom::DenomCmd;
+
+pub mod denom;
+
+/// `util` subcommand
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum UtilCmd {
+    /// Compute, expand and trace back the denominations of ICS-20 vouchers
+    #[clap(subcommand)]
+    Denom(DenomCmd),
+}
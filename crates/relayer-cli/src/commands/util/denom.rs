@@ -0,0 +1,44 @@
+//! `util denom` subcommand
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use self::expand::DenomExpandCmd;
+use self::hash::DenomHashCmd;
+use self::origin::DenomOriginCmd;
+
+pub mod expand;
+pub mod hash;
+pub mod origin;
+
+/// The prefix of the denomination of the vouchers minted by the transfer module.
+const IBC_DENOM_PREFIX: &str = "ibc/";
+
+/// `util denom` subcommand
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum DenomCmd {
+    /// Compute the IBC denomination of the voucher for a denomination trace
+    Hash(DenomHashCmd),
+
+    /// Expand the IBC denomination of a voucher into its denomination trace
+    Expand(DenomExpandCmd),
+
+    /// Trace a voucher back to the chain it originates from
+    Origin(DenomOriginCmd),
+}
+
+/// The hash of the given IBC denomination, with or without its `ibc/` prefix.
+fn denom_hash(denom: &str) -> &str {
+    denom.strip_prefix(IBC_DENOM_PREFIX).unwrap_or(denom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::denom_hash;
+
+    #[test]
+    fn strip_ibc_denom_prefix() {
+        assert_eq!(denom_hash("ibc/27394FB0"), "27394FB0");
+        assert_eq!(denom_hash("27394FB0"), "27394FB0");
+    }
+}
@@ -0,0 +1,89 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use super::denom_hash;
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_error, json, ErrorCode, Output};
+
+/// The data structure that represents the arguments when invoking the `util denom expand` CLI command.
+///
+/// The command has the following format:
+///
+/// `util denom expand --chain <CHAIN_ID> --denom <DENOM>`
+///
+/// If successful the full denomination trace of the voucher is displayed.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct DenomExpandCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain holding the voucher"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "denom",
+        required = true,
+        value_name = "DENOM",
+        help_heading = "REQUIRED",
+        help = "IBC denomination of the voucher, with or without its 'ibc/' prefix"
+    )]
+    denom: String,
+}
+
+impl Runnable for DenomExpandCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
+
+        match chain.query_denom_trace(denom_hash(&self.denom).to_owned()) {
+            Ok(denom_trace) if json() => Output::success(denom_trace).exit(),
+            Ok(denom_trace) => {
+                Output::success_msg(format!("{}/{}", denom_trace.path, denom_trace.base_denom))
+                    .exit()
+            }
+            Err(e) => Output::error(format!(
+                "there was a problem querying the denomination trace: {}",
+                e
+            ))
+            .with_code(ErrorCode::Connectivity)
+            .exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DenomExpandCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_denom_expand() {
+        assert_eq!(
+            DenomExpandCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                denom: "ibc/27394FB0".to_owned()
+            },
+            DenomExpandCmd::parse_from(["test", "--chain", "chain_id", "--denom", "ibc/27394FB0"])
+        )
+    }
+
+    #[test]
+    fn test_denom_expand_no_denom() {
+        assert!(DenomExpandCmd::try_parse_from(["test", "--chain", "chain_id"]).is_err())
+    }
+
+    #[test]
+    fn test_denom_expand_no_chain() {
+        assert!(DenomExpandCmd::try_parse_from(["test", "--denom", "ibc/27394FB0"]).is_err())
+    }
+}
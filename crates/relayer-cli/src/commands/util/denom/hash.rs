@@ -0,0 +1,72 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::chain::cosmos::query::denom_trace::compute_denom_hash;
+use ibc_relayer_types::applications::transfer::denom::PrefixedDenom;
+
+use super::IBC_DENOM_PREFIX;
+use crate::conclude::{ErrorCode, Output};
+
+/// The data structure that represents the arguments when invoking the `util denom hash` CLI command.
+///
+/// The command has the following format:
+///
+/// `util denom hash --trace <TRACE>`
+///
+/// If successful the IBC denomination of the vouchers with the given trace, ie. `ibc/<HASH>`,
+/// is displayed. No chain is queried, the hash is computed the same way as the transfer module does.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct DenomHashCmd {
+    #[clap(
+        long = "trace",
+        required = true,
+        value_name = "TRACE",
+        help_heading = "REQUIRED",
+        help = "Full denomination trace of the voucher, e.g. 'transfer/channel-0/uatom'"
+    )]
+    trace: PrefixedDenom,
+}
+
+impl Runnable for DenomHashCmd {
+    fn run(&self) {
+        if self.trace.trace_path().is_empty() {
+            Output::error(format!(
+                "'{}' is a base denomination, not the trace of a voucher",
+                self.trace
+            ))
+            .with_code(ErrorCode::User)
+            .exit()
+        }
+
+        let hash = compute_denom_hash(&self.trace.to_string());
+
+        Output::success_msg(format!("{}{}", IBC_DENOM_PREFIX, hash)).exit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DenomHashCmd;
+
+    use abscissa_core::clap::Parser;
+
+    #[test]
+    fn test_denom_hash() {
+        assert_eq!(
+            DenomHashCmd {
+                trace: "transfer/channel-0/uatom".parse().unwrap()
+            },
+            DenomHashCmd::parse_from(["test", "--trace", "transfer/channel-0/uatom"])
+        )
+    }
+
+    #[test]
+    fn test_denom_hash_invalid_trace() {
+        assert!(DenomHashCmd::try_parse_from(["test", "--trace", "transfer/uatom"]).is_err())
+    }
+
+    #[test]
+    fn test_denom_hash_no_trace() {
+        assert!(DenomHashCmd::try_parse_from(["test"]).is_err())
+    }
+}
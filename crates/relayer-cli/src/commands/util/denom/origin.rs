@@ -0,0 +1,191 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use serde::Serialize;
+
+use ibc_relayer::chain::counterparty::counterparty_chain_from_channel;
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::config::Config;
+use ibc_relayer_types::applications::transfer::denom::PrefixedDenom;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+use super::{denom_hash, IBC_DENOM_PREFIX};
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{json, ErrorCode, Output};
+
+/// The data structure that represents the arguments when invoking the `util denom origin` CLI command.
+///
+/// The command has the following format:
+///
+/// `util denom origin --chain <CHAIN_ID> --denom <DENOM>`
+///
+/// If successful the chains the voucher went through are displayed, from the given chain
+/// back to the chain the base denomination originates from. The counterparty of each channel
+/// in the trace is queried on the chain at its end, so the voucher can only be traced back
+/// through the chains in the configuration.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct DenomOriginCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain holding the voucher"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "denom",
+        required = true,
+        value_name = "DENOM",
+        help_heading = "REQUIRED",
+        help = "IBC denomination of the voucher, or its full denomination trace"
+    )]
+    denom: String,
+}
+
+/// A channel in the trace of a voucher, on the chain which received the voucher
+/// through it, along with the chain the voucher was sent from.
+#[derive(Debug, Serialize)]
+pub struct DenomHop {
+    pub chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_chain_id: ChainId,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DenomOrigin {
+    pub trace: String,
+    pub hops: Vec<DenomHop>,
+    pub base_denom: String,
+
+    /// The chain the base denomination originates from, unless the voucher
+    /// went through a chain which is not in the configuration.
+    pub origin_chain_id: Option<ChainId>,
+}
+
+impl Display for DenomOrigin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        writeln!(f, "trace: {}", self.trace)?;
+
+        for hop in &self.hops {
+            writeln!(
+                f,
+                "  {}: {}/{} -> {}",
+                hop.chain_id, hop.port_id, hop.channel_id, hop.counterparty_chain_id
+            )?;
+        }
+
+        match &self.origin_chain_id {
+            Some(chain_id) => write!(f, "origin: {} on {}", self.base_denom, chain_id),
+            None => write!(
+                f,
+                "origin: unknown, the voucher went through a chain which is not in the configuration"
+            ),
+        }
+    }
+}
+
+impl DenomOriginCmd {
+    fn execute(&self) -> eyre::Result<DenomOrigin> {
+        let config = app_config();
+
+        let denom = if self.denom.starts_with(IBC_DENOM_PREFIX) {
+            let chain = spawn_chain_runtime(&config, &self.chain_id)?;
+            let denom_trace = chain.query_denom_trace(denom_hash(&self.denom).to_owned())?;
+
+            format!("{}/{}", denom_trace.path, denom_trace.base_denom).parse()?
+        } else {
+            self.denom.parse()?
+        };
+
+        trace_origin(&config, &self.chain_id, &denom)
+    }
+}
+
+/// Follow the trace of the given denomination back from the given chain,
+/// for as long as the chains the voucher went through are in the configuration.
+fn trace_origin(
+    config: &Config,
+    chain_id: &ChainId,
+    denom: &PrefixedDenom,
+) -> eyre::Result<DenomOrigin> {
+    let mut hops = Vec::new();
+    let mut chain_id = Some(chain_id.clone());
+
+    for prefix in denom.trace_path().prefixes() {
+        let current_chain_id = match chain_id.take() {
+            Some(current) if config.has_chain(&current) => current,
+            _ => break,
+        };
+
+        let chain = spawn_chain_runtime(config, &current_chain_id)?;
+        let counterparty_chain_id =
+            counterparty_chain_from_channel(&chain, prefix.channel_id(), prefix.port_id())?;
+
+        hops.push(DenomHop {
+            chain_id: current_chain_id,
+            port_id: prefix.port_id().clone(),
+            channel_id: prefix.channel_id().clone(),
+            counterparty_chain_id: counterparty_chain_id.clone(),
+        });
+
+        chain_id = Some(counterparty_chain_id);
+    }
+
+    // The chain is only left unset if the trace could not be followed all the way back
+    Ok(DenomOrigin {
+        trace: denom.to_string(),
+        hops,
+        base_denom: denom.base_denom().to_string(),
+        origin_chain_id: chain_id,
+    })
+}
+
+impl Runnable for DenomOriginCmd {
+    fn run(&self) {
+        match self.execute() {
+            Ok(origin) if json() => Output::success(origin).exit(),
+            Ok(origin) => Output::success_msg(origin).exit(),
+            Err(e) => Output::error(format!(
+                "there was a problem tracing the origin of the denomination: {}",
+                e
+            ))
+            .with_code(ErrorCode::Connectivity)
+            .exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DenomOriginCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_denom_origin() {
+        assert_eq!(
+            DenomOriginCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                denom: "transfer/channel-0/uatom".to_owned()
+            },
+            DenomOriginCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--denom",
+                "transfer/channel-0/uatom"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_denom_origin_no_denom() {
+        assert!(DenomOriginCmd::try_parse_from(["test", "--chain", "chain_id"]).is_err())
+    }
+}
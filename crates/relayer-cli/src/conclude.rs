@@ -11,9 +11,18 @@
 //!
 //! ```ignore
 //! let e = String::from("error message");
-//! Output::error(e).exit();
+//! Output::error(e).with_code(ErrorCode::User).exit();
 //! // or as an alternative:
-//! Output::error(json!("error occurred")).exit();
+//! Output::error(json!("error occurred")).with_code(ErrorCode::User).exit();
+//! ```
+//!
+//! - Exit from a query/tx with an error of a specific class, so that scripts can tell the
+//! failure apart through the process exit code:
+//!
+//! ```ignore
+//! Output::error("the transaction failed").with_code(ErrorCode::Tx).exit();
+//! // or, from an error raised within the CLI:
+//! Output::error(format!("{}", e)).with_code(e.code()).exit();
 //! ```
 //!
 //! - Exit from a query/tx with an error of type `anomaly`:
@@ -23,7 +32,7 @@
 //!
 //! ```ignore
 //! let e: Error = Kind::Query.into();
-//! Output::error(format!("{}", e)).exit();
+//! Output::error(format!("{}", e)).with_code(ErrorCode::Connectivity).exit();
 //! ```
 //!
 //! #### Note:
@@ -54,13 +63,23 @@
 //! let end = ConnectionEnd::default();
 //! Output::success(h).with_result(end).exit();
 //! ```
+//!
+//! - Report the progress of a long-running command, without exiting:
+//!
+//! ```ignore
+//! Output::progress(events).print();
+//! ```
+//! In JSON mode, each progress report is printed on its own line ahead of the final output,
+//! so that the output of the command can be consumed as JSON Lines while it runs.
 
 use console::style;
 use core::fmt;
 
+use ibc_relayer::util::profile;
 use serde::Serialize;
 use tracing::warn;
 
+use crate::error::Error;
 use crate::prelude::app_reader;
 
 /// Functional-style method to exit a program.
@@ -68,26 +87,57 @@ use crate::prelude::app_reader;
 /// ## Note: See `Output::exit()` for the preferred method of exiting a relayer command.
 pub fn exit_with(out: Output) -> ! {
     let status = out.status;
+    let code = out.error_code;
 
     // Handle the output message
-    if json() {
-        println!("{}", serde_json::to_string(&out.into_json()).unwrap());
-    } else {
-        let status = match out.status {
-            Status::Success => style("SUCCESS").green(),
-            Status::Error => style("ERROR").red(),
-        };
-        println!("{} {}", status, out.result);
+    out.print();
+
+    if profile::is_enabled() {
+        report_profile();
     }
 
     // The return code
     if status == Status::Error {
-        std::process::exit(1);
+        std::process::exit(code.exit_code());
     } else {
         std::process::exit(0);
     }
 }
 
+/// Print the time spent in each phase of the command to `stderr`,
+/// so that it does not interfere with the result printed to `stdout`.
+fn report_profile() {
+    let report = profile::report();
+
+    if json() {
+        let phases: serde_json::Map<_, _> = report
+            .iter()
+            .map(|(phase, timing)| {
+                (
+                    serde_json::to_value(phase)
+                        .unwrap()
+                        .as_str()
+                        .unwrap()
+                        .to_owned(),
+                    serde_json::to_value(timing).unwrap(),
+                )
+            })
+            .collect();
+
+        eprintln!("{}", serde_json::json!({ "profile": phases }));
+    } else {
+        eprintln!("{}", style("PROFILE").cyan());
+        for (phase, timing) in report {
+            eprintln!(
+                "  {:<16}{:>6} calls  {:>12.3?}",
+                phase.to_string(),
+                timing.count,
+                timing.total
+            );
+        }
+    }
+}
+
 /// Returns true if the application global json flag `--json` is enabled.
 /// Returns false otherwise.
 pub fn json() -> bool {
@@ -95,8 +145,8 @@ pub fn json() -> bool {
     a.json_output()
 }
 
-/// Exits the program. Useful when a type produces an error which can no longer be propagated, and
-/// the program must exit instead.
+/// Exits the program with an error of the given class. Useful when a type produces an error
+/// which can no longer be propagated, and the program must exit instead.
 ///
 /// ## Example of use
 /// - Without this function:
@@ -104,16 +154,29 @@ pub fn json() -> bool {
 /// let res = ForeignClient::new(chains.src.clone(), chains.dst.clone());
 /// let client = match res {
 ///     Ok(client) => client,
-///     Err(e) => Output::error(format!("{}", e)).exit(),
+///     Err(e) => Output::error(format!("{}", e)).with_code(ErrorCode::Tx).exit(),
 /// };
 /// ```
 /// - With support from `exit_with_unrecoverable_error`:
 /// ```ignore
 /// let client_a = ForeignClient::new(chains.src.clone(), chains.dst.clone())
-///     .unwrap_or_else(exit_with_unrecoverable_error);
+///     .unwrap_or_else(exit_with_unrecoverable_error(ErrorCode::Tx));
 /// ```
-pub fn exit_with_unrecoverable_error<T, E: fmt::Display>(err: E) -> T {
-    Output::error(format!("{}", err)).exit()
+pub fn exit_with_unrecoverable_error<T, E: fmt::Display>(code: ErrorCode) -> impl FnOnce(E) -> T {
+    move |err| Output::error(format!("{}", err)).with_code(code).exit()
+}
+
+/// Exits the program with an error raised within the CLI, classified according to
+/// [`Error::code`]. The counterpart of `exit_with_unrecoverable_error` for the CLI `Error`.
+///
+/// ## Example of use
+/// ```ignore
+/// let chain = spawn_chain_runtime(&config, &self.chain_id).unwrap_or_else(exit_with_error);
+/// ```
+pub fn exit_with_error<T>(err: Error) -> T {
+    Output::error(format!("{}", err))
+        .with_code(err.code())
+        .exit()
 }
 
 /// The result to display before quitting, can either be a JSON value, some plain text,
@@ -138,13 +201,16 @@ impl fmt::Display for Result {
 }
 
 /// A CLI output with support for JSON serialization. The only mandatory field is the `status`,
-/// which typically signals a success (UNIX process return code `0`) or an error (code `1`). An
-/// optional `result` can be added to an output.
+/// which typically signals a success (UNIX process return code `0`) or an error (a non-zero
+/// code determined by the `error_code`). An optional `result` can be added to an output.
 ///
 pub struct Output {
     /// The return status
     pub status: Status,
 
+    /// The class of the error, only relevant when the status is `Status::Error`.
+    pub error_code: ErrorCode,
+
     /// The result of a command, such as the output from a query or transaction.
     pub result: Result,
 }
@@ -154,6 +220,7 @@ impl Output {
     pub fn new(status: Status) -> Self {
         Output {
             status,
+            error_code: ErrorCode::Unknown,
             result: Result::Nothing,
         }
     }
@@ -182,6 +249,12 @@ impl Output {
         self
     }
 
+    /// Builder-style method for setting the class of the error reported by an output object.
+    pub fn with_code(mut self, code: ErrorCode) -> Self {
+        self.error_code = code;
+        self
+    }
+
     /// Builder-style method for attaching a plain text message to an output object.
     pub fn with_msg(mut self, msg: impl ToString) -> Self {
         self.result = Result::Text(msg.to_string());
@@ -197,6 +270,20 @@ impl Output {
         Output::with_success().with_result(result)
     }
 
+    /// Quick-access constructor for an output reporting the progress of a command,
+    /// tagged with the input `result`.
+    pub fn progress<R>(result: R) -> Self
+    where
+        R: Serialize + core::fmt::Debug + 'static,
+    {
+        Output::new(Status::Progress).with_result(result)
+    }
+
+    /// Quick-access constructor for an output message reporting the progress of a command.
+    pub fn progress_msg(msg: impl ToString) -> Self {
+        Output::new(Status::Progress).with_msg(msg)
+    }
+
     /// Quick-access constructor for an output message signalling a error `status`.
     pub fn error(msg: impl ToString) -> Self {
         Output::with_error().with_msg(msg)
@@ -208,12 +295,30 @@ impl Output {
         Output::with_success().with_msg(msg)
     }
 
+    /// Prints the current output to stdout, as a single line in JSON mode, without exiting.
+    pub fn print(self) {
+        if json() {
+            println!("{}", serde_json::to_string(&self.into_json()).unwrap());
+        } else {
+            let status = match self.status {
+                Status::Success => style("SUCCESS").green(),
+                Status::Progress => style("PROGRESS").cyan(),
+                Status::Error => style("ERROR").red(),
+            };
+            println!("{} {}", status, self.result);
+        }
+    }
+
     /// Exits from the process with the current output. Convenience wrapper over `exit_with`.
     pub fn exit(self) -> ! {
         exit_with(self);
     }
 
-    /// Convert this output value to a JSON value
+    /// Convert this output value to a JSON value.
+    ///
+    /// A successful output is rendered as `{"status": "success", "result": ...}`,
+    /// a progress report as `{"status": "progress", "result": ...}`, while an error
+    /// is rendered as `{"status": "error", "error": {"code": ..., "message": ...}}`.
     pub fn into_json(self) -> serde_json::Value {
         let mut map = serde_json::Map::new();
 
@@ -229,7 +334,22 @@ impl Output {
             Result::Nothing => serde_json::Value::String("no output".to_string()),
         };
 
-        map.insert("result".to_string(), value);
+        match self.status {
+            Status::Success | Status::Progress => {
+                map.insert("result".to_string(), value);
+            }
+            Status::Error => {
+                let mut error = serde_json::Map::new();
+
+                error.insert(
+                    "code".to_string(),
+                    serde_json::to_value(self.error_code).unwrap(),
+                );
+                error.insert("message".to_string(), value);
+
+                map.insert("error".to_string(), serde_json::Value::Object(error));
+            }
+        }
 
         serde_json::Value::Object(map)
     }
@@ -259,6 +379,9 @@ pub enum Status {
     #[serde(rename(serialize = "success"))]
     Success,
 
+    #[serde(rename(serialize = "progress"))]
+    Progress,
+
     #[serde(rename(serialize = "error"))]
     Error,
 }
@@ -267,7 +390,95 @@ impl fmt::Display for Status {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Status::Success => write!(f, "Success"),
+            Status::Progress => write!(f, "Progress"),
             Status::Error => write!(f, "Error"),
         }
     }
 }
+
+/// The class of an error reported by a CLI command, which determines
+/// the exit code of the process.
+///
+/// Exit code `2` is shared with the errors reported by the argument parser
+/// when the command line is invalid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// An error which does not fall into any of the other classes.
+    Unknown,
+
+    /// Invalid arguments or input provided by the user.
+    User,
+
+    /// Missing or invalid configuration.
+    Config,
+
+    /// Failure to communicate with a chain.
+    Connectivity,
+
+    /// Failure to submit a transaction or to have it executed successfully.
+    Tx,
+}
+
+impl ErrorCode {
+    /// The code the process exits with when reporting an error of this class.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ErrorCode::Unknown => 1,
+            ErrorCode::User => 2,
+            ErrorCode::Config => 3,
+            ErrorCode::Connectivity => 4,
+            ErrorCode::Tx => 5,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ErrorCode, Output};
+
+    use serde_json::json;
+
+    #[test]
+    fn error_envelope() {
+        let out = Output::error("no connection").with_code(ErrorCode::Connectivity);
+
+        assert_eq!(out.error_code.exit_code(), 4);
+        assert_eq!(
+            out.into_json(),
+            json!({
+                "status": "error",
+                "error": {
+                    "code": "connectivity",
+                    "message": "no connection",
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn success_envelope() {
+        let out = Output::success_msg("done");
+
+        assert_eq!(
+            out.into_json(),
+            json!({
+                "status": "success",
+                "result": "done",
+            })
+        );
+    }
+
+    #[test]
+    fn progress_envelope() {
+        let out = Output::progress_msg("created client 07-tendermint-0");
+
+        assert_eq!(
+            out.into_json(),
+            json!({
+                "status": "progress",
+                "result": "created client 07-tendermint-0",
+            })
+        );
+    }
+}
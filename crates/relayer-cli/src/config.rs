@@ -8,7 +8,7 @@ use alloc::collections::BTreeSet;
 use std::path::PathBuf;
 
 use flex_error::{define_error, TraceError};
-use ibc_relayer::config::{ChainConfig, Config, ModeConfig};
+use ibc_relayer::config::{ChainConfig, Config, CoordinationConfig, ModeConfig};
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tendermint_light_client_verifier::types::TrustThreshold;
 use tracing_subscriber::filter::ParseError;
@@ -60,6 +60,31 @@ define_error! {
                     e.threshold, e.chain_id, e.reason)
             },
 
+        InvalidProofSpecs
+            {
+                chain_id: ChainId,
+                reason: String,
+            }
+            |e| {
+                format!("config file specifies invalid `proof_specs` for the chain '{0}', caused by: {1}",
+                    e.chain_id, e.reason)
+            },
+
+        SharedHandshakeKey
+            { chain_id: ChainId }
+            |e| {
+                format!("config file specifies the same `handshake_key_name` as `key_name` for the chain '{0}'; \
+                    the dedicated handshake runtime must sign with a different key",
+                    e.chain_id)
+            },
+
+        InvalidCoordination
+            { reason: String }
+            |e| {
+                format!("config file specifies invalid coordination config, caused by: {0}",
+                    e.reason)
+            },
+
         DeprecatedGasAdjustment
             {
                 gas_adjustment: f64,
@@ -96,11 +121,45 @@ pub fn validate_config(config: &Config) -> Result<(), Diagnostic<Error>> {
 
         // Validate gas-related settings
         validate_gas_settings(&c.id, c)?;
+
+        // Validate the custom proof specs, if any
+        validate_proof_specs(&c.id, c)?;
+
+        // Validate the key of the dedicated handshake runtime, if any
+        validate_handshake_key(&c.id, c)?;
     }
 
     // Check for invalid mode config
     validate_mode(&config.mode)?;
 
+    // Check that coordinated instances can be told apart
+    validate_coordination(&config.coordination)?;
+
+    Ok(())
+}
+
+fn validate_coordination(coordination: &CoordinationConfig) -> Result<(), Diagnostic<Error>> {
+    if !coordination.enabled {
+        return Ok(());
+    }
+
+    let has_instance_id = coordination
+        .instance_id
+        .as_ref()
+        .map_or(false, |id| !id.trim().is_empty());
+
+    if !has_instance_id {
+        return Err(Diagnostic::Error(Error::invalid_coordination(
+            "`instance_id` must be set to a name unique among the coordinated instances if `enabled` is set to true".to_string(),
+        )));
+    }
+
+    if coordination.claims_dir.is_none() {
+        return Err(Diagnostic::Error(Error::invalid_coordination(
+            "`claims_dir` must be set to a directory shared between the coordinated instances if `enabled` is set to true".to_string(),
+        )));
+    }
+
     Ok(())
 }
 
@@ -170,3 +229,21 @@ fn validate_gas_settings(id: &ChainId, config: &ChainConfig) -> Result<(), Diagn
 
     Ok(())
 }
+
+fn validate_handshake_key(id: &ChainId, config: &ChainConfig) -> Result<(), Diagnostic<Error>> {
+    if config.handshake_key_name.as_ref() == Some(&config.key_name) {
+        return Err(Diagnostic::Error(Error::shared_handshake_key(id.clone())));
+    }
+
+    Ok(())
+}
+
+fn validate_proof_specs(id: &ChainId, config: &ChainConfig) -> Result<(), Diagnostic<Error>> {
+    if let Some(proof_specs) = &config.proof_specs {
+        proof_specs.validate().map_err(|e| {
+            Diagnostic::Error(Error::invalid_proof_specs(id.clone(), e.to_string()))
+        })?;
+    }
+
+    Ok(())
+}
@@ -22,6 +22,13 @@ pub struct EntryPoint {
     #[clap(long = "json", help = "Enable JSON output")]
     pub json: bool,
 
+    /// Report the time spent in each phase of the command when it exits
+    #[clap(
+        long = "profile",
+        help = "Report the time spent in each phase of the command"
+    )]
+    pub profile: bool,
+
     /// Subcommand to execute.
     ///
     /// The `command` option will delegate option parsing to the command type,
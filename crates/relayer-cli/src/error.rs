@@ -16,11 +16,13 @@ use ibc_relayer::error::Error as RelayerError;
 use ibc_relayer::foreign_client::ForeignClientError;
 use ibc_relayer::keyring::errors::Error as KeyRingError;
 use ibc_relayer::link::error::LinkError;
-use ibc_relayer::spawn::SpawnError;
+use ibc_relayer::spawn::{SpawnError, SpawnErrorDetail};
 use ibc_relayer::supervisor::Error as SupervisorError;
 use ibc_relayer::transfer::TransferError;
 use ibc_relayer::upgrade_chain::UpgradeChainError;
 
+use crate::conclude::ErrorCode;
+
 define_error! {
     /// An error raised within the relayer CLI
     Error {
@@ -121,3 +123,34 @@ define_error! {
             |_| { "keyring error" },
     }
 }
+
+impl Error {
+    /// The class of this error, which determines the exit code of the process.
+    pub fn code(&self) -> ErrorCode {
+        match self.detail() {
+            ErrorDetail::Config(_) | ErrorDetail::MissingChainConfig(_) => ErrorCode::Config,
+            ErrorDetail::Spawn(e) => match e.source {
+                SpawnErrorDetail::MissingChainConfig(_) => ErrorCode::Config,
+                _ => ErrorCode::Connectivity,
+            },
+            ErrorDetail::Query(_) | ErrorDetail::Runtime(_) | ErrorDetail::Relayer(_) => {
+                ErrorCode::Connectivity
+            }
+            ErrorDetail::InvalidHash(_)
+            | ErrorDetail::CliArg(_)
+            | ErrorDetail::Keys(_)
+            | ErrorDetail::KeyRing(_)
+            | ErrorDetail::Signer(_)
+            | ErrorDetail::MissingCounterpartyChannelId(_) => ErrorCode::User,
+            ErrorDetail::Tx(_)
+            | ErrorDetail::Connection(_)
+            | ErrorDetail::Fee(_)
+            | ErrorDetail::Transfer(_)
+            | ErrorDetail::Channel(_)
+            | ErrorDetail::ForeignClient(_)
+            | ErrorDetail::Link(_)
+            | ErrorDetail::UpgradeChain(_) => ErrorCode::Tx,
+            ErrorDetail::Io(_) | ErrorDetail::Supervisor(_) => ErrorCode::Unknown,
+        }
+    }
+}
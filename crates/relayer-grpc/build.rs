@@ -0,0 +1,14 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Use the `protoc` binary bundled with `protoc-bin-vendored`, unless one is given
+    // explicitly, so that building this crate does not require `protoc` to be installed.
+    if std::env::var_os("PROTOC").is_none() {
+        std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    }
+
+    tonic_build::configure()
+        .build_server(true)
+        .build_client(true)
+        .compile(&["proto/hermes/admin/v1/admin.proto"], &["proto"])?;
+
+    Ok(())
+}
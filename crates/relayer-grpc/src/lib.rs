@@ -0,0 +1,21 @@
+//! gRPC admin service for Hermes.
+//!
+//! The service exposes the same requests as the REST server through versioned
+//! protobuf RPCs, along with a stream of the packet events observed by Hermes.
+//! Clients for other languages can be generated from the `.proto` definitions
+//! shipped in the `proto` directory of this crate.
+
+mod config;
+pub use config::Config;
+
+pub mod server;
+
+mod service;
+pub use service::AdminServer;
+
+/// Types and client/server stubs generated from the protobuf definitions.
+pub mod proto {
+    pub mod v1 {
+        tonic::include_proto!("hermes.admin.v1");
+    }
+}
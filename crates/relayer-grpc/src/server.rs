@@ -0,0 +1,64 @@
+use std::io;
+use std::net::ToSocketAddrs;
+use std::sync::Arc;
+use std::thread;
+
+use crossbeam_channel as channel;
+use tokio::sync::Notify;
+use tonic::transport::Server;
+use tracing::{error, info};
+
+use ibc_relayer::rest::request::Request;
+
+use crate::{proto::v1::admin_service_server::AdminServiceServer, AdminServer, Config};
+
+pub struct ServerHandle {
+    join_handle: thread::JoinHandle<()>,
+    stop: Arc<Notify>,
+}
+
+impl ServerHandle {
+    pub fn join(self) -> std::thread::Result<()> {
+        self.join_handle.join()
+    }
+
+    pub fn stop(&self) {
+        self.stop.notify_one();
+    }
+}
+
+/// Spawn the gRPC server in a thread of its own, forwarding
+/// the requests it receives to the supervisor through `sender`.
+pub fn spawn(config: Config, sender: channel::Sender<Request>) -> io::Result<ServerHandle> {
+    let address = config.address().to_socket_addrs()?.next().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("could not resolve address {}", config),
+        )
+    })?;
+
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?;
+
+    let stop = Arc::new(Notify::new());
+    let shutdown = stop.clone();
+
+    info!("starting gRPC admin server listening at {}", config);
+
+    let join_handle = thread::spawn(move || {
+        let service = AdminServiceServer::new(AdminServer::new(sender));
+
+        let result = rt.block_on(
+            Server::builder()
+                .add_service(service)
+                .serve_with_shutdown(address, async move { shutdown.notified().await }),
+        );
+
+        if let Err(e) = result {
+            error!("gRPC admin server failed: {}", e);
+        }
+    });
+
+    Ok(ServerHandle { join_handle, stop })
+}
@@ -0,0 +1,294 @@
+use core::fmt::Debug;
+use core::time::Duration;
+
+use crossbeam_channel as channel;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request as GrpcRequest, Response, Status};
+use tracing::{debug, error};
+
+use ibc_relayer::event::IbcEventWithHeight;
+use ibc_relayer::rest::{
+    request::{reply_channel, ReplySender, Request},
+    RestApiError,
+};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::proto::v1::{
+    admin_service_server::AdminService, ClearPacketsRequest, ClearPacketsResponse,
+    GetStatusRequest, GetStatusResponse, Height, ListWorkersRequest, ListWorkersResponse,
+    PacketEvent, StreamPacketEventsRequest, Worker,
+};
+
+/// Number of packet events buffered for each stream before the chains'
+/// subscriptions stop being drained, waiting for the client to catch up.
+const STREAM_BUFFER_SIZE: usize = 256;
+
+/// How often the packet events streams check whether their client went away
+/// while waiting for the next batch of events.
+const CLOSED_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Implementation of the [`AdminService`], which forwards the requests
+/// to the supervisor the same way the REST server does.
+#[derive(Clone, Debug)]
+pub struct AdminServer {
+    sender: channel::Sender<Request>,
+}
+
+impl AdminServer {
+    pub fn new(sender: channel::Sender<Request>) -> Self {
+        Self { sender }
+    }
+
+    /// Submit a request to the supervisor and wait for its reply,
+    /// without blocking the async runtime.
+    async fn submit<F, O>(&self, f: F) -> Result<O, Status>
+    where
+        F: FnOnce(ReplySender<O>) -> Request + Send + 'static,
+        O: Debug + Send + 'static,
+    {
+        let sender = self.sender.clone();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let (reply_sender, reply_receiver) = reply_channel();
+
+            sender
+                .send(f(reply_sender))
+                .map_err(|e| RestApiError::ChannelSend(e.to_string()))?;
+
+            reply_receiver
+                .recv()
+                .map_err(|e| RestApiError::ChannelRecv(e.to_string()))?
+        })
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?;
+
+        result.map_err(into_status)
+    }
+}
+
+#[tonic::async_trait]
+impl AdminService for AdminServer {
+    async fn get_status(
+        &self,
+        _request: GrpcRequest<GetStatusRequest>,
+    ) -> Result<Response<GetStatusResponse>, Status> {
+        let version = self
+            .submit(|reply_to| Request::Version { reply_to })
+            .await?;
+
+        let chain_ids = self
+            .submit(|reply_to| Request::GetChains { reply_to })
+            .await?;
+
+        Ok(Response::new(GetStatusResponse {
+            name: version.name,
+            version: version.version,
+            chain_ids: chain_ids.iter().map(ToString::to_string).collect(),
+        }))
+    }
+
+    async fn list_workers(
+        &self,
+        _request: GrpcRequest<ListWorkersRequest>,
+    ) -> Result<Response<ListWorkersResponse>, Status> {
+        let state = self.submit(|reply_to| Request::State { reply_to }).await?;
+
+        let workers = state
+            .workers
+            .into_iter()
+            .flat_map(|(object_type, descs)| {
+                let object_type = format!("{:?}", object_type).to_lowercase();
+
+                descs.into_iter().map(move |desc| Worker {
+                    id: desc.id.as_u64(),
+                    object_type: object_type.clone(),
+                    object: desc.object.short_name(),
+                    src_chain_id: desc.object.src_chain_id().to_string(),
+                    dst_chain_id: desc.object.dst_chain_id().to_string(),
+                })
+            })
+            .collect();
+
+        Ok(Response::new(ListWorkersResponse { workers }))
+    }
+
+    async fn clear_packets(
+        &self,
+        request: GrpcRequest<ClearPacketsRequest>,
+    ) -> Result<Response<ClearPacketsResponse>, Status> {
+        let chain_id = ChainId::from_string(&request.into_inner().chain_id);
+
+        self.submit(|reply_to| Request::ClearPackets { chain_id, reply_to })
+            .await?;
+
+        Ok(Response::new(ClearPacketsResponse {}))
+    }
+
+    type StreamPacketEventsStream = ReceiverStream<Result<PacketEvent, Status>>;
+
+    async fn stream_packet_events(
+        &self,
+        request: GrpcRequest<StreamPacketEventsRequest>,
+    ) -> Result<Response<Self::StreamPacketEventsStream>, Status> {
+        let chain_ids: Vec<ChainId> = match request.into_inner().chain_ids {
+            chain_ids if chain_ids.is_empty() => {
+                self.submit(|reply_to| Request::GetChains { reply_to })
+                    .await?
+            }
+            chain_ids => chain_ids
+                .iter()
+                .map(|id| ChainId::from_string(id))
+                .collect(),
+        };
+
+        let (tx, rx) = mpsc::channel(STREAM_BUFFER_SIZE);
+
+        for chain_id in chain_ids {
+            let subscription = self
+                .submit({
+                    let chain_id = chain_id.clone();
+                    move |reply_to| Request::SubscribeEvents { chain_id, reply_to }
+                })
+                .await?;
+
+            let tx = tx.clone();
+
+            // Forward the packet events of the chain until either the
+            // subscription is closed or the client goes away. The latter is
+            // checked periodically, so that the subscription is dropped even
+            // when the chain emits no packet events.
+            tokio::task::spawn_blocking(move || loop {
+                if tx.is_closed() {
+                    debug!(chain = %chain_id, "packet events stream closed by the client");
+                    return;
+                }
+
+                let batch = match subscription.recv_timeout(CLOSED_CHECK_INTERVAL) {
+                    Ok(batch) => batch,
+                    Err(channel::RecvTimeoutError::Timeout) => continue,
+                    Err(channel::RecvTimeoutError::Disconnected) => return,
+                };
+
+                let batch = match batch.as_ref() {
+                    Ok(batch) => batch,
+                    Err(e) => {
+                        error!(chain = %chain_id, "event subscription failed: {}", e);
+
+                        let _ = tx.blocking_send(Err(Status::unavailable(e.to_string())));
+                        return;
+                    }
+                };
+
+                for event in batch
+                    .events
+                    .iter()
+                    .filter_map(|e| packet_event(&chain_id, e))
+                {
+                    if tx.blocking_send(Ok(event)).is_err() {
+                        debug!(chain = %chain_id, "packet events stream closed by the client");
+                        return;
+                    }
+                }
+            });
+        }
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+}
+
+/// Convert the given event into a [`PacketEvent`], if it pertains to a packet.
+fn packet_event(chain_id: &ChainId, event: &IbcEventWithHeight) -> Option<PacketEvent> {
+    let packet = event.event.packet()?;
+
+    Some(PacketEvent {
+        chain_id: chain_id.to_string(),
+        height: Some(Height {
+            revision_number: event.height.revision_number(),
+            revision_height: event.height.revision_height(),
+        }),
+        event_type: event.event.event_type().as_str().to_string(),
+        sequence: packet.sequence.into(),
+        src_port_id: packet.source_port.to_string(),
+        src_channel_id: packet.source_channel.to_string(),
+        dst_port_id: packet.destination_port.to_string(),
+        dst_channel_id: packet.destination_channel.to_string(),
+    })
+}
+
+fn into_status(e: RestApiError) -> Status {
+    match e {
+        RestApiError::ChainConfigNotFound(_) => Status::not_found(e.to_string()),
+        RestApiError::InvalidChainId(_, _)
+        | RestApiError::InvalidPortId(_, _)
+        | RestApiError::InvalidChannelId(_, _)
+        | RestApiError::InvalidChainConfig(_) => Status::invalid_argument(e.to_string()),
+        RestApiError::Unimplemented => Status::unimplemented(e.to_string()),
+        RestApiError::Subscription(_, _) => Status::unavailable(e.to_string()),
+        RestApiError::PacketFilterUpdate(_, _) => Status::failed_precondition(e.to_string()),
+        RestApiError::ChannelSend(_)
+        | RestApiError::ChannelRecv(_)
+        | RestApiError::Serialization(_) => Status::internal(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::core::ics04_channel::events::SendPacket;
+    use ibc_relayer_types::core::ics04_channel::packet::Packet;
+    use ibc_relayer_types::events::IbcEvent;
+    use ibc_relayer_types::Height as IbcHeight;
+    use tonic::Code;
+
+    #[test]
+    fn packet_event_from_send_packet() {
+        let chain_id = ChainId::from_string("ibc-0");
+
+        let packet = Packet {
+            sequence: 42.into(),
+            ..Default::default()
+        };
+
+        let event = IbcEventWithHeight::new(
+            IbcEvent::SendPacket(SendPacket { packet }),
+            IbcHeight::new(0, 10).unwrap(),
+        );
+
+        let packet_event = packet_event(&chain_id, &event).expect("a packet event");
+
+        assert_eq!(packet_event.chain_id, "ibc-0");
+        assert_eq!(packet_event.event_type, "send_packet");
+        assert_eq!(packet_event.sequence, 42);
+        assert_eq!(
+            packet_event.height,
+            Some(Height {
+                revision_number: 0,
+                revision_height: 10
+            })
+        );
+    }
+
+    #[test]
+    fn status_codes() {
+        let chain_id = ChainId::from_string("ibc-0");
+
+        assert_eq!(
+            into_status(RestApiError::ChainConfigNotFound(chain_id.clone())).code(),
+            Code::NotFound
+        );
+        assert_eq!(
+            into_status(RestApiError::PacketFilterUpdate(
+                chain_id,
+                "denied by a wildcard pattern".to_string()
+            ))
+            .code(),
+            Code::FailedPrecondition
+        );
+        assert_eq!(
+            into_status(RestApiError::ChannelRecv("closed".to_string())).code(),
+            Code::Internal
+        );
+    }
+}
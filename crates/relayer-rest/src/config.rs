@@ -5,11 +5,21 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 pub struct Config {
     pub host: String,
     pub port: u16,
+    /// Whether to serve the packet events WebSocket endpoint
+    pub websocket: bool,
 }
 
 impl Config {
     pub fn new(host: String, port: u16) -> Self {
-        Self { host, port }
+        Self {
+            host,
+            port,
+            websocket: false,
+        }
+    }
+
+    pub fn with_websocket(self, websocket: bool) -> Self {
+        Self { websocket, ..self }
     }
 
     pub fn address(&self) -> (&str, u16) {
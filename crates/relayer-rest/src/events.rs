@@ -0,0 +1,352 @@
+//! WebSocket endpoint pushing the lifecycle events of the packets
+//! observed by the relayer to its subscribers, as they happen.
+
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crossbeam_channel as channel;
+use rouille::websocket::{self, Websocket};
+use serde::Serialize;
+use tracing::{debug, error};
+
+use ibc_relayer::config::filter::Wildcard;
+use ibc_relayer::event::IbcEventWithHeight;
+use ibc_relayer::rest::request::Request;
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::Height;
+
+use crate::handle::{all_chain_ids, subscribe_events};
+
+/// How long a connection may stay idle before it is probed with an empty
+/// binary message, so that a subscriber which went away is noticed, and its
+/// subscriptions dropped, even when no packet events are observed.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// A step in the lifecycle of a packet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PacketStatus {
+    /// The packet was sent on its source chain.
+    Sent,
+    /// The packet was received on its destination chain.
+    Relayed,
+    /// The acknowledgement of the packet was relayed back to its source chain.
+    Acknowledged,
+    /// The packet was timed out on its source chain.
+    TimedOut,
+}
+
+/// A packet lifecycle event, as pushed to the subscribers.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PacketStatusEvent {
+    pub status: PacketStatus,
+    /// The chain on which the event was observed.
+    pub chain_id: ChainId,
+    pub height: Height,
+    pub sequence: Sequence,
+    pub src_port_id: PortId,
+    pub src_channel_id: ChannelId,
+    pub dst_port_id: PortId,
+    pub dst_channel_id: ChannelId,
+}
+
+impl PacketStatusEvent {
+    /// The lifecycle event of the given event, if it pertains to a packet.
+    ///
+    /// The `WriteAcknowledgement` events, which are emitted alongside the
+    /// `ReceivePacket` events, are left out to avoid reporting a packet twice.
+    pub fn from_event(chain_id: &ChainId, event: &IbcEventWithHeight) -> Option<Self> {
+        let (status, packet) = match &event.event {
+            IbcEvent::SendPacket(e) => (PacketStatus::Sent, &e.packet),
+            IbcEvent::ReceivePacket(e) => (PacketStatus::Relayed, &e.packet),
+            IbcEvent::AcknowledgePacket(e) => (PacketStatus::Acknowledged, &e.packet),
+            IbcEvent::TimeoutPacket(e) => (PacketStatus::TimedOut, &e.packet),
+            IbcEvent::TimeoutOnClosePacket(e) => (PacketStatus::TimedOut, &e.packet),
+            _ => return None,
+        };
+
+        Some(Self::new(status, chain_id.clone(), event.height, packet))
+    }
+
+    fn new(status: PacketStatus, chain_id: ChainId, height: Height, packet: &Packet) -> Self {
+        Self {
+            status,
+            chain_id,
+            height,
+            sequence: packet.sequence,
+            src_port_id: packet.source_port.clone(),
+            src_channel_id: packet.source_channel.clone(),
+            dst_port_id: packet.destination_port.clone(),
+            dst_channel_id: packet.destination_channel.clone(),
+        }
+    }
+}
+
+/// The packets a subscriber is interested in, given with the `chains`, `port`
+/// and `channel` query parameters. The port and channel patterns may contain
+/// wildcards, and match either end of a packet.
+#[derive(Debug, Default)]
+pub struct EventFilter {
+    chain_ids: Vec<ChainId>,
+    port: Option<Wildcard>,
+    channel: Option<Wildcard>,
+}
+
+impl EventFilter {
+    pub fn from_request(request: &rouille::Request) -> Result<Self, String> {
+        let chain_ids = request
+            .get_param("chains")
+            .map(|chains| {
+                chains
+                    .split(',')
+                    .filter(|id| !id.is_empty())
+                    .map(ChainId::from_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let wildcard = |param: &str| {
+            request
+                .get_param(param)
+                .map(|pattern| {
+                    Wildcard::from_str(&pattern)
+                        .map_err(|e| format!("invalid {} pattern '{}': {}", param, pattern, e))
+                })
+                .transpose()
+        };
+
+        Ok(Self {
+            chain_ids,
+            port: wildcard("port")?,
+            channel: wildcard("channel")?,
+        })
+    }
+
+    pub fn matches(&self, event: &PacketStatusEvent) -> bool {
+        let matches = |pattern: &Option<Wildcard>, src: &str, dst: &str| {
+            pattern
+                .as_ref()
+                .map_or(true, |p| p.is_match(src) || p.is_match(dst))
+        };
+
+        matches(
+            &self.port,
+            event.src_port_id.as_str(),
+            event.dst_port_id.as_str(),
+        ) && matches(
+            &self.channel,
+            event.src_channel_id.as_str(),
+            event.dst_channel_id.as_str(),
+        )
+    }
+}
+
+/// Upgrade the given request to a WebSocket connection, over which the packet
+/// lifecycle events matching the filter given in the request are pushed.
+pub fn serve(request: &rouille::Request, sender: &channel::Sender<Request>) -> rouille::Response {
+    let filter = match EventFilter::from_request(request) {
+        Ok(filter) => filter,
+        Err(e) => return rouille::Response::text(e).with_status_code(400),
+    };
+
+    let (response, websocket) = match websocket::start(request, None::<&str>) {
+        Ok(upgrade) => upgrade,
+        Err(e) => return rouille::Response::text(e.to_string()).with_status_code(400),
+    };
+
+    let sender = sender.clone();
+
+    // The WebSocket is only available once the upgrade response is sent
+    thread::spawn(move || match websocket.recv() {
+        Ok(websocket) => push_events(websocket, filter, &sender),
+        Err(e) => debug!("WebSocket upgrade did not complete: {}", e),
+    });
+
+    response
+}
+
+fn push_events(mut websocket: Websocket, filter: EventFilter, sender: &channel::Sender<Request>) {
+    let chain_ids = if filter.chain_ids.is_empty() {
+        match all_chain_ids(sender) {
+            Ok(chain_ids) => chain_ids,
+            Err(e) => {
+                error!("failed to list the chains to push the events of: {}", e);
+                return;
+            }
+        }
+    } else {
+        filter.chain_ids.clone()
+    };
+
+    let (event_tx, event_rx) = channel::unbounded();
+
+    // Set once the subscriber went away, for the forwarding threads to stop
+    // and drop their subscription.
+    let closed = Arc::new(AtomicBool::new(false));
+
+    for chain_id in chain_ids {
+        let subscription = match subscribe_events(sender, &chain_id) {
+            Ok(subscription) => subscription,
+            Err(e) => {
+                let _ = websocket.send_text(&e.to_string());
+                closed.store(true, Ordering::Relaxed);
+                return;
+            }
+        };
+
+        let event_tx = event_tx.clone();
+        let closed = closed.clone();
+
+        // Forward the packet events of the chain until either the
+        // subscription is closed or the subscriber goes away.
+        thread::spawn(move || {
+            while !closed.load(Ordering::Relaxed) {
+                let batch = match subscription.recv_timeout(HEARTBEAT_INTERVAL) {
+                    Ok(batch) => batch,
+                    Err(channel::RecvTimeoutError::Timeout) => continue,
+                    Err(channel::RecvTimeoutError::Disconnected) => return,
+                };
+
+                let batch = match batch.as_ref() {
+                    Ok(batch) => batch,
+                    Err(e) => {
+                        error!(chain = %chain_id, "event subscription failed: {}", e);
+                        return;
+                    }
+                };
+
+                for event in batch
+                    .events
+                    .iter()
+                    .filter_map(|e| PacketStatusEvent::from_event(&chain_id, e))
+                {
+                    if event_tx.send(event).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
+    // Only the forwarding threads hold a sender from now on, so that the
+    // loop below ends once all the subscriptions are closed.
+    drop(event_tx);
+
+    forward_events(&mut websocket, &filter, &event_rx);
+
+    closed.store(true, Ordering::Relaxed);
+}
+
+/// Push the events matching the filter to the subscriber, until either all
+/// the subscriptions are closed or the subscriber goes away.
+fn forward_events(
+    websocket: &mut Websocket,
+    filter: &EventFilter,
+    event_rx: &channel::Receiver<PacketStatusEvent>,
+) {
+    loop {
+        let event = match event_rx.recv_timeout(HEARTBEAT_INTERVAL) {
+            Ok(event) => event,
+            Err(channel::RecvTimeoutError::Timeout) => {
+                // Writing to a connection which was closed by the other end fails,
+                // at the latest on the second write after it was closed.
+                if websocket.send_binary(&[]).is_err() {
+                    debug!("packet events WebSocket closed by the subscriber");
+                    return;
+                }
+
+                continue;
+            }
+            Err(channel::RecvTimeoutError::Disconnected) => return,
+        };
+
+        if !filter.matches(&event) {
+            continue;
+        }
+
+        let json = match serde_json::to_string(&event) {
+            Ok(json) => json,
+            Err(e) => {
+                error!("failed to serialize packet event {:?}: {}", event, e);
+                continue;
+            }
+        };
+
+        if websocket.send_text(&json).is_err() {
+            debug!("packet events WebSocket closed by the subscriber");
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::core::ics04_channel::events::{AcknowledgePacket, WriteAcknowledgement};
+
+    fn packet() -> Packet {
+        Packet {
+            sequence: 42.into(),
+            source_port: PortId::transfer(),
+            source_channel: ChannelId::new(0),
+            destination_port: PortId::transfer(),
+            destination_channel: ChannelId::new(7),
+            ..Default::default()
+        }
+    }
+
+    fn event(event: IbcEvent) -> IbcEventWithHeight {
+        IbcEventWithHeight::new(event, Height::new(0, 10).unwrap())
+    }
+
+    #[test]
+    fn packet_status_events() {
+        let chain_id = ChainId::from_string("ibc-0");
+
+        let ack = PacketStatusEvent::from_event(
+            &chain_id,
+            &event(IbcEvent::AcknowledgePacket(AcknowledgePacket {
+                packet: packet(),
+            })),
+        )
+        .expect("a packet status event");
+
+        assert_eq!(ack.status, PacketStatus::Acknowledged);
+        assert_eq!(ack.sequence, 42.into());
+
+        let write_ack = event(IbcEvent::WriteAcknowledgement(WriteAcknowledgement {
+            packet: packet(),
+            ack: Vec::new(),
+        }));
+
+        assert!(PacketStatusEvent::from_event(&chain_id, &write_ack).is_none());
+    }
+
+    #[test]
+    fn filter_packet_status_events() {
+        let event = PacketStatusEvent::new(
+            PacketStatus::Sent,
+            ChainId::from_string("ibc-0"),
+            Height::new(0, 10).unwrap(),
+            &packet(),
+        );
+
+        let filter = |port: Option<&str>, channel: Option<&str>| EventFilter {
+            chain_ids: Vec::new(),
+            port: port.map(|p| p.parse().unwrap()),
+            channel: channel.map(|c| c.parse().unwrap()),
+        };
+
+        assert!(filter(None, None).matches(&event));
+        assert!(filter(Some("transfer"), Some("channel-7")).matches(&event));
+        assert!(filter(None, Some("channel-*")).matches(&event));
+        assert!(!filter(Some("ica*"), None).matches(&event));
+        assert!(!filter(None, Some("channel-1")).matches(&event));
+    }
+}
@@ -6,13 +6,17 @@ use crossbeam_channel as channel;
 
 use ibc_relayer::supervisor::dump_state::SupervisorState;
 use ibc_relayer::{
-    config::ChainConfig,
+    chain::handle::Subscription,
+    config::{ChainConfig, PacketFilter, PacketFilterUpdate},
     rest::{
         request::{reply_channel, ReplySender, Request, VersionInfo},
         RestApiError,
     },
 };
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::{
+    error::ValidationError,
+    identifier::{ChainId, ChannelId, PortId},
+};
 
 pub const NAME: &str = env!(
     "CARGO_PKG_NAME",
@@ -58,6 +62,56 @@ pub fn chain_config(
     })
 }
 
+/// Whether a [`packet_filter_update`] should allow or deny the channel.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FilterAction {
+    Allow,
+    Deny,
+}
+
+pub fn packet_filter_update(
+    sender: &channel::Sender<Request>,
+    chain_id: &str,
+    port_id: &str,
+    channel_id: &str,
+    action: FilterAction,
+) -> Result<PacketFilter, RestApiError> {
+    let port_id: PortId = port_id
+        .parse()
+        .map_err(|e: ValidationError| RestApiError::InvalidPortId(port_id.to_string(), e.0))?;
+
+    let channel_id: ChannelId = channel_id.parse().map_err(|e: ValidationError| {
+        RestApiError::InvalidChannelId(channel_id.to_string(), e.0)
+    })?;
+
+    let update = match action {
+        FilterAction::Allow => PacketFilterUpdate::Allow {
+            port_id,
+            channel_id,
+        },
+        FilterAction::Deny => PacketFilterUpdate::Deny {
+            port_id,
+            channel_id,
+        },
+    };
+
+    submit_request(sender, |reply_to| Request::UpdatePacketFilter {
+        chain_id: ChainId::from_string(chain_id),
+        update,
+        reply_to,
+    })
+}
+
+pub fn subscribe_events(
+    sender: &channel::Sender<Request>,
+    chain_id: &ChainId,
+) -> Result<Subscription, RestApiError> {
+    submit_request(sender, |reply_to| Request::SubscribeEvents {
+        chain_id: chain_id.clone(),
+        reply_to,
+    })
+}
+
 pub fn supervisor_state(
     sender: &channel::Sender<Request>,
 ) -> Result<SupervisorState, RestApiError> {
@@ -4,6 +4,7 @@ extern crate rouille;
 mod config;
 pub use config::Config;
 
+pub mod events;
 pub mod server;
 
 pub(crate) mod handle;
@@ -7,7 +7,11 @@ use tracing::{info, trace};
 use ibc_relayer::rest::request::Request;
 
 use crate::{
-    handle::{all_chain_ids, assemble_version_info, chain_config, supervisor_state},
+    events,
+    handle::{
+        all_chain_ids, assemble_version_info, chain_config, packet_filter_update, supervisor_state,
+        FilterAction,
+    },
     Config,
 };
 
@@ -29,12 +33,19 @@ impl ServerHandle {
 pub fn spawn(config: Config) -> (ServerHandle, channel::Receiver<Request>) {
     let (req_tx, req_rx) = channel::unbounded::<Request>();
 
-    info!("starting REST API server listening at http://{}", config);
-    let handle = run(config, req_tx);
+    let handle = spawn_with_sender(config, req_tx);
 
     (handle, req_rx)
 }
 
+/// Spawn the REST server, forwarding the requests it receives through the given sender,
+/// eg. to share the channel to the supervisor with the gRPC admin server.
+pub fn spawn_with_sender(config: Config, sender: channel::Sender<Request>) -> ServerHandle {
+    info!("starting REST API server listening at http://{}", config);
+
+    run(config, sender)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "status", content = "result")]
 #[serde(rename_all = "lowercase")]
@@ -54,6 +65,8 @@ impl<R, E> From<Result<R, E>> for JsonResult<R, E> {
 
 #[allow(clippy::manual_strip)]
 fn run(config: Config, sender: channel::Sender<Request>) -> ServerHandle {
+    let websocket = config.websocket;
+
     let server = rouille::Server::new(config.address(), move |request| {
         router!(request,
             (GET) (/version) => {
@@ -76,12 +89,33 @@ fn run(config: Config, sender: channel::Sender<Request>) -> ServerHandle {
                 rouille::Response::json(&JsonResult::from(result))
             },
 
+            (POST) (/chain/{id: String}/packet_filter/{port: String}/{channel: String}) => {
+                trace!("[rest] POST /chain/{}/packet_filter/{}/{}", id, port, channel);
+                let result = packet_filter_update(&sender, &id, &port, &channel, FilterAction::Allow);
+                rouille::Response::json(&JsonResult::from(result))
+            },
+
+            (DELETE) (/chain/{id: String}/packet_filter/{port: String}/{channel: String}) => {
+                trace!("[rest] DELETE /chain/{}/packet_filter/{}/{}", id, port, channel);
+                let result = packet_filter_update(&sender, &id, &port, &channel, FilterAction::Deny);
+                rouille::Response::json(&JsonResult::from(result))
+            },
+
             (GET) (/state) => {
                 trace!("[rest] GET /state");
                 let result = supervisor_state(&sender);
                 rouille::Response::json(&JsonResult::from(result))
             },
 
+            (GET) (/events/ws) => {
+                trace!("[rest] GET /events/ws");
+                if websocket {
+                    events::serve(request, &sender)
+                } else {
+                    rouille::Response::empty_404()
+                }
+            },
+
             _ => rouille::Response::empty_404(),
         )
     })
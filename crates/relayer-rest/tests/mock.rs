@@ -3,7 +3,7 @@ use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 
 use ibc_relayer::{
-    config::ChainConfig,
+    config::{filter::ChannelFilters, ChainConfig, PacketFilter, PacketFilterUpdate},
     rest::request::{Request, VersionInfo},
     supervisor::dump_state::SupervisorState,
 };
@@ -25,6 +25,14 @@ enum JsonResult<R, E> {
 }
 
 fn run_test<R, F>(port: u16, path: &str, expected: R, handler: F)
+where
+    R: Serialize,
+    F: FnOnce(Request) -> TestResult + Send + 'static,
+{
+    run_test_with_method("GET", port, path, expected, handler)
+}
+
+fn run_test_with_method<R, F>(method: &str, port: u16, path: &str, expected: R, handler: F)
 where
     R: Serialize,
     F: FnOnce(Request) -> TestResult + Send + 'static,
@@ -41,7 +49,7 @@ where
         Err(e) => panic!("got an error: {}", e),
     });
 
-    let response = ureq::get(&format!("http://127.0.0.1:{}{}", port, path))
+    let response = ureq::request(method, &format!("http://127.0.0.1:{}{}", port, path))
         .call()
         .unwrap()
         .into_string()
@@ -126,7 +134,11 @@ fn get_chain() {
 
 #[test]
 fn state() {
-    let state = SupervisorState::new(vec!["mock-0".parse().unwrap()], std::iter::empty());
+    let state = SupervisorState::new(
+        vec!["mock-0".parse().unwrap()],
+        std::iter::empty(),
+        Vec::new(),
+    );
     let result: JsonResult<_, ()> = JsonResult::Success(state.clone());
 
     run_test(19104, "/state", result, |req| match req {
@@ -137,3 +149,65 @@ fn state() {
         req => TestResult::WrongRequest(req),
     });
 }
+
+#[test]
+fn allow_channel() {
+    let filter = PacketFilter::Allow(ChannelFilters::new(vec![]));
+    let result: JsonResult<_, ()> = JsonResult::Success(filter.clone());
+
+    run_test_with_method(
+        "POST",
+        19105,
+        "/chain/mock-0/packet_filter/transfer/channel-1",
+        result,
+        |req| match req {
+            Request::UpdatePacketFilter {
+                chain_id,
+                update:
+                    PacketFilterUpdate::Allow {
+                        port_id,
+                        channel_id,
+                    },
+                reply_to,
+            } if chain_id.as_str() == "mock-0"
+                && port_id.as_str() == "transfer"
+                && channel_id.as_str() == "channel-1" =>
+            {
+                reply_to.send(Ok(filter)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    );
+}
+
+#[test]
+fn deny_channel() {
+    let filter = PacketFilter::AllowAll;
+    let result: JsonResult<_, ()> = JsonResult::Success(filter.clone());
+
+    run_test_with_method(
+        "DELETE",
+        19106,
+        "/chain/mock-0/packet_filter/transfer/channel-1",
+        result,
+        |req| match req {
+            Request::UpdatePacketFilter {
+                chain_id,
+                update:
+                    PacketFilterUpdate::Deny {
+                        port_id,
+                        channel_id,
+                    },
+                reply_to,
+            } if chain_id.as_str() == "mock-0"
+                && port_id.as_str() == "transfer"
+                && channel_id.as_str() == "channel-1" =>
+            {
+                reply_to.send(Ok(filter)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    );
+}
@@ -11,3 +11,6 @@ pub mod error;
 pub mod events;
 pub mod msgs;
 pub mod packet_fee;
+
+/// ICS29 fee middleware current version.
+pub const VERSION: &str = "ics29-1";
@@ -2,3 +2,4 @@
 
 pub mod ics29_fee;
 pub mod transfer;
+pub mod wasm;
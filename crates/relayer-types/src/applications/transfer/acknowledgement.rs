@@ -2,6 +2,7 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 
 use serde::{Deserialize, Serialize};
 
+use crate::core::ics04_channel::msgs::acknowledgement::Acknowledgement as GenericAcknowledgement;
 use crate::prelude::*;
 
 use super::error::Error;
@@ -39,6 +40,30 @@ impl Acknowledgement {
     pub fn from_error(err: Error) -> Self {
         Self::Error(format!("{}: {}", ACK_ERR_STR, err))
     }
+
+    pub fn is_successful(&self) -> bool {
+        matches!(self, Self::Success(_))
+    }
+}
+
+/// Encode the acknowledgement in the canonical JSON form written by ibc-go,
+/// e.g. `{"result":"AQ=="}`, which is what the packet commitment is computed over.
+impl From<Acknowledgement> for GenericAcknowledgement {
+    fn from(ack: Acknowledgement) -> Self {
+        serde_json::to_vec(&ack)
+            .expect("ICS-20 acknowledgement serialization cannot fail")
+            .into()
+    }
+}
+
+/// Decode an acknowledgement written by an ICS-20 module, as found in
+/// the `write_acknowledgement` events of the destination chain.
+impl TryFrom<GenericAcknowledgement> for Acknowledgement {
+    type Error = Error;
+
+    fn try_from(ack: GenericAcknowledgement) -> Result<Self, Error> {
+        serde_json::from_slice(ack.as_ref()).map_err(|_| Error::ack_deserialization())
+    }
 }
 
 impl AsRef<[u8]> for Acknowledgement {
@@ -93,4 +118,23 @@ mod test {
         assert!(serde_json::from_str::<Acknowledgement>(r#"{"result":"AQ="}"#).is_err());
         assert!(serde_json::from_str::<Acknowledgement>(r#"{"success":"AQ=="}"#).is_err());
     }
+
+    #[test]
+    fn test_ack_generic_roundtrip() {
+        let success = GenericAcknowledgement::from(Acknowledgement::success());
+        assert_eq!(success.as_ref(), br#"{"result":"AQ=="}"#);
+        assert_eq!(
+            Acknowledgement::try_from(success).unwrap(),
+            Acknowledgement::success()
+        );
+
+        let error = Acknowledgement::Error("insufficient funds".to_owned());
+        assert_eq!(
+            Acknowledgement::try_from(GenericAcknowledgement::from(error.clone())).unwrap(),
+            error
+        );
+
+        let raw = GenericAcknowledgement::from(vec![0x01]);
+        assert!(Acknowledgement::try_from(raw).is_err());
+    }
 }
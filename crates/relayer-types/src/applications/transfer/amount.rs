@@ -1,15 +1,17 @@
 use core::str::FromStr;
 use derive_more::{Display, From, Into};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::error::Error;
 use crate::bigint::U256;
 use crate::prelude::*;
+use crate::serializers::serde_string;
 
 /// A type for representing token transfer amounts.
-#[derive(
-    Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize, Display, From, Into,
-)]
+///
+/// Amounts are serialized as decimal strings, as in the `Coin` and
+/// `FungibleTokenPacketData` protobuf and JSON encodings.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Display, From, Into)]
 pub struct Amount(pub U256);
 
 impl Amount {
@@ -20,6 +22,73 @@ impl Amount {
     pub fn checked_sub(self, rhs: impl Into<Amount>) -> Option<Self> {
         self.0.checked_sub(rhs.into().0).map(Self)
     }
+
+    /// Parse an amount given in display units, such as `1.5` for a token
+    /// whose base denomination has the given number of decimal places
+    /// (the `exponent` of its display unit in the chain metadata), into
+    /// an amount of the base denomination.
+    ///
+    /// Fails if the amount has more decimal places than the given exponent,
+    /// rather than silently truncating it.
+    pub fn from_decimal_str(s: &str, exponent: u32) -> Result<Self, Error> {
+        let invalid = || Error::invalid_decimal_amount(s.to_string(), exponent);
+
+        let (integral, fractional) = match s.split_once('.') {
+            Some((_, "")) => return Err(invalid()),
+            Some((integral, fractional)) => (integral, fractional.trim_end_matches('0')),
+            None => (s, ""),
+        };
+
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+
+        if integral.is_empty()
+            || !is_digits(integral)
+            || !is_digits(fractional)
+            || fractional.len() > exponent as usize
+        {
+            return Err(invalid());
+        }
+
+        let pow10 = |exp: usize| U256::from(10u64).checked_pow(U256::from(exp));
+        let parse = |part: &str| -> Result<U256, Error> {
+            if part.is_empty() {
+                Ok(U256::zero())
+            } else {
+                U256::from_dec_str(part).map_err(Error::invalid_amount)
+            }
+        };
+
+        let integral_scale = pow10(exponent as usize).ok_or_else(invalid)?;
+        let fractional_scale = pow10(exponent as usize - fractional.len()).ok_or_else(invalid)?;
+
+        parse(integral)?
+            .checked_mul(integral_scale)
+            .zip(parse(fractional)?.checked_mul(fractional_scale))
+            .and_then(|(integral, fractional)| integral.checked_add(fractional))
+            .map(Self)
+            .ok_or_else(invalid)
+    }
+
+    /// Format the amount in display units, for a token whose base
+    /// denomination has the given number of decimal places.
+    pub fn to_decimal_string(&self, exponent: u32) -> String {
+        let digits = self.0.to_string();
+        let exponent = exponent as usize;
+
+        if exponent == 0 {
+            return digits;
+        }
+
+        let digits = format!("{:0>width$}", digits, width = exponent + 1);
+        let (integral, fractional) = digits.split_at(digits.len() - exponent);
+        let fractional = fractional.trim_end_matches('0');
+
+        if fractional.is_empty() {
+            integral.to_string()
+        } else {
+            format!("{}.{}", integral, fractional)
+        }
+    }
 }
 
 impl FromStr for Amount {
@@ -31,6 +100,18 @@ impl FromStr for Amount {
     }
 }
 
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_string::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_string::deserialize(deserializer)
+    }
+}
+
 impl From<u64> for Amount {
     fn from(v: u64) -> Self {
         Self(v.into())
@@ -42,3 +123,84 @@ impl From<u128> for Amount {
         Self(amount.into())
     }
 }
+
+impl TryFrom<Amount> for u64 {
+    type Error = Error;
+
+    fn try_from(amount: Amount) -> Result<Self, Self::Error> {
+        if amount.0 > U256::from(u64::MAX) {
+            return Err(Error::amount_overflow(amount.to_string()));
+        }
+
+        Ok(amount.0.as_u64())
+    }
+}
+
+impl TryFrom<Amount> for u128 {
+    type Error = Error;
+
+    fn try_from(amount: Amount) -> Result<Self, Self::Error> {
+        if amount.0 > U256::from(u128::MAX) {
+            return Err(Error::amount_overflow(amount.to_string()));
+        }
+
+        Ok(amount.0.as_u128())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_decimal_amounts() {
+        let parse =
+            |s: &str, exponent| Amount::from_decimal_str(s, exponent).map(|a| a.to_string());
+
+        assert_eq!(parse("1.5", 6).unwrap(), "1500000");
+        assert_eq!(parse("0.000001", 6).unwrap(), "1");
+        assert_eq!(parse("42", 6).unwrap(), "42000000");
+        assert_eq!(parse("1.500", 2).unwrap(), "150");
+        assert_eq!(parse("42", 0).unwrap(), "42");
+
+        // More decimal places than the exponent, or malformed amounts
+        assert!(parse("0.0000001", 6).is_err());
+        assert!(parse("1.5", 0).is_err());
+        assert!(parse(".5", 6).is_err());
+        assert!(parse("1.", 6).is_err());
+        assert!(parse("-1", 6).is_err());
+        assert!(parse("1e6", 6).is_err());
+
+        // Overflowing amounts
+        assert!(parse(&U256::MAX.to_string(), 1).is_err());
+        assert!(parse("1", 100).is_err());
+    }
+
+    #[test]
+    fn format_decimal_amounts() {
+        assert_eq!(Amount::from(1500000u64).to_decimal_string(6), "1.5");
+        assert_eq!(Amount::from(1u64).to_decimal_string(6), "0.000001");
+        assert_eq!(Amount::from(42000000u64).to_decimal_string(6), "42");
+        assert_eq!(Amount::from(42u64).to_decimal_string(0), "42");
+    }
+
+    #[test]
+    fn checked_integer_conversions() {
+        let amount = Amount::from(u128::MAX);
+
+        assert_eq!(u128::try_from(amount).unwrap(), u128::MAX);
+        assert!(u64::try_from(amount).is_err());
+        assert!(u128::try_from(amount.checked_add(1u64).unwrap()).is_err());
+        assert_eq!(u64::try_from(Amount::from(42u64)).unwrap(), 42);
+    }
+
+    #[test]
+    fn serde_as_decimal_string() {
+        let amount = Amount::from(u128::MAX).checked_add(1u64).unwrap();
+        let json = serde_json::to_string(&amount).unwrap();
+
+        assert_eq!(json, r#""340282366920938463463374607431768211456""#);
+        assert_eq!(serde_json::from_str::<Amount>(&json).unwrap(), amount);
+        assert!(serde_json::from_str::<Amount>("42").is_err());
+    }
+}
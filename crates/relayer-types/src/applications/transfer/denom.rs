@@ -40,6 +40,14 @@ impl TracePrefix {
             channel_id,
         }
     }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
 }
 
 impl Display for TracePrefix {
@@ -77,6 +85,12 @@ impl TracePath {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Returns the prefixes of the path in the order in which they appear in it,
+    /// ie. starting with the prefix added by the last chain the token was sent to.
+    pub fn prefixes(&self) -> impl Iterator<Item = &TracePrefix> {
+        self.0.iter().rev()
+    }
 }
 
 impl<'a> TryFrom<Vec<&'a str>> for TracePath {
@@ -144,6 +158,14 @@ pub struct PrefixedDenom {
 }
 
 impl PrefixedDenom {
+    pub fn trace_path(&self) -> &TracePath {
+        &self.trace_path
+    }
+
+    pub fn base_denom(&self) -> &BaseDenom {
+        &self.base_denom
+    }
+
     /// Removes the specified prefix from the trace path if there is a match, otherwise does nothing.
     pub fn remove_trace_prefix(&mut self, prefix: &TracePrefix) {
         self.trace_path.remove_prefix(prefix)
@@ -384,6 +406,12 @@ mod tests {
         trace_path.remove_prefix(&prefix_1);
         assert!(trace_path.is_empty());
 
+        let trace_path = TracePath::from_str("transfer/channel-0/transfer/channel-1")?;
+        assert_eq!(
+            trace_path.prefixes().cloned().collect::<Vec<_>>(),
+            vec![prefix_2, prefix_1]
+        );
+
         Ok(())
     }
 }
@@ -72,9 +72,31 @@ define_error! {
             [ TraceError<FromDecStrErr> ]
             | _ | { "invalid amount" },
 
+        InvalidDecimalAmount
+            { amount: String, exponent: u32 }
+            | e | { format_args!("invalid decimal amount '{0}' with {1} decimal places", e.amount, e.exponent) },
+
+        AmountOverflow
+            { amount: String }
+            | e | { format_args!("amount {0} does not fit in the target integer type", e.amount) },
+
         InvalidToken
             | _ | { "invalid token" },
 
+        InvalidDenom
+            { denom: String }
+            | e | { format_args!("invalid denomination: '{0}'", e.denom) },
+
+        ZeroAmount
+            | _ | { "the amount of tokens to transfer must be greater than zero" },
+
+        MissingMsgField
+            { field: String }
+            | e | { format_args!("missing field `{0}` to build the message", e.field) },
+
+        MissingTimeout
+            | _ | { "the timeout height and the timeout timestamp cannot both be disabled" },
+
         Signer
             [ SignerError ]
             | _ | { "failed to parse signer" },
@@ -116,6 +138,14 @@ define_error! {
         SendDisabled
             | _ | { "send is not enabled" },
 
+        MemoTooLarge
+            { size: usize, max_size: usize }
+            | e | { format_args!("memo of {0} bytes is larger than the maximum of {1} bytes", e.size, e.max_size) },
+
+        ReceiverTooLarge
+            { size: usize, max_size: usize }
+            | e | { format_args!("receiver of {0} bytes is larger than the maximum of {1} bytes", e.size, e.max_size) },
+
         ParseAccountFailure
             | _ | { "failed to parse as AccountId" },
 
@@ -0,0 +1,136 @@
+//! Limits on the size of the fields of ICS-20 transfers which a chain accepts.
+//!
+//! ibc-go rejects the `MsgTransfer` messages and fails to receive the packets
+//! whose memo or receiver is larger than its limits, so that relaying such
+//! packets is guaranteed to fail on the destination chain.
+
+use serde::{Deserialize, Serialize};
+
+use super::error::Error;
+use super::raw::FungibleTokenPacketData as RawPacketData;
+use crate::prelude::*;
+
+/// The maximum size of the memo of a transfer accepted by ibc-go, in bytes.
+pub const DEFAULT_MAX_MEMO_SIZE: usize = 32768;
+
+/// The maximum size of the receiver of a transfer accepted by ibc-go, in bytes.
+pub const DEFAULT_MAX_RECEIVER_SIZE: usize = 2048;
+
+/// The maximum sizes of the fields of the transfers accepted by a chain.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TransferLimits {
+    pub max_memo_size: usize,
+    pub max_receiver_size: usize,
+}
+
+impl Default for TransferLimits {
+    /// By default, the limits of ibc-go.
+    fn default() -> Self {
+        Self {
+            max_memo_size: DEFAULT_MAX_MEMO_SIZE,
+            max_receiver_size: DEFAULT_MAX_RECEIVER_SIZE,
+        }
+    }
+}
+
+impl TransferLimits {
+    pub fn check_memo(&self, memo: &str) -> Result<(), Error> {
+        if memo.len() > self.max_memo_size {
+            return Err(Error::memo_too_large(memo.len(), self.max_memo_size));
+        }
+
+        Ok(())
+    }
+
+    pub fn check_receiver(&self, receiver: &str) -> Result<(), Error> {
+        if receiver.len() > self.max_receiver_size {
+            return Err(Error::receiver_too_large(
+                receiver.len(),
+                self.max_receiver_size,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Check the memo and receiver of the given raw packet data, if it is
+    /// the data of an ICS-20 transfer. The data of any other application
+    /// is not subject to these limits.
+    ///
+    /// The raw data is inspected rather than decoded into a
+    /// [`PacketData`](super::packet::PacketData), so that the limits
+    /// apply regardless of the validity of the other fields.
+    pub fn check_packet_data(&self, data: &[u8]) -> Result<(), Error> {
+        match serde_json::from_slice::<RawPacketData>(data) {
+            Ok(raw) => {
+                self.check_memo(&raw.memo)?;
+                self.check_receiver(&raw.receiver)
+            }
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::applications::transfer::error::ErrorDetail;
+
+    fn packet_data(receiver: &str, memo: &str) -> Vec<u8> {
+        format!(
+            r#"{{"denom":"uatom","amount":"100","sender":"cosmos1sender","receiver":"{}","memo":"{}"}}"#,
+            receiver, memo
+        )
+        .into_bytes()
+    }
+
+    #[test]
+    fn check_transfer_packet_data() {
+        let limits = TransferLimits {
+            max_memo_size: 8,
+            max_receiver_size: 16,
+        };
+
+        assert!(limits
+            .check_packet_data(&packet_data("cosmos1receiver", "memo"))
+            .is_ok());
+
+        assert!(matches!(
+            limits
+                .check_packet_data(&packet_data("cosmos1receiver", "a larger memo"))
+                .unwrap_err()
+                .detail(),
+            ErrorDetail::MemoTooLarge(e) if e.size == 13 && e.max_size == 8
+        ));
+
+        assert!(matches!(
+            limits
+                .check_packet_data(&packet_data("cosmos1largerreceiver", ""))
+                .unwrap_err()
+                .detail(),
+            ErrorDetail::ReceiverTooLarge(e) if e.size == 21 && e.max_size == 16
+        ));
+    }
+
+    #[test]
+    fn ignore_other_packet_data() {
+        let limits = TransferLimits {
+            max_memo_size: 0,
+            max_receiver_size: 0,
+        };
+
+        assert!(limits
+            .check_packet_data(br#"{"receiver":"cosmos1receiver","memo":"memo"}"#)
+            .is_ok());
+        assert!(limits.check_packet_data(b"mock packet data").is_ok());
+    }
+
+    #[test]
+    fn transfer_data_without_memo() {
+        let data = br#"{"denom":"uatom","amount":"100","sender":"cosmos1sender","receiver":"cosmos1receiver"}"#;
+
+        assert!(TransferLimits::default().check_packet_data(data).is_ok());
+    }
+}
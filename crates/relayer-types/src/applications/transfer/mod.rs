@@ -8,8 +8,10 @@ pub mod coin;
 pub mod denom;
 pub mod error;
 pub mod events;
+pub mod limits;
 pub mod msgs;
 pub mod packet;
+pub mod raw;
 
 pub use amount::*;
 pub use coin::*;
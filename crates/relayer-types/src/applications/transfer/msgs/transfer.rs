@@ -4,13 +4,15 @@ use crate::prelude::*;
 
 use ibc_proto::cosmos::base::v1beta1::Coin;
 use ibc_proto::google::protobuf::Any;
-use ibc_proto::ibc::applications::transfer::v1::MsgTransfer as RawMsgTransfer;
 use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
 
+use crate::applications::transfer::amount::Amount;
 use crate::applications::transfer::error::Error;
+use crate::applications::transfer::raw::MsgTransfer as RawMsgTransfer;
 use crate::core::ics04_channel::timeout::TimeoutHeight;
 use crate::core::ics24_host::identifier::{ChannelId, PortId};
-use crate::signer::Signer;
+use crate::signer::{Signer, SignerError};
 use crate::timestamp::Timestamp;
 use crate::tx_msg::Msg;
 
@@ -23,7 +25,10 @@ pub const TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
 /// packet, which might be the user of a command line application, should only
 /// have to specify the information related to the transfer of the token, and
 /// let the library figure out how to build the packet properly.
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// Like the other domain types, it can be serialized to JSON, eg. by indexers,
+/// without converting it to its Protobuf encoding first.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgTransfer<C = Coin> {
     /// the port on which the packet will be sent
     pub source_port: PortId,
@@ -41,6 +46,9 @@ pub struct MsgTransfer<C = Coin> {
     /// Timeout timestamp relative to the current block timestamp.
     /// The timeout is disabled when set to 0.
     pub timeout_timestamp: Timestamp,
+    /// the memo of the transfer, empty if it has none
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub memo: String,
 }
 
 impl Msg for MsgTransfer {
@@ -54,6 +62,43 @@ impl Msg for MsgTransfer {
     fn type_url(&self) -> String {
         TYPE_URL.to_string()
     }
+
+    /// Performs the same stateless checks as ibc-go on a transfer, so that
+    /// the transfers it would reject are caught before being submitted.
+    fn validate_basic(&self) -> Result<(), Self::ValidationError> {
+        if !is_valid_denom(&self.token.denom) {
+            return Err(Error::invalid_denom(self.token.denom.clone()));
+        }
+
+        let amount: Amount = self.token.amount.parse()?;
+        if amount.0.is_zero() {
+            return Err(Error::zero_amount());
+        }
+
+        for signer in [&self.sender, &self.receiver] {
+            if signer.as_ref().trim().is_empty() {
+                return Err(Error::signer(SignerError::empty_signer()));
+            }
+        }
+
+        if self.timeout_height == TimeoutHeight::Never
+            && self.timeout_timestamp == Timestamp::none()
+        {
+            return Err(Error::missing_timeout());
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the given denomination is valid according to the Cosmos SDK,
+/// ie. whether it matches `[a-zA-Z][a-zA-Z0-9/:._-]{2,127}`.
+fn is_valid_denom(denom: &str) -> bool {
+    let mut chars = denom.chars();
+
+    (3..=128).contains(&denom.len())
+        && chars.next().map_or(false, |c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || "/:._-".contains(c))
 }
 
 impl TryFrom<RawMsgTransfer> for MsgTransfer {
@@ -81,6 +126,7 @@ impl TryFrom<RawMsgTransfer> for MsgTransfer {
             receiver: raw_msg.receiver.parse().map_err(Error::signer)?,
             timeout_height,
             timeout_timestamp,
+            memo: raw_msg.memo,
         })
     }
 }
@@ -95,6 +141,7 @@ impl From<MsgTransfer> for RawMsgTransfer {
             receiver: domain_msg.receiver.to_string(),
             timeout_height: domain_msg.timeout_height.into(),
             timeout_timestamp: domain_msg.timeout_timestamp.nanoseconds(),
+            memo: domain_msg.memo,
         }
     }
 }
@@ -123,6 +170,109 @@ impl From<MsgTransfer> for Any {
     }
 }
 
+impl MsgTransfer {
+    pub fn builder() -> MsgTransferBuilder {
+        MsgTransferBuilder::default()
+    }
+}
+
+/// Builder of [`MsgTransfer`] messages, which are sent from the `transfer` port
+/// with no memo unless set otherwise.
+///
+/// Both the timeout height and the timeout timestamp are disabled by default,
+/// and at least one of them must be set for the message to be built.
+#[derive(Clone, Debug)]
+pub struct MsgTransferBuilder {
+    source_port: PortId,
+    source_channel: Option<ChannelId>,
+    token: Option<Coin>,
+    sender: Option<Signer>,
+    receiver: Option<Signer>,
+    timeout_height: TimeoutHeight,
+    timeout_timestamp: Timestamp,
+    memo: String,
+}
+
+impl Default for MsgTransferBuilder {
+    fn default() -> Self {
+        Self {
+            source_port: PortId::transfer(),
+            source_channel: None,
+            token: None,
+            sender: None,
+            receiver: None,
+            timeout_height: TimeoutHeight::no_timeout(),
+            timeout_timestamp: Timestamp::none(),
+            memo: String::new(),
+        }
+    }
+}
+
+impl MsgTransferBuilder {
+    pub fn with_source_port(mut self, source_port: PortId) -> Self {
+        self.source_port = source_port;
+        self
+    }
+
+    pub fn with_source_channel(mut self, source_channel: ChannelId) -> Self {
+        self.source_channel = Some(source_channel);
+        self
+    }
+
+    pub fn with_token(mut self, token: Coin) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    pub fn with_sender(mut self, sender: Signer) -> Self {
+        self.sender = Some(sender);
+        self
+    }
+
+    pub fn with_receiver(mut self, receiver: Signer) -> Self {
+        self.receiver = Some(receiver);
+        self
+    }
+
+    pub fn with_timeout_height(mut self, timeout_height: TimeoutHeight) -> Self {
+        self.timeout_height = timeout_height;
+        self
+    }
+
+    pub fn with_timeout_timestamp(mut self, timeout_timestamp: Timestamp) -> Self {
+        self.timeout_timestamp = timeout_timestamp;
+        self
+    }
+
+    pub fn with_memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = memo.into();
+        self
+    }
+
+    /// Build the message, checking that all the fields without a default are set,
+    /// and that the message passes [`MsgTransfer::validate_basic`].
+    pub fn build(self) -> Result<MsgTransfer, Error> {
+        let missing = |field: &str| Error::missing_msg_field(field.to_string());
+
+        let msg = MsgTransfer {
+            source_port: self.source_port,
+            source_channel: self
+                .source_channel
+                .ok_or_else(|| missing("source_channel"))?,
+            token: self.token.ok_or_else(|| missing("token"))?,
+            sender: self.sender.ok_or_else(|| missing("sender"))?,
+            receiver: self.receiver.ok_or_else(|| missing("receiver"))?,
+            timeout_height: self.timeout_height,
+            timeout_timestamp: self.timeout_timestamp,
+            memo: self.memo,
+        };
+
+        msg.validate_basic()?;
+
+        Ok(msg)
+    }
+}
+
 #[cfg(test)]
 pub mod test_util {
     use core::ops::Add;
@@ -134,6 +284,7 @@ pub mod test_util {
     use crate::bigint::U256;
     use crate::core::ics04_channel::packet::{Packet, Sequence};
     use crate::core::ics04_channel::timeout::TimeoutHeight;
+    use crate::prelude::*;
     use crate::signer::Signer;
     use crate::{
         applications::transfer::{BaseCoin, PrefixedCoin},
@@ -162,6 +313,7 @@ pub mod test_util {
             timeout_timestamp: timeout_timestamp
                 .unwrap_or_else(|| Timestamp::now().add(Duration::from_secs(10)).unwrap()),
             timeout_height,
+            memo: String::new(),
         }
     }
 
@@ -176,6 +328,7 @@ pub mod test_util {
                 token: coin,
                 sender: msg.sender.clone(),
                 receiver: msg.receiver.clone(),
+                memo: msg.memo.clone(),
             };
             serde_json::to_vec(&data).expect("PacketData's infallible Serialize impl failed")
         };
@@ -192,3 +345,137 @@ pub mod test_util {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::test_util::get_dummy_msg_transfer;
+    use super::*;
+
+    use crate::applications::transfer::error::ErrorDetail;
+    use crate::applications::transfer::PrefixedCoin;
+    use crate::core::ics02_client::height::Height;
+
+    fn msg_transfer(denom: &str, amount: &str) -> MsgTransfer {
+        MsgTransfer {
+            source_port: PortId::transfer(),
+            source_channel: ChannelId::default(),
+            token: Coin {
+                denom: denom.to_string(),
+                amount: amount.to_string(),
+            },
+            sender: "cosmos1sender".parse().unwrap(),
+            receiver: "cosmos1receiver".parse().unwrap(),
+            timeout_height: TimeoutHeight::At(Height::new(0, 10).unwrap()),
+            timeout_timestamp: Timestamp::none(),
+            memo: String::new(),
+        }
+    }
+
+    #[test]
+    fn validate_msg_transfer() {
+        assert!(msg_transfer("uatom", "100").validate_basic().is_ok());
+        assert!(msg_transfer(
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+            "1"
+        )
+        .validate_basic()
+        .is_ok());
+
+        for denom in ["", "u", "1atom", "uatom!"] {
+            assert!(matches!(
+                msg_transfer(denom, "100")
+                    .validate_basic()
+                    .unwrap_err()
+                    .detail(),
+                ErrorDetail::InvalidDenom(_)
+            ));
+        }
+
+        assert!(matches!(
+            msg_transfer("uatom", "0")
+                .validate_basic()
+                .unwrap_err()
+                .detail(),
+            ErrorDetail::ZeroAmount(_)
+        ));
+        assert!(matches!(
+            msg_transfer("uatom", "-1")
+                .validate_basic()
+                .unwrap_err()
+                .detail(),
+            ErrorDetail::InvalidAmount(_)
+        ));
+
+        let mut msg = msg_transfer("uatom", "100");
+        msg.timeout_height = TimeoutHeight::no_timeout();
+        assert!(matches!(
+            msg.validate_basic().unwrap_err().detail(),
+            ErrorDetail::MissingTimeout(_)
+        ));
+    }
+
+    #[test]
+    fn msg_transfer_json() {
+        let msg = get_dummy_msg_transfer(
+            TimeoutHeight::At(Height::new(0, 10).unwrap()),
+            Some(Timestamp::from_nanoseconds(1_000_000_000).unwrap()),
+        );
+
+        let json = serde_json::to_value(&msg).unwrap();
+        assert!(json.get("memo").is_none());
+
+        let decoded: MsgTransfer<PrefixedCoin> = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, msg);
+    }
+
+    #[test]
+    fn build_msg_transfer() {
+        let msg = msg_transfer("uatom", "100");
+
+        let built = MsgTransfer::builder()
+            .with_source_port(msg.source_port.clone())
+            .with_source_channel(msg.source_channel.clone())
+            .with_token(msg.token.clone())
+            .with_sender(msg.sender.clone())
+            .with_receiver(msg.receiver.clone())
+            .with_timeout_height(msg.timeout_height)
+            .build()
+            .unwrap();
+
+        assert_eq!(built, msg);
+
+        let missing_timeout = MsgTransfer::builder()
+            .with_source_channel(msg.source_channel.clone())
+            .with_token(msg.token.clone())
+            .with_sender(msg.sender.clone())
+            .with_receiver(msg.receiver.clone())
+            .build();
+
+        assert!(missing_timeout.is_err());
+
+        let missing_receiver = MsgTransfer::builder()
+            .with_source_channel(msg.source_channel.clone())
+            .with_token(msg.token)
+            .with_sender(msg.sender.clone())
+            .with_timeout_height(msg.timeout_height)
+            .build();
+
+        assert!(missing_receiver.is_err());
+
+        let invalid_token = MsgTransfer::builder()
+            .with_source_channel(msg.source_channel)
+            .with_token(Coin {
+                denom: "uatom".to_string(),
+                amount: "0".to_string(),
+            })
+            .with_sender(msg.sender)
+            .with_receiver(msg.receiver)
+            .with_timeout_height(msg.timeout_height)
+            .build();
+
+        assert!(matches!(
+            invalid_token.unwrap_err().detail(),
+            ErrorDetail::ZeroAmount(_)
+        ));
+    }
+}
@@ -1,11 +1,11 @@
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 use core::convert::TryFrom;
 use core::str::FromStr;
 
-use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
 use serde::{Deserialize, Serialize};
 
 use super::error::Error;
+use super::raw::FungibleTokenPacketData as RawPacketData;
 use super::{Amount, PrefixedCoin, PrefixedDenom};
 use crate::signer::Signer;
 
@@ -15,6 +15,9 @@ pub struct PacketData {
     pub token: PrefixedCoin,
     pub sender: Signer,
     pub receiver: Signer,
+    /// The memo of the transfer, eg. the instructions of the middlewares
+    /// of the destination chain, or empty if the transfer has none.
+    pub memo: String,
 }
 
 impl TryFrom<RawPacketData> for PacketData {
@@ -28,6 +31,7 @@ impl TryFrom<RawPacketData> for PacketData {
             token: PrefixedCoin { denom, amount },
             sender: raw_pkt_data.sender.parse().map_err(Error::signer)?,
             receiver: raw_pkt_data.receiver.parse().map_err(Error::signer)?,
+            memo: raw_pkt_data.memo,
         })
     }
 }
@@ -39,6 +43,46 @@ impl From<PacketData> for RawPacketData {
             amount: pkt_data.token.amount.to_string(),
             sender: pkt_data.sender.to_string(),
             receiver: pkt_data.receiver.to_string(),
+            memo: pkt_data.memo,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+
+    fn packet_data(memo: &str) -> PacketData {
+        PacketData {
+            token: "100uatom".parse::<PrefixedCoin>().unwrap(),
+            sender: "cosmos1sender".parse().unwrap(),
+            receiver: "cosmos1receiver".parse().unwrap(),
+            memo: memo.into(),
+        }
+    }
+
+    #[test]
+    fn packet_data_json_with_memo() {
+        let data = packet_data(r#"{"forward":{"port":"transfer","channel":"channel-1"}}"#);
+        let json: Vec<u8> = serde_json::to_vec(&data).unwrap();
+
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&json).unwrap()["memo"],
+            r#"{"forward":{"port":"transfer","channel":"channel-1"}}"#
+        );
+        assert_eq!(serde_json::from_slice::<PacketData>(&json).unwrap(), data);
+    }
+
+    #[test]
+    fn packet_data_json_without_memo() {
+        let json = serde_json::to_string(&packet_data("")).unwrap();
+
+        assert!(!json.contains("memo"));
+        assert_eq!(
+            serde_json::from_str::<PacketData>(&json).unwrap(),
+            packet_data("")
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Protobuf and JSON encodings of the ICS-20 transfer message and packet data,
+//! along with their `memo` field, which is not part of `ibc-proto` yet.
+//!
+//! The memo is only understood by the chains running ibc-go v6 or later.
+//! Being empty by default, it is left out of the encodings unless it is set,
+//! which keeps them compatible with the chains running older versions.
+
+use crate::prelude::*;
+
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+use serde::{Deserialize, Serialize};
+
+/// `ibc.applications.transfer.v1.MsgTransfer`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgTransfer {
+    #[prost(string, tag = "1")]
+    pub source_port: String,
+    #[prost(string, tag = "2")]
+    pub source_channel: String,
+    #[prost(message, optional, tag = "3")]
+    pub token: Option<Coin>,
+    #[prost(string, tag = "4")]
+    pub sender: String,
+    #[prost(string, tag = "5")]
+    pub receiver: String,
+    #[prost(message, optional, tag = "6")]
+    pub timeout_height: Option<RawHeight>,
+    /// Timeout timestamp, in nanoseconds since the Unix epoch.
+    #[prost(uint64, tag = "7")]
+    pub timeout_timestamp: u64,
+    #[prost(string, tag = "8")]
+    pub memo: String,
+}
+
+/// `ibc.applications.transfer.v2.FungibleTokenPacketData`
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, prost::Message)]
+pub struct FungibleTokenPacketData {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+    #[prost(string, tag = "2")]
+    pub amount: String,
+    #[prost(string, tag = "3")]
+    pub sender: String,
+    #[prost(string, tag = "4")]
+    pub receiver: String,
+    #[prost(string, tag = "5")]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub memo: String,
+}
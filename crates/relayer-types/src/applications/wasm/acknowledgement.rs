@@ -0,0 +1,120 @@
+//! The errors reported by contracts in the error acknowledgements written by `wasmd`,
+//! see [`AckStatus`](crate::core::ics04_channel::ack_status::AckStatus) for their decoding.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use crate::prelude::*;
+
+/// Codes of the `wasm` module errors which can be reported in an error acknowledgement.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WasmErrorCode {
+    InstantiateFailed,
+    ExecuteFailed,
+    OutOfGas,
+    NotFound,
+    InvalidMsg,
+    UnsupportedForContract,
+    /// A code which is not specific to the `wasm` module, or unknown to the relayer.
+    Other(u32),
+}
+
+impl WasmErrorCode {
+    pub fn from_code(code: u32) -> Self {
+        match code {
+            4 => Self::InstantiateFailed,
+            5 => Self::ExecuteFailed,
+            6 => Self::OutOfGas,
+            8 => Self::NotFound,
+            10 => Self::InvalidMsg,
+            17 => Self::UnsupportedForContract,
+            other => Self::Other(other),
+        }
+    }
+
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::InstantiateFailed => 4,
+            Self::ExecuteFailed => 5,
+            Self::OutOfGas => 6,
+            Self::NotFound => 8,
+            Self::InvalidMsg => 10,
+            Self::UnsupportedForContract => 17,
+            Self::Other(code) => *code,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::InstantiateFailed => "instantiate wasm contract failed",
+            Self::ExecuteFailed => "execute wasm contract failed",
+            Self::OutOfGas => "out of gas",
+            Self::NotFound => "not found",
+            Self::InvalidMsg => "invalid CosmosMsg from the contract",
+            Self::UnsupportedForContract => "unsupported for this contract",
+            Self::Other(_) => "unknown error",
+        }
+    }
+}
+
+impl Display for WasmErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{} (code {})", self.description(), self.code())
+    }
+}
+
+/// An error reported by a contract in an error acknowledgement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractError {
+    /// The `wasm` module error code, if the error message includes one.
+    pub code: Option<WasmErrorCode>,
+    /// The error message, without the code prefix.
+    pub message: String,
+}
+
+impl ContractError {
+    /// Parse an error acknowledgement message, which `wasmd` redacts to the
+    /// `ABCI code: <code>: <message>` form.
+    pub fn parse(message: &str) -> Self {
+        let code = message
+            .strip_prefix("ABCI code: ")
+            .and_then(|rest| rest.split_once(": "))
+            .and_then(|(code, rest)| code.parse::<u32>().ok().map(|code| (code, rest)));
+
+        match code {
+            Some((code, rest)) => Self {
+                code: Some(WasmErrorCode::from_code(code)),
+                message: rest.to_string(),
+            },
+            None => Self {
+                code: None,
+                message: message.to_string(),
+            },
+        }
+    }
+}
+
+impl Display for ContractError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match &self.code {
+            Some(code) => write!(f, "{}: {}", code, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_contract_error() {
+        let err =
+            ContractError::parse("ABCI code: 5: error handling packet: see events for details");
+        assert_eq!(err.code, Some(WasmErrorCode::ExecuteFailed));
+        assert_eq!(err.message, "error handling packet: see events for details");
+
+        let err = ContractError::parse("insufficient funds");
+        assert_eq!(err.code, None);
+        assert_eq!(err.message, "insufficient funds");
+    }
+}
@@ -0,0 +1,8 @@
+//! Support for the IBC entry points of CosmWasm contracts, as exposed by `wasmd`-based chains.
+//!
+//! Contracts are bound to ports of the form `wasm.<contract address>`, see
+//! [`PortId::wasm_contract_address`](crate::core::ics24_host::identifier::PortId::wasm_contract_address).
+
+pub mod acknowledgement;
+
+pub use crate::core::ics24_host::validate::WASM_PORT_PREFIX;
@@ -1,4 +1,5 @@
 use core::convert::{TryFrom, TryInto};
+use core::fmt::{Display, Error as FmtError, Formatter};
 use core::time::Duration;
 
 use prost::Message;
@@ -24,6 +25,7 @@ use crate::core::ics23_commitment::specs::ProofSpecs;
 use crate::core::ics24_host::identifier::ChainId;
 use crate::prelude::*;
 use crate::timestamp::{Timestamp, ZERO_DURATION};
+use crate::utils::pretty::{PrettyDuration, PrettyOption, PrettySlice};
 use crate::Height;
 
 pub const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
@@ -100,6 +102,11 @@ impl ClientState {
             ));
         }
 
+        // Disallow malformed proof-specs, eg. custom ones for non-standard stores
+        proof_specs.validate().map_err(|e| {
+            Error::validation(format!("ClientState proof-specs are invalid: {}", e))
+        })?;
+
         Ok(Self {
             chain_id,
             trust_level,
@@ -194,6 +201,55 @@ impl ClientState {
             _ => Ok(()),
         }
     }
+
+    /// A view of this client state without its proof specs, suitable for display.
+    pub fn redacted(&self) -> RedactedClientState {
+        RedactedClientState {
+            chain_id: self.chain_id.clone(),
+            trust_level: self.trust_level,
+            trusting_period: self.trusting_period,
+            unbonding_period: self.unbonding_period,
+            max_clock_drift: self.max_clock_drift,
+            latest_height: self.latest_height,
+            upgrade_path: self.upgrade_path.clone(),
+            allow_update: self.allow_update,
+            frozen_height: self.frozen_height,
+        }
+    }
+}
+
+impl Display for ClientState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "ClientState {{ chain_id: {}, latest_height: {}, frozen_height: {}, trust_level: {}, trusting_period: {}, unbonding_period: {}, max_clock_drift: {}, upgrade_path: {}, allow_update: {{ after_expiry: {}, after_misbehaviour: {} }} }}",
+            self.chain_id,
+            self.latest_height,
+            PrettyOption(&self.frozen_height),
+            self.trust_level,
+            PrettyDuration(&self.trusting_period),
+            PrettyDuration(&self.unbonding_period),
+            PrettyDuration(&self.max_clock_drift),
+            PrettySlice(&self.upgrade_path),
+            self.allow_update.after_expiry,
+            self.allow_update.after_misbehaviour,
+        )
+    }
+}
+
+/// A [`ClientState`] without its proof specs, which are the same for
+/// all clients of Cosmos SDK chains and make up most of its serialized form.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RedactedClientState {
+    pub chain_id: ChainId,
+    pub trust_level: TrustThreshold,
+    pub trusting_period: Duration,
+    pub unbonding_period: Duration,
+    pub max_clock_drift: Duration,
+    pub latest_height: Height,
+    pub upgrade_path: Vec<String>,
+    pub allow_update: AllowUpdate,
+    pub frozen_height: Option<Height>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -330,6 +386,67 @@ impl From<ClientState> for RawTmClientState {
     }
 }
 
+/// Client states are encoded with Borsh as their Protobuf encoding, since the trust
+/// threshold and proof specs they contain have no Borsh encoding of their own.
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for ClientState {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        let bytes = Protobuf::<RawTmClientState>::encode_vec(self).map_err(|_| {
+            borsh::maybestd::io::Error::from(borsh::maybestd::io::ErrorKind::InvalidData)
+        })?;
+
+        borsh::BorshSerialize::serialize(&bytes, writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for ClientState {
+    fn deserialize(buf: &mut &[u8]) -> borsh::maybestd::io::Result<Self> {
+        let bytes = <Vec<u8> as borsh::BorshDeserialize>::deserialize(buf)?;
+
+        Protobuf::<RawTmClientState>::decode_vec(&bytes)
+            .map_err(|_| borsh::maybestd::io::ErrorKind::InvalidData.into())
+    }
+}
+
+/// Client states are SCALE encoded as their Protobuf encoding too, for the same reason.
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Encode for ClientState {
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+        let bytes = RawTmClientState::from(self.clone()).encode_to_vec();
+
+        parity_scale_codec::Encode::encode_to(&bytes, dest)
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::EncodeLike for ClientState {}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Decode for ClientState {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        let bytes = <Vec<u8> as parity_scale_codec::Decode>::decode(input)?;
+
+        Protobuf::<RawTmClientState>::decode_vec(&bytes).map_err(|_| "invalid client state".into())
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl scale_info::TypeInfo for ClientState {
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        scale_info::Type::builder()
+            .path(scale_info::Path::new("ClientState", module_path!()))
+            .composite(scale_info::build::Fields::unnamed().field(|f| f.ty::<Vec<u8>>()))
+    }
+}
+
 impl Protobuf<Any> for ClientState {}
 
 impl TryFrom<Any> for ClientState {
@@ -374,7 +491,9 @@ mod tests {
     use ibc_proto::ics23::ProofSpec as Ics23ProofSpec;
     use tendermint_rpc::endpoint::abci_query::AbciQuery;
 
+    use crate::clients::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
     use crate::clients::ics07_tendermint::client_state::{AllowUpdate, ClientState};
+    use crate::clients::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
     use crate::core::ics02_client::trust_threshold::TrustThreshold;
     use crate::core::ics23_commitment::specs::ProofSpecs;
     use crate::core::ics24_host::identifier::ChainId;
@@ -475,6 +594,18 @@ mod tests {
                 name: "Invalid (empty) proof specs".to_string(),
                 params: ClientStateParams {
                     proof_specs: ProofSpecs::from(Vec::<Ics23ProofSpec>::new()),
+                    ..default_params.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Invalid (malformed) proof specs".to_string(),
+                params: ClientStateParams {
+                    proof_specs: ProofSpecs::from(vec![Ics23ProofSpec {
+                        max_depth: 1,
+                        min_depth: 2,
+                        ..Vec::<Ics23ProofSpec>::from(ProofSpecs::default()).remove(0)
+                    }]),
                     ..default_params
                 },
                 want_pass: false,
@@ -667,6 +798,30 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn client_state_display_and_redacted() {
+        let client_state = get_dummy_tendermint_client_state(get_dummy_tendermint_header());
+
+        let display = client_state.to_string();
+        assert!(display.contains(&format!("chain_id: {}", client_state.chain_id)));
+        assert!(display.contains(&format!("latest_height: {}", client_state.latest_height)));
+        assert!(display.contains("frozen_height: None"));
+        assert!(!display.contains("proof_specs"));
+
+        let redacted = client_state.redacted();
+        assert_eq!(redacted.chain_id, client_state.chain_id);
+        assert_eq!(redacted.latest_height, client_state.latest_height);
+        assert_eq!(redacted.trust_level, client_state.trust_level);
+        assert_eq!(redacted.allow_update, client_state.allow_update);
+
+        let json = serde_json::to_value(&redacted).unwrap();
+        assert!(json.get("proof_specs").is_none());
+        assert_eq!(
+            json["latest_height"],
+            serde_json::to_value(client_state.latest_height).unwrap()
+        );
+    }
 }
 
 #[cfg(any(test, feature = "mocks"))]
@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+use core::fmt::{Display, Error as FmtError, Formatter};
+
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::ConsensusState as RawConsensusState;
 use ibc_proto::protobuf::Protobuf;
@@ -34,6 +36,16 @@ impl ConsensusState {
     }
 }
 
+impl Display for ConsensusState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "ConsensusState {{ timestamp: {}, root: {}, next_validators_hash: {} }}",
+            self.timestamp, self.root, self.next_validators_hash
+        )
+    }
+}
+
 impl crate::core::ics02_client::consensus_state::ConsensusState for ConsensusState {
     fn client_type(&self) -> ClientType {
         ClientType::Tendermint
@@ -149,11 +161,28 @@ impl From<Header> for ConsensusState {
 
 #[cfg(test)]
 mod tests {
+    use crate::prelude::*;
+
     use tendermint_rpc::endpoint::abci_query::AbciQuery;
     use test_log::test;
 
+    use crate::clients::ics07_tendermint::consensus_state::ConsensusState;
+    use crate::clients::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
     use crate::test::test_serialization_roundtrip;
 
+    #[test]
+    fn display() {
+        let consensus_state = ConsensusState::from(get_dummy_tendermint_header());
+        let display = consensus_state.to_string();
+
+        assert!(display.contains(&format!("timestamp: {}", consensus_state.timestamp)));
+        assert!(display.contains(&format!("root: {}", consensus_state.root)));
+        assert!(display.contains(&format!(
+            "next_validators_hash: {}",
+            consensus_state.next_validators_hash
+        )));
+    }
+
     #[test]
     fn serialization_roundtrip_no_proof() {
         let json_data =
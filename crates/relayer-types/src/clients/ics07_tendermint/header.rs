@@ -1,4 +1,4 @@
-use alloc::string::ToString;
+use crate::prelude::*;
 use core::cmp::Ordering;
 use core::fmt::{Display, Error as FmtError, Formatter};
 
@@ -14,6 +14,7 @@ use tendermint::validator::Set as ValidatorSet;
 use crate::clients::ics07_tendermint::error::Error;
 use crate::core::ics02_client::client_type::ClientType;
 use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics23_commitment::commitment::CommitmentRoot;
 use crate::core::ics24_host::identifier::ChainId;
 use crate::timestamp::Timestamp;
 use crate::utils::pretty::{PrettySignedHeader, PrettyValidatorSet};
@@ -55,6 +56,59 @@ impl Header {
     pub fn compatible_with(&self, other_header: &Header) -> bool {
         headers_compatible(&self.signed_header, &other_header.signed_header)
     }
+
+    /// A view of this header without its commit signatures and with only the
+    /// addresses of the validators in its validator sets, suitable for display.
+    pub fn redacted(&self) -> RedactedHeader {
+        let header = &self.signed_header.header;
+
+        RedactedHeader {
+            chain_id: header.chain_id.to_string(),
+            height: self.height(),
+            time: header.time,
+            app_hash: CommitmentRoot::from_bytes(header.app_hash.as_ref()),
+            validators_hash: header.validators_hash,
+            next_validators_hash: header.next_validators_hash,
+            validator_set: RedactedValidatorSet::from(&self.validator_set),
+            trusted_height: self.trusted_height,
+            trusted_validator_set: RedactedValidatorSet::from(&self.trusted_validator_set),
+        }
+    }
+}
+
+/// A [`Header`] without the commit signatures and the public keys of the
+/// validators, which make up most of its serialized form.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RedactedHeader {
+    pub chain_id: String,
+    pub height: Height,
+    pub time: tendermint::Time,
+    pub app_hash: CommitmentRoot,
+    pub validators_hash: tendermint::Hash,
+    pub next_validators_hash: tendermint::Hash,
+    pub validator_set: RedactedValidatorSet,
+    pub trusted_height: Height,
+    pub trusted_validator_set: RedactedValidatorSet,
+}
+
+/// A validator set reduced to the addresses of its validators and their total voting power.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RedactedValidatorSet {
+    pub validators: Vec<tendermint::account::Id>,
+    pub total_voting_power: tendermint::vote::Power,
+}
+
+impl From<&ValidatorSet> for RedactedValidatorSet {
+    fn from(validator_set: &ValidatorSet) -> Self {
+        Self {
+            validators: validator_set
+                .validators()
+                .iter()
+                .map(|validator| validator.address)
+                .collect(),
+            total_voting_power: validator_set.total_voting_power(),
+        }
+    }
 }
 
 pub fn headers_compatible(header: &SignedHeader, other: &SignedHeader) -> bool {
@@ -239,3 +293,31 @@ pub mod test_util {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use super::test_util::get_dummy_ics07_header;
+
+    #[test]
+    fn redacted_header() {
+        let header = get_dummy_ics07_header();
+        let redacted = header.redacted();
+
+        assert_eq!(redacted.height, header.height());
+        assert_eq!(redacted.trusted_height, header.trusted_height);
+        assert_eq!(
+            redacted.validator_set.validators,
+            vec![header.validator_set.validators()[0].address]
+        );
+        assert_eq!(
+            redacted.validator_set.total_voting_power,
+            header.validator_set.total_voting_power()
+        );
+
+        let json = serde_json::to_value(&redacted).unwrap();
+        assert!(json.get("signed_header").is_none());
+        assert!(json["validator_set"].get("proposer").is_none());
+    }
+}
@@ -5,6 +5,7 @@ use crate::prelude::*;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::MsgCreateClient as RawMsgCreateClient;
 use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
 
 use crate::core::ics02_client::error::Error;
 use crate::signer::Signer;
@@ -13,9 +14,11 @@ use crate::tx_msg::Msg;
 pub const TYPE_URL: &str = "/ibc.core.client.v1.MsgCreateClient";
 
 /// A type of message that triggers the creation of a new on-chain (IBC) client.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgCreateClient {
+    #[serde(with = "crate::serializers::serde_any")]
     pub client_state: Any,
+    #[serde(with = "crate::serializers::serde_any")]
     pub consensus_state: Any,
     pub signer: Signer,
 }
@@ -3,6 +3,7 @@ use crate::prelude::*;
 use ibc_proto::google::protobuf::Any as ProtoAny;
 use ibc_proto::ibc::core::client::v1::MsgSubmitMisbehaviour as RawMsgSubmitMisbehaviour;
 use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
 
 use crate::core::ics02_client::error::Error;
 use crate::core::ics24_host::identifier::ClientId;
@@ -12,11 +13,12 @@ use crate::tx_msg::Msg;
 pub const TYPE_URL: &str = "/ibc.core.client.v1.MsgSubmitMisbehaviour";
 
 /// A type of message that submits client misbehaviour proof.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgSubmitMisbehaviour {
     /// client unique identifier
     pub client_id: ClientId,
     /// misbehaviour used for freezing the light client
+    #[serde(with = "crate::serializers::serde_any")]
     pub misbehaviour: ProtoAny,
     /// signer address
     pub signer: Signer,
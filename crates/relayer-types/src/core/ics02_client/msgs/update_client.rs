@@ -5,6 +5,7 @@ use crate::prelude::*;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::MsgUpdateClient as RawMsgUpdateClient;
 use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
 
 use crate::core::ics02_client::error::Error;
 use crate::core::ics24_host::error::ValidationError;
@@ -15,9 +16,10 @@ use crate::tx_msg::Msg;
 pub const TYPE_URL: &str = "/ibc.core.client.v1.MsgUpdateClient";
 
 /// A type of message that triggers the update of an on-chain (IBC) client with new headers.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgUpdateClient {
     pub client_id: ClientId,
+    #[serde(with = "crate::serializers::serde_any")]
     pub header: Any,
     pub signer: Signer,
 }
@@ -97,5 +99,9 @@ mod tests {
         let raw_back = RawMsgUpdateClient::from(msg_back.clone());
         assert_eq!(msg, msg_back);
         assert_eq!(raw, raw_back);
+
+        let json = serde_json::to_string(&msg).unwrap();
+        let msg_back: MsgUpdateClient = serde_json::from_str(&json).unwrap();
+        assert_eq!(msg, msg_back);
     }
 }
@@ -8,6 +8,7 @@ use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::MsgUpgradeClient as RawMsgUpgradeClient;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
 use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
 
 use crate::core::ics02_client::error::Error;
 use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
@@ -19,12 +20,16 @@ use crate::tx_msg::Msg;
 pub(crate) const TYPE_URL: &str = "/ibc.core.client.v1.MsgUpgradeClient";
 
 /// A type of message that triggers the upgrade of an on-chain (IBC) client.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MsgUpgradeClient {
     pub client_id: ClientId,
+    #[serde(with = "crate::serializers::serde_any")]
     pub client_state: Any,
+    #[serde(with = "crate::serializers::serde_any")]
     pub consensus_state: Any,
+    #[serde(with = "crate::serializers::serde_proto_hex")]
     pub proof_upgrade_client: RawMerkleProof,
+    #[serde(with = "crate::serializers::serde_proto_hex")]
     pub proof_upgrade_consensus_state: RawMerkleProof,
     pub signer: Signer,
 }
@@ -192,5 +197,9 @@ mod tests {
         let raw_back: RawMsgUpgradeClient = RawMsgUpgradeClient::from(msg_back.clone());
         assert_eq!(msg, msg_back);
         assert_eq!(raw, raw_back);
+
+        let json = serde_json::to_string(&msg).unwrap();
+        let msg_back: MsgUpgradeClient = serde_json::from_str(&json).unwrap();
+        assert_eq!(msg, msg_back);
     }
 }
@@ -116,6 +116,10 @@ define_error! {
                     e.connection_id, e.counterparty_connection_id)
             },
 
+        MissingMsgField
+            { field: String }
+            | e | { format_args!("missing field `{0}` to build the message", e.field) },
+
         MissingCounterparty
             | _ | { "missing counterparty" },
 
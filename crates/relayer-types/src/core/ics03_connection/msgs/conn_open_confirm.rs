@@ -3,6 +3,7 @@ use crate::prelude::*;
 use ibc_proto::protobuf::Protobuf;
 
 use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenConfirm as RawMsgConnectionOpenConfirm;
+use serde::{Deserialize, Serialize};
 
 use crate::core::ics03_connection::error::Error;
 use crate::core::ics24_host::identifier::ConnectionId;
@@ -15,7 +16,7 @@ pub const TYPE_URL: &str = "/ibc.core.connection.v1.MsgConnectionOpenConfirm";
 ///
 /// Message definition for `MsgConnectionOpenConfirm` (i.e., `ConnOpenConfirm` datagram).
 ///
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgConnectionOpenConfirm {
     pub connection_id: ConnectionId,
     pub proofs: Proofs,
@@ -9,10 +9,11 @@ use core::{
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenTry as RawMsgConnectionOpenTry;
 use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
 
 use crate::core::ics03_connection::connection::Counterparty;
 use crate::core::ics03_connection::error::Error;
-use crate::core::ics03_connection::version::Version;
+use crate::core::ics03_connection::version::{get_compatible_versions, Version};
 use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
 use crate::core::ics24_host::identifier::{ClientId, ConnectionId};
 use crate::proofs::{ConsensusProof, Proofs};
@@ -25,10 +26,11 @@ pub const TYPE_URL: &str = "/ibc.core.connection.v1.MsgConnectionOpenTry";
 ///
 /// Message definition `MsgConnectionOpenTry`  (i.e., `ConnOpenTry` datagram).
 ///
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgConnectionOpenTry {
     pub previous_connection_id: Option<ConnectionId>,
     pub client_id: ClientId,
+    #[serde(default, with = "crate::serializers::serde_option_any")]
     pub client_state: Option<Any>,
     pub counterparty: Counterparty,
     pub counterparty_versions: Vec<Version>,
@@ -43,6 +45,112 @@ impl MsgConnectionOpenTry {
     pub fn consensus_height(&self) -> Option<Height> {
         self.proofs.consensus_proof().map(|proof| proof.height())
     }
+
+    pub fn builder() -> MsgConnectionOpenTryBuilder {
+        MsgConnectionOpenTryBuilder::default()
+    }
+}
+
+/// Builder of [`MsgConnectionOpenTry`] messages, proposing the versions compatible
+/// with this implementation, with no delay period and no client state, unless set
+/// otherwise.
+///
+/// The proofs have no default, as a message with empty proofs is rejected by any chain.
+#[derive(Clone, Debug)]
+pub struct MsgConnectionOpenTryBuilder {
+    client_id: Option<ClientId>,
+    client_state: Option<Any>,
+    counterparty: Option<Counterparty>,
+    counterparty_versions: Vec<Version>,
+    proofs: Option<Proofs>,
+    delay_period: Duration,
+    signer: Option<Signer>,
+}
+
+impl Default for MsgConnectionOpenTryBuilder {
+    fn default() -> Self {
+        Self {
+            client_id: None,
+            client_state: None,
+            counterparty: None,
+            counterparty_versions: get_compatible_versions(),
+            proofs: None,
+            delay_period: Duration::ZERO,
+            signer: None,
+        }
+    }
+}
+
+impl MsgConnectionOpenTryBuilder {
+    pub fn with_client_id(mut self, client_id: ClientId) -> Self {
+        self.client_id = Some(client_id);
+        self
+    }
+
+    pub fn with_client_state(mut self, client_state: Any) -> Self {
+        self.client_state = Some(client_state);
+        self
+    }
+
+    pub fn with_counterparty(mut self, counterparty: Counterparty) -> Self {
+        self.counterparty = Some(counterparty);
+        self
+    }
+
+    pub fn with_counterparty_versions(mut self, counterparty_versions: Vec<Version>) -> Self {
+        self.counterparty_versions = counterparty_versions;
+        self
+    }
+
+    pub fn with_proofs(mut self, proofs: Proofs) -> Self {
+        self.proofs = Some(proofs);
+        self
+    }
+
+    pub fn with_delay_period(mut self, delay_period: Duration) -> Self {
+        self.delay_period = delay_period;
+        self
+    }
+
+    pub fn with_signer(mut self, signer: Signer) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Build the message, checking that all the fields without a default are set,
+    /// that some versions are proposed, and that the proofs of the client and
+    /// consensus states are present.
+    pub fn build(self) -> Result<MsgConnectionOpenTry, Error> {
+        let missing = |field: &str| Error::missing_msg_field(field.to_string());
+
+        let client_id = self.client_id.ok_or_else(|| missing("client_id"))?;
+        let counterparty = self.counterparty.ok_or_else(|| missing("counterparty"))?;
+        let proofs = self.proofs.ok_or_else(|| missing("proofs"))?;
+        let signer = self.signer.ok_or_else(|| missing("signer"))?;
+
+        if self.counterparty_versions.is_empty() {
+            return Err(Error::empty_versions());
+        }
+
+        if proofs.client_proof().is_none() {
+            return Err(Error::null_client_proof());
+        }
+
+        if proofs.consensus_proof().is_none() {
+            return Err(Error::missing_consensus_height());
+        }
+
+        Ok(MsgConnectionOpenTry {
+            previous_connection_id: None,
+            client_id,
+            client_state: self.client_state,
+            counterparty,
+            counterparty_versions: self.counterparty_versions,
+            proofs,
+            delay_period: self.delay_period,
+            signer,
+        })
+    }
 }
 
 impl Msg for MsgConnectionOpenTry {
@@ -350,6 +458,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn build_connection_open_try() {
+        let msg = MsgConnectionOpenTry::try_from(get_dummy_raw_msg_conn_open_try(10, 34)).unwrap();
+
+        let built = MsgConnectionOpenTry::builder()
+            .with_client_id(msg.client_id.clone())
+            .with_counterparty(msg.counterparty.clone())
+            .with_proofs(msg.proofs.clone())
+            .with_signer(msg.signer.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            built,
+            MsgConnectionOpenTry {
+                previous_connection_id: None,
+                ..msg.clone()
+            }
+        );
+
+        let missing_proofs = MsgConnectionOpenTry::builder()
+            .with_client_id(msg.client_id.clone())
+            .with_counterparty(msg.counterparty.clone())
+            .with_signer(msg.signer.clone())
+            .build();
+
+        assert!(missing_proofs.is_err());
+
+        let no_versions = MsgConnectionOpenTry::builder()
+            .with_client_id(msg.client_id)
+            .with_counterparty(msg.counterparty)
+            .with_counterparty_versions(vec![])
+            .with_proofs(msg.proofs)
+            .with_signer(msg.signer)
+            .build();
+
+        assert!(no_versions.is_err());
+    }
+
     #[test]
     fn to_and_from() {
         let raw = get_dummy_raw_msg_conn_open_try(10, 34);
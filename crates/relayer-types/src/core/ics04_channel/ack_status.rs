@@ -0,0 +1,98 @@
+//! Classification of packet acknowledgements as successful or failed, as far as
+//! can be told without knowing the application which wrote them.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use crate::applications::transfer::acknowledgement::Acknowledgement;
+use crate::prelude::*;
+
+/// The outcome of the handling of a packet by the application on the
+/// destination chain, as reported by its acknowledgement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AckStatus {
+    Success,
+    /// The application failed to handle the packet, with the given error.
+    Error(String),
+}
+
+impl AckStatus {
+    /// Classify the given raw acknowledgement.
+    ///
+    /// Acknowledgements following the ICS-04 recommended envelope, such as the ones
+    /// written by the ICS-20 transfer, the interchain accounts host and the `wasm`
+    /// modules, are either `{"result":"<base64>"}` or `{"error":"<message>"}`,
+    /// as decoded by the ICS-20 [`Acknowledgement`]. Any other acknowledgement,
+    /// including a result specific to the application which wrote it, is deemed
+    /// successful, since error acknowledgements are only defined by the envelope.
+    pub fn from_bytes(ack: &[u8]) -> Self {
+        match serde_json::from_slice::<Acknowledgement>(ack) {
+            Ok(Acknowledgement::Error(message)) => Self::Error(message),
+            Ok(Acknowledgement::Success(_)) | Err(_) => Self::Success,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Success)
+    }
+
+    /// The error reported by the application, if the packet could not be handled.
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            Self::Success => None,
+            Self::Error(message) => Some(message),
+        }
+    }
+}
+
+impl Display for AckStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Success => write!(f, "success"),
+            Self::Error(message) => write!(f, "error: {}", message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn classify_transfer_acks() {
+        assert_eq!(
+            AckStatus::from_bytes(br#"{"result":"AQ=="}"#),
+            AckStatus::Success
+        );
+        assert_eq!(
+            AckStatus::from_bytes(
+                br#"{"error":"ABCI code: 1: error handling packet: see events for details"}"#
+            ),
+            AckStatus::Error(
+                "ABCI code: 1: error handling packet: see events for details".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn classify_ica_acks() {
+        assert_eq!(
+            AckStatus::from_bytes(
+                br#"{"result":"EiwKKi9jb3Ntb3MuYmFuay52MWJldGExLk1zZ1NlbmRSZXNwb25zZQ=="}"#
+            ),
+            AckStatus::Success
+        );
+        assert_eq!(
+            AckStatus::from_bytes(
+                br#"{"error":"ABCI code: 5: error handling packet: see events for details"}"#
+            )
+            .error(),
+            Some("ABCI code: 5: error handling packet: see events for details")
+        );
+    }
+
+    #[test]
+    fn classify_raw_acks() {
+        assert!(AckStatus::from_bytes(&[0x01]).is_success());
+        assert!(AckStatus::from_bytes(b"mock acknowledgement").is_success());
+    }
+}
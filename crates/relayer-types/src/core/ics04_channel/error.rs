@@ -53,6 +53,18 @@ define_error! {
             [ TraceError<TendermintError> ]
             | _ | { "invalid version" },
 
+        InvalidVersionMetadata
+            { version: String, reason: String }
+            | e | {
+                format_args!("invalid version metadata '{}': {}", e.version, e.reason)
+            },
+
+        UnsupportedFeeVersion
+            { fee_version: String }
+            | e | {
+                format_args!("unsupported fee version '{}', only 'ics29-1' is supported", e.fee_version)
+            },
+
         Signer
             [ SignerError ]
             | _ | { "invalid signer address" },
@@ -99,6 +111,10 @@ define_error! {
         MissingChannel
             | _ | { "missing channel end" },
 
+        MissingMsgField
+            { field: String }
+            | e | { format_args!("missing field `{0}` to build the message", e.field) },
+
         InvalidVersionLengthConnection
             | _ | { "single version must be negociated on connection before opening channel" },
 
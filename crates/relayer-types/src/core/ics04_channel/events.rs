@@ -2,11 +2,13 @@
 
 use core::fmt::{Display, Error as FmtError, Formatter};
 use serde_derive::{Deserialize, Serialize};
+use subtle_encoding::hex;
 use tendermint::abci::tag::Tag;
 use tendermint::abci::Event as AbciEvent;
 
 use crate::core::ics04_channel::error::Error;
 use crate::core::ics04_channel::packet::Packet;
+use crate::core::ics04_channel::version::Version;
 use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
 use crate::events::{Error as EventError, IbcEvent, IbcEventType};
 use crate::prelude::*;
@@ -18,10 +20,16 @@ pub const CHANNEL_ID_ATTRIBUTE_KEY: &str = "channel_id";
 pub const PORT_ID_ATTRIBUTE_KEY: &str = "port_id";
 pub const COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY: &str = "counterparty_channel_id";
 pub const COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY: &str = "counterparty_port_id";
+pub const VERSION_ATTRIBUTE_KEY: &str = "version";
 
 /// Packet event attribute keys
+///
+/// The packet data and acknowledgement are emitted both as is, when they are
+/// valid UTF-8, and hex-encoded, like ibc-go does, so that binary payloads
+/// survive the conversion to and from ABCI events.
 pub const PKT_SEQ_ATTRIBUTE_KEY: &str = "packet_sequence";
 pub const PKT_DATA_ATTRIBUTE_KEY: &str = "packet_data";
+pub const PKT_DATA_HEX_ATTRIBUTE_KEY: &str = "packet_data_hex";
 pub const PKT_SRC_PORT_ATTRIBUTE_KEY: &str = "packet_src_port";
 pub const PKT_SRC_CHANNEL_ATTRIBUTE_KEY: &str = "packet_src_channel";
 pub const PKT_DST_PORT_ATTRIBUTE_KEY: &str = "packet_dst_port";
@@ -29,6 +37,7 @@ pub const PKT_DST_CHANNEL_ATTRIBUTE_KEY: &str = "packet_dst_channel";
 pub const PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY: &str = "packet_timeout_height";
 pub const PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "packet_timeout_timestamp";
 pub const PKT_ACK_ATTRIBUTE_KEY: &str = "packet_ack";
+pub const PKT_ACK_HEX_ATTRIBUTE_KEY: &str = "packet_ack_hex";
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct Attributes {
@@ -37,6 +46,9 @@ pub struct Attributes {
     pub connection_id: ConnectionId,
     pub counterparty_port_id: PortId,
     pub counterparty_channel_id: Option<ChannelId>,
+    /// The version of the channel end, only reported by the
+    /// `ChanOpenInit` and `ChanOpenTry` events of recent chains.
+    pub version: Option<Version>,
 }
 
 impl Attributes {
@@ -99,6 +111,13 @@ impl From<Attributes> for Vec<Tag> {
             };
             attributes.push(channel_id);
         }
+        if let Some(version) = a.version {
+            let version = Tag {
+                key: VERSION_ATTRIBUTE_KEY.parse().unwrap(),
+                value: version.to_string().parse().unwrap(),
+            };
+            attributes.push(version);
+        }
         attributes
     }
 }
@@ -155,13 +174,11 @@ impl TryFrom<Packet> for Vec<Tag> {
                 .unwrap(),
         };
         attributes.push(timeout_timestamp);
-        let val =
-            String::from_utf8(p.data).expect("hex-encoded string should always be valid UTF-8");
-        let packet_data = Tag {
-            key: PKT_DATA_ATTRIBUTE_KEY.parse().unwrap(),
-            value: val.parse().unwrap(),
-        };
-        attributes.push(packet_data);
+        attributes.extend(bytes_tags(
+            PKT_DATA_ATTRIBUTE_KEY,
+            PKT_DATA_HEX_ATTRIBUTE_KEY,
+            p.data,
+        ));
         let ack = Tag {
             key: PKT_ACK_ATTRIBUTE_KEY.parse().unwrap(),
             value: "".parse().unwrap(),
@@ -171,6 +188,29 @@ impl TryFrom<Packet> for Vec<Tag> {
     }
 }
 
+/// The tags holding the given bytes: as is if they are valid UTF-8,
+/// and hex-encoded under `hex_key` in any case.
+fn bytes_tags(key: &str, hex_key: &str, bytes: Vec<u8>) -> Vec<Tag> {
+    let hex_tag = Tag {
+        key: hex_key.parse().unwrap(),
+        value: String::from_utf8(hex::encode(&bytes))
+            .expect("hex-encoded string should always be valid UTF-8")
+            .parse()
+            .unwrap(),
+    };
+
+    match String::from_utf8(bytes) {
+        Ok(value) => vec![
+            Tag {
+                key: key.parse().unwrap(),
+                value: value.parse().unwrap(),
+            },
+            hex_tag,
+        ],
+        Err(_) => vec![hex_tag],
+    }
+}
+
 pub trait EventType {
     fn event_type() -> IbcEventType;
 }
@@ -182,6 +222,8 @@ pub struct OpenInit {
     pub connection_id: ConnectionId,
     pub counterparty_port_id: PortId,
     pub counterparty_channel_id: Option<ChannelId>,
+    /// The version the application settled on for the new channel end, if reported.
+    pub version: Option<Version>,
 }
 
 impl OpenInit {
@@ -212,6 +254,7 @@ impl From<OpenInit> for Attributes {
             connection_id: ev.connection_id,
             counterparty_port_id: ev.counterparty_port_id,
             counterparty_channel_id: ev.counterparty_channel_id,
+            version: ev.version,
         }
     }
 }
@@ -235,6 +278,8 @@ pub struct OpenTry {
     pub connection_id: ConnectionId,
     pub counterparty_port_id: PortId,
     pub counterparty_channel_id: Option<ChannelId>,
+    /// The version the application settled on for the new channel end, if reported.
+    pub version: Option<Version>,
 }
 
 impl Display for OpenTry {
@@ -256,6 +301,7 @@ impl From<OpenTry> for Attributes {
             connection_id: ev.connection_id,
             counterparty_port_id: ev.counterparty_port_id,
             counterparty_channel_id: ev.counterparty_channel_id,
+            version: ev.version,
         }
     }
 }
@@ -308,6 +354,7 @@ impl From<OpenAck> for Attributes {
             connection_id: ev.connection_id,
             counterparty_port_id: ev.counterparty_port_id,
             counterparty_channel_id: ev.counterparty_channel_id,
+            version: None,
         }
     }
 }
@@ -365,6 +412,7 @@ impl From<OpenConfirm> for Attributes {
             connection_id: ev.connection_id,
             counterparty_port_id: ev.counterparty_port_id,
             counterparty_channel_id: ev.counterparty_channel_id,
+            version: None,
         }
     }
 }
@@ -416,6 +464,7 @@ impl From<CloseInit> for Attributes {
             connection_id: ev.connection_id,
             counterparty_port_id: ev.counterparty_port_id,
             counterparty_channel_id: ev.counterparty_channel_id,
+            version: None,
         }
     }
 }
@@ -495,6 +544,7 @@ impl From<CloseConfirm> for Attributes {
             connection_id: ev.connection_id,
             counterparty_port_id: ev.counterparty_port_id,
             counterparty_channel_id: ev.counterparty_channel_id,
+            version: None,
         }
     }
 }
@@ -535,7 +585,28 @@ macro_rules! impl_try_from_attribute_for_event {
     };
 }
 
-impl_try_from_attribute_for_event!(OpenInit, OpenTry, OpenAck, OpenConfirm, CloseConfirm);
+impl_try_from_attribute_for_event!(OpenAck, OpenConfirm, CloseConfirm);
+
+macro_rules! impl_try_from_attribute_for_versioned_event {
+    ($($event:ty),+) => {
+        $(impl TryFrom<Attributes> for $event {
+            type Error = EventError;
+
+            fn try_from(attrs: Attributes) -> Result<Self, Self::Error> {
+                Ok(Self {
+                    port_id: attrs.port_id,
+                    channel_id: attrs.channel_id,
+                    connection_id: attrs.connection_id,
+                    counterparty_port_id: attrs.counterparty_port_id,
+                    counterparty_channel_id: attrs.counterparty_channel_id,
+                    version: attrs.version,
+                })
+            }
+        })+
+    };
+}
+
+impl_try_from_attribute_for_versioned_event!(OpenInit, OpenTry);
 
 macro_rules! impl_from_ibc_to_abci_event {
     ($($event:ty),+) => {
@@ -693,14 +764,13 @@ impl TryFrom<WriteAcknowledgement> for AbciEvent {
 
     fn try_from(v: WriteAcknowledgement) -> Result<Self, Self::Error> {
         let mut attributes = Vec::<Tag>::try_from(v.packet)?;
-        let val =
-            String::from_utf8(v.ack).expect("hex-encoded string should always be valid UTF-8");
-        // No actual conversion from string to `Tag::Key` or `Tag::Value`
-        let ack = Tag {
-            key: PKT_ACK_ATTRIBUTE_KEY.parse().unwrap(),
-            value: val.parse().unwrap(),
-        };
-        attributes.push(ack);
+        // Replace the empty acknowledgement of the packet attributes
+        attributes.retain(|tag| tag.key.as_ref() != PKT_ACK_ATTRIBUTE_KEY);
+        attributes.extend(bytes_tags(
+            PKT_ACK_ATTRIBUTE_KEY,
+            PKT_ACK_HEX_ATTRIBUTE_KEY,
+            v.ack,
+        ));
         Ok(AbciEvent {
             type_str: IbcEventType::WriteAck.as_str().to_string(),
             attributes,
@@ -1,6 +1,7 @@
 //! ICS 04: Channel implementation that facilitates communication between
 //! applications and the chains those applications are built upon.
 
+pub mod ack_status;
 pub mod channel;
 pub mod commitment;
 pub mod error;
@@ -3,7 +3,9 @@ use crate::prelude::*;
 use derive_more::{From, Into};
 use ibc_proto::ibc::core::channel::v1::MsgAcknowledgement as RawMsgAcknowledgement;
 use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
 
+use crate::core::ics04_channel::ack_status::AckStatus;
 use crate::core::ics04_channel::error::Error;
 use crate::core::ics04_channel::packet::Packet;
 use crate::proofs::Proofs;
@@ -13,13 +15,25 @@ use crate::tx_msg::Msg;
 pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgAcknowledgement";
 
 /// A generic Acknowledgement type that modules may interpret as they like.
-#[derive(Clone, Debug, PartialEq, Eq, From, Into)]
-pub struct Acknowledgement(Vec<u8>);
+#[derive(Clone, Debug, PartialEq, Eq, From, Into, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Acknowledgement(
+    #[serde(
+        serialize_with = "crate::serializers::ser_hex_upper",
+        deserialize_with = "crate::serializers::deser_hex_upper"
+    )]
+    Vec<u8>,
+);
 
 impl Acknowledgement {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Whether this acknowledgement reports a success or an error, see [`AckStatus::from_bytes`].
+    pub fn status(&self) -> AckStatus {
+        AckStatus::from_bytes(&self.0)
+    }
 }
 
 impl AsRef<[u8]> for Acknowledgement {
@@ -31,7 +45,7 @@ impl AsRef<[u8]> for Acknowledgement {
 ///
 /// Message definition for packet acknowledgements.
 ///
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgAcknowledgement {
     pub packet: Packet,
     pub acknowledgement: Acknowledgement,
@@ -161,6 +175,7 @@ mod test {
 
     use ibc_proto::ibc::core::channel::v1::MsgAcknowledgement as RawMsgAcknowledgement;
 
+    use crate::core::ics04_channel::ack_status::AckStatus;
     use crate::core::ics04_channel::error::Error;
     use crate::core::ics04_channel::msgs::acknowledgement::test_util::get_dummy_raw_msg_acknowledgement;
     use crate::core::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
@@ -230,4 +245,13 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn msg_acknowledgement_json() {
+        let msg = MsgAcknowledgement::try_from(get_dummy_raw_msg_acknowledgement(50)).unwrap();
+
+        let json = serde_json::to_string(&msg).unwrap();
+        let msg_back: MsgAcknowledgement = serde_json::from_str(&json).unwrap();
+        assert_eq!(msg, msg_back);
+    }
 }
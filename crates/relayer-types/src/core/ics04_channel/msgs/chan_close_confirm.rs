@@ -3,6 +3,7 @@ use crate::prelude::*;
 use ibc_proto::protobuf::Protobuf;
 
 use ibc_proto::ibc::core::channel::v1::MsgChannelCloseConfirm as RawMsgChannelCloseConfirm;
+use serde::{Deserialize, Serialize};
 
 use crate::core::ics04_channel::error::Error;
 use crate::core::ics24_host::identifier::{ChannelId, PortId};
@@ -16,7 +17,7 @@ pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelCloseConfirm";
 /// Message definition for the second step in the channel close handshake (the `ChanCloseConfirm`
 /// datagram).
 ///
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgChannelCloseConfirm {
     pub port_id: PortId,
     pub channel_id: ChannelId,
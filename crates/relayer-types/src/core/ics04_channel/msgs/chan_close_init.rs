@@ -3,6 +3,7 @@ use crate::prelude::*;
 use ibc_proto::protobuf::Protobuf;
 
 use ibc_proto::ibc::core::channel::v1::MsgChannelCloseInit as RawMsgChannelCloseInit;
+use serde::{Deserialize, Serialize};
 
 use crate::core::ics04_channel::error::Error;
 use crate::core::ics24_host::identifier::{ChannelId, PortId};
@@ -14,7 +15,7 @@ pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelCloseInit";
 ///
 /// Message definition for the first step in the channel close handshake (`ChanCloseInit` datagram).
 ///
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgChannelCloseInit {
     pub port_id: PortId,
     pub channel_id: ChannelId,
@@ -8,13 +8,14 @@ use crate::tx_msg::Msg;
 
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenAck as RawMsgChannelOpenAck;
 use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
 
 pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenAck";
 
 ///
 /// Message definition for the third step in the channel open handshake (`ChanOpenAck` datagram).
 ///
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgChannelOpenAck {
     pub port_id: PortId,
     pub channel_id: ChannelId,
@@ -1,19 +1,21 @@
-use crate::core::ics04_channel::channel::ChannelEnd;
+use crate::core::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
 use crate::core::ics04_channel::error::Error;
-use crate::core::ics24_host::identifier::PortId;
+use crate::core::ics04_channel::version::Version;
+use crate::core::ics24_host::identifier::{ConnectionId, PortId};
 use crate::prelude::*;
 use crate::signer::Signer;
 use crate::tx_msg::Msg;
 
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenInit as RawMsgChannelOpenInit;
 use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
 
 pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenInit";
 
 ///
 /// Message definition for the first step in the channel open handshake (`ChanOpenInit` datagram).
 ///
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgChannelOpenInit {
     pub port_id: PortId,
     pub channel: ChannelEnd,
@@ -28,6 +30,80 @@ impl MsgChannelOpenInit {
             signer,
         }
     }
+
+    pub fn builder() -> MsgChannelOpenInitBuilder {
+        MsgChannelOpenInitBuilder::default()
+    }
+}
+
+/// Builder of [`MsgChannelOpenInit`] messages, for an unordered channel
+/// with an empty version, which lets the application pick its default
+/// version, unless set otherwise.
+#[derive(Clone, Debug, Default)]
+pub struct MsgChannelOpenInitBuilder {
+    port_id: Option<PortId>,
+    ordering: Order,
+    connection_id: Option<ConnectionId>,
+    counterparty_port_id: Option<PortId>,
+    version: Version,
+    signer: Option<Signer>,
+}
+
+impl MsgChannelOpenInitBuilder {
+    pub fn with_port_id(mut self, port_id: PortId) -> Self {
+        self.port_id = Some(port_id);
+        self
+    }
+
+    pub fn with_ordering(mut self, ordering: Order) -> Self {
+        self.ordering = ordering;
+        self
+    }
+
+    pub fn with_connection_id(mut self, connection_id: ConnectionId) -> Self {
+        self.connection_id = Some(connection_id);
+        self
+    }
+
+    pub fn with_counterparty_port_id(mut self, counterparty_port_id: PortId) -> Self {
+        self.counterparty_port_id = Some(counterparty_port_id);
+        self
+    }
+
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn with_signer(mut self, signer: Signer) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Build the message, checking that all the fields without a default are set,
+    /// and that the resulting channel end is valid.
+    pub fn build(self) -> Result<MsgChannelOpenInit, Error> {
+        let missing = |field: &str| Error::missing_msg_field(field.to_string());
+
+        let port_id = self.port_id.ok_or_else(|| missing("port_id"))?;
+        let connection_id = self.connection_id.ok_or_else(|| missing("connection_id"))?;
+        let counterparty_port_id = self
+            .counterparty_port_id
+            .ok_or_else(|| missing("counterparty_port_id"))?;
+        let signer = self.signer.ok_or_else(|| missing("signer"))?;
+
+        let channel = ChannelEnd::new(
+            State::Init,
+            self.ordering,
+            Counterparty::new(counterparty_port_id, None),
+            vec![connection_id],
+            self.version,
+        );
+
+        channel.validate_basic()?;
+
+        Ok(MsgChannelOpenInit::new(port_id, channel, signer))
+    }
 }
 
 impl Msg for MsgChannelOpenInit {
@@ -91,6 +167,7 @@ pub mod test_util {
 
 #[cfg(test)]
 mod tests {
+    use crate::core::ics04_channel::channel::State;
     use crate::core::ics04_channel::msgs::chan_open_init::test_util::get_dummy_raw_msg_chan_open_init;
     use crate::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
     use crate::prelude::*;
@@ -148,6 +225,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn build_channel_open_init() {
+        let msg = MsgChannelOpenInit::try_from(get_dummy_raw_msg_chan_open_init()).unwrap();
+
+        let built = MsgChannelOpenInit::builder()
+            .with_port_id(msg.port_id.clone())
+            .with_ordering(msg.channel.ordering)
+            .with_connection_id(msg.channel.connection_hops[0].clone())
+            .with_counterparty_port_id(msg.channel.remote.port_id.clone())
+            .with_version(msg.channel.version.clone())
+            .with_signer(msg.signer.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(built.port_id, msg.port_id);
+        assert_eq!(built.channel.state, State::Init);
+        assert_eq!(built.channel.remote.channel_id, None);
+        assert_eq!(built.channel.connection_hops, msg.channel.connection_hops);
+
+        let missing_connection = MsgChannelOpenInit::builder()
+            .with_port_id(msg.port_id)
+            .with_counterparty_port_id(msg.channel.remote.port_id)
+            .with_signer(msg.signer)
+            .build();
+
+        assert!(missing_connection.is_err());
+    }
+
     #[test]
     fn to_and_from() {
         let raw = get_dummy_raw_msg_chan_open_init();
@@ -10,6 +10,7 @@ use crate::tx_msg::Msg;
 
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenTry as RawMsgChannelOpenTry;
 use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
 
 use core::str::FromStr;
 
@@ -18,7 +19,7 @@ pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenTry";
 ///
 /// Message definition for the second step in the channel open handshake (`ChanOpenTry` datagram).
 ///
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgChannelOpenTry {
     pub port_id: PortId,
     pub previous_channel_id: Option<ChannelId>,
@@ -60,9 +61,11 @@ impl Msg for MsgChannelOpenTry {
         TYPE_URL.to_string()
     }
 
-    fn validate_basic(&self) -> Result<(), ValidationError> {
+    fn validate_basic(&self) -> Result<(), Self::ValidationError> {
         match self.channel.counterparty().channel_id() {
-            None => Err(ValidationError::invalid_counterparty_channel_id()),
+            None => Err(ChannelError::invalid_counterparty_channel_id(
+                ValidationError::invalid_counterparty_channel_id(),
+            )),
             Some(_c) => Ok(()),
         }
     }
@@ -108,8 +111,7 @@ impl TryFrom<RawMsgChannelOpenTry> for MsgChannelOpenTry {
             signer: raw_msg.signer.parse().map_err(ChannelError::signer)?,
         };
 
-        msg.validate_basic()
-            .map_err(ChannelError::invalid_counterparty_channel_id)?;
+        msg.validate_basic()?;
 
         Ok(msg)
     }
@@ -3,6 +3,7 @@ use crate::prelude::*;
 use ibc_proto::protobuf::Protobuf;
 
 use ibc_proto::ibc::core::channel::v1::MsgRecvPacket as RawMsgRecvPacket;
+use serde::{Deserialize, Serialize};
 
 use crate::core::ics04_channel::error::Error;
 use crate::core::ics04_channel::packet::Packet;
@@ -15,7 +16,7 @@ pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgRecvPacket";
 ///
 /// Message definition for the "packet receiving" datagram.
 ///
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgRecvPacket {
     pub packet: Packet,
     pub proofs: Proofs,
@@ -195,5 +196,9 @@ mod test {
         let msg_back = MsgRecvPacket::try_from(raw_back.clone()).unwrap();
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
+
+        let json = serde_json::to_string(&msg).unwrap();
+        let msg_back: MsgRecvPacket = serde_json::from_str(&json).unwrap();
+        assert_eq!(msg, msg_back);
     }
 }
@@ -3,6 +3,7 @@ use crate::prelude::*;
 use ibc_proto::protobuf::Protobuf;
 
 use ibc_proto::ibc::core::channel::v1::MsgTimeout as RawMsgTimeout;
+use serde::{Deserialize, Serialize};
 
 use crate::core::ics04_channel::error::Error;
 use crate::core::ics04_channel::packet::{Packet, Sequence};
@@ -15,7 +16,7 @@ pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgTimeout";
 ///
 /// Message definition for packet timeout domain type.
 ///
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgTimeout {
     pub packet: Packet,
     pub next_sequence_recv: Sequence,
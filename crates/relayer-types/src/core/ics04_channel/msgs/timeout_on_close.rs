@@ -2,6 +2,7 @@ use crate::prelude::*;
 
 use ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose as RawMsgTimeoutOnClose;
 use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
 
 use crate::core::ics04_channel::error::Error;
 use crate::core::ics04_channel::packet::{Packet, Sequence};
@@ -14,7 +15,7 @@ pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgTimeoutOnClose";
 ///
 /// Message definition for packet timeout domain type.
 ///
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MsgTimeoutOnClose {
     pub packet: Packet,
     pub next_sequence_recv: Sequence,
@@ -43,6 +43,18 @@ impl core::fmt::Display for PacketMsgType {
 #[derive(
     Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize,
 )]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 pub struct Sequence(u64);
 
 impl FromStr for Sequence {
@@ -84,13 +96,28 @@ impl core::fmt::Display for Sequence {
 }
 
 #[derive(Clone, Default, Hash, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 pub struct Packet {
     pub sequence: Sequence,
     pub source_port: PortId,
     pub source_channel: ChannelId,
     pub destination_port: PortId,
     pub destination_channel: ChannelId,
-    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    #[serde(
+        serialize_with = "crate::serializers::ser_hex_upper",
+        deserialize_with = "crate::serializers::deser_hex_upper"
+    )]
     pub data: Vec<u8>,
     pub timeout_height: TimeoutHeight,
     pub timeout_timestamp: Timestamp,
@@ -449,4 +476,44 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn borsh_encoding() {
+        for (timeout_height, timeout_timestamp) in [(15, 0), (0, 100)] {
+            let packet =
+                Packet::try_from(get_dummy_raw_packet(timeout_height, timeout_timestamp)).unwrap();
+
+            let bytes = borsh::BorshSerialize::try_to_vec(&packet).unwrap();
+            let decoded: Packet = borsh::BorshDeserialize::try_from_slice(&bytes).unwrap();
+
+            assert_eq!(decoded, packet);
+        }
+    }
+
+    #[cfg(feature = "parity-scale-codec")]
+    #[test]
+    fn scale_encoding() {
+        use parity_scale_codec::{Decode, Encode};
+
+        for (timeout_height, timeout_timestamp) in [(15, 0), (0, 100)] {
+            let packet =
+                Packet::try_from(get_dummy_raw_packet(timeout_height, timeout_timestamp)).unwrap();
+
+            let bytes = packet.encode();
+            let decoded = Packet::decode(&mut bytes.as_slice()).unwrap();
+
+            assert_eq!(decoded, packet);
+        }
+    }
+
+    #[test]
+    fn packet_json_roundtrip() {
+        let packet = Packet::try_from(get_dummy_raw_packet(10, 0)).unwrap();
+
+        let json = serde_json::to_string(&packet).unwrap();
+        let decoded: Packet = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, packet);
+    }
 }
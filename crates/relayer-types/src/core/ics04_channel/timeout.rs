@@ -19,6 +19,18 @@ use crate::prelude::*;
 /// as invalid. Thus, it must be parsed specially, where this special case means
 /// "no timeout".
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 pub enum TimeoutHeight {
     Never,
     At(Height),
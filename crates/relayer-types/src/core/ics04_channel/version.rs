@@ -8,7 +8,8 @@ use core::str::FromStr;
 use serde_derive::{Deserialize, Serialize};
 use serde_json as json;
 
-use crate::applications::transfer;
+use super::error::Error;
+use crate::applications::{ics29_fee, transfer};
 use crate::prelude::*;
 
 /// The version field for a `ChannelEnd`.
@@ -16,7 +17,7 @@ use crate::prelude::*;
 /// This field is opaque to the core IBC protocol.
 /// No explicit validation is necessary, and the
 /// spec (v1) currently allows empty strings.
-#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct Version(pub String);
 
 impl Version {
@@ -29,29 +30,98 @@ impl Version {
     }
 
     pub fn ics20_with_fee() -> Self {
-        let val = json::json!({
-            "fee_version": "ics29-1",
-            "app_version": transfer::VERSION,
-        });
-
-        Self::new(val.to_string())
+        Self::ics20().with_fee()
     }
 
     pub fn empty() -> Self {
         Self::new("".to_string())
     }
 
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Wrap this application version into the composite version
+    /// negotiated by the ICS29 fee middleware.
+    ///
+    /// A version which is already fee-enabled is returned as is.
+    pub fn with_fee(self) -> Self {
+        if self.supports_fee() {
+            return self;
+        }
+
+        FeeMetadata::new(self).into()
+    }
+
+    /// The fee middleware metadata of this version, or `None` if this
+    /// version is not a composite version, ie. not a JSON object
+    /// with a `fee_version` field.
+    ///
+    /// Fails if this version is a composite version which is
+    /// either malformed or has an unsupported fee version.
+    pub fn fee_metadata(&self) -> Result<Option<FeeMetadata>, Error> {
+        let value = match json::from_str::<json::Value>(&self.0) {
+            Ok(value @ json::Value::Object(_)) => value,
+            _ => return Ok(None),
+        };
+
+        if value.get("fee_version").is_none() {
+            return Ok(None);
+        }
+
+        let metadata: FeeMetadata = json::from_value(value)
+            .map_err(|e| Error::invalid_version_metadata(self.0.clone(), e.to_string()))?;
+
+        metadata.validate()?;
+
+        Ok(Some(metadata))
+    }
+
+    /// The version of the application underneath the fee middleware,
+    /// or this version itself if it is not a composite version.
+    pub fn app_version(&self) -> Result<Version, Error> {
+        Ok(self
+            .fee_metadata()?
+            .map(|metadata| metadata.app_version)
+            .unwrap_or_else(|| self.clone()))
+    }
+
     pub fn supports_fee(&self) -> bool {
-        json::from_str::<json::Value>(&self.0)
-            .ok()
-            .and_then(|val| {
-                let _app_version = val.get("app_version")?.as_str()?;
+        matches!(self.fee_metadata(), Ok(Some(_)))
+    }
+}
+
+/// The composite version negotiated by the ICS29 fee middleware,
+/// wrapping the version of the underlying application.
+///
+/// Encoded as eg. `{"fee_version":"ics29-1","app_version":"ics20-1"}`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FeeMetadata {
+    pub fee_version: String,
+    pub app_version: Version,
+}
 
-                let fee_version = val.get("fee_version")?.as_str()?;
+impl FeeMetadata {
+    pub fn new(app_version: Version) -> Self {
+        Self {
+            fee_version: ics29_fee::VERSION.to_string(),
+            app_version,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.fee_version != ics29_fee::VERSION {
+            return Err(Error::unsupported_fee_version(self.fee_version.clone()));
+        }
 
-                Some(fee_version == "ics29-1")
-            })
-            .unwrap_or(false)
+        Ok(())
+    }
+}
+
+impl From<FeeMetadata> for Version {
+    fn from(metadata: FeeMetadata) -> Self {
+        // Serializing a struct of strings cannot fail
+        Self::new(json::to_string(&metadata).expect("fee metadata serialization"))
     }
 }
 
@@ -84,7 +154,8 @@ impl Display for Version {
 
 #[cfg(test)]
 mod test {
-    use super::Version;
+    use super::{FeeMetadata, Version};
+    use crate::core::ics04_channel::error::ErrorDetail;
 
     #[test]
     fn test_ics29_version() {
@@ -98,4 +169,42 @@ mod test {
             assert!(version.supports_fee());
         }
     }
+
+    #[test]
+    fn test_fee_version_metadata() {
+        let version = Version::ics20_with_fee();
+        assert_eq!(
+            version.as_str(),
+            r#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#
+        );
+        assert_eq!(version.app_version().unwrap(), Version::ics20());
+        assert_eq!(version.clone().with_fee(), version);
+
+        // The order of the fields does not matter
+        let version = Version::new(r#"{"app_version":"ics20-1","fee_version":"ics29-1"}"#.into());
+        assert_eq!(
+            version.fee_metadata().unwrap(),
+            Some(FeeMetadata::new(Version::ics20()))
+        );
+
+        // Plain versions, including JSON versions of other applications
+        for plain in ["ics20-1", "", r#"{"version":"ics27-1"}"#] {
+            let version = Version::new(plain.to_string());
+            assert_eq!(version.fee_metadata().unwrap(), None);
+            assert_eq!(version.app_version().unwrap(), version);
+        }
+
+        let version = Version::new(r#"{"fee_version":"ics29-2","app_version":"ics20-1"}"#.into());
+        assert!(matches!(
+            version.fee_metadata().unwrap_err().detail(),
+            ErrorDetail::UnsupportedFeeVersion(_)
+        ));
+        assert!(!version.supports_fee());
+
+        let version = Version::new(r#"{"fee_version":"ics29-1"}"#.into());
+        assert!(matches!(
+            version.fee_metadata().unwrap_err().detail(),
+            ErrorDetail::InvalidVersionMetadata(_)
+        ));
+    }
 }
@@ -5,7 +5,7 @@ use crate::tx_msg::encode_message;
 
 use core::{convert::TryFrom, fmt};
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
 use subtle_encoding::{Encoding, Hex};
 
 use super::merkle::MerkleProof;
@@ -13,7 +13,10 @@ use super::merkle::MerkleProof;
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct CommitmentRoot {
-    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    #[serde(
+        serialize_with = "crate::serializers::ser_hex_upper",
+        deserialize_with = "crate::serializers::deser_hex_upper"
+    )]
     bytes: Vec<u8>,
 }
 
@@ -24,6 +27,13 @@ impl fmt::Debug for CommitmentRoot {
     }
 }
 
+impl fmt::Display for CommitmentRoot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = Hex::upper_case().encode_to_string(&self.bytes).unwrap();
+        write!(f, "{}", hex)
+    }
+}
+
 impl CommitmentRoot {
     pub fn from_bytes(bytes: &[u8]) -> Self {
         Self {
@@ -63,6 +73,17 @@ impl fmt::Debug for CommitmentProofBytes {
     }
 }
 
+impl<'de> Deserialize<'de> for CommitmentProofBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::serializers::deser_hex_upper(deserializer)?
+            .try_into()
+            .map_err(de::Error::custom)
+    }
+}
+
 impl TryFrom<Vec<u8>> for CommitmentProofBytes {
     type Error = ProofError;
 
@@ -110,7 +131,7 @@ impl TryFrom<CommitmentProofBytes> for RawMerkleProof {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Hash, Deserialize, Default)]
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
 pub struct CommitmentPrefix {
     bytes: Vec<u8>,
 }
@@ -147,12 +168,49 @@ impl fmt::Debug for CommitmentPrefix {
     }
 }
 
+/// The serde encoding of a [`CommitmentPrefix`]: a string if the prefix is
+/// valid UTF-8, as it is for all the chains in practice, or its hex encoding
+/// otherwise, e.g. `{"hex":"DEADBEEF"}`, so that it can be decoded unambiguously.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum CommitmentPrefixRepr {
+    Utf8(String),
+    Hex {
+        #[serde(
+            serialize_with = "crate::serializers::ser_hex_upper",
+            deserialize_with = "crate::serializers::deser_hex_upper"
+        )]
+        hex: Vec<u8>,
+    },
+}
+
 impl Serialize for CommitmentPrefix {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        format!("{:?}", self).serialize(serializer)
+        let repr = match core::str::from_utf8(self.as_bytes()) {
+            Ok(prefix) => CommitmentPrefixRepr::Utf8(prefix.to_string()),
+            Err(_) => CommitmentPrefixRepr::Hex {
+                hex: self.bytes.clone(),
+            },
+        };
+
+        repr.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CommitmentPrefix {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = match CommitmentPrefixRepr::deserialize(deserializer)? {
+            CommitmentPrefixRepr::Utf8(prefix) => prefix.into_bytes(),
+            CommitmentPrefixRepr::Hex { hex } => hex,
+        };
+
+        bytes.try_into().map_err(de::Error::custom)
     }
 }
 
@@ -169,3 +227,47 @@ pub mod test_util {
         RawMerkleProof { proofs: mproofs }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commitment_root_display() {
+        let root = CommitmentRoot::from_bytes(&[0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(root.to_string(), "DEADBEEF");
+    }
+
+    #[test]
+    fn commitment_json_roundtrip() {
+        let root = CommitmentRoot::from_bytes(&[0xde, 0xad, 0xbe, 0xef]);
+        let json = serde_json::to_string(&root).unwrap();
+        assert_eq!(json, r#""DEADBEEF""#);
+        assert_eq!(serde_json::from_str::<CommitmentRoot>(&json).unwrap(), root);
+
+        let proof = CommitmentProofBytes::try_from(vec![1, 2, 3]).unwrap();
+        let json = serde_json::to_string(&proof).unwrap();
+        assert_eq!(
+            serde_json::from_str::<CommitmentProofBytes>(&json).unwrap(),
+            proof
+        );
+        assert!(serde_json::from_str::<CommitmentProofBytes>(r#""""#).is_err());
+
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+        let json = serde_json::to_string(&prefix).unwrap();
+        assert_eq!(json, r#""ibc""#);
+        assert_eq!(
+            serde_json::from_str::<CommitmentPrefix>(&json).unwrap(),
+            prefix
+        );
+        assert!(serde_json::from_str::<CommitmentPrefix>(r#""""#).is_err());
+
+        let prefix = CommitmentPrefix::try_from(vec![0xff, 0x00]).unwrap();
+        let json = serde_json::to_string(&prefix).unwrap();
+        assert_eq!(json, r#"{"hex":"FF00"}"#);
+        assert_eq!(
+            serde_json::from_str::<CommitmentPrefix>(&json).unwrap(),
+            prefix
+        );
+    }
+}
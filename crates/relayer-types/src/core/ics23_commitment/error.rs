@@ -1,3 +1,4 @@
+use crate::prelude::*;
 use flex_error::{define_error, TraceError};
 use prost::DecodeError;
 
@@ -34,6 +35,16 @@ define_error! {
             |_| { "invalid merkle proof" },
 
         VerificationFailure
-            |_| { "proof verification failed" }
+            |_| { "proof verification failed" },
+
+        EmptyProofSpecs
+            |_| { "empty proof specs" },
+
+        InvalidProofSpec
+            {
+                index: usize,
+                reason: String,
+            }
+            |e| { format_args!("invalid proof spec at index {}: {}", e.index, e.reason) },
     }
 }
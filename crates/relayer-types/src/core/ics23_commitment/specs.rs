@@ -1,3 +1,4 @@
+use crate::core::ics23_commitment::error::Error;
 use crate::prelude::*;
 use ibc_proto::ics23::{InnerSpec as IbcInnerSpec, LeafOp as IbcLeafOp, ProofSpec as IbcProofSpec};
 use ics23::{InnerSpec as Ics23InnerSpec, LeafOp as Ics23LeafOp, ProofSpec as Ics23ProofSpec};
@@ -26,6 +27,22 @@ impl ProofSpecs {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Check that each of the proof specifications is well-formed, so that
+    /// the proofs verified against them can be valid at all, eg. when the
+    /// specifications of a chain with a non-standard store are given.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.is_empty() {
+            return Err(Error::empty_proof_specs());
+        }
+
+        for (index, spec) in self.0.iter().enumerate() {
+            spec.validate()
+                .map_err(|reason| Error::invalid_proof_spec(index, reason.to_string()))?;
+        }
+
+        Ok(())
+    }
 }
 
 impl Default for ProofSpecs {
@@ -66,6 +83,46 @@ impl From<ProofSpecs> for Vec<IbcProofSpec> {
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 struct ProofSpec(IbcProofSpec);
 
+impl ProofSpec {
+    fn validate(&self) -> Result<(), &'static str> {
+        let spec = &self.0;
+
+        if spec.leaf_spec.is_none() {
+            return Err("missing leaf spec");
+        }
+
+        let inner_spec = spec.inner_spec.as_ref().ok_or("missing inner spec")?;
+
+        if spec.min_depth < 0 || spec.max_depth < 0 {
+            return Err("negative depth");
+        }
+
+        if spec.max_depth > 0 && spec.min_depth > spec.max_depth {
+            return Err("min depth is larger than max depth");
+        }
+
+        if inner_spec.child_size <= 0 {
+            return Err("child size must be positive");
+        }
+
+        if inner_spec.min_prefix_length < 0
+            || inner_spec.min_prefix_length > inner_spec.max_prefix_length
+        {
+            return Err("invalid prefix length range");
+        }
+
+        // The child order must be a permutation of the indices of the children
+        let mut child_order = inner_spec.child_order.clone();
+        child_order.sort_unstable();
+
+        if child_order.is_empty() || !child_order.iter().copied().eq(0..child_order.len() as i32) {
+            return Err("child order is not a permutation of the children");
+        }
+
+        Ok(())
+    }
+}
+
 impl From<Ics23ProofSpec> for ProofSpec {
     fn from(spec: Ics23ProofSpec) -> Self {
         Self(IbcProofSpec {
@@ -146,3 +203,26 @@ impl From<InnerSpec> for Ics23InnerSpec {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_proof_specs() {
+        assert!(ProofSpecs::cosmos().validate().is_ok());
+        assert!(ProofSpecs::from(Vec::<IbcProofSpec>::new())
+            .validate()
+            .is_err());
+
+        let mut spec = ics23::iavl_spec();
+        spec.inner_spec.as_mut().unwrap().child_order = vec![0, 2];
+        assert!(ProofSpecs::from(vec![ics23::tendermint_spec(), spec])
+            .validate()
+            .is_err());
+
+        let mut spec = ics23::iavl_spec();
+        spec.leaf_spec = None;
+        assert!(ProofSpecs::from(vec![spec]).validate().is_err());
+    }
+}
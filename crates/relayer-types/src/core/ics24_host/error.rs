@@ -23,6 +23,10 @@ define_error! {
             { id: String }
             | e | { format_args!("identifier {0} must only contain alphanumeric characters or `.`, `_`, `+`, `-`, `#`, - `[`, `]`, `<`, `>`", e.id) },
 
+        InvalidWasmPortId
+            { id: String }
+            | e | { format_args!("port identifier {0} must be of the form `wasm.<contract address>`, with a lowercase bech32 contract address", e.id) },
+
         Empty
             | _ | { "identifier cannot be empty" },
 
@@ -6,6 +6,12 @@ use super::error::ValidationError as Error;
 const PATH_SEPARATOR: char = '/';
 const VALID_SPECIAL_CHARS: &str = "._+-#[]<>";
 
+/// Prefix of the ports bound to CosmWasm contracts, followed by the contract address.
+pub const WASM_PORT_PREFIX: &str = "wasm.";
+
+/// Characters which can appear in the data part of a bech32 address.
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
 /// Default validator function for identifiers.
 ///
 /// A valid identifier only contain lowercase alphabetic characters, and be of a given min and max
@@ -67,6 +73,46 @@ pub fn validate_port_identifier(id: &str) -> Result<(), Error> {
     validate_identifier(id, 2, 128)
 }
 
+/// Validator function for the identifiers of the ports bound to CosmWasm contracts,
+/// ie. `wasm.<contract address>`, eg. when given by the user.
+///
+/// This is stricter than [`validate_port_identifier`], which only enforces ICS-24,
+/// so that the ports of the chains which do not follow this convention can still
+/// be parsed.
+pub fn validate_wasm_port_identifier(id: &str) -> Result<(), Error> {
+    validate_port_identifier(id)?;
+
+    match id.strip_prefix(WASM_PORT_PREFIX) {
+        Some(contract) => validate_wasm_contract_address(id, contract),
+        None => Err(Error::invalid_wasm_port_id(id.to_string())),
+    }
+}
+
+/// Validator function for the contract address of the ports bound to CosmWasm contracts.
+///
+/// The address must be a lowercase bech32 string, ie. a non-empty human-readable part
+/// followed by the `1` separator and at least six characters of the bech32 charset.
+/// The checksum is not verified.
+fn validate_wasm_contract_address(id: &str, contract: &str) -> Result<(), Error> {
+    let valid = match contract.rsplit_once('1') {
+        Some((hrp, data)) => {
+            !hrp.is_empty()
+                && hrp
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+                && data.len() >= 6
+                && data.chars().all(|c| BECH32_CHARSET.contains(c))
+        }
+        None => false,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::invalid_wasm_port_id(id.to_string()))
+    }
+}
+
 /// Default validator function for Channel identifiers.
 ///
 /// A valid identifier must be between 8-64 characters and only contain
@@ -79,7 +125,7 @@ pub fn validate_channel_identifier(id: &str) -> Result<(), Error> {
 mod tests {
     use crate::core::ics24_host::validate::{
         validate_channel_identifier, validate_client_identifier, validate_connection_identifier,
-        validate_identifier, validate_port_identifier,
+        validate_identifier, validate_port_identifier, validate_wasm_port_identifier,
     };
     use test_log::test;
 
@@ -99,6 +145,28 @@ mod tests {
         assert!(id.is_err())
     }
 
+    #[test]
+    fn parse_wasm_port_id() {
+        let id = validate_wasm_port_identifier(
+            "wasm.wasm14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9s0phg4d",
+        );
+        assert!(id.is_ok());
+
+        // contract address is not bech32
+        let id = validate_wasm_port_identifier("wasm.Wasm1contract");
+        assert!(id.is_err());
+
+        let id = validate_wasm_port_identifier("wasm.");
+        assert!(id.is_err());
+
+        let id = validate_wasm_port_identifier("transfer");
+        assert!(id.is_err());
+
+        // any valid ICS-24 identifier is still a valid port identifier
+        let id = validate_port_identifier("wasm.Wasm1contract");
+        assert!(id.is_ok());
+    }
+
     #[test]
     fn parse_invalid_connection_id_min() {
         // invalid min connection id
@@ -520,9 +520,43 @@ impl From<ModuleEventAttribute> for Tag {
                 .parse()
                 .expect("Key::from_str() impl is infallible"),
             value: attr
-                .key
+                .value
                 .parse()
                 .expect("Value::from_str() impl is infallible"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_event_to_abci_event() {
+        let event = ModuleEvent {
+            kind: "fungible_token_packet".to_string(),
+            module_name: "transfer".parse().unwrap(),
+            attributes: vec![("receiver", "cosmos1receiver").into()],
+        };
+
+        let abci_event = AbciEvent::try_from(event).unwrap();
+
+        assert_eq!(abci_event.type_str, "fungible_token_packet");
+        assert_eq!(abci_event.attributes[0].key.to_string(), "receiver");
+        assert_eq!(
+            abci_event.attributes[0].value.to_string(),
+            "cosmos1receiver"
+        );
+    }
+
+    #[test]
+    fn module_event_with_ibc_event_kind() {
+        let event = ModuleEvent {
+            kind: "send_packet".to_string(),
+            module_name: "transfer".parse().unwrap(),
+            attributes: Vec::new(),
+        };
+
+        assert!(AbciEvent::try_from(event).is_err());
+    }
+}
@@ -61,6 +61,7 @@ pub mod handler;
 pub mod keys;
 pub mod macros;
 pub mod proofs;
+pub mod proto_json;
 pub mod relayer;
 pub mod signer;
 pub mod timestamp;
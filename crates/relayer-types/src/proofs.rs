@@ -1,6 +1,6 @@
 use flex_error::{define_error, TraceError};
 use prost::EncodeError;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
 use crate::Height;
@@ -23,7 +23,7 @@ define_error! {
 /// Structure comprising proofs in a message. Proofs are typically present in messages for
 /// handshake protocols, e.g., ICS3 connection (open) handshake or ICS4 channel (open and close)
 /// handshake, as well as for ICS4 packets, timeouts, and acknowledgements.
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Proofs {
     object_proof: CommitmentProofBytes,
     client_proof: Option<CommitmentProofBytes>,
@@ -80,7 +80,7 @@ impl Proofs {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ConsensusProof {
     proof: CommitmentProofBytes,
     height: Height,
@@ -0,0 +1,492 @@
+//! Encoding of the IBC messages in the proto3 JSON format, as output by the
+//! Cosmos SDK, e.g. by `simd tx ... --generate-only`, and accepted by its
+//! `tx sign` and `tx broadcast` commands.
+//!
+//! The SDK encodes the messages with their original field names, and emits all
+//! fields, including the ones set to their default value. The 64-bit integers
+//! are encoded as strings, the bytes in base64 and the enums by their name.
+//!
+//! Messages are encoded from and decoded to their `Any` representation, so that
+//! they can be handled regardless of their type. Only the ICS-20 transfer and
+//! the ICS-04 channel and packet messages are supported for now.
+
+use crate::prelude::*;
+
+use flex_error::{define_error, TraceError};
+use ibc_proto::cosmos::base::v1beta1::Coin as RawCoin;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::channel::v1::{
+    Channel as RawChannel, Counterparty as RawCounterparty,
+    MsgAcknowledgement as RawMsgAcknowledgement,
+    MsgChannelCloseConfirm as RawMsgChannelCloseConfirm,
+    MsgChannelCloseInit as RawMsgChannelCloseInit, MsgChannelOpenAck as RawMsgChannelOpenAck,
+    MsgChannelOpenConfirm as RawMsgChannelOpenConfirm, MsgChannelOpenInit as RawMsgChannelOpenInit,
+    MsgChannelOpenTry as RawMsgChannelOpenTry, MsgRecvPacket as RawMsgRecvPacket,
+    MsgTimeout as RawMsgTimeout, MsgTimeoutOnClose as RawMsgTimeoutOnClose, Packet as RawPacket,
+};
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+use prost::Message;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::applications::transfer::raw::MsgTransfer as RawMsgTransfer;
+
+define_error! {
+    #[derive(Debug)]
+    Error {
+        UnsupportedTypeUrl
+            { type_url: String }
+            | e | { format_args!("no proto3 JSON encoding for messages of type {}", e.type_url) },
+
+        Decode
+            [ TraceError<prost::DecodeError> ]
+            | _ | { "failed to decode the protobuf message" },
+
+        Json
+            [ TraceError<serde_json::Error> ]
+            | _ | { "invalid proto3 JSON message" },
+    }
+}
+
+/// Encode the given message in the proto3 JSON format, along with its type URL
+/// in the `@type` field.
+pub fn to_proto_json(msg: &Any) -> Result<String, Error> {
+    let json = JsonMsg::decode(msg)?;
+    serde_json::to_string(&json).map_err(Error::json)
+}
+
+/// Decode a message encoded in the proto3 JSON format, with its type URL
+/// in the `@type` field.
+pub fn from_proto_json(json: &str) -> Result<Any, Error> {
+    let json: JsonMsg = serde_json::from_str(json).map_err(Error::json)?;
+    Ok(json.encode())
+}
+
+macro_rules! json_msgs {
+    ($($variant:ident($raw:ty, $def:tt, $type_url:tt)),* $(,)?) => {
+        #[derive(Serialize, Deserialize)]
+        #[serde(tag = "@type")]
+        enum JsonMsg {
+            $(
+                #[serde(rename = $type_url)]
+                $variant(#[serde(with = $def)] $raw),
+            )*
+        }
+
+        impl JsonMsg {
+            fn decode(msg: &Any) -> Result<Self, Error> {
+                match msg.type_url.as_str() {
+                    $(
+                        $type_url => <$raw>::decode(msg.value.as_slice())
+                            .map(Self::$variant)
+                            .map_err(Error::decode),
+                    )*
+                    _ => Err(Error::unsupported_type_url(msg.type_url.clone())),
+                }
+            }
+
+            fn encode(&self) -> Any {
+                match self {
+                    $(
+                        Self::$variant(raw) => Any {
+                            type_url: $type_url.to_string(),
+                            value: raw.encode_to_vec(),
+                        },
+                    )*
+                }
+            }
+        }
+    };
+}
+
+json_msgs! {
+    Transfer(RawMsgTransfer, "MsgTransferDef", "/ibc.applications.transfer.v1.MsgTransfer"),
+    ChannelOpenInit(RawMsgChannelOpenInit, "MsgChannelOpenInitDef", "/ibc.core.channel.v1.MsgChannelOpenInit"),
+    ChannelOpenTry(RawMsgChannelOpenTry, "MsgChannelOpenTryDef", "/ibc.core.channel.v1.MsgChannelOpenTry"),
+    ChannelOpenAck(RawMsgChannelOpenAck, "MsgChannelOpenAckDef", "/ibc.core.channel.v1.MsgChannelOpenAck"),
+    ChannelOpenConfirm(RawMsgChannelOpenConfirm, "MsgChannelOpenConfirmDef", "/ibc.core.channel.v1.MsgChannelOpenConfirm"),
+    ChannelCloseInit(RawMsgChannelCloseInit, "MsgChannelCloseInitDef", "/ibc.core.channel.v1.MsgChannelCloseInit"),
+    ChannelCloseConfirm(RawMsgChannelCloseConfirm, "MsgChannelCloseConfirmDef", "/ibc.core.channel.v1.MsgChannelCloseConfirm"),
+    RecvPacket(RawMsgRecvPacket, "MsgRecvPacketDef", "/ibc.core.channel.v1.MsgRecvPacket"),
+    Acknowledgement(RawMsgAcknowledgement, "MsgAcknowledgementDef", "/ibc.core.channel.v1.MsgAcknowledgement"),
+    Timeout(RawMsgTimeout, "MsgTimeoutDef", "/ibc.core.channel.v1.MsgTimeout"),
+    TimeoutOnClose(RawMsgTimeoutOnClose, "MsgTimeoutOnCloseDef", "/ibc.core.channel.v1.MsgTimeoutOnClose"),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawMsgTransfer")]
+struct MsgTransferDef {
+    source_port: String,
+    source_channel: String,
+    #[serde(with = "option_coin")]
+    token: Option<RawCoin>,
+    sender: String,
+    receiver: String,
+    #[serde(with = "option_height")]
+    timeout_height: Option<RawHeight>,
+    #[serde(with = "u64_string")]
+    timeout_timestamp: u64,
+    #[serde(default)]
+    memo: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawMsgChannelOpenInit")]
+struct MsgChannelOpenInitDef {
+    port_id: String,
+    #[serde(with = "option_channel")]
+    channel: Option<RawChannel>,
+    signer: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawMsgChannelOpenTry")]
+struct MsgChannelOpenTryDef {
+    port_id: String,
+    #[serde(default)]
+    previous_channel_id: String,
+    #[serde(with = "option_channel")]
+    channel: Option<RawChannel>,
+    counterparty_version: String,
+    #[serde(with = "base64_bytes")]
+    proof_init: Vec<u8>,
+    #[serde(with = "option_height")]
+    proof_height: Option<RawHeight>,
+    signer: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawMsgChannelOpenAck")]
+struct MsgChannelOpenAckDef {
+    port_id: String,
+    channel_id: String,
+    counterparty_channel_id: String,
+    counterparty_version: String,
+    #[serde(with = "base64_bytes")]
+    proof_try: Vec<u8>,
+    #[serde(with = "option_height")]
+    proof_height: Option<RawHeight>,
+    signer: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawMsgChannelOpenConfirm")]
+struct MsgChannelOpenConfirmDef {
+    port_id: String,
+    channel_id: String,
+    #[serde(with = "base64_bytes")]
+    proof_ack: Vec<u8>,
+    #[serde(with = "option_height")]
+    proof_height: Option<RawHeight>,
+    signer: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawMsgChannelCloseInit")]
+struct MsgChannelCloseInitDef {
+    port_id: String,
+    channel_id: String,
+    signer: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawMsgChannelCloseConfirm")]
+struct MsgChannelCloseConfirmDef {
+    port_id: String,
+    channel_id: String,
+    #[serde(with = "base64_bytes")]
+    proof_init: Vec<u8>,
+    #[serde(with = "option_height")]
+    proof_height: Option<RawHeight>,
+    signer: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawMsgRecvPacket")]
+struct MsgRecvPacketDef {
+    #[serde(with = "option_packet")]
+    packet: Option<RawPacket>,
+    #[serde(with = "base64_bytes")]
+    proof_commitment: Vec<u8>,
+    #[serde(with = "option_height")]
+    proof_height: Option<RawHeight>,
+    signer: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawMsgAcknowledgement")]
+struct MsgAcknowledgementDef {
+    #[serde(with = "option_packet")]
+    packet: Option<RawPacket>,
+    #[serde(with = "base64_bytes")]
+    acknowledgement: Vec<u8>,
+    #[serde(with = "base64_bytes")]
+    proof_acked: Vec<u8>,
+    #[serde(with = "option_height")]
+    proof_height: Option<RawHeight>,
+    signer: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawMsgTimeout")]
+struct MsgTimeoutDef {
+    #[serde(with = "option_packet")]
+    packet: Option<RawPacket>,
+    #[serde(with = "base64_bytes")]
+    proof_unreceived: Vec<u8>,
+    #[serde(with = "option_height")]
+    proof_height: Option<RawHeight>,
+    #[serde(with = "u64_string")]
+    next_sequence_recv: u64,
+    signer: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawMsgTimeoutOnClose")]
+struct MsgTimeoutOnCloseDef {
+    #[serde(with = "option_packet")]
+    packet: Option<RawPacket>,
+    #[serde(with = "base64_bytes")]
+    proof_unreceived: Vec<u8>,
+    #[serde(with = "base64_bytes")]
+    proof_close: Vec<u8>,
+    #[serde(with = "option_height")]
+    proof_height: Option<RawHeight>,
+    #[serde(with = "u64_string")]
+    next_sequence_recv: u64,
+    signer: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawPacket")]
+struct PacketDef {
+    #[serde(with = "u64_string")]
+    sequence: u64,
+    source_port: String,
+    source_channel: String,
+    destination_port: String,
+    destination_channel: String,
+    #[serde(with = "base64_bytes")]
+    data: Vec<u8>,
+    #[serde(with = "option_height")]
+    timeout_height: Option<RawHeight>,
+    #[serde(with = "u64_string")]
+    timeout_timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawChannel")]
+struct ChannelDef {
+    #[serde(with = "channel_state")]
+    state: i32,
+    #[serde(with = "channel_order")]
+    ordering: i32,
+    #[serde(with = "option_counterparty")]
+    counterparty: Option<RawCounterparty>,
+    connection_hops: Vec<String>,
+    version: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawCounterparty")]
+struct CounterpartyDef {
+    port_id: String,
+    channel_id: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawHeight")]
+struct HeightDef {
+    #[serde(with = "u64_string")]
+    revision_number: u64,
+    #[serde(with = "u64_string")]
+    revision_height: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "RawCoin")]
+struct CoinDef {
+    denom: String,
+    amount: String,
+}
+
+/// Defines a module to (de)serialize an optional message with the given
+/// remote definition, encoding a missing message as `null`.
+macro_rules! option_def {
+    ($module:ident, $raw:ty, $def:tt) => {
+        mod $module {
+            use super::*;
+
+            pub fn serialize<S: Serializer>(
+                value: &Option<$raw>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                #[derive(Serialize)]
+                struct Helper<'a>(#[serde(with = $def)] &'a $raw);
+
+                value.as_ref().map(Helper).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<$raw>, D::Error> {
+                #[derive(Deserialize)]
+                struct Helper(#[serde(with = $def)] $raw);
+
+                let helper = Option::<Helper>::deserialize(deserializer)?;
+                Ok(helper.map(|Helper(raw)| raw))
+            }
+        }
+    };
+}
+
+option_def!(option_height, RawHeight, "HeightDef");
+option_def!(option_coin, RawCoin, "CoinDef");
+option_def!(option_packet, RawPacket, "PacketDef");
+option_def!(option_channel, RawChannel, "ChannelDef");
+option_def!(option_counterparty, RawCounterparty, "CounterpartyDef");
+
+/// Encodes a `uint64` as a string, and decodes it from either a string or a number.
+mod u64_string {
+    use super::*;
+
+    use serde::de::Error as _;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(u64),
+    }
+
+    pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        match StringOrNumber::deserialize(deserializer)? {
+            StringOrNumber::String(s) => s.parse().map_err(D::Error::custom),
+            StringOrNumber::Number(n) => Ok(n),
+        }
+    }
+}
+
+/// Encodes `bytes` in standard base64, with padding.
+mod base64_bytes {
+    use super::*;
+
+    use serde::de::Error as _;
+    use subtle_encoding::base64;
+
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        let encoded = String::from_utf8(base64::encode(value)).expect("base64 is valid UTF-8");
+        serializer.serialize_str(&encoded)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        base64::decode(encoded).map_err(D::Error::custom)
+    }
+}
+
+/// Defines a module to (de)serialize a protobuf enum by the names of its values.
+macro_rules! enum_def {
+    ($module:ident, $name:literal, [$($value:literal => $str:literal),* $(,)?]) => {
+        mod $module {
+            use super::*;
+
+            use serde::de::Error as _;
+            use serde::ser::Error as _;
+
+            pub fn serialize<S: Serializer>(value: &i32, serializer: S) -> Result<S::Ok, S::Error> {
+                match *value {
+                    $($value => serializer.serialize_str($str),)*
+                    _ => Err(S::Error::custom(format_args!("unknown {} {}", $name, value))),
+                }
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i32, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                match value.as_str() {
+                    $($str => Ok($value),)*
+                    _ => Err(D::Error::custom(format_args!("unknown {} {}", $name, value))),
+                }
+            }
+        }
+    };
+}
+
+enum_def!(channel_state, "channel state", [
+    0 => "STATE_UNINITIALIZED_UNSPECIFIED",
+    1 => "STATE_INIT",
+    2 => "STATE_TRYOPEN",
+    3 => "STATE_OPEN",
+    4 => "STATE_CLOSED",
+]);
+
+enum_def!(channel_order, "channel ordering", [
+    0 => "ORDER_NONE_UNSPECIFIED",
+    1 => "ORDER_UNORDERED",
+    2 => "ORDER_ORDERED",
+]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::applications::transfer::msgs::transfer;
+    use crate::core::ics04_channel::msgs::{chan_open_init, recv_packet};
+
+    fn roundtrip(json: &str, type_url: &str) {
+        let any = from_proto_json(json).unwrap();
+        assert_eq!(any.type_url, type_url);
+        assert_eq!(to_proto_json(&any).unwrap(), json);
+    }
+
+    #[test]
+    fn msg_transfer_golden() {
+        // In the format output by `simd tx ibc-transfer transfer ... --generate-only`
+        let json = r#"{"@type":"/ibc.applications.transfer.v1.MsgTransfer","source_port":"transfer","source_channel":"channel-0","token":{"denom":"stake","amount":"1000"},"sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","receiver":"cosmos1ywz8l3njhx93tz7wrzsu2ftw6mlj43elfwr8ll","timeout_height":{"revision_number":"1","revision_height":"1000"},"timeout_timestamp":"1671017425000000000","memo":""}"#;
+
+        roundtrip(json, transfer::TYPE_URL);
+    }
+
+    #[test]
+    fn msg_chan_open_init_golden() {
+        let json = r#"{"@type":"/ibc.core.channel.v1.MsgChannelOpenInit","port_id":"transfer","channel":{"state":"STATE_INIT","ordering":"ORDER_UNORDERED","counterparty":{"port_id":"transfer","channel_id":""},"connection_hops":["connection-0"],"version":"ics20-1"},"signer":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"}"#;
+
+        roundtrip(json, chan_open_init::TYPE_URL);
+    }
+
+    #[test]
+    fn msg_recv_packet_golden() {
+        let json = r#"{"@type":"/ibc.core.channel.v1.MsgRecvPacket","packet":{"sequence":"1","source_port":"transfer","source_channel":"channel-0","destination_port":"transfer","destination_channel":"channel-1","data":"eyJhbW91bnQiOiIxMDAwIn0=","timeout_height":{"revision_number":"1","revision_height":"1000"},"timeout_timestamp":"0"},"proof_commitment":"CgRwcm9vZg==","proof_height":{"revision_number":"0","revision_height":"42"},"signer":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"}"#;
+
+        roundtrip(json, recv_packet::TYPE_URL);
+
+        let any = from_proto_json(json).unwrap();
+        let raw = RawMsgRecvPacket::decode(any.value.as_slice()).unwrap();
+        assert_eq!(raw.packet.unwrap().data, br#"{"amount":"1000"}"#);
+    }
+
+    #[test]
+    fn accept_numbers_and_missing_memo() {
+        let json = r#"{"@type":"/ibc.applications.transfer.v1.MsgTransfer","source_port":"transfer","source_channel":"channel-0","token":null,"sender":"a","receiver":"b","timeout_height":null,"timeout_timestamp":42}"#;
+
+        let any = from_proto_json(json).unwrap();
+        let raw = RawMsgTransfer::decode(any.value.as_slice()).unwrap();
+
+        assert_eq!(raw.timeout_timestamp, 42);
+        assert!(raw.memo.is_empty());
+    }
+
+    #[test]
+    fn reject_unsupported_messages() {
+        let any = Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![],
+        };
+
+        assert!(to_proto_json(&any).is_err());
+        assert!(from_proto_json(r#"{"@type":"/cosmos.bank.v1beta1.MsgSend"}"#).is_err());
+    }
+}
@@ -1,3 +1,7 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::de::{Deserialize, Deserializer, Error as _};
 use serde::ser::{Serialize, Serializer};
 use subtle_encoding::{Encoding, Hex};
 
@@ -10,6 +14,16 @@ where
     hex.serialize(serializer)
 }
 
+/// Deserializes the bytes serialized by [`ser_hex_upper`],
+/// accepting both upper and lower case hex strings.
+pub fn deser_hex_upper<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let hex = String::deserialize(deserializer)?.to_ascii_uppercase();
+    Hex::upper_case().decode(hex).map_err(D::Error::custom)
+}
+
 pub mod serde_string {
     use alloc::string::String;
     use core::fmt::Display;
@@ -36,3 +50,94 @@ pub mod serde_string {
             .map_err(de::Error::custom)
     }
 }
+
+/// Serializes a Protobuf [`Any`](ibc_proto::google::protobuf::Any), such as the client
+/// states and headers carried by the client and connection messages, as its type URL
+/// and its value hex encoded like [`ser_hex_upper`].
+pub mod serde_any {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use ibc_proto::google::protobuf::Any;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    pub(super) struct AnyRef<'a> {
+        type_url: &'a str,
+        #[serde(serialize_with = "super::ser_hex_upper")]
+        value: &'a [u8],
+    }
+
+    impl<'a> From<&'a Any> for AnyRef<'a> {
+        fn from(any: &'a Any) -> Self {
+            Self {
+                type_url: &any.type_url,
+                value: &any.value,
+            }
+        }
+    }
+
+    #[derive(Deserialize)]
+    pub(super) struct AnyDef {
+        type_url: String,
+        #[serde(deserialize_with = "super::deser_hex_upper")]
+        value: Vec<u8>,
+    }
+
+    impl From<AnyDef> for Any {
+        fn from(any: AnyDef) -> Self {
+            Self {
+                type_url: any.type_url,
+                value: any.value,
+            }
+        }
+    }
+
+    pub fn serialize<S: Serializer>(value: &Any, serializer: S) -> Result<S::Ok, S::Error> {
+        AnyRef::from(value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Any, D::Error> {
+        AnyDef::deserialize(deserializer).map(Any::from)
+    }
+}
+
+/// Serializes an optional Protobuf `Any` like [`serde_any`].
+pub mod serde_option_any {
+    use ibc_proto::google::protobuf::Any;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::serde_any::{AnyDef, AnyRef};
+
+    pub fn serialize<S: Serializer>(value: &Option<Any>, serializer: S) -> Result<S::Ok, S::Error> {
+        value.as_ref().map(AnyRef::from).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Any>, D::Error> {
+        Option::<AnyDef>::deserialize(deserializer).map(|any| any.map(Any::from))
+    }
+}
+
+/// Serializes a raw Protobuf message, such as a Merkle proof, as its hex encoded
+/// Protobuf encoding, like [`ser_hex_upper`].
+pub mod serde_proto_hex {
+    use prost::Message;
+    use serde::de::Error as _;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<T: Message, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::ser_hex_upper(value.encode_to_vec(), serializer)
+    }
+
+    pub fn deserialize<'de, T: Message + Default, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let bytes = super::deser_hex_upper(deserializer)?;
+        T::decode(bytes.as_slice()).map_err(D::Error::custom)
+    }
+}
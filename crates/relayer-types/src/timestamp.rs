@@ -36,6 +36,65 @@ impl Hash for Timestamp {
     }
 }
 
+/// Timestamps are encoded with Borsh as in Protobuf, ie. as a number of nanoseconds
+/// since the Unix epoch, `0` meaning no timestamp.
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for Timestamp {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        borsh::BorshSerialize::serialize(&self.nanoseconds(), writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for Timestamp {
+    fn deserialize(buf: &mut &[u8]) -> borsh::maybestd::io::Result<Self> {
+        let nanoseconds = <u64 as borsh::BorshDeserialize>::deserialize(buf)?;
+
+        Self::from_nanoseconds(nanoseconds)
+            .map_err(|_| borsh::maybestd::io::ErrorKind::InvalidData.into())
+    }
+}
+
+/// Timestamps are SCALE encoded as in Protobuf too, ie. as a number of nanoseconds.
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Encode for Timestamp {
+    fn size_hint(&self) -> usize {
+        parity_scale_codec::Encode::size_hint(&self.nanoseconds())
+    }
+
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+        parity_scale_codec::Encode::encode_to(&self.nanoseconds(), dest)
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::EncodeLike for Timestamp {}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Decode for Timestamp {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        let nanoseconds = <u64 as parity_scale_codec::Decode>::decode(input)?;
+
+        Self::from_nanoseconds(nanoseconds).map_err(|_| "invalid timestamp".into())
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl scale_info::TypeInfo for Timestamp {
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        scale_info::Type::builder()
+            .path(scale_info::Path::new("Timestamp", module_path!()))
+            .composite(scale_info::build::Fields::unnamed().field(|f| f.ty::<u64>()))
+    }
+}
+
 /// The expiry result when comparing two timestamps.
 /// - If either timestamp is invalid (0), the result is `InvalidTimestamp`.
 /// - If the left timestamp is strictly after the right timestamp, the result is `Expired`.
@@ -1,7 +1,6 @@
 use ibc_proto::google::protobuf::Any;
 use prost::{EncodeError, Message};
 
-use crate::core::ics24_host::error::ValidationError;
 use crate::prelude::*;
 
 pub trait Msg: Clone {
@@ -33,7 +32,8 @@ pub trait Msg: Clone {
         })
     }
 
-    fn validate_basic(&self) -> Result<(), ValidationError> {
+    /// Stateless checks of the message, which do not depend on the state of the chain.
+    fn validate_basic(&self) -> Result<(), Self::ValidationError> {
         Ok(())
     }
 }
@@ -6,20 +6,31 @@ use core::{
     str::FromStr,
     time::Duration,
 };
+use futures::stream::{self, StreamExt, TryStreamExt};
 use num_bigint::BigInt;
+use once_cell::sync::OnceCell;
 use std::cmp::Ordering;
 use std::thread;
-
+use std::time::Instant;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::channel::v1::{
+    QueryPacketAcknowledgementsRequest as RawQueryPacketAcknowledgementsRequest,
+    QueryPacketCommitmentsRequest as RawQueryPacketCommitmentsRequest,
+    QueryUnreceivedAcksRequest as RawQueryUnreceivedAcksRequest,
+    QueryUnreceivedPacketsRequest as RawQueryUnreceivedPacketsRequest,
+};
 use ibc_proto::protobuf::Protobuf;
 use tendermint::block::Height as TmHeight;
-use tendermint::{abci::Path as TendermintABCIPath, node::info::TxIndexStatus};
+use tendermint::{abci::Path as TendermintABCIPath, node::info::TxIndexStatus, Hash};
+use tendermint_light_client_verifier::options::Options as TmOptions;
 use tendermint_light_client_verifier::types::LightBlock as TmLightBlock;
 use tendermint_rpc::{
     endpoint::broadcast::tx_sync::Response, endpoint::status, Client, HttpClient, Order,
 };
 use tokio::runtime::Runtime as TokioRuntime;
 use tonic::{codegen::http::Uri, metadata::AsciiMetadataValue};
-use tracing::{error, instrument, trace, warn};
+use tracing::{debug, error, info, instrument, trace, warn};
 
 use ibc_proto::cosmos::staking::v1beta1::Params as StakingParams;
 use ibc_relayer_types::clients::ics07_tendermint::header::Header as TmHeader;
@@ -29,8 +40,10 @@ use ibc_relayer_types::core::ics03_connection::connection::{
     ConnectionEnd, IdentifiedConnectionEnd,
 };
 use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
-use ibc_relayer_types::core::ics04_channel::packet::Sequence;
-use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
+use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics23_commitment::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
@@ -41,6 +54,7 @@ use ibc_relayer_types::core::ics24_host::path::{
 use ibc_relayer_types::core::ics24_host::{
     ClientUpgradePath, Path, IBC_QUERY_PATH, SDK_UPGRADE_QUERY_PATH,
 };
+use ibc_relayer_types::proofs::Proofs;
 use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::Height as ICSHeight;
 use ibc_relayer_types::{
@@ -54,16 +68,27 @@ use ibc_relayer_types::{
 
 use crate::account::Balance;
 use crate::chain::client::ClientSettings;
+use crate::chain::cosmos::compatibility::ChainVersions;
+use crate::chain::cosmos::divergence::DivergenceGuard;
 use crate::chain::cosmos::encode::key_entry_to_signer;
 use crate::chain::cosmos::fee::maybe_register_counterparty_payee;
+use crate::chain::cosmos::fee_budget::FeeBudget;
 use crate::chain::cosmos::query::account::get_or_fetch_account;
 use crate::chain::cosmos::query::balance::{query_all_balances, query_balance};
-use crate::chain::cosmos::query::denom_trace::query_denom_trace;
+use crate::chain::cosmos::query::consumer::{query_consumer_params, ConsumerParams};
+use crate::chain::cosmos::query::denom_trace::{
+    compute_denom_hash, query_denom_hash, query_denom_trace,
+};
+use crate::chain::cosmos::query::proof::{verify_query_proof, wait_for_block, ProofAnchor};
 use crate::chain::cosmos::query::status::query_status;
 use crate::chain::cosmos::query::tx::{
-    filter_matching_event, query_packets_from_block, query_packets_from_txs, query_txs,
+    filter_matching_event, query_packets_from_block, query_packets_from_block_range,
+    query_packets_from_txs, query_tx_messages, query_txs,
 };
-use crate::chain::cosmos::query::{abci_query, fetch_version_specs, packet_query, QueryResponse};
+use crate::chain::cosmos::query::{
+    abci_query, fetch_version_specs, packet_query, packet_query_in_height_window, QueryResponse,
+};
+use crate::chain::cosmos::staleness::{grpc_response_height, HeightGuard};
 use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::gas::{
@@ -77,11 +102,11 @@ use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
 use crate::chain::requests::{Qualified, QueryPacketEventDataRequest};
 use crate::chain::tracking::TrackedMsgs;
 use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
-use crate::config::ChainConfig;
+use crate::config::{default, ChainConfig};
 use crate::consensus_state::{AnyConsensusState, AnyConsensusStateWithHeight};
 use crate::denom::DenomTrace;
-use crate::error::Error;
-use crate::event::monitor::{EventMonitor, EventReceiver, TxMonitorCmd};
+use crate::error::{Error, QUERY_PROOF_EXPECT_MSG};
+use crate::event::monitor::{BatchConfig, EventMonitor, EventReceiver, TxMonitorCmd};
 use crate::event::IbcEventWithHeight;
 use crate::keyring::{KeyEntry, KeyRing};
 use crate::light_client::tendermint::LightClient as TmLightClient;
@@ -96,32 +121,47 @@ use crate::{
 };
 
 use super::requests::{
-    IncludeProof, QueryChannelClientStateRequest, QueryChannelRequest, QueryChannelsRequest,
-    QueryClientConnectionsRequest, QueryClientStateRequest, QueryClientStatesRequest,
-    QueryConnectionChannelsRequest, QueryConnectionRequest, QueryConnectionsRequest,
-    QueryConsensusStateRequest, QueryConsensusStatesRequest, QueryHeight,
+    BuildPacketProofsRequest, IncludeProof, QueryChannelClientStateRequest, QueryChannelRequest,
+    QueryChannelsRequest, QueryClientConnectionsRequest, QueryClientStateRequest,
+    QueryClientStatesRequest, QueryConnectionChannelsRequest, QueryConnectionRequest,
+    QueryConnectionsRequest, QueryConsensusStateRequest, QueryConsensusStatesRequest, QueryHeight,
     QueryHostConsensusStateRequest, QueryNextSequenceReceiveRequest,
     QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementsRequest,
     QueryPacketCommitmentRequest, QueryPacketCommitmentsRequest, QueryPacketReceiptRequest,
-    QueryTxRequest, QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
+    QueryTxHash, QueryTxRequest, QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
     QueryUpgradedClientStateRequest, QueryUpgradedConsensusStateRequest,
 };
 
 pub mod batch;
 pub mod client;
 pub mod compatibility;
+pub mod divergence;
 pub mod encode;
 pub mod estimate;
 pub mod fee;
+pub mod fee_budget;
 pub mod gas;
+pub mod multisig;
 pub mod query;
 pub mod retry;
 pub mod simulate;
+pub mod staleness;
 pub mod tx;
 pub mod types;
 pub mod version;
 pub mod wait;
 
+/// How long to wait before retrying a query served by a lagging gRPC endpoint.
+const GRPC_LAG_BACKOFF: Duration = Duration::from_millis(300);
+
+/// How many blocks below the queried height are scanned for packet events when the
+/// node does not index transactions and the request does not specify a minimum height.
+const MAX_PACKET_EVENTS_BLOCK_SCAN: u64 = 100;
+
+/// How many of the most recent blocks seen on a quarantined node are fetched
+/// again, and compared with the blocks seen earlier, to lift its quarantine.
+const REVERIFIED_BLOCKS: usize = 10;
+
 /// fraction of the maximum block size defined in the Tendermint core consensus parameters.
 pub const GENESIS_MAX_BYTES_MAX_FRACTION: f64 = 0.9;
 // https://github.com/cosmos/cosmos-sdk/blob/v0.44.0/types/errors/errors.go#L115-L117
@@ -135,6 +175,18 @@ pub struct CosmosSdkChain {
     keybase: KeyRing,
     /// A cached copy of the account information
     account: Option<Account>,
+    /// The versions of the SDK and IBC-go modules of the chain
+    versions: ChainVersions,
+    /// The highest height observed on the chain, to detect a lagging gRPC endpoint
+    height_guard: HeightGuard,
+    /// The latest blocks reported by the node, to detect a rollback or fork
+    divergence_guard: DivergenceGuard,
+    /// The latest header verified by the light client, to verify query proofs against
+    proof_anchor: ProofAnchor,
+    /// The trust options of the light client when verifying the headers of query proofs
+    proof_light_client_options: OnceCell<TmOptions>,
+    /// A cached copy of the commitment prefix of the chain
+    commitment_prefix: OnceCell<CommitmentPrefix>,
 }
 
 impl CosmosSdkChain {
@@ -299,11 +351,41 @@ impl CosmosSdkChain {
         Ok(params)
     }
 
-    /// The unbonding period of this chain
+    /// Query the parameters of the `ccvconsumer` module, which replace the
+    /// staking parameters on Interchain Security consumer chains
+    pub fn query_consumer_params(&self) -> Result<ConsumerParams, Error> {
+        crate::time!("query_consumer_params");
+        crate::telemetry!(query, self.id(), "query_consumer_params");
+
+        self.block_on(query_consumer_params(&self.grpc_addr))
+    }
+
+    /// The unbonding period of this chain.
+    ///
+    /// For chains without a staking module, such as consumer chains, falls back to
+    /// the `unbonding_period` of the chain configuration if set, and otherwise to
+    /// the unbonding period of the `ccvconsumer` module.
     pub fn unbonding_period(&self) -> Result<Duration, Error> {
         crate::time!("unbonding_period");
 
-        let unbonding_time = self.query_staking_params()?.unbonding_time.ok_or_else(|| {
+        let staking_params = match self.query_staking_params() {
+            Ok(params) => params,
+            Err(e) => {
+                if let Some(unbonding_period) = self.config.unbonding_period {
+                    return Ok(unbonding_period);
+                }
+
+                return self
+                    .query_consumer_params()
+                    .map(|params| params.unbonding_period)
+                    .map_err(|consumer_e| {
+                        debug!("failed to query the ccvconsumer params: {}", consumer_e);
+                        e
+                    });
+            }
+        };
+
+        let unbonding_time = staking_params.unbonding_time.ok_or_else(|| {
             Error::grpc_response_param("no unbonding time in staking params".to_string())
         })?;
 
@@ -313,11 +395,21 @@ impl CosmosSdkChain {
         ))
     }
 
-    /// The number of historical entries kept by this chain
+    /// The number of historical entries kept by this chain, taken from the
+    /// `ccvconsumer` module for chains without a staking module.
     pub fn historical_entries(&self) -> Result<u32, Error> {
         crate::time!("historical_entries");
 
-        self.query_staking_params().map(|p| p.historical_entries)
+        self.query_staking_params()
+            .map(|p| p.historical_entries)
+            .or_else(|e| {
+                self.query_consumer_params()
+                    .map(|params| params.historical_entries)
+                    .map_err(|consumer_e| {
+                        debug!("failed to query the ccvconsumer params: {}", consumer_e);
+                        e
+                    })
+            })
     }
 
     /// Run a future to completion on the Tokio runtime.
@@ -345,6 +437,10 @@ impl CosmosSdkChain {
             return Err(Error::private_store());
         }
 
+        if prove {
+            self.ensure_not_quarantined()?;
+        }
+
         let response = self.block_on(abci_query(
             &self.rpc_client,
             &self.config.rpc_addr,
@@ -354,12 +450,134 @@ impl CosmosSdkChain {
             prove,
         ))?;
 
-        // TODO - Verify response proof, if requested.
-        if prove {}
+        if prove && self.config.verify_query_proofs {
+            self.verify_query_proof(&data, &response)?;
+        }
 
         Ok(response)
     }
 
+    /// Verify the proof included in the response to a provable query for
+    /// the given path, before it is used to build a message.
+    fn verify_query_proof(&self, data: &Path, response: &QueryResponse) -> Result<(), Error> {
+        crate::time!("verify_query_proof");
+
+        let proof_height = response.height.increment();
+
+        self.block_on(wait_for_block(
+            &self.config.id,
+            &self.rpc_client,
+            &self.config.rpc_addr,
+            self.config.max_block_time,
+            proof_height,
+        ))?;
+
+        let root = self.verified_app_hash(proof_height)?;
+
+        let proof_specs = self.config.proof_specs.clone().unwrap_or_default();
+        let prefix = self
+            .commitment_prefix
+            .get_or_try_init(|| self.query_commitment_prefix())?;
+
+        verify_query_proof(
+            &self.config.id,
+            &proof_specs,
+            root,
+            prefix,
+            &data.to_string(),
+            response,
+        )
+    }
+
+    /// The app hash of the header at the given height, verified by the light client
+    /// starting from the latest header it verified.
+    ///
+    /// The first anchor is the header verified when updating a client of this chain,
+    /// see [`ChainEndpoint::verify_header`], or, if a proof is verified before that,
+    /// the latest header of the node, which is then trusted on first use. The same
+    /// happens once the anchor falls outside of the trusting period.
+    fn verified_app_hash(&self, height: TmHeight) -> Result<CommitmentRoot, Error> {
+        let options = *self.proof_light_client_options.get_or_try_init(|| {
+            Ok::<_, Error>(TmOptions {
+                trust_threshold: self.config.trust_threshold,
+                trusting_period: self.trusting_period(self.unbonding_period()?),
+                clock_drift: self.config.clock_drift,
+            })
+        })?;
+
+        let anchor = self.proof_anchor.get().filter(|anchor| {
+            tendermint::Time::now()
+                .duration_since(anchor.time())
+                .map_or(true, |elapsed| elapsed < options.trusting_period)
+        });
+
+        let anchor = match anchor {
+            Some(anchor) => anchor,
+            None => {
+                warn!(
+                    chain = %self.id(),
+                    "no recent verified header to check query proofs against, \
+                    trusting the latest header of the node"
+                );
+
+                self.proof_anchor.reset();
+                self.light_client.fetch_latest()?
+            }
+        };
+
+        let verified = self.light_client.verify_from(anchor, height, options)?;
+        self.proof_anchor.advance(&verified);
+
+        Ok(CommitmentRoot::from_bytes(
+            verified.signed_header.header.app_hash.as_ref(),
+        ))
+    }
+
+    /// Query the values at the given paths along with their proofs, with at most
+    /// `proof_query_concurrency` queries in flight at once, and return the
+    /// proofs in the order of the queries.
+    fn query_proofs(&self, queries: Vec<(Path, ICSHeight)>) -> Result<Vec<MerkleProof>, Error> {
+        crate::time!("query_proofs");
+
+        self.ensure_not_quarantined()?;
+
+        let concurrency = self.config.proof_query_concurrency.max(1);
+
+        let responses = queries.into_iter().map(|(data, height)| async move {
+            // SAFETY: Creating a Path from a constant; this should never fail
+            let path = TendermintABCIPath::from_str(IBC_QUERY_PATH)
+                .expect("Turning IBC query path constant into a Tendermint ABCI path");
+
+            let height = TmHeight::try_from(QueryHeight::Specific(height))?;
+
+            let response = abci_query(
+                &self.rpc_client,
+                &self.config.rpc_addr,
+                path,
+                data.to_string(),
+                height,
+                true,
+            )
+            .await?;
+
+            Ok::<_, Error>((data, response))
+        });
+
+        let responses: Vec<(Path, QueryResponse)> =
+            self.block_on(stream::iter(responses).buffered(concurrency).try_collect())?;
+
+        responses
+            .into_iter()
+            .map(|(data, response)| {
+                if self.config.verify_query_proofs {
+                    self.verify_query_proof(&data, &response)?;
+                }
+
+                response.proof.ok_or_else(Error::empty_response_proof)
+            })
+            .collect()
+    }
+
     /// Perform an ABCI query against the client upgrade sub-store.
     ///
     /// The data is returned in its raw format `Vec<u8>`, and is either the
@@ -400,6 +618,128 @@ impl CosmosSdkChain {
         Ok((response.value, proof))
     }
 
+    /// Run a gRPC query against the latest state of the chain, along with the height at which
+    /// it was served if known, and retry it while that height lags behind the highest height
+    /// observed on the chain, for up to twice the `max_block_time`.
+    ///
+    /// This guards against gRPC endpoints which serve a stale state, eg. load-balanced
+    /// endpoints whose nodes are not all in sync.
+    fn guard_grpc_query<T>(
+        &self,
+        mut query: impl FnMut() -> Result<(T, Option<ICSHeight>), Error>,
+    ) -> Result<T, Error> {
+        let start_time = Instant::now();
+
+        loop {
+            let (result, height) = query()?;
+
+            let height = match height {
+                Some(height) => height,
+                None => return Ok(result),
+            };
+
+            let lag = self.height_guard.lag(height);
+
+            crate::telemetry!(grpc_node_lag, self.id(), lag);
+
+            if lag == 0 {
+                self.height_guard.observe(height);
+                return Ok(result);
+            }
+
+            if start_time.elapsed() > self.config.max_block_time * 2 {
+                return Err(Error::stale_grpc_node(
+                    self.id().clone(),
+                    self.config.grpc_addr.to_string(),
+                    height,
+                    self.height_guard.highest().unwrap_or(height),
+                ));
+            }
+
+            warn!(
+                chain = %self.id(),
+                "gRPC endpoint {} served a query at height {}, {} blocks behind the highest observed height, retrying",
+                self.config.grpc_addr,
+                height,
+                lag
+            );
+
+            thread::sleep(GRPC_LAG_BACKOFF);
+        }
+    }
+
+    /// Check that the node did not fork away from the blocks it reported earlier,
+    /// given the block it now reports as its latest one.
+    ///
+    /// A node which diverged is quarantined, so that no proof nor header is built from
+    /// its state, until the recent blocks seen earlier are fetched from it again
+    /// through the light client, with the same hashes.
+    fn check_divergence(&self, height: ICSHeight, hash: Hash) -> Result<(), Error> {
+        if self.divergence_guard.quarantined().is_some() {
+            if !self.serves_seen_blocks(height)? {
+                return self.ensure_not_quarantined();
+            }
+
+            info!(
+                chain = %self.id(),
+                "node {} serves the blocks seen earlier again, lifting its quarantine",
+                self.config.rpc_addr
+            );
+
+            self.divergence_guard.release();
+        }
+
+        if let Some(divergence) = self.divergence_guard.observe(height, hash) {
+            error!(
+                chain = %self.id(),
+                "node {} diverged from the blocks seen earlier, no proof will be built from it until it recovers: {}",
+                self.config.rpc_addr,
+                divergence
+            );
+
+            crate::telemetry!(node_divergence, self.id());
+
+            self.divergence_guard.quarantine(divergence);
+
+            return self.ensure_not_quarantined();
+        }
+
+        Ok(())
+    }
+
+    /// Whether the node, which reports the given latest height, serves the
+    /// same blocks as the most recent ones seen before it was quarantined.
+    fn serves_seen_blocks(&self, height: ICSHeight) -> Result<bool, Error> {
+        let seen_blocks = self.divergence_guard.seen_blocks();
+
+        if seen_blocks
+            .last()
+            .map_or(false, |(highest, _)| height < *highest)
+        {
+            return Ok(false);
+        }
+
+        for (seen_height, seen_hash) in seen_blocks.iter().rev().take(REVERIFIED_BLOCKS) {
+            if self.light_client.fetch_header_hash(*seen_height)? != *seen_hash {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Fail if the node is quarantined after diverging from the blocks seen earlier.
+    fn ensure_not_quarantined(&self) -> Result<(), Error> {
+        match self.divergence_guard.quarantined() {
+            Some(divergence) => Err(Error::diverged_node(
+                self.id().clone(),
+                self.config.rpc_addr.to_string(),
+                divergence.to_string(),
+            )),
+            None => Ok(()),
+        }
+    }
+
     /// Query the chain status via an RPC query.
     ///
     /// Returns an error if the node is still syncing and has not caught up,
@@ -574,7 +914,11 @@ impl CosmosSdkChain {
         for seq in request.sequences.iter() {
             let response = self
                 .block_on(self.rpc_client.block_search(
-                    packet_query(request, *seq),
+                    packet_query_in_height_window(
+                        packet_query(request, *seq),
+                        "block.height",
+                        request,
+                    ),
                     1,
                     1, // there should only be a single match for this query
                     Order::Ascending,
@@ -606,6 +950,47 @@ impl CosmosSdkChain {
         }
         Ok((begin_block_events, end_block_events))
     }
+
+    /// Whether the node indexes transactions and can therefore serve
+    /// `tx_search` and `block_search` queries.
+    fn is_tx_indexing_enabled(&self) -> Result<bool, Error> {
+        let status = self
+            .block_on(self.rpc_client.status())
+            .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+
+        Ok(status.node_info.other.tx_index == TxIndexStatus::On)
+    }
+
+    /// Query the packet events matching the request by scanning the blocks within the
+    /// window of heights of the request, for nodes which do not index transactions.
+    /// If the request does not specify a minimum height, only the last
+    /// [`MAX_PACKET_EVENTS_BLOCK_SCAN`] blocks below the queried height are scanned.
+    fn query_packets_from_block_window(
+        &self,
+        request: &QueryPacketEventDataRequest,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let max_height = match request.height.get() {
+            QueryHeight::Latest => self.query_chain_latest_height()?,
+            QueryHeight::Specific(height) => height,
+        };
+
+        let max_height = max_height.revision_height();
+
+        let min_height = match request.min_height {
+            Some(min_height) => min_height.revision_height(),
+            None => max_height
+                .saturating_sub(MAX_PACKET_EVENTS_BLOCK_SCAN - 1)
+                .max(1),
+        };
+
+        self.block_on(query_packets_from_block_range(
+            self.id(),
+            &self.rpc_client,
+            &self.config.rpc_addr,
+            request,
+            min_height..=max_height,
+        ))
+    }
 }
 
 impl ChainEndpoint for CosmosSdkChain {
@@ -627,9 +1012,40 @@ impl ChainEndpoint for CosmosSdkChain {
         let grpc_addr = Uri::from_str(&config.grpc_addr.to_string())
             .map_err(|e| Error::invalid_uri(config.grpc_addr.to_string(), e))?;
 
-        let tx_config = TxConfig::try_from(&config)?;
+        let mut tx_config = TxConfig::try_from(&config)?;
+
+        // Share the daily fee budget of the chain with its other chain runtimes,
+        // or stop enforcing it if it was unset.
+        tx_config.fee_budget = match config.max_daily_fee_spend {
+            Some(max) => Some(FeeBudget::shared(
+                &config.id,
+                &config.gas_price.denom,
+                max,
+                Some(&default::state_store_dir()),
+            )),
+            None => {
+                FeeBudget::remove(&config.id);
+                None
+            }
+        };
 
         // Retrieve the version specification of this chain
+        let version_specs = match rt.block_on(fetch_version_specs(&config.id, &grpc_addr)) {
+            Ok(specs) => Some(specs),
+            Err(e) => {
+                warn!(
+                    chain = %config.id,
+                    "failed to probe the chain versions, relying on the `compat_mode` config: {}",
+                    e
+                );
+
+                None
+            }
+        };
+
+        let versions = ChainVersions::new(version_specs.as_ref(), config.compat_mode.as_ref());
+
+        debug!(chain = %config.id, "chain versions: {}", versions);
 
         let chain = Self {
             config,
@@ -640,6 +1056,12 @@ impl ChainEndpoint for CosmosSdkChain {
             keybase,
             account: None,
             tx_config,
+            versions,
+            height_guard: HeightGuard::default(),
+            divergence_guard: DivergenceGuard::default(),
+            proof_anchor: ProofAnchor::default(),
+            proof_light_client_options: OnceCell::new(),
+            commitment_prefix: OnceCell::new(),
         };
 
         Ok(chain)
@@ -654,6 +1076,7 @@ impl ChainEndpoint for CosmosSdkChain {
         let (mut event_monitor, event_receiver, monitor_tx) = EventMonitor::new(
             self.config.id.clone(),
             self.config.websocket_addr.clone(),
+            BatchConfig::from_chain_config(&self.config),
             rt,
         )
         .map_err(Error::event_monitor)?;
@@ -687,7 +1110,7 @@ impl ChainEndpoint for CosmosSdkChain {
     /// Currently this checks that:
     ///     - the node responds OK to `/health` RPC call;
     ///     - the node has transaction indexing enabled;
-    ///     - the SDK version is supported;
+    ///     - the SDK and IBC-go versions are supported, along with each other;
     ///
     /// Emits a log warning in case anything is amiss.
     /// Exits early if any health check fails, without doing any
@@ -701,6 +1124,13 @@ impl ChainEndpoint for CosmosSdkChain {
             return Ok(HealthCheck::Unhealthy(Box::new(e)));
         }
 
+        if let Err(e) = check_compatibility(self) {
+            warn!("Chain '{}' runs unsupported module versions", self.id());
+            warn!("    Reason: {}", e.detail());
+
+            return Ok(HealthCheck::Incompatible(Box::new(e)));
+        }
+
         if let Err(e) = self.validate_params() {
             warn!("Hermes might be misconfigured for chain '{}'", self.id());
             warn!("    Reason: {}", e.detail());
@@ -719,9 +1149,16 @@ impl ChainEndpoint for CosmosSdkChain {
         target: ICSHeight,
         client_state: &AnyClientState,
     ) -> Result<Self::LightBlock, Error> {
-        self.light_client
+        let verified = self
+            .light_client
             .verify(trusted, target, client_state)
-            .map(|v| v.target)
+            .map(|v| v.target)?;
+
+        if self.config.verify_query_proofs {
+            self.proof_anchor.advance(&verified);
+        }
+
+        Ok(verified)
     }
 
     /// Given a client update event that includes the header used in a client update,
@@ -801,8 +1238,7 @@ impl ChainEndpoint for CosmosSdkChain {
     }
 
     fn ibc_version(&self) -> Result<Option<semver::Version>, Error> {
-        let version_specs = self.block_on(fetch_version_specs(self.id(), &self.grpc_addr))?;
-        Ok(version_specs.ibc_go)
+        Ok(self.versions.ibc_go.clone())
     }
 
     fn query_balance(&self, key_name: Option<&str>, denom: Option<&str>) -> Result<Balance, Error> {
@@ -844,6 +1280,14 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(denom_trace)
     }
 
+    fn query_denom_hash(&self, trace: String) -> Result<String, Error> {
+        if self.versions.supports_denom_hash() {
+            self.block_on(query_denom_hash(&self.grpc_addr, &trace))
+        } else {
+            Ok(compute_denom_hash(&trace))
+        }
+    }
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
         crate::time!("query_commitment_prefix");
         crate::telemetry!(query, self.id(), "query_commitment_prefix");
@@ -885,6 +1329,9 @@ impl ChainEndpoint for CosmosSdkChain {
             .map_err(|_| Error::invalid_height_no_source())?;
             let timestamp = latest_app_block.header.time.into();
 
+            self.check_divergence(height, latest_app_block.block_id.hash)?;
+            self.height_guard.observe(height);
+
             Ok(ChainStatus { height, timestamp })
         } else {
             // The `/blockchain` query failed to return the header we wanted
@@ -1419,26 +1866,28 @@ impl ChainEndpoint for CosmosSdkChain {
             )
             .map_err(Error::grpc_transport)?;
 
-        let request = tonic::Request::new(request.into());
+        let request: RawQueryPacketCommitmentsRequest = request.into();
 
-        let response = self
-            .block_on(client.packet_commitments(request))
-            .map_err(Error::grpc_status)?
-            .into_inner();
+        self.guard_grpc_query(|| {
+            let response = self
+                .block_on(client.packet_commitments(tonic::Request::new(request.clone())))
+                .map_err(Error::grpc_status)?
+                .into_inner();
 
-        let mut commitment_sequences: Vec<Sequence> = response
-            .commitments
-            .into_iter()
-            .map(|v| v.sequence.into())
-            .collect();
-        commitment_sequences.sort_unstable();
+            let mut commitment_sequences: Vec<Sequence> = response
+                .commitments
+                .into_iter()
+                .map(|v| v.sequence.into())
+                .collect();
+            commitment_sequences.sort_unstable();
 
-        let height = response
-            .height
-            .and_then(|raw_height| raw_height.try_into().ok())
-            .ok_or_else(|| Error::grpc_response_param("height".to_string()))?;
+            let height = response
+                .height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or_else(|| Error::grpc_response_param("height".to_string()))?;
 
-        Ok((commitment_sequences, height))
+            Ok(((commitment_sequences, height), Some(height)))
+        })
     }
 
     fn query_packet_receipt(
@@ -1482,19 +1931,25 @@ impl ChainEndpoint for CosmosSdkChain {
             )
             .map_err(Error::grpc_transport)?;
 
-        let request = tonic::Request::new(request.into());
+        let request: RawQueryUnreceivedPacketsRequest = request.into();
 
-        let mut response = self
-            .block_on(client.unreceived_packets(request))
-            .map_err(Error::grpc_status)?
-            .into_inner();
+        self.guard_grpc_query(|| {
+            let response = self
+                .block_on(client.unreceived_packets(tonic::Request::new(request.clone())))
+                .map_err(Error::grpc_status)?;
 
-        response.sequences.sort_unstable();
-        Ok(response
-            .sequences
-            .into_iter()
-            .map(|seq| seq.into())
-            .collect())
+            let height = grpc_response_height(self.id().version(), response.metadata());
+
+            let mut sequences: Vec<Sequence> = response
+                .into_inner()
+                .sequences
+                .into_iter()
+                .map(|seq| seq.into())
+                .collect();
+            sequences.sort_unstable();
+
+            Ok((sequences, height))
+        })
     }
 
     fn query_packet_acknowledgement(
@@ -1538,25 +1993,27 @@ impl ChainEndpoint for CosmosSdkChain {
             )
             .map_err(Error::grpc_transport)?;
 
-        let request = tonic::Request::new(request.into());
+        let request: RawQueryPacketAcknowledgementsRequest = request.into();
 
-        let response = self
-            .block_on(client.packet_acknowledgements(request))
-            .map_err(Error::grpc_status)?
-            .into_inner();
+        self.guard_grpc_query(|| {
+            let response = self
+                .block_on(client.packet_acknowledgements(tonic::Request::new(request.clone())))
+                .map_err(Error::grpc_status)?
+                .into_inner();
 
-        let acks_sequences = response
-            .acknowledgements
-            .into_iter()
-            .map(|v| v.sequence.into())
-            .collect();
+            let acks_sequences = response
+                .acknowledgements
+                .into_iter()
+                .map(|v| v.sequence.into())
+                .collect();
 
-        let height = response
-            .height
-            .and_then(|raw_height| raw_height.try_into().ok())
-            .ok_or_else(|| Error::grpc_response_param("height".to_string()))?;
+            let height = response
+                .height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or_else(|| Error::grpc_response_param("height".to_string()))?;
 
-        Ok((acks_sequences, height))
+            Ok(((acks_sequences, height), Some(height)))
+        })
     }
 
     /// Queries the unreceived acknowledgements sequences associated with a channel.
@@ -1575,19 +2032,25 @@ impl ChainEndpoint for CosmosSdkChain {
             )
             .map_err(Error::grpc_transport)?;
 
-        let request = tonic::Request::new(request.into());
+        let request: RawQueryUnreceivedAcksRequest = request.into();
 
-        let mut response = self
-            .block_on(client.unreceived_acks(request))
-            .map_err(Error::grpc_status)?
-            .into_inner();
+        self.guard_grpc_query(|| {
+            let response = self
+                .block_on(client.unreceived_acks(tonic::Request::new(request.clone())))
+                .map_err(Error::grpc_status)?;
 
-        response.sequences.sort_unstable();
-        Ok(response
-            .sequences
-            .into_iter()
-            .map(|seq| seq.into())
-            .collect())
+            let height = grpc_response_height(self.id().version(), response.metadata());
+
+            let mut sequences: Vec<Sequence> = response
+                .into_inner()
+                .sequences
+                .into_iter()
+                .map(|seq| seq.into())
+                .collect();
+            sequences.sort_unstable();
+
+            Ok((sequences, height))
+        })
     }
 
     fn query_next_sequence_receive(
@@ -1653,6 +2116,17 @@ impl ChainEndpoint for CosmosSdkChain {
         ))
     }
 
+    fn query_tx_messages(&self, request: QueryTxHash) -> Result<Vec<Any>, Error> {
+        crate::time!("query_tx_messages");
+        crate::telemetry!(query, self.id(), "query_tx_messages");
+
+        self.block_on(query_tx_messages(
+            &self.rpc_client,
+            &self.config.rpc_addr,
+            &request.0,
+        ))
+    }
+
     /// This function queries transactions for packet events matching certain criteria.
     /// It returns at most one packet event for each sequence specified in the request.
     ///    Note - there is no way to format the packet query such that it asks for Tx-es with either
@@ -1662,6 +2136,8 @@ impl ChainEndpoint for CosmosSdkChain {
     ///    Therefore, for packets we perform one tx_search for each sequence.
     ///    Alternatively, a single query for all packets could be performed but it would return all
     ///    packets ever sent.
+    /// If the node does not index transactions, the blocks within the window of heights
+    /// of the request are scanned instead.
     fn query_packet_events(
         &self,
         mut request: QueryPacketEventDataRequest,
@@ -1669,6 +2145,9 @@ impl ChainEndpoint for CosmosSdkChain {
         crate::time!("query_packet_events");
         crate::telemetry!(query, self.id(), "query_packet_events");
 
+        request.sequences = request.paged_sequences().to_vec();
+        request.pagination = None;
+
         match request.height {
             Qualified::Equal(_) => self.block_on(query_packets_from_block(
                 self.id(),
@@ -1677,12 +2156,31 @@ impl ChainEndpoint for CosmosSdkChain {
                 &request,
             )),
             Qualified::SmallerEqual(_) => {
-                let tx_events = self.block_on(query_packets_from_txs(
+                let tx_events = match self.block_on(query_packets_from_txs(
                     self.id(),
                     &self.rpc_client,
                     &self.config.rpc_addr,
                     &request,
-                ))?;
+                )) {
+                    Ok(tx_events) => tx_events,
+                    Err(e) => {
+                        if self.is_tx_indexing_enabled()? {
+                            return Err(e);
+                        }
+
+                        warn!(
+                            "transaction indexing is disabled on chain {}, \
+                            falling back to scanning blocks for packet events: {}",
+                            self.id(),
+                            e
+                        );
+
+                        let mut events = self.query_packets_from_block_window(&request)?;
+                        sort_events_by_sequence(&mut events);
+
+                        return Ok(events);
+                    }
+                };
 
                 let recvd_sequences: Vec<_> = tx_events
                     .iter()
@@ -1759,7 +2257,10 @@ impl ChainEndpoint for CosmosSdkChain {
             .trusting_period
             .unwrap_or_else(|| self.trusting_period(unbonding_period));
 
-        let proof_specs = self.config.proof_specs.clone().unwrap_or_default();
+        let proof_specs = settings
+            .proof_specs
+            .or_else(|| self.config.proof_specs.clone())
+            .unwrap_or_default();
 
         // Build the client state.
         TmClientState::new(
@@ -1796,6 +2297,8 @@ impl ChainEndpoint for CosmosSdkChain {
     ) -> Result<(Self::Header, Vec<Self::Header>), Error> {
         crate::time!("build_header");
 
+        self.ensure_not_quarantined()?;
+
         // Get the light block at target_height from chain.
         let Verified { target, supporting } = self.light_client.header_and_minimal_set(
             trusted_height,
@@ -1806,12 +2309,102 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok((target, supporting))
     }
 
+    /// Builds the proofs for a batch of packet messages, querying them concurrently
+    /// rather than one after the other, which is where most of the time spent
+    /// assembling the operational data of a link goes.
+    fn build_packet_proofs_batch(
+        &self,
+        requests: Vec<BuildPacketProofsRequest>,
+    ) -> Result<Vec<Proofs>, Error> {
+        crate::time!("build_packet_proofs_batch");
+
+        let mut queries = Vec::with_capacity(requests.len());
+
+        for request in &requests {
+            let (port_id, channel_id, sequence) = (
+                request.port_id.clone(),
+                request.channel_id.clone(),
+                request.sequence,
+            );
+
+            let packet_path: Path = match request.packet_type {
+                PacketMsgType::Recv => CommitmentsPath {
+                    port_id,
+                    channel_id,
+                    sequence,
+                }
+                .into(),
+                PacketMsgType::Ack => AcksPath {
+                    port_id,
+                    channel_id,
+                    sequence,
+                }
+                .into(),
+                PacketMsgType::TimeoutUnordered | PacketMsgType::TimeoutOnClose => ReceiptsPath {
+                    port_id,
+                    channel_id,
+                    sequence,
+                }
+                .into(),
+                PacketMsgType::TimeoutOrdered => SeqRecvsPath(port_id, channel_id).into(),
+            };
+
+            if request.packet_type == PacketMsgType::TimeoutOnClose {
+                let channel_path =
+                    ChannelEndsPath(request.port_id.clone(), request.channel_id.clone());
+
+                queries.push((channel_path.into(), request.height));
+            }
+
+            queries.push((packet_path, request.height));
+        }
+
+        let mut proofs = self.query_proofs(queries)?.into_iter();
+        let mut next_proof = || {
+            let proof = proofs.next().expect(QUERY_PROOF_EXPECT_MSG);
+            CommitmentProofBytes::try_from(proof).map_err(Error::malformed_proof)
+        };
+
+        requests
+            .iter()
+            .map(|request| {
+                let channel_proof = if request.packet_type == PacketMsgType::TimeoutOnClose {
+                    Some(next_proof()?)
+                } else {
+                    None
+                };
+
+                Proofs::new(
+                    next_proof()?,
+                    None,
+                    None,
+                    channel_proof,
+                    request.height.increment(),
+                )
+                .map_err(Error::malformed_proof)
+            })
+            .collect()
+    }
+
     fn maybe_register_counterparty_payee(
         &mut self,
         channel_id: &ChannelId,
         port_id: &PortId,
         counterparty_payee: &Signer,
     ) -> Result<(), Error> {
+        // The payee is still registered if the version of the chain is unknown,
+        // in which case the transaction fails if the middleware is missing.
+        if self.versions.lacks_fees() {
+            warn!(
+                chain = %self.id(),
+                "chain does not support the fee middleware ({}), \
+                not registering counterparty payee",
+                self.versions
+            );
+
+            return Ok(());
+        }
+
         let address = self.get_signer()?;
         let key_entry = self.key()?;
 
@@ -1872,7 +2465,6 @@ fn client_id_suffix(client_id: &ClientId) -> Option<u64> {
 
 fn do_health_check(chain: &CosmosSdkChain) -> Result<(), Error> {
     let chain_id = chain.id();
-    let grpc_address = chain.grpc_addr.to_string();
     let rpc_address = chain.config.rpc_addr.to_string();
 
     // Checkup on the self-reported health endpoint
@@ -1909,6 +2501,15 @@ fn do_health_check(chain: &CosmosSdkChain) -> Result<(), Error> {
         );
     }
 
+    Ok(())
+}
+
+/// Check that the versions of the SDK and IBC-go modules of the chain are supported,
+/// along with each other.
+fn check_compatibility(chain: &CosmosSdkChain) -> Result<(), Error> {
+    let chain_id = chain.id();
+    let grpc_address = chain.grpc_addr.to_string();
+
     let version_specs = chain.block_on(fetch_version_specs(&chain.config.id, &chain.grpc_addr))?;
 
     // Checkup on the underlying SDK & IBC-go versions
@@ -60,6 +60,8 @@ pub async fn send_batched_messages_and_wait_commit(
     )
     .await?;
 
+    settle_fees(config, &tx_sync_results);
+
     let events = tx_sync_results
         .into_iter()
         .flat_map(|el| el.events)
@@ -243,11 +245,25 @@ async fn sequential_send_messages_as_batches(
             &mut tx_sync_results,
         )
         .await?;
+
+        settle_fees(config, &tx_sync_results);
     }
 
     Ok(tx_sync_results)
 }
 
+/// Settle the fees reserved in the daily fee budget of the chain, if any,
+/// for the transactions whose result was received.
+fn settle_fees(config: &TxConfig, tx_sync_results: &[TxSyncResult]) {
+    if let Some(fee_budget) = &config.fee_budget {
+        for tx_sync_result in tx_sync_results {
+            if let Some(gas_used) = tx_sync_result.gas_used {
+                fee_budget.settle(&tx_sync_result.response.hash.to_string(), gas_used);
+            }
+        }
+    }
+}
+
 fn response_to_tx_sync_result(
     chain_id: &ChainId,
     message_count: usize,
@@ -266,12 +282,14 @@ fn response_to_tx_sync_result(
         TxSyncResult {
             response,
             events: events_per_tx,
+            gas_used: None,
             status: TxStatus::ReceivedResponse,
         }
     } else {
         TxSyncResult {
             response,
             events: Vec::new(),
+            gas_used: None,
             status: TxStatus::Pending { message_count },
         }
     }
@@ -5,6 +5,7 @@ use core::time::Duration;
 use tracing::warn;
 
 use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
+use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
 
 use crate::config::ChainConfig;
 use crate::foreign_client::CreateOptions;
@@ -16,6 +17,7 @@ pub struct Settings {
     pub max_clock_drift: Duration,
     pub trusting_period: Option<Duration>,
     pub trust_threshold: TrustThreshold,
+    pub proof_specs: Option<ProofSpecs>,
 }
 
 impl Settings {
@@ -41,10 +43,14 @@ impl Settings {
         let trust_threshold = options
             .trust_threshold
             .unwrap_or_else(|| src_chain_config.trust_threshold.into());
+        let proof_specs = options
+            .proof_specs
+            .or_else(|| src_chain_config.proof_specs.clone());
         Settings {
             max_clock_drift,
             trusting_period: options.trusting_period,
             trust_threshold,
+            proof_specs,
         }
     }
 }
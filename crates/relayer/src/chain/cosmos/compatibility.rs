@@ -1,9 +1,12 @@
 //! Cosmos-SDK compatibility constants and diagnostic methods.
 
+use core::fmt::{Display, Error as FmtError, Formatter};
+
 use thiserror::Error;
 use tracing::debug;
 
 use super::version;
+use crate::config::CompatMode;
 
 /// Specifies the SDK module version requirement.
 ///
@@ -22,6 +25,102 @@ const SDK_MODULE_VERSION_REQ: &str = ">=0.41, <0.47";
 /// [features]: https://hermes.informal.systems/features.html
 const IBC_GO_MODULE_VERSION_REQ: &str = ">=1.1, <=5";
 
+/// The Cosmos SDK versions each supported major version of the IBC-go module
+/// was released against, as pairs of IBC-go and SDK requirements.
+///
+/// A chain combining an IBC-go version with an SDK version other than the ones
+/// listed here is unsupported, as it typically fails in confusing ways deep
+/// into relaying, eg. with proofs which cannot be verified.
+///
+/// # Note: Should be consistent with [features] guide page.
+///
+/// [features]: https://hermes.informal.systems/features.html
+const IBC_GO_SDK_COMPATIBILITY: &[(&str, &str)] = &[
+    ("^1", ">=0.44, <0.45"),
+    ("^2", ">=0.44, <0.45"),
+    ("^3", ">=0.45, <0.46"),
+    ("^4", ">=0.45, <0.46"),
+    ("^5", ">=0.46, <0.47"),
+];
+
+/// Specifies the IBC-go module versions which expose the
+/// `DenomHash` query of the ICS 20 transfer module.
+const IBC_GO_DENOM_HASH_VERSION_REQ: &str = ">=3.1";
+
+/// Specifies the IBC-go module versions which include
+/// the ICS 29 fee middleware.
+const IBC_GO_FEE_VERSION_REQ: &str = ">=4";
+
+/// The versions of the Cosmos SDK and IBC-go modules of a chain,
+/// which determine the protocol features the chain supports.
+///
+/// Built from the versions probed from the chain, if any, with the
+/// versions set in the `compat_mode` section of the chain config taking
+/// precedence. A version is `None` if it could neither be probed nor was
+/// set in the config, in which case the features requiring a minimum
+/// version of the corresponding module are considered unsupported.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChainVersions {
+    pub cosmos_sdk: Option<semver::Version>,
+    pub ibc_go: Option<semver::Version>,
+}
+
+impl ChainVersions {
+    pub fn new(probed: Option<&version::Specs>, overrides: Option<&CompatMode>) -> Self {
+        let overrides = overrides.cloned().unwrap_or_default();
+
+        Self {
+            cosmos_sdk: overrides
+                .cosmos_sdk
+                .or_else(|| probed.map(|specs| specs.cosmos_sdk.clone())),
+            ibc_go: overrides
+                .ibc_go
+                .or_else(|| probed.and_then(|specs| specs.ibc_go.clone())),
+        }
+    }
+
+    /// Whether the transfer module exposes the `DenomHash` query.
+    /// If not, the hash of a denomination trace must be computed locally.
+    pub fn supports_denom_hash(&self) -> bool {
+        ibc_go_matches(self.ibc_go.as_ref(), IBC_GO_DENOM_HASH_VERSION_REQ)
+    }
+
+    /// Whether the chain includes the ICS 29 fee middleware.
+    pub fn supports_fees(&self) -> bool {
+        ibc_go_matches(self.ibc_go.as_ref(), IBC_GO_FEE_VERSION_REQ)
+    }
+
+    /// Whether the chain is known not to include the ICS 29 fee middleware,
+    /// ie. whether its IBC-Go version is known and predates it.
+    pub fn lacks_fees(&self) -> bool {
+        self.ibc_go.is_some() && !self.supports_fees()
+    }
+}
+
+impl Display for ChainVersions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        fn or_unknown(version: Option<&semver::Version>) -> String {
+            version
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "UNKNOWN".to_string())
+        }
+
+        write!(
+            f,
+            "Cosmos SDK {}, IBC-Go {}",
+            or_unknown(self.cosmos_sdk.as_ref()),
+            or_unknown(self.ibc_go.as_ref())
+        )
+    }
+}
+
+fn ibc_go_matches(version: Option<&semver::Version>, requirements: &str) -> bool {
+    let reqs = semver::VersionReq::parse(requirements)
+        .expect("parsing the IBC-Go module feature requirements into semver");
+
+    version.map_or(false, |version| reqs.matches(version))
+}
+
 #[derive(Error, Debug)]
 pub enum Diagnostic {
     #[error(
@@ -31,11 +130,19 @@ pub enum Diagnostic {
 
     #[error("Ibc-Go module at version '{found}' does not meet compatibility requirements {requirements}")]
     MismatchingIbcGoModuleVersion { requirements: String, found: String },
+
+    #[error("Ibc-Go module at version '{ibc_go}' is not supported along with SDK module at version '{cosmos_sdk}', which must meet requirements {requirements}")]
+    UnsupportedVersionCombination {
+        ibc_go: String,
+        cosmos_sdk: String,
+        requirements: String,
+    },
 }
 
 /// Runs a diagnostic check on the provided [`VersionInfo`]
 /// to ensure that the Sdk & IBC-go modules version match
-/// the predefined requirements.
+/// the predefined requirements, and that they are supported
+/// together as per [`IBC_GO_SDK_COMPATIBILITY`].
 ///
 /// Returns `None` upon success, or a [`Diagnostic`] upon
 /// an error.
@@ -49,6 +156,7 @@ pub(crate) fn run_diagnostic(v: &version::Specs) -> Result<(), Diagnostic> {
 
     sdk_diagnostic(&v.cosmos_sdk)?;
     ibc_go_diagnostic(v.ibc_go.as_ref())?;
+    combination_diagnostic(&v.cosmos_sdk, v.ibc_go.as_ref())?;
 
     Ok(())
 }
@@ -87,3 +195,105 @@ fn ibc_go_diagnostic(version_info: Option<&semver::Version>) -> Result<(), Diagn
         },
     }
 }
+
+fn combination_diagnostic(
+    sdk_version: &semver::Version,
+    ibc_go_version: Option<&semver::Version>,
+) -> Result<(), Diagnostic> {
+    // Chains without the standalone ibc-go module are not subject to the matrix
+    let ibc_go_version = match ibc_go_version {
+        Some(version) => version,
+        None => return Ok(()),
+    };
+
+    let sdk_requirements = IBC_GO_SDK_COMPATIBILITY
+        .iter()
+        .find(|(ibc_go_reqs, _)| ibc_go_matches(Some(ibc_go_version), ibc_go_reqs))
+        .map(|(_, sdk_reqs)| *sdk_reqs);
+
+    match sdk_requirements {
+        // The IBC-go versions outside of the matrix are reported by `ibc_go_diagnostic`
+        None => Ok(()),
+        Some(requirements) => {
+            let sdk_reqs = semver::VersionReq::parse(requirements)
+                .expect("parsing the SDK module compatibility requirements into semver");
+
+            match sdk_reqs.matches(sdk_version) {
+                true => Ok(()),
+                false => Err(Diagnostic::UnsupportedVersionCombination {
+                    ibc_go: ibc_go_version.to_string(),
+                    cosmos_sdk: sdk_version.to_string(),
+                    requirements: requirements.to_string(),
+                }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(v: &str) -> semver::Version {
+        semver::Version::parse(v).unwrap()
+    }
+
+    #[test]
+    fn features_follow_ibc_go_version() {
+        let v3 = ChainVersions {
+            cosmos_sdk: Some(version("0.45.0")),
+            ibc_go: Some(version("3.3.0")),
+        };
+        assert!(v3.supports_denom_hash());
+        assert!(!v3.supports_fees());
+        assert!(v3.lacks_fees());
+
+        let v4 = ChainVersions {
+            ibc_go: Some(version("4.1.0")),
+            ..v3
+        };
+        assert!(v4.supports_denom_hash());
+        assert!(v4.supports_fees());
+        assert!(!v4.lacks_fees());
+
+        let unknown = ChainVersions::default();
+        assert!(!unknown.supports_denom_hash());
+        assert!(!unknown.supports_fees());
+        assert!(!unknown.lacks_fees());
+    }
+
+    #[test]
+    fn overrides_take_precedence() {
+        let probed = version::Specs {
+            cosmos_sdk: version("0.45.0"),
+            ibc_go: Some(version("3.0.0")),
+            tendermint: version("0.34.21"),
+        };
+
+        let overrides = CompatMode {
+            cosmos_sdk: None,
+            ibc_go: Some(version("4.0.0")),
+        };
+
+        let versions = ChainVersions::new(Some(&probed), Some(&overrides));
+
+        assert_eq!(versions.cosmos_sdk, Some(version("0.45.0")));
+        assert_eq!(versions.ibc_go, Some(version("4.0.0")));
+    }
+
+    #[test]
+    fn unsupported_version_combinations() {
+        assert!(combination_diagnostic(&version("0.45.9"), Some(&version("4.2.0"))).is_ok());
+        assert!(combination_diagnostic(&version("0.46.4"), Some(&version("5.1.0"))).is_ok());
+        assert!(combination_diagnostic(&version("0.44.0"), None).is_ok());
+
+        assert!(matches!(
+            combination_diagnostic(&version("0.46.4"), Some(&version("3.4.0"))),
+            Err(Diagnostic::UnsupportedVersionCombination { .. })
+        ));
+        assert!(matches!(
+            combination_diagnostic(&version("0.45.9"), Some(&version("5.0.0"))),
+            Err(Diagnostic::UnsupportedVersionCombination { .. })
+        ));
+    }
+}
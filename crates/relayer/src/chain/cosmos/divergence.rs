@@ -0,0 +1,163 @@
+//! Guard against nodes which fork away from the blocks seen earlier.
+//!
+//! A node whose application state is rolled back, eg. after an app hash mismatch
+//! or a manual `rollback`, may commit different blocks at the heights Hermes already
+//! saw. Proofs built from such a node do not match the headers known to the
+//! counterparty clients.
+//!
+//! The [`DivergenceGuard`] keeps track of the hashes of the latest blocks reported
+//! by the node, so that such a fork can be detected. Once detected, the node is
+//! quarantined until it serves the blocks seen earlier again.
+//!
+//! A lower height than reported before is not a divergence by itself, since it is
+//! also reported by a node which lags behind another one serving the same endpoint,
+//! eg. behind a load balancer.
+
+use alloc::collections::BTreeMap;
+use core::fmt::{Display, Error as FmtError, Formatter};
+use std::sync::Mutex;
+
+use ibc_relayer_types::Height;
+use tendermint::Hash;
+
+/// How many of the latest blocks reported by the node are remembered.
+const MAX_SEEN_BLOCKS: usize = 100;
+
+/// The node reported another block than the one seen before at the same height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence {
+    pub height: Height,
+    pub seen: Hash,
+    pub reported: Hash,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "reported block {} at height {}, where block {} was seen before",
+            self.reported, self.height, self.seen
+        )
+    }
+}
+
+/// Keeps track of the latest blocks reported by a node, and of
+/// whether it is quarantined after diverging from them.
+#[derive(Debug, Default)]
+pub struct DivergenceGuard {
+    seen: Mutex<BTreeMap<Height, Hash>>,
+    quarantine: Mutex<Option<Divergence>>,
+}
+
+impl DivergenceGuard {
+    /// Record that the node reported the given block as its latest one,
+    /// and return how it diverged from the blocks seen earlier, if it did.
+    ///
+    /// Only blocks above the highest one seen so far are recorded, so that the
+    /// blocks reported by a diverged or lagging node do not replace the ones
+    /// seen earlier.
+    pub fn observe(&self, height: Height, hash: Hash) -> Option<Divergence> {
+        let mut seen = self.seen.lock().unwrap();
+
+        if let Some(&seen_hash) = seen.get(&height) {
+            if seen_hash != hash {
+                return Some(Divergence {
+                    height,
+                    seen: seen_hash,
+                    reported: hash,
+                });
+            }
+
+            return None;
+        }
+
+        if let Some((&highest, _)) = seen.iter().next_back() {
+            if height < highest {
+                return None;
+            }
+        }
+
+        seen.insert(height, hash);
+
+        while seen.len() > MAX_SEEN_BLOCKS {
+            let lowest = *seen.keys().next().expect("seen blocks are not empty");
+            seen.remove(&lowest);
+        }
+
+        None
+    }
+
+    /// The latest blocks reported by the node, by ascending height.
+    pub fn seen_blocks(&self) -> Vec<(Height, Hash)> {
+        let seen = self.seen.lock().unwrap();
+        seen.iter().map(|(height, hash)| (*height, *hash)).collect()
+    }
+
+    /// How the node diverged, if it is quarantined.
+    pub fn quarantined(&self) -> Option<Divergence> {
+        self.quarantine.lock().unwrap().clone()
+    }
+
+    /// Quarantine the node after it diverged in the given way.
+    pub fn quarantine(&self, divergence: Divergence) {
+        *self.quarantine.lock().unwrap() = Some(divergence);
+    }
+
+    /// Lift the quarantine of the node, once it serves the blocks seen earlier again.
+    pub fn release(&self) {
+        *self.quarantine.lock().unwrap() = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn height(revision_height: u64) -> Height {
+        Height::new(1, revision_height).unwrap()
+    }
+
+    fn hash(byte: u8) -> Hash {
+        Hash::Sha256([byte; 32])
+    }
+
+    #[test]
+    fn detect_fork_and_tolerate_lag() {
+        let guard = DivergenceGuard::default();
+
+        assert_eq!(guard.observe(height(10), hash(10)), None);
+        assert_eq!(guard.observe(height(10), hash(10)), None);
+        assert_eq!(guard.observe(height(11), hash(11)), None);
+
+        // A lagging node reports a lower height, either seen before or not
+        assert_eq!(guard.observe(height(9), hash(9)), None);
+        assert_eq!(guard.observe(height(10), hash(10)), None);
+
+        assert_eq!(
+            guard.observe(height(10), hash(42)),
+            Some(Divergence {
+                height: height(10),
+                seen: hash(10),
+                reported: hash(42),
+            })
+        );
+
+        assert_eq!(
+            guard.seen_blocks(),
+            vec![(height(10), hash(10)), (height(11), hash(11))]
+        );
+    }
+
+    #[test]
+    fn remember_latest_blocks_only() {
+        let guard = DivergenceGuard::default();
+
+        for h in 1..=(MAX_SEEN_BLOCKS as u64 + 5) {
+            guard.observe(height(h), hash(h as u8));
+        }
+
+        let seen = guard.seen_blocks();
+        assert_eq!(seen.len(), MAX_SEEN_BLOCKS);
+        assert_eq!(seen[0].0, height(6));
+    }
+}
@@ -187,7 +187,10 @@ pub fn encode_to_bech32(address: &str, account_prefix: &str) -> Result<String, E
     Ok(encoded)
 }
 
-fn auth_info_and_bytes(signer_info: SignerInfo, fee: Fee) -> Result<(AuthInfo, Vec<u8>), Error> {
+pub(crate) fn auth_info_and_bytes(
+    signer_info: SignerInfo,
+    fee: Fee,
+) -> Result<(AuthInfo, Vec<u8>), Error> {
     let auth_info = AuthInfo {
         signer_infos: vec![signer_info],
         fee: Some(fee),
@@ -205,7 +208,7 @@ fn auth_info_and_bytes(signer_info: SignerInfo, fee: Fee) -> Result<(AuthInfo, V
     Ok((auth_info, auth_buf))
 }
 
-fn tx_body_and_bytes(
+pub(crate) fn tx_body_and_bytes(
     proto_msgs: &[Any],
     memo: &Memo,
     extension_options: Vec<Any>,
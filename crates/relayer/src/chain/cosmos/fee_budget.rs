@@ -0,0 +1,530 @@
+//! Caps on the fees Hermes spends on a chain each day, as configured
+//! with the `max_daily_fee_spend` setting of the chain.
+//!
+//! Once the budget of a chain is exhausted, no transaction is submitted to it
+//! until the next day, which protects operators from runaway gas prices.
+//! The budget of a chain is shared by all the chain runtimes spawned for it,
+//! eg. the dedicated runtimes of the handshake workers.
+//!
+//! The maximum fee of a transaction, ie. its gas limit times the gas price, is
+//! reserved when the transaction is submitted, and replaced by the fee for the gas
+//! actually used once the result of the transaction is known. Transactions whose
+//! result is never seen, eg. when transaction confirmation is disabled, therefore
+//! count for their maximum fee. The fees spent over the current day are persisted,
+//! so that restarting Hermes does not reset the budget.
+
+use alloc::collections::BTreeMap;
+use core::time::Duration;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ibc_proto::cosmos::tx::v1beta1::Fee;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::error::Error;
+use crate::telemetry;
+use crate::util::lock::{LockExt, RwArc};
+
+/// How long a day of spending lasts, after which the fees spent are reset.
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// The budgets of the chains with a `max_daily_fee_spend`, indexed by chain identifier.
+static BUDGETS: Lazy<RwArc<BTreeMap<ChainId, FeeBudget>>> = Lazy::new(Default::default);
+
+/// The daily fee budget of a chain.
+///
+/// The amounts are in the smallest unit of the denomination, as in the fees
+/// of the transactions, so that they are accounted for exactly.
+#[derive(Clone, Debug)]
+pub struct FeeBudget {
+    chain_id: ChainId,
+    spending: RwArc<Spending>,
+}
+
+#[derive(Debug)]
+struct Spending {
+    denom: String,
+    max_daily_spend: u128,
+    day_start: SystemTime,
+    /// The fees paid by the transactions whose result is known.
+    spent: u128,
+    /// The maximum fees of the submitted transactions whose result
+    /// is not known yet, indexed by transaction hash.
+    reserved: BTreeMap<String, Reservation>,
+    exhausted: bool,
+    /// The file the spending is persisted to, if any.
+    file: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+struct Reservation {
+    amount: u128,
+    gas_limit: u64,
+}
+
+/// The spending of a chain over the current day, as persisted across restarts.
+#[derive(Serialize, Deserialize)]
+struct PersistedSpending {
+    denom: String,
+    /// The start of the current day, in seconds since the Unix epoch.
+    day_start: u64,
+    spent: u128,
+}
+
+impl Spending {
+    fn new(denom: String, max_daily_spend: u128, file: Option<PathBuf>) -> Self {
+        let mut spending = Self {
+            denom,
+            max_daily_spend,
+            day_start: SystemTime::now(),
+            spent: 0,
+            reserved: BTreeMap::new(),
+            exhausted: false,
+            file,
+        };
+
+        spending.load();
+        spending
+    }
+
+    /// Start a new day if the current one is over.
+    ///
+    /// The reservations of the transactions whose result was not seen
+    /// over the past day are dropped as well.
+    fn roll_over(&mut self, now: SystemTime) {
+        let elapsed = now.duration_since(self.day_start).unwrap_or_default();
+
+        if elapsed >= DAY {
+            self.day_start = now;
+            self.spent = 0;
+            self.reserved.clear();
+            self.exhausted = false;
+        }
+    }
+
+    /// The fees spent over the current day, counting the reserved ones.
+    fn total(&self) -> u128 {
+        self.reserved
+            .values()
+            .map(|reservation| reservation.amount)
+            .fold(self.spent, u128::saturating_add)
+    }
+
+    /// Restore the spending of the current day from its file, if any.
+    fn load(&mut self) {
+        let file = match &self.file {
+            Some(file) => file,
+            None => return,
+        };
+
+        let persisted = match fs::read(file) {
+            Ok(contents) => serde_json::from_slice::<PersistedSpending>(&contents),
+            Err(e) if e.kind() == ErrorKind::NotFound => return,
+            Err(e) => {
+                warn!("failed to read fee budget file {}: {}", file.display(), e);
+                return;
+            }
+        };
+
+        match persisted {
+            // The fees spent in another denomination are not comparable to the limit
+            Ok(persisted) if persisted.denom == self.denom => {
+                self.day_start = UNIX_EPOCH + Duration::from_secs(persisted.day_start);
+                self.spent = persisted.spent;
+                self.roll_over(SystemTime::now());
+            }
+            Ok(_) => {}
+            Err(e) => warn!("failed to decode fee budget file {}: {}", file.display(), e),
+        }
+    }
+
+    /// Persist the spending of the current day to its file, if any,
+    /// counting the reserved fees as spent.
+    fn save(&self) {
+        let file = match &self.file {
+            Some(file) => file,
+            None => return,
+        };
+
+        let persisted = PersistedSpending {
+            denom: self.denom.clone(),
+            day_start: self
+                .day_start
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            spent: self.total(),
+        };
+
+        if let Err(e) = write_atomically(file, &persisted) {
+            warn!("failed to write fee budget file {}: {}", file.display(), e);
+        }
+    }
+}
+
+/// Write the given spending to a temporary file first and rename it,
+/// so that the budget file is never left partially written.
+fn write_atomically(file: &Path, persisted: &PersistedSpending) -> std::io::Result<()> {
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let tmp_file = file.with_extension("tmp");
+
+    fs::write(&tmp_file, serde_json::to_vec(persisted)?)?;
+    fs::rename(&tmp_file, file)
+}
+
+/// The status of the daily fee budget of a chain, as reported by the REST API.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeBudgetStatus {
+    pub chain_id: ChainId,
+    pub denom: String,
+    pub max_daily_spend: u128,
+    /// The fees spent over the current day, including the maximum fees
+    /// of the transactions whose result is not known yet.
+    pub spent: u128,
+    /// Whether transactions are being withheld until the next day.
+    pub exhausted: bool,
+}
+
+impl FeeBudget {
+    /// The fee budget of the given chain, in the given denomination,
+    /// shared with the other chain runtimes of that chain.
+    ///
+    /// The fees spent over the current day are restored from, and persisted to,
+    /// a file in the given directory, if any.
+    ///
+    /// If the chain already has a budget, eg. when a chain runtime is spawned
+    /// from another configuration of the chain in the same process, its limit
+    /// is updated for all the chain runtimes sharing it.
+    /// The fees spent over the current day are only reset if the denomination
+    /// changed, since they are no longer comparable to the new limit then.
+    pub fn shared(
+        chain_id: &ChainId,
+        denom: &str,
+        max_daily_spend: u64,
+        state_dir: Option<&Path>,
+    ) -> Self {
+        let max_daily_spend = u128::from(max_daily_spend);
+        let mut budgets = BUDGETS.acquire_write();
+
+        let budget = budgets
+            .entry(chain_id.clone())
+            .or_insert_with(|| {
+                let file = state_dir.map(|dir| dir.join(chain_id.as_str()).join("fee_budget.json"));
+
+                Self::new(
+                    chain_id.clone(),
+                    Spending::new(denom.to_string(), max_daily_spend, file),
+                )
+            })
+            .clone();
+
+        {
+            let mut spending = budget.spending.acquire_write();
+
+            if spending.denom != denom {
+                spending.denom = denom.to_string();
+                spending.spent = 0;
+                spending.reserved.clear();
+                spending.save();
+            }
+
+            if spending.max_daily_spend != max_daily_spend {
+                spending.max_daily_spend = max_daily_spend;
+                spending.exhausted = false;
+            }
+        }
+
+        budget
+    }
+
+    /// Remove the budget of the given chain, once its `max_daily_fee_spend` is unset.
+    pub fn remove(chain_id: &ChainId) {
+        BUDGETS.acquire_write().remove(chain_id);
+    }
+
+    fn new(chain_id: ChainId, spending: Spending) -> Self {
+        Self {
+            chain_id,
+            spending: <RwArc<_>>::new_lock(spending),
+        }
+    }
+
+    /// Check that the given fee fits in what remains of the budget for the day,
+    /// before submitting a transaction paying it.
+    pub fn check(&self, fee: &Fee) -> Result<(), Error> {
+        let mut spending = self.spending.acquire_write();
+
+        spending.roll_over(SystemTime::now());
+
+        let amount = amount_of(fee, &spending.denom);
+        let spent = spending.total();
+
+        if spent.saturating_add(amount) > spending.max_daily_spend {
+            if !spending.exhausted {
+                warn!(
+                    chain = %self.chain_id,
+                    "daily fee budget exhausted, {}{} out of {}{} were spent today, \
+                    no transaction will be submitted until tomorrow",
+                    spent,
+                    spending.denom,
+                    spending.max_daily_spend,
+                    spending.denom,
+                );
+            }
+
+            spending.exhausted = true;
+
+            telemetry!(fee_budget_exhausted, &self.chain_id);
+
+            return Err(Error::fee_budget_exhausted(
+                self.chain_id.clone(),
+                spent,
+                spending.max_daily_spend,
+                spending.denom.clone(),
+            ));
+        }
+
+        spending.exhausted = false;
+
+        Ok(())
+    }
+
+    /// Reserve the given fee for the transaction with the given hash, which was
+    /// submitted paying it, until the gas it used is known, see [`FeeBudget::settle`].
+    pub fn reserve(&self, tx_hash: &str, fee: &Fee) {
+        let mut spending = self.spending.acquire_write();
+
+        spending.roll_over(SystemTime::now());
+
+        let amount = amount_of(fee, &spending.denom);
+
+        spending.reserved.insert(
+            tx_hash.to_string(),
+            Reservation {
+                amount,
+                gas_limit: fee.gas_limit,
+            },
+        );
+
+        spending.save();
+        self.report(&spending);
+    }
+
+    /// Record that the transaction with the given hash was executed using the given
+    /// amount of gas, replacing the fee reserved for it by the fee for that gas.
+    ///
+    /// Does nothing if no fee is reserved for the transaction, eg. because it was
+    /// already settled or reserved on the previous day.
+    pub fn settle(&self, tx_hash: &str, gas_used: u64) {
+        let mut spending = self.spending.acquire_write();
+
+        spending.roll_over(SystemTime::now());
+
+        let reservation = match spending.reserved.remove(tx_hash) {
+            Some(reservation) => reservation,
+            None => return,
+        };
+
+        let paid = fee_for_gas(&reservation, gas_used);
+        spending.spent = spending.spent.saturating_add(paid);
+
+        spending.save();
+        self.report(&spending);
+    }
+
+    fn report(&self, spending: &Spending) {
+        telemetry!(
+            fee_budget_spent,
+            &self.chain_id,
+            spending.total() as f64,
+            &spending.denom
+        );
+    }
+
+    pub fn status(&self) -> FeeBudgetStatus {
+        let mut spending = self.spending.acquire_write();
+
+        spending.roll_over(SystemTime::now());
+
+        FeeBudgetStatus {
+            chain_id: self.chain_id.clone(),
+            denom: spending.denom.clone(),
+            max_daily_spend: spending.max_daily_spend,
+            spent: spending.total(),
+            exhausted: spending.exhausted,
+        }
+    }
+}
+
+/// The amount of the given fee in the given denomination.
+///
+/// The amounts which cannot be parsed are counted as exceeding any budget,
+/// so that a transaction paying them is withheld rather than unaccounted for.
+fn amount_of(fee: &Fee, denom: &str) -> u128 {
+    fee.amount
+        .iter()
+        .filter(|coin| coin.denom == denom)
+        .map(|coin| coin.amount.parse::<u128>().unwrap_or(u128::MAX))
+        .fold(0, u128::saturating_add)
+}
+
+/// The share of the reserved fee paying for the given amount of gas, rounded up,
+/// which is the reserved fee itself if the whole gas limit was used.
+fn fee_for_gas(reservation: &Reservation, gas_used: u64) -> u128 {
+    if reservation.gas_limit == 0 || gas_used >= reservation.gas_limit {
+        return reservation.amount;
+    }
+
+    let gas_limit = u128::from(reservation.gas_limit);
+
+    match reservation.amount.checked_mul(u128::from(gas_used)) {
+        Some(product) => (product + gas_limit - 1) / gas_limit,
+        None => reservation.amount,
+    }
+}
+
+/// The status of the fee budgets of all the chains with one.
+pub fn fee_budgets() -> Vec<FeeBudgetStatus> {
+    BUDGETS
+        .acquire_read()
+        .values()
+        .map(FeeBudget::status)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_proto::cosmos::base::v1beta1::Coin;
+
+    fn fee(amount: u64) -> Fee {
+        Fee {
+            amount: vec![Coin {
+                denom: "stake".to_string(),
+                amount: amount.to_string(),
+            }],
+            gas_limit: 100_000,
+            payer: String::new(),
+            granter: String::new(),
+        }
+    }
+
+    fn budget(max_daily_spend: u128, file: Option<PathBuf>) -> FeeBudget {
+        FeeBudget::new(
+            ChainId::from_string("ibc-0"),
+            Spending::new("stake".to_string(), max_daily_spend, file),
+        )
+    }
+
+    #[test]
+    fn withhold_transactions_once_exhausted() {
+        let budget = budget(1000, None);
+
+        assert!(budget.check(&fee(600)).is_ok());
+        budget.reserve("A", &fee(600));
+
+        assert!(budget.check(&fee(400)).is_ok());
+        assert!(budget.check(&fee(500)).is_err());
+
+        let status = budget.status();
+        assert_eq!(status.spent, 600);
+        assert!(status.exhausted);
+    }
+
+    #[test]
+    fn settle_fees_for_the_gas_used() {
+        let budget = budget(1000, None);
+
+        budget.reserve("A", &fee(600));
+        budget.reserve("B", &fee(300));
+
+        budget.settle("A", 25_000);
+        assert_eq!(budget.status().spent, 450);
+
+        // Using more gas than the limit never costs more than the reserved fee
+        budget.settle("B", 200_000);
+        assert_eq!(budget.status().spent, 450);
+
+        // Settling again, or an unknown transaction, changes nothing
+        budget.settle("A", 100_000);
+        budget.settle("C", 100_000);
+        assert_eq!(budget.status().spent, 450);
+
+        assert!(budget.check(&fee(550)).is_ok());
+        assert!(budget.check(&fee(551)).is_err());
+    }
+
+    #[test]
+    fn reset_spending_every_day() {
+        let budget = budget(1000, None);
+
+        budget.reserve("A", &fee(500));
+        budget.reserve("B", &fee(500));
+        budget.settle("A", 100_000);
+        assert!(budget.check(&fee(1)).is_err());
+
+        if let Some(yesterday) = SystemTime::now().checked_sub(DAY) {
+            budget.spending.acquire_write().day_start = yesterday;
+
+            assert!(budget.check(&fee(1)).is_ok());
+            assert!(!budget.status().exhausted);
+            assert_eq!(budget.status().spent, 0);
+        }
+    }
+
+    #[test]
+    fn persist_spending_across_restarts() {
+        let dir = std::env::temp_dir().join(format!("hermes-fee-budget-{}", std::process::id()));
+        let file = dir.join("fee_budget.json");
+
+        let budget = budget(1000, Some(file.clone()));
+        budget.reserve("A", &fee(600));
+        budget.reserve("B", &fee(300));
+        budget.settle("A", 50_000);
+
+        // Unsettled reservations are persisted as spent
+        let restarted = self::budget(1000, Some(file.clone()));
+        assert_eq!(restarted.status().spent, 600);
+
+        // Spending in another denomination is not restored
+        let other = FeeBudget::new(
+            ChainId::from_string("ibc-0"),
+            Spending::new("uatom".to_string(), 1000, Some(file)),
+        );
+        assert_eq!(other.status().spent, 0);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn update_shared_budget_limit() {
+        let chain_id = ChainId::from_string("ibc-budget-update");
+
+        let budget = FeeBudget::shared(&chain_id, "stake", 1000, None);
+        budget.reserve("A", &fee(800));
+        assert!(budget.check(&fee(500)).is_err());
+
+        let updated = FeeBudget::shared(&chain_id, "stake", 2000, None);
+        assert!(budget.check(&fee(500)).is_ok());
+        assert_eq!(updated.status().max_daily_spend, 2000);
+        assert_eq!(updated.status().spent, 800);
+
+        FeeBudget::shared(&chain_id, "uatom", 2000, None);
+        assert_eq!(budget.status().spent, 0);
+
+        FeeBudget::remove(&chain_id);
+        assert!(fee_budgets()
+            .iter()
+            .all(|status| status.chain_id != chain_id));
+    }
+}
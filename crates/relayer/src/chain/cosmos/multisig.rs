@@ -0,0 +1,325 @@
+//! Submitting transactions from a multisig account.
+//!
+//! Since Hermes does not hold the private keys of the members of a multisig
+//! account, submitting a transaction from such an account happens in three steps,
+//! driven by the `tx multisig` commands rather than by the relaying workers:
+//!
+//! 1. [`build_multisig_tx`] builds the unsigned transaction, fixing upfront which
+//!    members of the multisig account are going to sign it;
+//! 2. each of these members signs the transaction with [`sign_multisig_tx`],
+//!    producing a [`PartialSignature`];
+//! 3. [`assemble_multisig_tx`] combines the partial signatures into the multisig
+//!    signature, and the resulting transaction is submitted with
+//!    [`broadcast_multisig_tx`].
+//!
+//! Transactions are signed in `SIGN_MODE_DIRECT`, in which the signer info, and
+//! therefore the set of signers, is part of the signed bytes. This differs from the
+//! multisig commands of the Cosmos SDK, eg. `tx sign --multisig` and `tx multisign`,
+//! which sign in `SIGN_MODE_LEGACY_AMINO_JSON`: the partial signatures produced by
+//! either tool cannot be combined by the other one. The amino JSON sign mode is not
+//! used since most IBC messages, such as client updates and packet messages, have
+//! no amino JSON encoding.
+
+use bitcoin::hashes::hex::ToHex;
+use ibc_proto::cosmos::crypto::multisig::v1beta1::{CompactBitArray, MultiSignature};
+use ibc_proto::cosmos::tx::v1beta1::mode_info::{Multi, Single, Sum};
+use ibc_proto::cosmos::tx::v1beta1::{Fee, ModeInfo, SignDoc, SignerInfo, TxRaw};
+use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::cosmos::encode::{auth_info_and_bytes, tx_body_and_bytes};
+use crate::chain::cosmos::query::tx::all_ibc_events_from_tx_search_response;
+use crate::chain::cosmos::tx::broadcast_tx_sync;
+use crate::chain::cosmos::types::account::Account;
+use crate::chain::cosmos::types::config::TxConfig;
+use crate::chain::cosmos::wait::wait_tx_succeed;
+use crate::config::types::Memo;
+use crate::config::AddressType;
+use crate::error::Error;
+use crate::event::IbcEventWithHeight;
+use crate::keyring::{sign_message, KeyEntry, MultisigKey};
+
+/// `SIGN_MODE_DIRECT`
+const SIGN_MODE_DIRECT: i32 = 1;
+
+/// An unsigned transaction from a multisig account, to be
+/// signed by each of the given `signers`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigTx {
+    pub chain_id: ChainId,
+    /// The multisig account sending the transaction
+    pub account: String,
+    pub account_number: u64,
+    pub sequence: u64,
+    /// Hex-encoded public keys of the members of the multisig account
+    /// expected to sign the transaction, in the order of their signatures.
+    pub signers: Vec<String>,
+    #[serde(with = "base64_bytes")]
+    pub body_bytes: Vec<u8>,
+    #[serde(with = "base64_bytes")]
+    pub auth_info_bytes: Vec<u8>,
+}
+
+impl MultisigTx {
+    /// The bytes to sign, ie. the protobuf encoding of the `SignDoc`.
+    pub fn sign_doc_bytes(&self) -> Vec<u8> {
+        SignDoc {
+            body_bytes: self.body_bytes.clone(),
+            auth_info_bytes: self.auth_info_bytes.clone(),
+            chain_id: self.chain_id.to_string(),
+            account_number: self.account_number,
+        }
+        .encode_to_vec()
+    }
+}
+
+/// The signature of a [`MultisigTx`] by one of the members of the multisig account.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialSignature {
+    /// Hex-encoded public key of the signer
+    pub public_key: String,
+    #[serde(with = "base64_bytes")]
+    pub signature: Vec<u8>,
+}
+
+/// Build an unsigned transaction from the given multisig account, to be signed
+/// by the members of the account at the given `signers` positions.
+pub fn build_multisig_tx(
+    config: &TxConfig,
+    key: &MultisigKey,
+    signers: &[usize],
+    account: &Account,
+    tx_memo: &Memo,
+    messages: &[Any],
+    fee: &Fee,
+) -> Result<MultisigTx, Error> {
+    let mut signers = signers.to_vec();
+    signers.sort_unstable();
+    signers.dedup();
+
+    let valid_signers = signers.len() >= key.threshold as usize
+        && signers.iter().all(|&index| index < key.public_keys.len());
+
+    if !valid_signers {
+        return Err(Error::multisig_signers(
+            signers,
+            key.threshold,
+            key.public_keys.len(),
+        ));
+    }
+
+    let mode_info = ModeInfo {
+        sum: Some(Sum::Multi(Multi {
+            bitarray: Some(signers_bit_array(key.public_keys.len(), &signers)),
+            mode_infos: vec![
+                ModeInfo {
+                    sum: Some(Sum::Single(Single {
+                        mode: SIGN_MODE_DIRECT
+                    })),
+                };
+                signers.len()
+            ],
+        })),
+    };
+
+    let signer_info = SignerInfo {
+        public_key: Some(key.to_any()),
+        mode_info: Some(mode_info),
+        sequence: account.sequence.to_u64(),
+    };
+
+    let (_, body_bytes) = tx_body_and_bytes(messages, tx_memo, config.extension_options.clone())?;
+    let (_, auth_info_bytes) = auth_info_and_bytes(signer_info, fee.clone())?;
+
+    Ok(MultisigTx {
+        chain_id: config.chain_id.clone(),
+        account: key.account.clone(),
+        account_number: account.number.to_u64(),
+        sequence: account.sequence.to_u64(),
+        signers: signers
+            .iter()
+            .map(|&index| key.public_keys[index].to_hex())
+            .collect(),
+        body_bytes,
+        auth_info_bytes,
+    })
+}
+
+/// Sign the given transaction with the key of one of its expected signers.
+pub fn sign_multisig_tx(
+    tx: &MultisigTx,
+    key_entry: &KeyEntry,
+    address_type: &AddressType,
+) -> Result<PartialSignature, Error> {
+    let public_key = key_entry.public_key.to_pub().to_bytes().to_hex();
+
+    if !tx.signers.contains(&public_key) {
+        return Err(Error::multisig_not_a_signer(key_entry.account.clone()));
+    }
+
+    let signature =
+        sign_message(key_entry, tx.sign_doc_bytes(), address_type).map_err(Error::key_base)?;
+
+    Ok(PartialSignature {
+        public_key,
+        signature,
+    })
+}
+
+/// Combine the partial signatures of all the expected signers of the
+/// given transaction, and return the encoded signed transaction.
+pub fn assemble_multisig_tx(
+    tx: &MultisigTx,
+    signatures: &[PartialSignature],
+) -> Result<Vec<u8>, Error> {
+    let signatures = tx
+        .signers
+        .iter()
+        .map(|signer| {
+            signatures
+                .iter()
+                .find(|signature| signature.public_key.eq_ignore_ascii_case(signer))
+                .map(|signature| signature.signature.clone())
+                .ok_or_else(|| Error::multisig_missing_signature(signer.clone()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let multi_signature = MultiSignature { signatures };
+
+    let tx_raw = TxRaw {
+        body_bytes: tx.body_bytes.clone(),
+        auth_info_bytes: tx.auth_info_bytes.clone(),
+        signatures: vec![multi_signature.encode_to_vec()],
+    };
+
+    let mut tx_bytes = Vec::new();
+    Message::encode(&tx_raw, &mut tx_bytes)
+        .map_err(|e| Error::protobuf_encode("Transaction".to_string(), e))?;
+
+    Ok(tx_bytes)
+}
+
+/// Broadcast a signed multisig transaction, and wait for it to be committed.
+pub async fn broadcast_multisig_tx(
+    config: &TxConfig,
+    tx_bytes: Vec<u8>,
+) -> Result<Vec<IbcEventWithHeight>, Error> {
+    let response = broadcast_tx_sync(&config.rpc_client, &config.rpc_address, tx_bytes).await?;
+
+    if response.code.is_err() {
+        return Err(Error::check_tx(response));
+    }
+
+    let response = wait_tx_succeed(
+        &config.rpc_client,
+        &config.rpc_address,
+        &config.rpc_timeout,
+        &response.hash,
+    )
+    .await?;
+
+    Ok(all_ibc_events_from_tx_search_response(
+        &config.chain_id,
+        response,
+    ))
+}
+
+/// The bit array of the members of a multisig account signing a transaction,
+/// where the most significant bit of the first byte stands for the first member.
+fn signers_bit_array(members: usize, signers: &[usize]) -> CompactBitArray {
+    let mut elems = vec![0u8; (members + 7) / 8];
+
+    for &index in signers {
+        elems[index / 8] |= 1 << (7 - index % 8);
+    }
+
+    CompactBitArray {
+        extra_bits_stored: (members % 8) as u32,
+        elems,
+    }
+}
+
+mod base64_bytes {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use subtle_encoding::base64;
+
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let encoded =
+            String::from_utf8(base64::encode(bytes)).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&encoded)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let encoded = String::deserialize(deserializer)?;
+        base64::decode(encoded).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signers_bit_array_layout() {
+        let bit_array = signers_bit_array(3, &[0, 2]);
+        assert_eq!(bit_array.extra_bits_stored, 3);
+        assert_eq!(bit_array.elems, vec![0b1010_0000]);
+
+        let bit_array = signers_bit_array(10, &[1, 8]);
+        assert_eq!(bit_array.extra_bits_stored, 2);
+        assert_eq!(bit_array.elems, vec![0b0100_0000, 0b1000_0000]);
+    }
+
+    #[test]
+    fn assemble_orders_signatures_by_signer() {
+        let tx = MultisigTx {
+            chain_id: ChainId::from_string("ibc-0"),
+            account: "cosmos1multisig".to_string(),
+            account_number: 7,
+            sequence: 3,
+            signers: vec!["02aa".to_string(), "02bb".to_string()],
+            body_bytes: vec![1, 2, 3],
+            auth_info_bytes: vec![4, 5, 6],
+        };
+
+        let signature = |public_key: &str, signature: u8| PartialSignature {
+            public_key: public_key.to_string(),
+            signature: vec![signature],
+        };
+
+        assert!(assemble_multisig_tx(&tx, &[signature("02aa", 1)]).is_err());
+
+        let tx_bytes =
+            assemble_multisig_tx(&tx, &[signature("02BB", 2), signature("02aa", 1)]).unwrap();
+        let tx_raw = TxRaw::decode(tx_bytes.as_slice()).unwrap();
+        let multi_signature = MultiSignature::decode(tx_raw.signatures[0].as_slice()).unwrap();
+
+        assert_eq!(tx_raw.body_bytes, tx.body_bytes);
+        assert_eq!(multi_signature.signatures, vec![vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn multisig_tx_json_roundtrip() {
+        let tx = MultisigTx {
+            chain_id: ChainId::from_string("ibc-0"),
+            account: "cosmos1multisig".to_string(),
+            account_number: 7,
+            sequence: 3,
+            signers: vec!["02aa".to_string()],
+            body_bytes: vec![1, 2, 3],
+            auth_info_bytes: vec![4, 5, 6],
+        };
+
+        let json = serde_json::to_string(&tx).unwrap();
+        assert!(json.contains("\"body_bytes\":\"AQID\""));
+        assert_eq!(serde_json::from_str::<MultisigTx>(&json).unwrap(), tx);
+    }
+}
@@ -12,13 +12,17 @@ use tendermint_rpc::query::Query;
 use tendermint_rpc::{Client, HttpClient, Url};
 
 use crate::chain::cosmos::version::Specs;
-use crate::chain::requests::{QueryClientEventRequest, QueryPacketEventDataRequest, QueryTxHash};
+use crate::chain::requests::{
+    QueryClientEventRequest, QueryHeight, QueryPacketEventDataRequest, QueryTxHash,
+};
 use crate::error::Error;
 
 pub mod account;
 pub mod balance;
+pub mod consumer;
 pub mod denom_trace;
 pub mod fee;
+pub mod proof;
 pub mod status;
 pub mod tx;
 
@@ -53,6 +57,27 @@ pub fn packet_query(request: &QueryPacketEventDataRequest, seq: Sequence) -> Que
     )
 }
 
+/// Restricts the given packet query to the events which occurred within the
+/// window of heights of the request, where `height_key` is the indexed key holding
+/// the height of the searched objects, ie. `tx.height` or `block.height`.
+pub fn packet_query_in_height_window(
+    query: Query,
+    height_key: &str,
+    request: &QueryPacketEventDataRequest,
+) -> Query {
+    let query = match request.min_height {
+        Some(min_height) => query.and_gte(height_key, min_height.revision_height()),
+        None => query,
+    };
+
+    match request.height.get() {
+        QueryHeight::Specific(max_height) => {
+            query.and_lte(height_key, max_height.revision_height())
+        }
+        QueryHeight::Latest => query,
+    }
+}
+
 pub fn header_query(request: &QueryClientEventRequest) -> Query {
     Query::eq(
         format!("{}.client_id", request.event_id.as_str()),
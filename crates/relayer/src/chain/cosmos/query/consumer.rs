@@ -0,0 +1,81 @@
+//! Queries of the `ccvconsumer` module of Interchain Security consumer chains,
+//! which run without a staking module of their own.
+//!
+//! The Interchain Security protos are not part of `ibc-proto`, so the messages
+//! below only declare the fields of the `ccvconsumer` parameters used by Hermes.
+//!
+//! Cf: <https://github.com/cosmos/interchain-security/blob/v1.0.0/proto/interchain_security/ccv/consumer/v1/consumer.proto>
+
+use core::time::Duration;
+
+use http::uri::{PathAndQuery, Uri};
+use ibc_proto::google::protobuf::Duration as RawDuration;
+use tonic::codec::ProstCodec;
+use tonic::transport::Endpoint;
+
+use crate::error::Error;
+
+const QUERY_PARAMS_PATH: &str = "/interchain_security.ccv.consumer.v1.Query/QueryParams";
+
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+struct QueryParamsRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct QueryParamsResponse {
+    #[prost(message, optional, tag = "1")]
+    params: Option<RawConsumerParams>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct RawConsumerParams {
+    #[prost(int64, tag = "8")]
+    historical_entries: i64,
+    #[prost(message, optional, tag = "9")]
+    unbonding_period: Option<RawDuration>,
+}
+
+/// The parameters of the `ccvconsumer` module which stand in
+/// for the staking parameters on a consumer chain.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ConsumerParams {
+    pub historical_entries: u32,
+    pub unbonding_period: Duration,
+}
+
+/// Uses the GRPC client to retrieve the parameters of the `ccvconsumer` module.
+pub async fn query_consumer_params(grpc_address: &Uri) -> Result<ConsumerParams, Error> {
+    let mut client = tonic::client::Grpc::new(
+        Endpoint::from(grpc_address.clone())
+            .connect()
+            .await
+            .map_err(Error::grpc_transport)?,
+    );
+
+    client.ready().await.map_err(Error::grpc_transport)?;
+
+    let response: tonic::Response<QueryParamsResponse> = client
+        .unary(
+            tonic::Request::new(QueryParamsRequest {}),
+            PathAndQuery::from_static(QUERY_PARAMS_PATH),
+            ProstCodec::default(),
+        )
+        .await
+        .map_err(Error::grpc_status)?;
+
+    let params = response
+        .into_inner()
+        .params
+        .ok_or_else(|| Error::grpc_response_param("no ccvconsumer params".to_string()))?;
+
+    let unbonding_period = params.unbonding_period.ok_or_else(|| {
+        Error::grpc_response_param("no unbonding period in ccvconsumer params".to_string())
+    })?;
+
+    Ok(ConsumerParams {
+        historical_entries: params.historical_entries as u32,
+        unbonding_period: Duration::new(
+            unbonding_period.seconds as u64,
+            unbonding_period.nanos as u32,
+        ),
+    })
+}
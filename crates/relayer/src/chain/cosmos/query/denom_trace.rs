@@ -1,7 +1,8 @@
 use http::uri::Uri;
+use sha2::{Digest, Sha256};
 
 use ibc_proto::ibc::applications::transfer::v1::{
-    query_client::QueryClient, QueryDenomTraceRequest,
+    query_client::QueryClient, QueryDenomHashRequest, QueryDenomTraceRequest,
 };
 
 use crate::{denom::DenomTrace, error::Error};
@@ -31,3 +32,43 @@ pub async fn query_denom_trace(grpc_address: &Uri, hash: &str) -> Result<DenomTr
         base_denom: denom_trace.base_denom,
     })
 }
+
+// Uses the GRPC client to retrieve the hash of a denom trace, e.g. `transfer/channel-0/uatom`.
+// The `DenomHash` query is only available on chains with IBC-go v3.1 or later,
+// see `compute_denom_hash` for other chains.
+pub async fn query_denom_hash(grpc_address: &Uri, trace: &str) -> Result<String, Error> {
+    let mut client = QueryClient::connect(grpc_address.clone())
+        .await
+        .map_err(Error::grpc_transport)?;
+
+    let request = tonic::Request::new(QueryDenomHashRequest {
+        trace: trace.to_owned(),
+    });
+
+    let response = client
+        .denom_hash(request)
+        .await
+        .map(|r| r.into_inner())
+        .map_err(Error::grpc_status)?;
+
+    Ok(response.hash)
+}
+
+// Computes the hash of a denom trace the same way as the transfer module does,
+// ie. the uppercase hex encoding of the SHA-256 hash of the full trace.
+pub fn compute_denom_hash(trace: &str) -> String {
+    hex::encode_upper(Sha256::digest(trace.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compute_denom_hash;
+
+    #[test]
+    fn denom_hash_matches_transfer_module() {
+        assert_eq!(
+            compute_denom_hash("transfer/channel-0/uatom"),
+            "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use core::time::Duration;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use ibc_relayer_types::core::ics23_commitment::commitment::{CommitmentPrefix, CommitmentRoot};
+use ibc_relayer_types::core::ics23_commitment::error::Error as CommitmentError;
+use ibc_relayer_types::core::ics23_commitment::merkle::{apply_prefix, MerkleProof};
+use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use tendermint::block::Height;
+use tendermint_light_client_verifier::types::LightBlock;
+use tendermint_rpc::{Client, HttpClient, Url};
+use tokio::time::sleep;
+use tracing::{debug, trace};
+
+use crate::chain::cosmos::query::QueryResponse;
+use crate::error::Error;
+
+const WAIT_BACKOFF: Duration = Duration::from_millis(300);
+
+/// The latest header of the chain verified by the light client, from which the
+/// headers whose app hash the query proofs are checked against are verified in turn.
+///
+/// Advancing it with every header verified keeps it within the trusting period,
+/// and keeps the verification of the headers of the next proofs short.
+#[derive(Debug, Default)]
+pub struct ProofAnchor {
+    block: Mutex<Option<LightBlock>>,
+}
+
+impl ProofAnchor {
+    /// The latest header verified so far, if any.
+    pub fn get(&self) -> Option<LightBlock> {
+        self.block.lock().unwrap().clone()
+    }
+
+    /// Record the given verified header, if it is higher than the current anchor.
+    pub fn advance(&self, verified: &LightBlock) {
+        let mut block = self.block.lock().unwrap();
+
+        if block
+            .as_ref()
+            .map_or(true, |block| verified.height() > block.height())
+        {
+            *block = Some(verified.clone());
+        }
+    }
+
+    /// Forget the current anchor, eg. once it is outside of the trusting period.
+    pub fn reset(&self) {
+        *self.block.lock().unwrap() = None;
+    }
+}
+
+/// Verify the proof included in the response to a provable ABCI query for `path`.
+///
+/// A proof obtained by querying the chain at height `h` is checked by the counterparty
+/// client against the root of its consensus state at height `h + 1`, ie. against the
+/// app hash of the header at height `h + 1`. The proof is checked here against that
+/// same app hash, given as `root`, which must come from a verified header.
+///
+/// An empty response value stands for an absent key, in which case
+/// the proof is checked to be a proof of non-membership.
+pub fn verify_query_proof(
+    chain_id: &ChainId,
+    proof_specs: &ProofSpecs,
+    root: CommitmentRoot,
+    prefix: &CommitmentPrefix,
+    path: &str,
+    response: &QueryResponse,
+) -> Result<(), Error> {
+    let proof = response
+        .proof
+        .as_ref()
+        .ok_or_else(Error::empty_response_proof)?;
+
+    verify_proof(proof_specs, root, prefix, path, &response.value, proof).map_err(|e| {
+        Error::invalid_query_proof(
+            chain_id.clone(),
+            path.to_string(),
+            response.height.value(),
+            e,
+        )
+    })?;
+
+    trace!(
+        id = %chain_id,
+        "verified proof for path {} at height {}",
+        path,
+        response.height
+    );
+
+    Ok(())
+}
+
+/// Verify the given (non-)membership proof for `path` against the given root.
+pub fn verify_proof(
+    proof_specs: &ProofSpecs,
+    root: CommitmentRoot,
+    prefix: &CommitmentPrefix,
+    path: &str,
+    value: &[u8],
+    proof: &MerkleProof,
+) -> Result<(), CommitmentError> {
+    let merkle_path = apply_prefix(prefix, vec![path.to_string()]);
+
+    if value.is_empty() {
+        proof.verify_non_membership(proof_specs, root.into(), merkle_path)
+    } else {
+        proof.verify_membership(proof_specs, root.into(), merkle_path, value.to_vec(), 0)
+    }
+}
+
+/// Wait for the block at the given height to be committed,
+/// for up to twice the `max_block_time`.
+pub async fn wait_for_block(
+    chain_id: &ChainId,
+    rpc_client: &HttpClient,
+    rpc_address: &Url,
+    max_block_time: Duration,
+    height: Height,
+) -> Result<(), Error> {
+    let start_time = Instant::now();
+
+    loop {
+        match rpc_client.commit(height).await {
+            Ok(_) => return Ok(()),
+            Err(e) if start_time.elapsed() > max_block_time * 2 => {
+                return Err(Error::rpc(rpc_address.clone(), e));
+            }
+            Err(_) => {
+                debug!(
+                    id = %chain_id,
+                    "waiting for block {} to be committed to verify query proof",
+                    height
+                );
+
+                sleep(WAIT_BACKOFF).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_proof_rejects_empty_proof() {
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+        let root = CommitmentRoot::from_bytes(&[1; 32]);
+        let proof = MerkleProof { proofs: vec![] };
+
+        let path = "clients/07-tendermint-0/clientState";
+
+        assert!(verify_proof(
+            &ProofSpecs::default(),
+            root.clone(),
+            &prefix,
+            path,
+            b"value",
+            &proof
+        )
+        .is_err());
+
+        assert!(verify_proof(&ProofSpecs::default(), root, &prefix, path, b"", &proof).is_err());
+    }
+}
@@ -1,14 +1,21 @@
+use core::ops::RangeInclusive;
+
+use ibc_proto::cosmos::tx::v1beta1::{TxBody, TxRaw};
+use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics02_client::height::Height;
 use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::Height as ICSHeight;
+use prost::Message;
 use tendermint::abci::transaction::Hash as TxHash;
 use tendermint::abci::Event;
 use tendermint_rpc::endpoint::tx::Response as TxResponse;
 use tendermint_rpc::{Client, HttpClient, Order, Url};
 
-use crate::chain::cosmos::query::{header_query, packet_query, tx_hash_query};
+use crate::chain::cosmos::query::{
+    header_query, packet_query, packet_query_in_height_window, tx_hash_query,
+};
 use crate::chain::cosmos::types::events;
 use crate::chain::requests::{
     QueryClientEventRequest, QueryHeight, QueryPacketEventDataRequest, QueryTxHash, QueryTxRequest,
@@ -111,7 +118,7 @@ pub async fn query_packets_from_txs(
         // query first (and only) Tx that includes the event specified in the query request
         let mut response = rpc_client
             .tx_search(
-                packet_query(request, *seq),
+                packet_query_in_height_window(packet_query(request, *seq), "tx.height", request),
                 false,
                 1,
                 1, // get only the first Tx matching the query
@@ -141,6 +148,8 @@ pub async fn query_packets_from_txs(
 
 /// This function queries packet events from a given block, events matching certain criteria.
 /// It returns at most one packet event for each sequence specified in the request.
+/// The events emitted by `BeginBlock` and `EndBlock` are included, in the order in which
+/// they were emitted, so that the packets sent outside of a transaction are found as well.
 pub async fn query_packets_from_block(
     chain_id: &ChainId,
     rpc_client: &HttpClient,
@@ -162,24 +171,88 @@ pub async fn query_packets_from_block(
     let height = Height::new(chain_id.version(), u64::from(tm_height))
         .map_err(|_| Error::invalid_height_no_source())?;
 
-    let exact_tx_block_results = rpc_client
+    let response = rpc_client
         .block_results(tm_height)
         .await
-        .map_err(|e| Error::rpc(rpc_address.clone(), e))?
-        .txs_results;
-
-    if let Some(txs) = exact_tx_block_results {
-        for tx in txs.iter() {
-            let tx_copy = tx.clone();
-            result.append(
-                &mut tx_copy
-                    .events
-                    .into_iter()
-                    .filter_map(|e| filter_matching_event(e, request, &request.sequences))
-                    .map(|e| IbcEventWithHeight::new(e, height))
-                    .collect(),
-            )
+        .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
+
+    let tx_events = response
+        .txs_results
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|tx| tx.events);
+
+    result.extend(
+        response
+            .begin_block_events
+            .unwrap_or_default()
+            .into_iter()
+            .chain(tx_events)
+            .chain(response.end_block_events.unwrap_or_default())
+            .filter_map(|e| filter_matching_event(e, request, &request.sequences))
+            .map(|e| IbcEventWithHeight::new(e, height)),
+    );
+
+    Ok(result)
+}
+
+/// This function scans the blocks within the given range of heights for packet events
+/// matching the request, in the order in which they occurred. It only relies on the
+/// `block_results` RPC endpoint, and therefore also works with nodes which do not index
+/// transactions, at the cost of one RPC call per block.
+/// The scan stops as soon as an event was found for each sequence specified in the request.
+pub async fn query_packets_from_block_range(
+    chain_id: &ChainId,
+    rpc_client: &HttpClient,
+    rpc_address: &Url,
+    request: &QueryPacketEventDataRequest,
+    heights: RangeInclusive<u64>,
+) -> Result<Vec<IbcEventWithHeight>, Error> {
+    crate::time!("query_packets_from_block_range");
+    crate::telemetry!(query, chain_id, "query_packets_from_block_range");
+
+    let mut result: Vec<IbcEventWithHeight> = vec![];
+    let mut remaining: Vec<Sequence> = request.sequences.clone();
+
+    for revision_height in heights {
+        if remaining.is_empty() {
+            break;
         }
+
+        let tm_height = tendermint::block::Height::try_from(revision_height)
+            .map_err(|_| Error::invalid_height_no_source())?;
+
+        let height = Height::new(chain_id.version(), revision_height)
+            .map_err(|_| Error::invalid_height_no_source())?;
+
+        let response = rpc_client
+            .block_results(tm_height)
+            .await
+            .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
+
+        let tx_events = response
+            .txs_results
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|tx| tx.events);
+
+        let events: Vec<IbcEventWithHeight> = response
+            .begin_block_events
+            .unwrap_or_default()
+            .into_iter()
+            .chain(tx_events)
+            .chain(response.end_block_events.unwrap_or_default())
+            .filter_map(|e| filter_matching_event(e, request, &remaining))
+            .map(|e| IbcEventWithHeight::new(e, height))
+            .collect();
+
+        for event in events.iter() {
+            if let Some(packet) = event.event.packet() {
+                remaining.retain(|seq| *seq != packet.sequence);
+            }
+        }
+
+        result.extend(events);
     }
 
     Ok(result)
@@ -310,6 +383,27 @@ pub async fn query_tx_response(
     Ok(response.txs.into_iter().next())
 }
 
+/// Query the messages of the transaction with the given hash.
+/// Returns an empty vector if no such transaction was committed.
+pub async fn query_tx_messages(
+    rpc_client: &HttpClient,
+    rpc_address: &Url,
+    tx_hash: &TxHash,
+) -> Result<Vec<Any>, Error> {
+    let response = match query_tx_response(rpc_client, rpc_address, tx_hash).await? {
+        Some(response) => response,
+        None => return Ok(vec![]),
+    };
+
+    let tx_raw = TxRaw::decode(response.tx.as_bytes())
+        .map_err(|e| Error::protobuf_decode("TxRaw".to_string(), e))?;
+
+    let tx_body = TxBody::decode(tx_raw.body_bytes.as_slice())
+        .map_err(|e| Error::protobuf_decode("TxBody".to_string(), e))?;
+
+    Ok(tx_body.messages)
+}
+
 pub fn all_ibc_events_from_tx_search_response(
     chain_id: &ChainId,
     response: TxResponse,
@@ -3,9 +3,11 @@ use ibc_proto::cosmos::tx::v1beta1::{SimulateRequest, SimulateResponse, Tx};
 use tonic::codegen::http::Uri;
 
 use crate::error::Error;
+use crate::util::profile::{self, Phase};
 
 pub async fn send_tx_simulate(grpc_address: &Uri, tx: Tx) -> Result<SimulateResponse, Error> {
     crate::time!("send_tx_simulate");
+    let _timing = profile::measure(Phase::Simulation);
 
     // The `tx` field of `SimulateRequest` was deprecated in Cosmos SDK 0.43 in favor of `tx_bytes`.
     let mut tx_bytes = vec![];
@@ -0,0 +1,107 @@
+//! Guard against gRPC endpoints serving state which lags behind the chain head.
+//!
+//! Some nodes, typically behind a load balancer, serve gRPC queries from a state which
+//! lags behind the head reported by their RPC endpoint, or behind the state served by
+//! the previous query. Acting upon such stale state leads to sporadic failures, eg.
+//! relaying packets which were already received, or building proofs at heights which
+//! the counterparty client cannot verify.
+//!
+//! The [`HeightGuard`] keeps track of the highest height observed on a chain, so that
+//! queries served at a lower height can be detected and retried.
+
+use std::sync::Mutex;
+
+use ibc_relayer_types::Height;
+use tonic::metadata::MetadataMap;
+
+/// The gRPC metadata key under which the Cosmos SDK reports the height a query was served at.
+pub const GRPC_BLOCK_HEIGHT_KEY: &str = "x-cosmos-block-height";
+
+/// Keeps track of the highest height observed on a chain.
+#[derive(Debug, Default)]
+pub struct HeightGuard {
+    highest: Mutex<Option<Height>>,
+}
+
+impl HeightGuard {
+    /// Record that the chain was observed at the given height.
+    pub fn observe(&self, height: Height) {
+        let mut highest = self.highest.lock().unwrap();
+
+        if highest.map_or(true, |highest| height > highest) {
+            *highest = Some(height);
+        }
+    }
+
+    /// The highest height observed so far, if any.
+    pub fn highest(&self) -> Option<Height> {
+        *self.highest.lock().unwrap()
+    }
+
+    /// The number of blocks by which the given height lags
+    /// behind the highest height observed so far.
+    ///
+    /// A height of a previous revision of the chain lags behind by
+    /// at least the number of blocks of the current revision.
+    pub fn lag(&self, height: Height) -> u64 {
+        match self.highest() {
+            Some(highest) if height < highest => {
+                if height.revision_number() == highest.revision_number() {
+                    highest.revision_height() - height.revision_height()
+                } else {
+                    highest.revision_height()
+                }
+            }
+            _ => 0,
+        }
+    }
+}
+
+/// The height at which a gRPC query was served, as reported in the response metadata.
+pub fn grpc_response_height(revision_number: u64, metadata: &MetadataMap) -> Option<Height> {
+    let height = metadata
+        .get(GRPC_BLOCK_HEIGHT_KEY)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()?;
+
+    Height::new(revision_number, height).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn height(revision_number: u64, revision_height: u64) -> Height {
+        Height::new(revision_number, revision_height).unwrap()
+    }
+
+    #[test]
+    fn lag_behind_highest_observed_height() {
+        let guard = HeightGuard::default();
+        assert_eq!(guard.lag(height(1, 10)), 0);
+
+        guard.observe(height(1, 10));
+        guard.observe(height(1, 8));
+        assert_eq!(guard.highest(), Some(height(1, 10)));
+
+        assert_eq!(guard.lag(height(1, 7)), 3);
+        assert_eq!(guard.lag(height(1, 10)), 0);
+        assert_eq!(guard.lag(height(1, 12)), 0);
+        assert_eq!(guard.lag(height(0, 1000)), 10);
+        assert_eq!(guard.lag(height(2, 1)), 0);
+    }
+
+    #[test]
+    fn response_height_from_metadata() {
+        let mut metadata = MetadataMap::new();
+        assert_eq!(grpc_response_height(1, &metadata), None);
+
+        metadata.insert(GRPC_BLOCK_HEIGHT_KEY, "42".parse().unwrap());
+        assert_eq!(grpc_response_height(1, &metadata), Some(height(1, 42)));
+
+        metadata.insert(GRPC_BLOCK_HEIGHT_KEY, "not-a-height".parse().unwrap());
+        assert_eq!(grpc_response_height(1, &metadata), None);
+    }
+}
@@ -15,6 +15,7 @@ use crate::config::types::Memo;
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
 use crate::keyring::KeyEntry;
+use crate::util::profile::{self, Phase};
 
 use super::batch::send_batched_messages_and_wait_commit;
 
@@ -27,7 +28,21 @@ pub async fn estimate_fee_and_send_tx(
 ) -> Result<Response, Error> {
     let fee = estimate_tx_fees(config, key_entry, account, tx_memo, messages).await?;
 
-    send_tx_with_fee(config, key_entry, account, tx_memo, messages, &fee).await
+    if let Some(fee_budget) = &config.fee_budget {
+        fee_budget.check(&fee)?;
+    }
+
+    let response = send_tx_with_fee(config, key_entry, account, tx_memo, messages, &fee).await?;
+
+    // The fee is paid as soon as the transaction passes `CheckTx`, even if it
+    // fails afterwards, so it is reserved until the gas it used is known.
+    if let Some(fee_budget) = &config.fee_budget {
+        if response.code.is_ok() {
+            fee_budget.reserve(&response.hash.to_string(), &fee);
+        }
+    }
+
+    Ok(response)
 }
 
 async fn send_tx_with_fee(
@@ -46,11 +61,13 @@ async fn send_tx_with_fee(
 }
 
 /// Perform a `broadcast_tx_sync`, and return the corresponding deserialized response data.
-async fn broadcast_tx_sync(
+pub(crate) async fn broadcast_tx_sync(
     rpc_client: &HttpClient,
     rpc_address: &Url,
     data: Vec<u8>,
 ) -> Result<Response, Error> {
+    let _timing = profile::measure(Phase::Broadcast);
+
     let response = rpc_client
         .broadcast_tx_sync(data.into())
         .await
@@ -96,6 +113,13 @@ pub async fn simple_send_tx(
     )
     .await?;
 
+    if let Some(fee_budget) = &config.fee_budget {
+        fee_budget.settle(
+            &response.hash.to_string(),
+            u64::from(response.tx_result.gas_used),
+        );
+    }
+
     let events = all_ibc_events_from_tx_search_response(&config.chain_id, response);
 
     Ok(events)
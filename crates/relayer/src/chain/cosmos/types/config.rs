@@ -5,6 +5,7 @@ use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tendermint_rpc::{HttpClient, Url};
 
+use crate::chain::cosmos::fee_budget::FeeBudget;
 use crate::chain::cosmos::types::gas::GasConfig;
 use crate::config::types::{MaxMsgNum, MaxTxSize};
 use crate::config::{AddressType, ChainConfig};
@@ -22,6 +23,8 @@ pub struct TxConfig {
     pub max_msg_num: MaxMsgNum,
     pub max_tx_size: MaxTxSize,
     pub extension_options: Vec<Any>,
+    /// The daily fee budget of the chain, if any, set up by the chain runtime.
+    pub fee_budget: Option<FeeBudget>,
 }
 
 impl<'a> TryFrom<&'a ChainConfig> for TxConfig {
@@ -53,6 +56,7 @@ impl<'a> TryFrom<&'a ChainConfig> for TxConfig {
             max_msg_num: config.max_msg_num,
             max_tx_size: config.max_tx_size,
             extension_options,
+            fee_budget: None,
         })
     }
 }
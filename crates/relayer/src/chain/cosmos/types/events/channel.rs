@@ -36,6 +36,9 @@ fn extract_attributes(object: &RawObject<'_>, namespace: &str) -> Result<Attribu
             &format!("{}.counterparty_channel_id", namespace),
         )
         .and_then(|v| v.parse().ok()),
+
+        version: maybe_extract_attribute(object, &format!("{}.version", namespace))
+            .and_then(|v| v.parse().ok()),
     })
 }
 
@@ -21,5 +21,7 @@ pub struct TxSyncResult {
     pub response: Response,
     // the events generated by a Tx once executed
     pub events: Vec<IbcEventWithHeight>,
+    // the gas used by a Tx once executed
+    pub gas_used: Option<u64>,
     pub status: TxStatus,
 }
@@ -32,7 +32,7 @@ const TENDERMINT_MODULE_NAME: &str = "tendermint/tendermint";
 /// Assumes that the network runs on Cosmos SDK.
 /// Stores both the SDK version as well as
 /// the IBC-go module version (if existing).
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Specs {
     pub cosmos_sdk: semver::Version,
     pub ibc_go: Option<semver::Version>,
@@ -16,6 +16,7 @@ use crate::chain::cosmos::types::events::from_tx_response_event;
 use crate::chain::cosmos::types::tx::{TxStatus, TxSyncResult};
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
+use crate::util::profile::{self, Phase};
 
 const WAIT_BACKOFF: Duration = Duration::from_millis(300);
 
@@ -33,6 +34,8 @@ pub async fn wait_for_block_commits(
         return Ok(());
     }
 
+    let _timing = profile::measure(Phase::Confirmation);
+
     let start_time = Instant::now();
 
     let hashes = tx_sync_results
@@ -84,6 +87,7 @@ async fn update_tx_sync_result(
 
         if let Some(response) = response {
             tx_sync_result.status = TxStatus::ReceivedResponse;
+            tx_sync_result.gas_used = Some(u64::from(response.tx_result.gas_used));
 
             let height = Height::new(chain_id.version(), u64::from(response.height)).unwrap();
             if response.tx_result.code.is_err() {
@@ -3,6 +3,7 @@ use core::convert::TryFrom;
 
 use tokio::runtime::Runtime as TokioRuntime;
 
+use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics02_client::consensus_state::ConsensusState;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
@@ -53,6 +54,9 @@ use super::requests::{
 pub enum HealthCheck {
     Healthy,
     Unhealthy(Box<Error>),
+    /// The chain runs versions of its modules which are not supported by Hermes,
+    /// either on their own or along with each other.
+    Incompatible(Box<Error>),
 }
 
 /// The result of the application status query.
@@ -164,6 +168,9 @@ pub trait ChainEndpoint: Sized {
     /// Query the denomination trace given a trace hash.
     fn query_denom_trace(&self, hash: String) -> Result<DenomTrace, Error>;
 
+    /// Query the hash of the given denomination trace, e.g. `transfer/channel-0/uatom`.
+    fn query_denom_hash(&self, trace: String) -> Result<String, Error>;
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error>;
 
     fn query_compatible_versions(&self) -> Result<Vec<Version>, Error> {
@@ -338,6 +345,10 @@ pub trait ChainEndpoint: Sized {
 
     fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEventWithHeight>, Error>;
 
+    /// Query the messages of the transaction with the given hash,
+    /// or an empty vector if there is no such transaction.
+    fn query_tx_messages(&self, request: QueryTxHash) -> Result<Vec<Any>, Error>;
+
     fn query_packet_events(
         &self,
         request: QueryPacketEventDataRequest,
@@ -601,6 +612,29 @@ pub trait ChainEndpoint: Sized {
         Ok(proofs)
     }
 
+    /// Builds the proofs for a batch of packet messages, in the order of the requests.
+    ///
+    /// Fails if the proofs of any of the packets cannot be built. By default, the
+    /// proofs are built one after the other; chains which can query them concurrently
+    /// should override this method.
+    fn build_packet_proofs_batch(
+        &self,
+        requests: Vec<BuildPacketProofsRequest>,
+    ) -> Result<Vec<Proofs>, Error> {
+        requests
+            .into_iter()
+            .map(|request| {
+                self.build_packet_proofs(
+                    request.packet_type,
+                    request.port_id,
+                    request.channel_id,
+                    request.sequence,
+                    request.height,
+                )
+            })
+            .collect()
+    }
+
     fn maybe_register_counterparty_payee(
         &mut self,
         channel_id: &ChannelId,
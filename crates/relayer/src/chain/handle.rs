@@ -4,6 +4,7 @@ use core::fmt::{self, Debug, Display};
 use crossbeam_channel as channel;
 use tracing::Span;
 
+use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::{
     core::{
         ics02_client::events::UpdateClient,
@@ -38,6 +39,7 @@ use crate::{
     keyring::KeyEntry,
     light_client::AnyHeader,
     misbehaviour::MisbehaviourEvidence,
+    util::profile::Phase,
 };
 
 use super::{
@@ -157,6 +159,11 @@ pub enum ChainRequest {
         reply_to: ReplyTo<DenomTrace>,
     },
 
+    QueryDenomHash {
+        trace: String,
+        reply_to: ReplyTo<String>,
+    },
+
     QueryApplicationStatus {
         reply_to: ReplyTo<ChainStatus>,
     },
@@ -294,6 +301,11 @@ pub enum ChainRequest {
         reply_to: ReplyTo<Proofs>,
     },
 
+    BuildPacketProofsBatch {
+        requests: Vec<BuildPacketProofsRequest>,
+        reply_to: ReplyTo<Vec<Proofs>>,
+    },
+
     QueryPacketCommitment {
         request: QueryPacketCommitmentRequest,
         include_proof: IncludeProof,
@@ -337,6 +349,11 @@ pub enum ChainRequest {
         reply_to: ReplyTo<Vec<IbcEventWithHeight>>,
     },
 
+    QueryTxMessages {
+        request: QueryTxHash,
+        reply_to: ReplyTo<Vec<Any>>,
+    },
+
     QueryPacketEventData {
         request: QueryPacketEventDataRequest,
         reply_to: ReplyTo<Vec<IbcEventWithHeight>>,
@@ -355,6 +372,32 @@ pub enum ChainRequest {
     },
 }
 
+impl ChainRequest {
+    /// The phase of a command in which the chain runtime handles this request,
+    /// or `None` if the request is not a query, see [`profile`](crate::util::profile).
+    pub fn phase(&self) -> Option<Phase> {
+        match self {
+            Self::BuildConnectionProofsAndClientState { .. }
+            | Self::BuildChannelProofs { .. }
+            | Self::BuildPacketProofs { .. }
+            | Self::BuildPacketProofsBatch { .. } => Some(Phase::ProofQuery),
+
+            Self::Shutdown { .. }
+            | Self::HealthCheck { .. }
+            | Self::Subscribe { .. }
+            | Self::SendMessagesAndWaitCommit { .. }
+            | Self::SendMessagesAndWaitCheckTx { .. }
+            | Self::Config { .. }
+            | Self::Signer { .. }
+            | Self::GetKey { .. }
+            | Self::AddKey { .. }
+            | Self::MaybeRegisterCounterpartyPayee { .. } => None,
+
+            _ => Some(Phase::Query),
+        }
+    }
+}
+
 pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
     fn new(chain_id: ChainId, sender: channel::Sender<(Span, ChainRequest)>) -> Self;
 
@@ -413,6 +456,9 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
     /// Query the denomination trace given a trace hash.
     fn query_denom_trace(&self, hash: String) -> Result<DenomTrace, Error>;
 
+    /// Query the hash of the given denomination trace, e.g. `transfer/channel-0/uatom`.
+    fn query_denom_hash(&self, trace: String) -> Result<String, Error>;
+
     /// Query the latest height and timestamp the application is at
     fn query_application_status(&self) -> Result<ChainStatus, Error>;
 
@@ -572,6 +618,13 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         height: Height,
     ) -> Result<Proofs, Error>;
 
+    /// Builds the proofs of the given packet messages, in the same order.
+    /// Fails if the proofs of any of these messages cannot be built.
+    fn build_packet_proofs_batch(
+        &self,
+        requests: Vec<BuildPacketProofsRequest>,
+    ) -> Result<Vec<Proofs>, Error>;
+
     /// Performs a query to retrieve a stored packet commitment hash, stored on
     /// the chain at path `path::CommitmentsPath`. A proof can optionally be
     /// returned along with the result.
@@ -640,6 +693,8 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
 
     fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEventWithHeight>, Error>;
 
+    fn query_tx_messages(&self, request: QueryTxHash) -> Result<Vec<Any>, Error>;
+
     fn query_packet_events(
         &self,
         request: QueryPacketEventDataRequest,
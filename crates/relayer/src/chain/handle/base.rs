@@ -3,6 +3,7 @@ use core::fmt::{Debug, Display, Error as FmtError, Formatter};
 use crossbeam_channel as channel;
 use tracing::Span;
 
+use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::{
     core::{
         ics02_client::events::UpdateClient,
@@ -165,6 +166,10 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::QueryDenomTrace { hash, reply_to })
     }
 
+    fn query_denom_hash(&self, trace: String) -> Result<String, Error> {
+        self.send(|reply_to| ChainRequest::QueryDenomHash { trace, reply_to })
+    }
+
     fn query_application_status(&self) -> Result<ChainStatus, Error> {
         self.send(|reply_to| ChainRequest::QueryApplicationStatus { reply_to })
     }
@@ -402,6 +407,13 @@ impl ChainHandle for BaseChainHandle {
         })
     }
 
+    fn build_packet_proofs_batch(
+        &self,
+        requests: Vec<BuildPacketProofsRequest>,
+    ) -> Result<Vec<Proofs>, Error> {
+        self.send(|reply_to| ChainRequest::BuildPacketProofsBatch { requests, reply_to })
+    }
+
     fn query_packet_commitment(
         &self,
         request: QueryPacketCommitmentRequest,
@@ -470,6 +482,10 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::QueryPacketEventDataFromTxs { request, reply_to })
     }
 
+    fn query_tx_messages(&self, request: QueryTxHash) -> Result<Vec<Any>, Error> {
+        self.send(|reply_to| ChainRequest::QueryTxMessages { request, reply_to })
+    }
+
     fn query_packet_events(
         &self,
         request: QueryPacketEventDataRequest,
@@ -2,6 +2,7 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use crossbeam_channel as channel;
 use tracing::Span;
 
+use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics03_connection::connection::IdentifiedConnectionEnd;
 use ibc_relayer_types::core::ics04_channel::channel::IdentifiedChannelEnd;
@@ -141,6 +142,10 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
         self.inner().query_denom_trace(hash)
     }
 
+    fn query_denom_hash(&self, trace: String) -> Result<String, Error> {
+        self.inner().query_denom_hash(trace)
+    }
+
     fn query_application_status(&self) -> Result<ChainStatus, Error> {
         self.inner().query_application_status()
     }
@@ -415,6 +420,13 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
             .build_packet_proofs(packet_type, port_id, channel_id, sequence, height)
     }
 
+    fn build_packet_proofs_batch(
+        &self,
+        requests: Vec<BuildPacketProofsRequest>,
+    ) -> Result<Vec<Proofs>, Error> {
+        self.inner().build_packet_proofs_batch(requests)
+    }
+
     fn query_packet_commitment(
         &self,
         request: QueryPacketCommitmentRequest,
@@ -472,6 +484,10 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
         self.inner().query_txs(request)
     }
 
+    fn query_tx_messages(&self, request: QueryTxHash) -> Result<Vec<Any>, Error> {
+        self.inner().query_tx_messages(request)
+    }
+
     fn query_packet_events(
         &self,
         request: QueryPacketEventDataRequest,
@@ -5,6 +5,8 @@ use std::sync::{Arc, RwLock, RwLockReadGuard};
 use crossbeam_channel as channel;
 use tracing::{debug, Span};
 
+use ibc_proto::google::protobuf::Any;
+
 use crate::account::Balance;
 use crate::chain::client::ClientSettings;
 use crate::chain::endpoint::{ChainStatus, HealthCheck};
@@ -169,6 +171,11 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().query_denom_trace(hash)
     }
 
+    fn query_denom_hash(&self, trace: String) -> Result<String, Error> {
+        self.inc_metric("query_denom_hash");
+        self.inner().query_denom_hash(trace)
+    }
+
     fn query_application_status(&self) -> Result<ChainStatus, Error> {
         self.inc_metric("query_application_status");
         self.inner().query_application_status()
@@ -392,6 +399,14 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
             .build_packet_proofs(packet_type, port_id, channel_id, sequence, height)
     }
 
+    fn build_packet_proofs_batch(
+        &self,
+        requests: Vec<BuildPacketProofsRequest>,
+    ) -> Result<Vec<Proofs>, Error> {
+        self.inc_metric("build_packet_proofs_batch");
+        self.inner().build_packet_proofs_batch(requests)
+    }
+
     fn query_packet_commitment(
         &self,
         request: QueryPacketCommitmentRequest,
@@ -457,6 +472,11 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().query_txs(request)
     }
 
+    fn query_tx_messages(&self, request: QueryTxHash) -> Result<Vec<Any>, Error> {
+        self.inc_metric("query_tx_messages");
+        self.inner().query_tx_messages(request)
+    }
+
     fn query_packet_events(
         &self,
         request: QueryPacketEventDataRequest,
@@ -6,6 +6,7 @@ use crossbeam_channel as channel;
 use tendermint_testgen::light_block::TmLightBlock;
 use tokio::runtime::Runtime;
 
+use ibc_proto::google::protobuf::Any;
 use ibc::clients::ics07_tendermint::client_state::{AllowUpdate, ClientState as TmClientState};
 use ibc::clients::ics07_tendermint::consensus_state::ConsensusState as TendermintConsensusState;
 use ibc::clients::ics07_tendermint::header::Header as TendermintHeader;
@@ -49,7 +50,7 @@ use super::requests::{
     QueryConsensusStateRequest, QueryConsensusStatesRequest, QueryHostConsensusStateRequest,
     QueryNextSequenceReceiveRequest, QueryPacketAcknowledgementRequest,
     QueryPacketAcknowledgementsRequest, QueryPacketCommitmentRequest,
-    QueryPacketCommitmentsRequest, QueryPacketReceiptRequest, QueryTxRequest,
+    QueryPacketCommitmentsRequest, QueryPacketReceiptRequest, QueryTxHash, QueryTxRequest,
     QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest, QueryUpgradedClientStateRequest,
     QueryUpgradedConsensusStateRequest,
 };
@@ -203,6 +204,10 @@ impl ChainEndpoint for MockChain {
         unimplemented!()
     }
 
+    fn query_denom_hash(&self, _trace: String) -> Result<String, Error> {
+        unimplemented!()
+    }
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
         unimplemented!()
     }
@@ -359,6 +364,10 @@ impl ChainEndpoint for MockChain {
         unimplemented!()
     }
 
+    fn query_tx_messages(&self, _request: QueryTxHash) -> Result<Vec<Any>, Error> {
+        unimplemented!()
+    }
+
     fn query_packet_events(
         &self,
         _request: QueryPacketEventDataRequest,
@@ -523,18 +532,28 @@ pub mod test_utils {
             gas_adjustment: None,
             gas_multiplier: None,
             fee_granter: None,
+            max_daily_fee_spend: None,
             max_msg_num: Default::default(),
             max_tx_size: Default::default(),
             clock_drift: Duration::from_secs(5),
             max_block_time: Duration::from_secs(10),
             trusting_period: Some(Duration::from_secs(14 * 24 * 60 * 60)), // 14 days
+            unbonding_period: None,
             trust_threshold: Default::default(),
             packet_filter: PacketFilter::default(),
             address_type: AddressType::default(),
             memo_prefix: Default::default(),
+            verify_query_proofs: false,
+            proof_query_concurrency: crate::config::default::proof_query_concurrency(),
+            event_batch_delay: None,
+            priority_channels: Default::default(),
+            recv_only_channels: Default::default(),
+            ack_only_channels: Default::default(),
+            transfer_limits: Default::default(),
             proof_specs: Default::default(),
             extension_options: Default::default(),
             sequential_batch_tx: false,
+            compat_mode: None,
         }
     }
 }
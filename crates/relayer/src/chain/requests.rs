@@ -1,4 +1,5 @@
 use core::fmt::{self, Display};
+use core::ops::RangeInclusive;
 
 use crate::error::Error;
 
@@ -21,7 +22,7 @@ use ibc_proto::ibc::core::connection::v1::{
     QueryClientConnectionsRequest as RawQueryClientConnectionsRequest,
     QueryConnectionsRequest as RawQueryConnectionsRequest,
 };
-use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc_relayer_types::events::WithBlockDataType;
 use ibc_relayer_types::Height;
@@ -404,6 +405,8 @@ pub struct QueryTxHash(pub TxHash);
 /// - with sequences in `sequences`
 /// - that occurred at a height either smaller or equal to `height` or exactly at `height`,
 ///   as specified by `event_height_qualifier`
+/// - that occurred at a height greater or equal to `min_height`, if set
+/// - restricted to the page of `sequences` specified by `pagination`, if set
 #[derive(Clone, Debug)]
 pub struct QueryPacketEventDataRequest {
     pub event_id: WithBlockDataType,
@@ -413,6 +416,39 @@ pub struct QueryPacketEventDataRequest {
     pub destination_port_id: PortId,
     pub sequences: Vec<Sequence>,
     pub height: Qualified<QueryHeight>,
+    pub min_height: Option<Height>,
+    pub pagination: Option<PageRequest>,
+}
+
+impl QueryPacketEventDataRequest {
+    /// Query the packets with a sequence within the given range, bounds included.
+    pub fn with_sequence_range(mut self, range: RangeInclusive<Sequence>) -> Self {
+        let (start, end) = range.into_inner();
+        self.sequences = (u64::from(start)..=u64::from(end))
+            .map(Sequence::from)
+            .collect();
+        self
+    }
+
+    /// The sequences to query, ie. the requested `sequences` restricted to the
+    /// page specified by the `offset` and `limit` of `pagination`, if any.
+    /// A `limit` of zero means that the page is unbounded.
+    pub fn paged_sequences(&self) -> &[Sequence] {
+        let page = match &self.pagination {
+            Some(page) => page,
+            None => return &self.sequences,
+        };
+
+        let len = self.sequences.len();
+        let start = usize::try_from(page.offset).unwrap_or(usize::MAX).min(len);
+        let end = match usize::try_from(page.limit) {
+            Ok(0) => len,
+            Ok(limit) => start.saturating_add(limit).min(len),
+            Err(_) => len,
+        };
+
+        &self.sequences[start..end]
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -454,3 +490,85 @@ pub struct QueryClientEventRequest {
     pub client_id: ClientId,
     pub consensus_height: Height,
 }
+
+/// The proofs to build for a packet message, as part of a batch,
+/// see [`ChainHandle::build_packet_proofs_batch`](crate::chain::handle::ChainHandle::build_packet_proofs_batch).
+#[derive(Clone, Debug)]
+pub struct BuildPacketProofsRequest {
+    pub packet_type: PacketMsgType,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+    pub height: Height,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+
+    fn packet_events_request() -> QueryPacketEventDataRequest {
+        QueryPacketEventDataRequest {
+            event_id: WithBlockDataType::SendPacket,
+            source_channel_id: ChannelId::from_str("channel-0").unwrap(),
+            source_port_id: PortId::transfer(),
+            destination_channel_id: ChannelId::from_str("channel-1").unwrap(),
+            destination_port_id: PortId::transfer(),
+            sequences: vec![],
+            height: Qualified::SmallerEqual(QueryHeight::Latest),
+            min_height: None,
+            pagination: None,
+        }
+    }
+
+    fn sequences(seqs: &[u64]) -> Vec<Sequence> {
+        seqs.iter().copied().map(Sequence::from).collect()
+    }
+
+    #[test]
+    fn packet_events_sequence_range() {
+        let request = packet_events_request().with_sequence_range(3.into()..=6.into());
+
+        assert_eq!(request.sequences, sequences(&[3, 4, 5, 6]));
+        assert_eq!(request.paged_sequences(), request.sequences.as_slice());
+
+        let empty = packet_events_request().with_sequence_range(6.into()..=3.into());
+        assert!(empty.sequences.is_empty());
+    }
+
+    #[test]
+    fn packet_events_paged_sequences() {
+        let mut request = packet_events_request().with_sequence_range(1.into()..=10.into());
+
+        request.pagination = Some(PageRequest {
+            offset: 2,
+            limit: 3,
+            ..Default::default()
+        });
+        assert_eq!(request.paged_sequences(), sequences(&[3, 4, 5]));
+
+        request.pagination = Some(PageRequest {
+            offset: 8,
+            limit: 5,
+            ..Default::default()
+        });
+        assert_eq!(request.paged_sequences(), sequences(&[9, 10]));
+
+        request.pagination = Some(PageRequest {
+            offset: 12,
+            limit: 5,
+            ..Default::default()
+        });
+        assert!(request.paged_sequences().is_empty());
+
+        request.pagination = Some(PageRequest {
+            offset: 7,
+            ..Default::default()
+        });
+        assert_eq!(request.paged_sequences(), sequences(&[8, 9, 10]));
+
+        request.pagination = Some(PageRequest::all());
+        assert_eq!(request.paged_sequences().len(), 10);
+    }
+}
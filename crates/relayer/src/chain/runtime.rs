@@ -5,6 +5,7 @@ use crossbeam_channel as channel;
 use tokio::runtime::Runtime as TokioRuntime;
 use tracing::{error, Span};
 
+use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::{
     core::{
         ics02_client::events::UpdateClient,
@@ -41,6 +42,7 @@ use crate::{
     keyring::KeyEntry,
     light_client::AnyHeader,
     misbehaviour::MisbehaviourEvidence,
+    util::profile,
 };
 
 use super::{
@@ -48,13 +50,14 @@ use super::{
     endpoint::{ChainEndpoint, ChainStatus, HealthCheck},
     handle::{ChainHandle, ChainRequest, ReplyTo, Subscription},
     requests::{
-        IncludeProof, QueryChannelClientStateRequest, QueryChannelRequest, QueryChannelsRequest,
-        QueryClientConnectionsRequest, QueryClientStateRequest, QueryClientStatesRequest,
-        QueryConnectionChannelsRequest, QueryConnectionRequest, QueryConnectionsRequest,
-        QueryConsensusStateRequest, QueryConsensusStatesRequest, QueryHostConsensusStateRequest,
+        BuildPacketProofsRequest, IncludeProof, QueryChannelClientStateRequest,
+        QueryChannelRequest, QueryChannelsRequest, QueryClientConnectionsRequest,
+        QueryClientStateRequest, QueryClientStatesRequest, QueryConnectionChannelsRequest,
+        QueryConnectionRequest, QueryConnectionsRequest, QueryConsensusStateRequest,
+        QueryConsensusStatesRequest, QueryHostConsensusStateRequest,
         QueryNextSequenceReceiveRequest, QueryPacketAcknowledgementRequest,
         QueryPacketAcknowledgementsRequest, QueryPacketCommitmentRequest,
-        QueryPacketCommitmentsRequest, QueryPacketReceiptRequest, QueryTxRequest,
+        QueryPacketCommitmentsRequest, QueryPacketReceiptRequest, QueryTxHash, QueryTxRequest,
         QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest, QueryUpgradedClientStateRequest,
         QueryUpgradedConsensusStateRequest,
     },
@@ -231,6 +234,7 @@ where
                     };
 
                     let _span = span.entered();
+                    let _timing = event.phase().map(profile::measure);
 
                     match event {
                         ChainRequest::Shutdown { reply_to } => {
@@ -315,6 +319,10 @@ where
                             self.query_denom_trace(hash, reply_to)?
                         },
 
+                        ChainRequest::QueryDenomHash { trace, reply_to } => {
+                            self.query_denom_hash(trace, reply_to)?
+                        },
+
                         ChainRequest::QueryApplicationStatus { reply_to } => {
                             self.query_application_status(reply_to)?
                         },
@@ -383,6 +391,10 @@ where
                             self.build_packet_proofs(packet_type, port_id, channel_id, sequence, height, reply_to)?
                         },
 
+                        ChainRequest::BuildPacketProofsBatch { requests, reply_to } => {
+                            self.build_packet_proofs_batch(requests, reply_to)?
+                        },
+
                         ChainRequest::QueryPacketCommitment { request, include_proof, reply_to } => {
                             self.query_packet_commitment(request, include_proof, reply_to)?
                         },
@@ -419,6 +431,10 @@ where
                             self.query_txs(request, reply_to)?
                         },
 
+                        ChainRequest::QueryTxMessages { request, reply_to } => {
+                            self.query_tx_messages(request, reply_to)?
+                        },
+
                         ChainRequest::QueryPacketEventData { request, reply_to } => {
                             self.query_packet_events(request, reply_to)?
                         },
@@ -506,6 +522,11 @@ where
         reply_to.send(denom_trace).map_err(Error::send)
     }
 
+    fn query_denom_hash(&self, trace: String, reply_to: ReplyTo<String>) -> Result<(), Error> {
+        let denom_hash = self.chain.query_denom_hash(trace);
+        reply_to.send(denom_hash).map_err(Error::send)
+    }
+
     fn query_application_status(&self, reply_to: ReplyTo<ChainStatus>) -> Result<(), Error> {
         let latest_timestamp = self.chain.query_application_status();
         reply_to.send(latest_timestamp).map_err(Error::send)
@@ -788,6 +809,15 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn build_packet_proofs_batch(
+        &self,
+        requests: Vec<BuildPacketProofsRequest>,
+        reply_to: ReplyTo<Vec<Proofs>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.build_packet_proofs_batch(requests);
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn query_packet_commitment(
         &self,
         request: QueryPacketCommitmentRequest,
@@ -877,6 +907,15 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn query_tx_messages(
+        &self,
+        request: QueryTxHash,
+        reply_to: ReplyTo<Vec<Any>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_tx_messages(request);
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn query_packet_events(
         &self,
         request: QueryPacketEventDataRequest,
@@ -90,6 +90,10 @@ pub struct ChannelSide<Chain: ChainHandle> {
     port_id: PortId,
     channel_id: Option<ChannelId>,
     version: Option<Version>,
+    /// The version proposed for this end in `ChanOpenInit` or `ChanOpenTry`,
+    /// if the application settled on a different one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proposed_version: Option<Version>,
 }
 
 impl<Chain: ChainHandle> Display for ChannelSide<Chain> {
@@ -119,6 +123,7 @@ impl<Chain: ChainHandle> ChannelSide<Chain> {
             port_id,
             channel_id,
             version,
+            proposed_version: None,
         }
     }
 
@@ -126,6 +131,67 @@ impl<Chain: ChainHandle> ChannelSide<Chain> {
         self.chain.id()
     }
 
+    /// Adopt the version stored in the given channel end of this side, if any.
+    ///
+    /// The application, or a middleware such as ICS29 fee middleware, may modify
+    /// the version proposed in `ChanOpenInit` or `ChanOpenTry` before storing the
+    /// channel end, in which case the stored version prevails.
+    fn update_version(&mut self, channel_end: &ChannelEnd) {
+        if channel_end.state_matches(&State::Uninitialized) {
+            return;
+        }
+
+        let stored_version = channel_end.version();
+
+        if self.version.as_ref() != Some(stored_version) {
+            debug!(
+                chain = %self.chain.id(),
+                port = %self.port_id,
+                channel = %PrettyOption(&self.channel_id),
+                expected_version = %PrettyOption(&self.version),
+                stored_version = %stored_version,
+                "using the channel version stored on chain"
+            );
+
+            if self.proposed_version.is_none() {
+                self.proposed_version = self.version.take();
+            }
+
+            self.version = Some(stored_version.clone());
+        }
+    }
+
+    /// Adopt the version reported in the given `ChanOpenInit` or `ChanOpenTry`
+    /// event for this side, keeping track of the given proposed version if the
+    /// application settled on another one.
+    ///
+    /// Chains which do not report the version in these events are assumed to
+    /// have kept the proposed version, until their channel end says otherwise.
+    fn settle_version(&mut self, proposed: Option<Version>, event: &IbcEvent) {
+        let negotiated = match extract_channel_version(event) {
+            Some(version) => version.clone(),
+            None => {
+                self.version = proposed;
+                return;
+            }
+        };
+
+        if let Some(proposed) = proposed.filter(|proposed| proposed != &negotiated) {
+            info!(
+                chain = %self.chain.id(),
+                port = %self.port_id,
+                channel = %PrettyOption(&self.channel_id),
+                proposed_version = %proposed,
+                version = %negotiated,
+                "the application modified the proposed channel version"
+            );
+
+            self.proposed_version = Some(proposed);
+        }
+
+        self.version = Some(negotiated);
+    }
+
     pub fn client_id(&self) -> &ClientId {
         &self.client_id
     }
@@ -146,6 +212,10 @@ impl<Chain: ChainHandle> ChannelSide<Chain> {
         self.version.as_ref()
     }
 
+    pub fn proposed_version(&self) -> Option<&Version> {
+        self.proposed_version.as_ref()
+    }
+
     pub fn map_chain<ChainB: ChainHandle>(
         self,
         mapper: impl Fn(Chain) -> ChainB,
@@ -157,6 +227,7 @@ impl<Chain: ChainHandle> ChannelSide<Chain> {
             port_id: self.port_id,
             channel_id: self.channel_id,
             version: self.version,
+            proposed_version: self.proposed_version,
         }
     }
 }
@@ -574,7 +645,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         let b_channel = self.b_channel(updated_relayer_b_id)?;
         let b_counterparty_id = b_channel.counterparty().channel_id();
 
-        if b_counterparty_id.is_some() && b_counterparty_id != relayer_a_id {
+        let a_channel_outdated = b_counterparty_id.is_some() && b_counterparty_id != relayer_a_id;
+
+        if a_channel_outdated {
             if updated_relayer_b_id == relayer_b_id.as_ref() {
                 warn!(
                     "updating the expected {} of side_a({}) since it is different than the \
@@ -597,6 +670,12 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
                 );
             }
         }
+
+        if !a_channel_outdated {
+            self.a_side.update_version(&a_channel);
+        }
+        self.b_side.update_version(&b_channel);
+
         Ok((*a_channel.state(), *b_channel.state()))
     }
 
@@ -612,6 +691,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         match (a_state, b_state) {
             // send the Init message to chain a (source)
             (State::Uninitialized, State::Uninitialized) => {
+                self.a_side.version = Some(self.flipped().init_version());
+
                 let event = self
                     .flipped()
                     .build_chan_open_init_and_send()
@@ -621,6 +702,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
                     })?;
                 let channel_id = extract_channel_id(&event)?;
                 self.a_side.channel_id = Some(channel_id.clone());
+
+                let proposed = self.a_side.version.clone();
+                self.a_side.settle_version(proposed, &event);
             }
 
             // send the Try message to chain a (source)
@@ -632,6 +716,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
 
                 let channel_id = extract_channel_id(&event)?;
                 self.a_side.channel_id = Some(channel_id.clone());
+
+                let proposed = self.b_side.version.clone();
+                self.a_side.settle_version(proposed, &event);
             }
 
             // send the Try message to chain b (destination)
@@ -643,6 +730,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
 
                 let channel_id = extract_channel_id(&event)?;
                 self.b_side.channel_id = Some(channel_id.clone());
+
+                let proposed = self.a_side.version.clone();
+                self.b_side.settle_version(proposed, &event);
             }
 
             // send the Ack message to chain a (source)
@@ -817,19 +907,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         })
     }
 
-    pub fn build_chan_open_init(&self) -> Result<Vec<Any>, ChannelError> {
-        let signer = self
-            .dst_chain()
-            .get_signer()
-            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
-
-        let counterparty = Counterparty::new(self.src_port_id().clone(), None);
-
-        // If the user supplied a version, use that.
-        // Otherwise, either use the version defined for the `transfer`
-        // or an empty version if the port is non-standard.
-        let version = self
-            .dst_version()
+    /// The version to propose in `ChanOpenInit` on the destination chain.
+    ///
+    /// If the user supplied a version, use that.
+    /// Otherwise, either use the version defined for the `transfer`
+    /// or an empty version if the port is non-standard.
+    fn init_version(&self) -> Version {
+        self.dst_version()
             .cloned()
             .or_else(|| version::default_by_port(self.dst_port_id()))
             .unwrap_or_else(|| {
@@ -841,7 +925,22 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
                 );
 
                 Version::empty()
-            });
+            })
+    }
+
+    pub fn build_chan_open_init(&self) -> Result<Vec<Any>, ChannelError> {
+        let signer = self
+            .dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        let counterparty = Counterparty::new(self.src_port_id().clone(), None);
+
+        let version = self.init_version();
+
+        version.fee_metadata().map_err(|e| {
+            ChannelError::invalid_version(self.dst_chain().id(), version.clone(), e)
+        })?;
 
         let channel = ChannelEnd::new(
             State::Init,
@@ -1013,6 +1112,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         // Re-use the version that was either set on ChanOpenInit or overwritten by the application.
         let version = src_channel.version().clone();
 
+        version.fee_metadata().map_err(|e| {
+            ChannelError::invalid_version(self.src_chain().id(), version.clone(), e)
+        })?;
+
         let channel = ChannelEnd::new(
             State::TryOpen,
             *src_channel.ordering(),
@@ -1480,6 +1583,16 @@ pub fn extract_channel_id(event: &IbcEvent) -> Result<&ChannelId, ChannelError>
     .ok_or_else(|| ChannelError::missing_event("cannot extract channel_id from result".to_string()))
 }
 
+/// The version settled on by the application, as reported in the given
+/// `ChanOpenInit` or `ChanOpenTry` event, if any.
+pub fn extract_channel_version(event: &IbcEvent) -> Option<&Version> {
+    match event {
+        IbcEvent::OpenInitChannel(ev) => ev.version.as_ref(),
+        IbcEvent::OpenTryChannel(ev) => ev.version.as_ref(),
+        _ => None,
+    }
+}
+
 /// Enumeration of proof carrying ICS4 message, helper for relayer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ChannelMsgType {
@@ -4,6 +4,8 @@ use flex_error::{define_error, ErrorMessageTracer};
 
 use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
 use ibc_relayer_types::core::ics04_channel::channel::State;
+use ibc_relayer_types::core::ics04_channel::error::Error as ChannelVersionError;
+use ibc_relayer_types::core::ics04_channel::version::Version;
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, PortChannelId, PortId,
 };
@@ -34,6 +36,14 @@ define_error! {
                     e.reason)
             },
 
+        InvalidVersion
+            { chain_id: ChainId, version: Version }
+            [ ChannelVersionError ]
+            | e | {
+                format_args!("invalid channel version '{0}' on chain {1}",
+                    e.version, e.chain_id)
+            },
+
         MissingLocalChannelId
             |_| { "failed due to missing local channel id" },
 
@@ -9,8 +9,8 @@ use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::clients::ics07_tendermint::client_state::{
-    ClientState as TmClientState, UpgradeOptions as TmUpgradeOptions,
-    TENDERMINT_CLIENT_STATE_TYPE_URL,
+    ClientState as TmClientState, RedactedClientState as TmRedactedClientState,
+    UpgradeOptions as TmUpgradeOptions, TENDERMINT_CLIENT_STATE_TYPE_URL,
 };
 use ibc_relayer_types::core::ics02_client::client_state::{
     downcast_client_state, ClientState, UpgradeOptions,
@@ -85,6 +85,15 @@ impl AnyClientState {
         }
     }
 
+    pub fn trusting_period(&self) -> Option<Duration> {
+        match self {
+            AnyClientState::Tendermint(state) => Some(state.trusting_period),
+
+            #[cfg(test)]
+            AnyClientState::Mock(_) => None,
+        }
+    }
+
     pub fn max_clock_drift(&self) -> Duration {
         match self {
             AnyClientState::Tendermint(state) => state.max_clock_drift,
@@ -111,6 +120,40 @@ impl AnyClientState {
             AnyClientState::Mock(mock_state) => mock_state.refresh_time(),
         }
     }
+
+    /// A view of this client state without its bulky fields, such as the
+    /// proof specs of Tendermint clients, suitable for display.
+    pub fn redacted(&self) -> RedactedAnyClientState {
+        match self {
+            AnyClientState::Tendermint(tm_state) => {
+                RedactedAnyClientState::Tendermint(tm_state.redacted())
+            }
+
+            #[cfg(test)]
+            AnyClientState::Mock(mock_state) => RedactedAnyClientState::Mock(*mock_state),
+        }
+    }
+}
+
+impl core::fmt::Display for AnyClientState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            AnyClientState::Tendermint(tm_state) => write!(f, "{}", tm_state),
+
+            #[cfg(test)]
+            AnyClientState::Mock(mock_state) => write!(f, "{:?}", mock_state),
+        }
+    }
+}
+
+/// The redacted form of an [`AnyClientState`], see [`AnyClientState::redacted`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "type")]
+pub enum RedactedAnyClientState {
+    Tendermint(TmRedactedClientState),
+
+    #[cfg(test)]
+    Mock(MockClientState),
 }
 
 impl Protobuf<Any> for AnyClientState {}
@@ -301,4 +344,18 @@ mod tests {
         let tm_client_state_back = AnyClientState::try_from(raw).unwrap();
         assert_eq!(tm_client_state, tm_client_state_back);
     }
+
+    #[test]
+    fn redacted_client_state_is_tagged_like_the_full_one() {
+        let tm_client_state: AnyClientState =
+            get_dummy_tendermint_client_state(get_dummy_tendermint_header()).into();
+
+        let full = serde_json::to_value(&tm_client_state).unwrap();
+        let redacted = serde_json::to_value(&tm_client_state.redacted()).unwrap();
+
+        assert_eq!(redacted["type"], full["type"]);
+        assert_eq!(redacted["chain_id"], full["chain_id"]);
+        assert!(full.get("proof_specs").is_some());
+        assert!(redacted.get("proof_specs").is_none());
+    }
 }
@@ -11,17 +11,24 @@ use core::{
     fmt::{Display, Error as FmtError, Formatter},
     time::Duration,
 };
-use std::{fs, fs::File, io::Write, path::Path};
+use std::{
+    fs,
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 use ibc_proto::google::protobuf::Any;
 use serde_derive::{Deserialize, Serialize};
 use tendermint_light_client_verifier::types::TrustThreshold;
 
+use ibc_relayer_types::applications::transfer::limits::TransferLimits;
 use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::timestamp::ZERO_DURATION;
 
 use crate::chain::ChainType;
+use crate::config::filter::ChannelFilters;
 use crate::config::gas_multiplier::GasMultiplier;
 use crate::config::types::{MaxMsgNum, MaxTxSize, Memo};
 use crate::error::Error as RelayerError;
@@ -30,7 +37,7 @@ use crate::keyring::Store;
 
 pub use error::Error;
 
-pub use filter::PacketFilter;
+pub use filter::{PacketFilter, PacketFilterUpdate};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GasPrice {
@@ -125,6 +132,59 @@ pub mod default {
     pub fn auto_register_counterparty_payee() -> bool {
         false
     }
+
+    pub fn prune_acked_packets() -> bool {
+        true
+    }
+
+    pub fn claim_lease() -> Duration {
+        Duration::from_secs(60)
+    }
+
+    pub fn claim_range_size() -> u64 {
+        100
+    }
+
+    pub fn standby_probe_interval() -> Duration {
+        Duration::from_secs(30)
+    }
+
+    pub fn proof_query_concurrency() -> usize {
+        8
+    }
+
+    pub fn webhook_events() -> Vec<String> {
+        [
+            "receive_packet",
+            "acknowledge_packet",
+            "timeout_packet",
+            "client_misbehaviour",
+            "connection_open_confirm",
+            "channel_open_confirm",
+        ]
+        .iter()
+        .map(|event_type| event_type.to_string())
+        .collect()
+    }
+
+    pub fn webhook_batch_size() -> usize {
+        50
+    }
+
+    pub fn webhook_batch_interval() -> Duration {
+        Duration::from_secs(1)
+    }
+
+    pub fn webhook_max_retries() -> u32 {
+        5
+    }
+
+    pub fn state_store_dir() -> PathBuf {
+        dirs_next::home_dir()
+            .unwrap_or_default()
+            .join(".hermes")
+            .join("state")
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -137,7 +197,17 @@ pub struct Config {
     #[serde(default)]
     pub rest: RestConfig,
     #[serde(default)]
+    pub grpc: GrpcConfig,
+    #[serde(default)]
     pub telemetry: TelemetryConfig,
+    #[serde(default)]
+    pub coordination: CoordinationConfig,
+    #[serde(default)]
+    pub state_store: StateStoreConfig,
+    #[serde(default)]
+    pub standby: StandbyConfig,
+    #[serde(default)]
+    pub webhook: WebhookConfig,
     #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
     pub chains: Vec<ChainConfig>,
 }
@@ -202,8 +272,16 @@ impl Default for ModeConfig {
                 refresh: true,
                 misbehaviour: false,
             },
-            connections: Connections { enabled: false },
-            channels: Channels { enabled: false },
+            connections: Connections {
+                enabled: false,
+                dedicated_runtime: false,
+                handshake_timeout: None,
+                cleanup_stuck_handshakes: false,
+            },
+            channels: Channels {
+                enabled: false,
+                dedicated_runtime: false,
+            },
             packets: Packets {
                 enabled: true,
                 ..Default::default()
@@ -226,12 +304,29 @@ pub struct Clients {
 #[serde(deny_unknown_fields)]
 pub struct Connections {
     pub enabled: bool,
+    /// Whether connection handshake workers should submit their requests to
+    /// a chain runtime of their own, instead of sharing it with the other workers.
+    #[serde(default)]
+    pub dedicated_runtime: bool,
+    /// How long a connection handshake may remain stuck in the `Init` or `TryOpen`
+    /// state before its worker gives up on completing it and records it as dead.
+    /// Handshakes are retried indefinitely if not set.
+    #[serde(default, with = "humantime_serde")]
+    pub handshake_timeout: Option<Duration>,
+    /// Whether to attempt the next step of a stuck handshake one last time,
+    /// when possible, before giving up on it.
+    #[serde(default)]
+    pub cleanup_stuck_handshakes: bool,
 }
 
 #[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Channels {
     pub enabled: bool,
+    /// Whether channel handshake workers should submit their requests to
+    /// a chain runtime of their own, instead of sharing it with the other workers.
+    #[serde(default)]
+    pub dedicated_runtime: bool,
 }
 
 #[derive(Copy, Clone, Debug, Deserialize, Serialize)]
@@ -246,6 +341,11 @@ pub struct Packets {
     pub tx_confirmation: bool,
     #[serde(default = "default::auto_register_counterparty_payee")]
     pub auto_register_counterparty_payee: bool,
+    /// Whether to skip the acknowledgements of the packets whose commitments were
+    /// already deleted on their source chain, checking all of them at once before
+    /// relaying them.
+    #[serde(default = "default::prune_acked_packets")]
+    pub prune_acked_packets: bool,
 }
 
 impl Default for Packets {
@@ -256,6 +356,7 @@ impl Default for Packets {
             clear_on_start: default::clear_on_start(),
             tx_confirmation: default::tx_confirmation(),
             auto_register_counterparty_payee: default::auto_register_counterparty_payee(),
+            prune_acked_packets: default::prune_acked_packets(),
         }
     }
 }
@@ -324,6 +425,10 @@ pub struct RestConfig {
     pub enabled: bool,
     pub host: String,
     pub port: u16,
+    /// Whether to push the packet lifecycle events to the
+    /// subscribers of the `/events/ws` WebSocket endpoint.
+    #[serde(default)]
+    pub websocket: bool,
 }
 
 impl Default for RestConfig {
@@ -332,10 +437,155 @@ impl Default for RestConfig {
             enabled: false,
             host: "127.0.0.1".to_string(),
             port: 3000,
+            websocket: false,
+        }
+    }
+}
+
+/// Configuration of the gRPC admin service, see the `ibc-relayer-grpc` crate.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GrpcConfig {
+    pub enabled: bool,
+    pub host: String,
+    pub port: u16,
+}
+
+impl Default for GrpcConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: "127.0.0.1".to_string(),
+            port: 3002,
+        }
+    }
+}
+
+/// Coordination between multiple instances of Hermes relaying on the same channels,
+/// see [`crate::coordination`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CoordinationConfig {
+    pub enabled: bool,
+    /// Must be set, and unique among the coordinated instances, if coordination is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<String>,
+    /// Must be set, and shared between the coordinated instances, if coordination is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claims_dir: Option<PathBuf>,
+    #[serde(default = "default::claim_lease", with = "humantime_serde")]
+    pub lease: Duration,
+    #[serde(default = "default::claim_range_size")]
+    pub range_size: u64,
+}
+
+/// Default values for the coordination configuration.
+///
+/// # IMPORTANT: Remember to update the default config.toml whenever these values change.
+impl Default for CoordinationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            instance_id: None,
+            claims_dir: None,
+            lease: default::claim_lease(),
+            range_size: default::claim_range_size(),
+        }
+    }
+}
+
+/// Persistence of the relaying progress of the workers across restarts,
+/// see [`crate::state_store`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct StateStoreConfig {
+    pub enabled: bool,
+    #[serde(default = "default::state_store_dir")]
+    pub dir: PathBuf,
+}
+
+/// Default values for the state store configuration.
+///
+/// # IMPORTANT: Remember to update the default config.toml whenever these values change.
+impl Default for StateStoreConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dir: default::state_store_dir(),
+        }
+    }
+}
+
+/// Warm standby of the chains which are unavailable when Hermes starts,
+/// see [`crate::supervisor::standby`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct StandbyConfig {
+    pub enabled: bool,
+    #[serde(default = "default::standby_probe_interval", with = "humantime_serde")]
+    pub probe_interval: Duration,
+}
+
+/// Default values for the standby configuration.
+///
+/// # IMPORTANT: Remember to update the default config.toml whenever these values change.
+impl Default for StandbyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            probe_interval: default::standby_probe_interval(),
         }
     }
 }
 
+/// Export of the IBC events observed by Hermes as CloudEvents
+/// to an HTTP endpoint, see [`crate::webhook`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookConfig {
+    pub enabled: bool,
+    pub url: String,
+    /// The types of the IBC events to export, eg. `receive_packet`.
+    #[serde(default = "default::webhook_events")]
+    pub events: Vec<String>,
+    #[serde(default = "default::webhook_batch_size")]
+    pub batch_size: usize,
+    #[serde(default = "default::webhook_batch_interval", with = "humantime_serde")]
+    pub batch_interval: Duration,
+    #[serde(default = "default::webhook_max_retries")]
+    pub max_retries: u32,
+}
+
+/// Default values for the webhook configuration.
+///
+/// # IMPORTANT: Remember to update the default config.toml whenever these values change.
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: "http://127.0.0.1:8080/events".to_string(),
+            events: default::webhook_events(),
+            batch_size: default::webhook_batch_size(),
+            batch_interval: default::webhook_batch_interval(),
+            max_retries: default::webhook_max_retries(),
+        }
+    }
+}
+
+/// Overrides the versions of the Cosmos SDK and IBC-go modules of a chain,
+/// which are otherwise probed from the chain at startup.
+///
+/// The versions determine which protocol features Hermes relies on
+/// when interacting with the chain, e.g. the ICS 29 fee middleware.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CompatMode {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cosmos_sdk: Option<semver::Version>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ibc_go: Option<semver::Version>,
+}
+
 /// It defines the address generation method
 /// TODO: Ethermint `pk_type` to be restricted
 /// after the Cosmos SDK release with ethsecp256k1
@@ -380,6 +630,12 @@ pub struct ChainConfig {
     pub rpc_timeout: Duration,
     pub account_prefix: String,
     pub key_name: String,
+    /// The key used by the dedicated handshake runtime of this chain, see
+    /// `mode.connections.dedicated_runtime`. It must differ from `key_name`, since
+    /// two runtimes signing with the same account would each track its sequence
+    /// separately and reject each other's transactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handshake_key_name: Option<String>,
     #[serde(default)]
     pub key_store_type: Store,
     pub store_prefix: String,
@@ -391,6 +647,14 @@ pub struct ChainConfig {
     pub gas_multiplier: Option<GasMultiplier>,
 
     pub fee_granter: Option<String>,
+
+    /// The maximum amount of fees, in the smallest unit of the denomination of the gas
+    /// price, which Hermes may spend on this chain each day. Once it is reached, no
+    /// transaction is submitted to this chain until the next day, while transactions
+    /// to its counterparties are still submitted. Fees are not capped if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_daily_fee_spend: Option<u64>,
+
     #[serde(default)]
     pub max_msg_num: MaxMsgNum,
     #[serde(default)]
@@ -413,9 +677,49 @@ pub struct ChainConfig {
     #[serde(default, with = "humantime_serde")]
     pub trusting_period: Option<Duration>,
 
+    /// The unbonding period of the chain, used when it has no staking module to
+    /// query it from, as is the case of Interchain Security consumer chains.
+    #[serde(default, with = "humantime_serde")]
+    pub unbonding_period: Option<Duration>,
+
     #[serde(default)]
     pub memo_prefix: Memo,
 
+    /// Whether to verify the Merkle proofs returned by the full node against the
+    /// app hash of the corresponding block, as verified by the light client,
+    /// before using them to build messages.
+    #[serde(default)]
+    pub verify_query_proofs: bool,
+
+    /// The maximum number of proof queries sent to the full node at once
+    /// when building the proofs of a batch of packets.
+    #[serde(default = "default::proof_query_concurrency")]
+    pub proof_query_concurrency: usize,
+
+    /// How long the event monitor waits for more events after the last one it received,
+    /// before sending the events collected so far to the workers. If not set, the events
+    /// of a block are only sent once the events of the next block start coming in.
+    #[serde(default, with = "humantime_serde")]
+    pub event_batch_delay: Option<Duration>,
+
+    /// The channels on which the event monitor sends the events collected so far
+    /// to the workers as soon as it observes a `SendPacket` event, without waiting
+    /// for the rest of the events of the block.
+    #[serde(default, skip_serializing_if = "ChannelFilters::is_empty")]
+    pub priority_channels: ChannelFilters,
+
+    /// The channels of this chain on which only the packets sent from this chain are
+    /// relayed to the counterparty chain, leaving the acknowledgements and timeouts
+    /// to other relayers.
+    #[serde(default, skip_serializing_if = "ChannelFilters::is_empty")]
+    pub recv_only_channels: ChannelFilters,
+
+    /// The channels of this chain on which only the acknowledgements and timeouts of
+    /// the packets sent from this chain are relayed, leaving the packets themselves
+    /// to other relayers.
+    #[serde(default, skip_serializing_if = "ChannelFilters::is_empty")]
+    pub ack_only_channels: ChannelFilters,
+
     // Note: These last few need to be last otherwise we run into `ValueAfterTable` error when serializing to TOML.
     //       That's because these are all tables and have to come last when serializing.
     #[serde(
@@ -444,10 +748,132 @@ pub struct ChainConfig {
     #[serde(default)]
     pub packet_filter: PacketFilter,
 
+    /// The maximum sizes of the memo and receiver of the ICS-20 transfers accepted by
+    /// this chain. Packets exceeding them are not relayed to this chain, since they
+    /// are guaranteed to fail, and are eventually timed out instead.
+    #[serde(default)]
+    pub transfer_limits: TransferLimits,
+
     #[serde(default)]
     pub address_type: AddressType,
     #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
     pub extension_options: Vec<ExtensionOption>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compat_mode: Option<CompatMode>,
+}
+
+impl ChainConfig {
+    /// Which of the packet messages are relayed from the given channel of this chain,
+    /// as per the `recv_only_channels` and `ack_only_channels` settings.
+    /// A channel matching both of them is relayed in full.
+    pub fn packet_relay_mode(&self, port_id: &PortId, channel_id: &ChannelId) -> PacketRelayMode {
+        let recv_only = self.recv_only_channels.matches((port_id, channel_id));
+        let ack_only = self.ack_only_channels.matches((port_id, channel_id));
+
+        match (recv_only, ack_only) {
+            (true, false) => PacketRelayMode::RecvOnly,
+            (false, true) => PacketRelayMode::AckOnly,
+            _ => PacketRelayMode::All,
+        }
+    }
+}
+
+/// Which of the packet messages are relayed on a path, so that operators can split
+/// the relaying of the packets and of their acknowledgements across instances.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PacketRelayMode {
+    /// Relay the packets, their acknowledgements and their timeouts.
+    All,
+    /// Only relay the packets, ie. submit `MsgRecvPacket`s to the destination chain.
+    RecvOnly,
+    /// Only relay the acknowledgements and timeouts of the packets.
+    AckOnly,
+}
+
+impl Default for PacketRelayMode {
+    fn default() -> Self {
+        Self::All
+    }
+}
+
+impl PacketRelayMode {
+    pub fn relays_recv_packets(&self) -> bool {
+        matches!(self, Self::All | Self::RecvOnly)
+    }
+
+    pub fn relays_acks_and_timeouts(&self) -> bool {
+        matches!(self, Self::All | Self::AckOnly)
+    }
+}
+
+impl Display for PacketRelayMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::All => write!(f, "all"),
+            Self::RecvOnly => write!(f, "recv-only"),
+            Self::AckOnly => write!(f, "ack-only"),
+        }
+    }
+}
+
+/// Which of the packet messages are relayed on a path from a channel end on the
+/// source chain to its counterparty on the destination chain.
+///
+/// The packets sent from each chain are relayed as per the mode of the channel end
+/// of that chain. On a given path, these are the packets sent from the source chain,
+/// which are received on the destination chain or timed out on the source chain,
+/// and the packets sent from the destination chain, whose acknowledgements are
+/// written on the source chain and relayed back to the destination chain.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PathRelayModes {
+    /// The mode of the channel end on the source chain of the path.
+    pub src: PacketRelayMode,
+    /// The mode of the channel end on the destination chain of the path.
+    pub dst: PacketRelayMode,
+}
+
+impl PathRelayModes {
+    /// The relay modes of the path from the given channel end on the `src` chain
+    /// to its counterparty on the `dst` chain.
+    pub fn new(
+        src: Option<&ChainConfig>,
+        src_port_id: &PortId,
+        src_channel_id: &ChannelId,
+        dst: Option<&ChainConfig>,
+        dst_port_id: &PortId,
+        dst_channel_id: &ChannelId,
+    ) -> Self {
+        Self {
+            src: src
+                .map(|config| config.packet_relay_mode(src_port_id, src_channel_id))
+                .unwrap_or_default(),
+            dst: dst
+                .map(|config| config.packet_relay_mode(dst_port_id, dst_channel_id))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Whether the packets sent from the source chain are relayed to the destination chain.
+    pub fn relays_recv_packets(&self) -> bool {
+        self.src.relays_recv_packets()
+    }
+
+    /// Whether the timeouts of the packets sent from the source chain are relayed to it.
+    pub fn relays_timeouts(&self) -> bool {
+        self.src.relays_acks_and_timeouts()
+    }
+
+    /// Whether the acknowledgements of the packets sent from the destination chain,
+    /// written on the source chain, are relayed back to the destination chain.
+    pub fn relays_acks(&self) -> bool {
+        self.dst.relays_acks_and_timeouts()
+    }
+}
+
+impl Display for PathRelayModes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{} (source), {} (destination)", self.src, self.dst)
+    }
 }
 
 /// Attempt to load and parse the TOML config file as a `Config`.
@@ -482,9 +908,13 @@ pub(crate) fn store_writer(config: &Config, mut writer: impl Write) -> Result<()
 
 #[cfg(test)]
 mod tests {
-    use super::{load, store_writer};
+    use super::{load, store_writer, PacketRelayMode, PathRelayModes};
     use test_log::test;
 
+    use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+
+    use crate::config::filter::{ChannelFilters, FilterPattern};
+
     #[test]
     fn parse_valid_config() {
         let path = concat!(
@@ -509,4 +939,82 @@ mod tests {
         let mut buffer = Vec::new();
         store_writer(&config, &mut buffer).unwrap();
     }
+
+    #[test]
+    fn packet_relay_mode_of_channels() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example.toml"
+        );
+
+        let mut config = load(path).expect("could not parse config");
+        let chain_config = &mut config.chains[0];
+
+        let exact = |channel: u64| {
+            ChannelFilters::new(vec![(
+                FilterPattern::Exact(PortId::transfer()),
+                FilterPattern::Exact(ChannelId::new(channel)),
+            )])
+        };
+
+        chain_config.recv_only_channels = exact(0);
+        chain_config.ack_only_channels = exact(1);
+
+        let mode = |channel: u64| {
+            chain_config.packet_relay_mode(&PortId::transfer(), &ChannelId::new(channel))
+        };
+
+        assert_eq!(mode(0), PacketRelayMode::RecvOnly);
+        assert_eq!(mode(1), PacketRelayMode::AckOnly);
+        assert_eq!(mode(2), PacketRelayMode::All);
+    }
+
+    #[test]
+    fn path_relay_modes_of_both_directions() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example.toml"
+        );
+
+        let mut config = load(path).expect("could not parse config");
+
+        // Only relay the acknowledgements and timeouts of the packets sent from
+        // `channel-0` on chain A to `channel-1` on chain B.
+        config.chains[0].ack_only_channels = ChannelFilters::new(vec![(
+            FilterPattern::Exact(PortId::transfer()),
+            FilterPattern::Exact(ChannelId::new(0)),
+        )]);
+
+        let (chain_a, chain_b) = (&config.chains[0], &config.chains[1]);
+        let (channel_a, channel_b) = (ChannelId::new(0), ChannelId::new(1));
+        let port = PortId::transfer();
+
+        let a_to_b = PathRelayModes::new(
+            Some(chain_a),
+            &port,
+            &channel_a,
+            Some(chain_b),
+            &port,
+            &channel_b,
+        );
+        let b_to_a = PathRelayModes::new(
+            Some(chain_b),
+            &port,
+            &channel_b,
+            Some(chain_a),
+            &port,
+            &channel_a,
+        );
+
+        // The packets sent from A are left to other relayers, but not their timeouts...
+        assert!(!a_to_b.relays_recv_packets());
+        assert!(a_to_b.relays_timeouts());
+        // ... nor their acknowledgements, written on B and relayed on the path from B to A.
+        assert!(b_to_a.relays_acks());
+
+        // The packets sent from B, their timeouts and acknowledgements are all relayed.
+        assert!(b_to_a.relays_recv_packets());
+        assert!(b_to_a.relays_timeouts());
+        assert!(a_to_b.relays_acks());
+    }
 }
@@ -7,6 +7,22 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 use itertools::Itertools;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
+flex_error::define_error! {
+    Error {
+        WildcardMatch
+            {
+                port_id: PortId,
+                channel_id: ChannelId,
+                filters: String,
+            }
+            |e| {
+                format_args!("channel {}/{} is matched by a wildcard pattern of the packet filter [{}], \
+                    which cannot be updated for a single channel",
+                    e.port_id, e.channel_id, e.filters)
+            },
+    }
+}
+
 /// Represents the ways in which packets can be filtered.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(
@@ -41,6 +57,115 @@ impl PacketFilter {
             PacketFilter::AllowAll => true,
         }
     }
+
+    /// Update the filter so that packets on the channel with [`PortId`] and [`ChannelId`]
+    /// are allowed to be relayed.
+    ///
+    /// Returns true if the filter was modified, false if the channel was already allowed,
+    /// and an error if the channel is denied by a wildcard pattern, which would have to be
+    /// removed, and with it the other channels it denies.
+    pub fn allow_channel(&mut self, port_id: PortId, channel_id: ChannelId) -> Result<bool, Error> {
+        if self.is_allowed(&port_id, &channel_id) {
+            return Ok(false);
+        }
+
+        match self {
+            PacketFilter::Allow(filters) => Ok(filters.insert_exact(port_id, channel_id)),
+            PacketFilter::Deny(filters) => {
+                if filters.matches_wildcard((&port_id, &channel_id)) {
+                    return Err(Error::wildcard_match(
+                        port_id,
+                        channel_id,
+                        filters.to_string(),
+                    ));
+                }
+
+                Ok(filters.remove_exact(&port_id, &channel_id))
+            }
+            PacketFilter::AllowAll => Ok(false),
+        }
+    }
+
+    /// Update the filter so that packets on the channel with [`PortId`] and [`ChannelId`]
+    /// are no longer relayed.
+    ///
+    /// Returns true if the filter was modified, false if the channel was already denied,
+    /// and an error if the channel is allowed by a wildcard pattern, which would have to be
+    /// removed, and with it the other channels it allows.
+    pub fn deny_channel(&mut self, port_id: PortId, channel_id: ChannelId) -> Result<bool, Error> {
+        if !self.is_allowed(&port_id, &channel_id) {
+            return Ok(false);
+        }
+
+        match self {
+            PacketFilter::Allow(filters) => {
+                if filters.matches_wildcard((&port_id, &channel_id)) {
+                    return Err(Error::wildcard_match(
+                        port_id,
+                        channel_id,
+                        filters.to_string(),
+                    ));
+                }
+
+                Ok(filters.remove_exact(&port_id, &channel_id))
+            }
+            PacketFilter::Deny(filters) => Ok(filters.insert_exact(port_id, channel_id)),
+            PacketFilter::AllowAll => {
+                *self = PacketFilter::Deny(ChannelFilters::new(vec![(
+                    FilterPattern::Exact(port_id),
+                    FilterPattern::Exact(channel_id),
+                )]));
+
+                Ok(true)
+            }
+        }
+    }
+}
+
+/// An update to the [`PacketFilter`] of a chain, applied at runtime.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PacketFilterUpdate {
+    /// Start relaying packets on the given channel.
+    Allow {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// Stop relaying packets on the given channel.
+    Deny {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+}
+
+impl PacketFilterUpdate {
+    pub fn port_id(&self) -> &PortId {
+        match self {
+            Self::Allow { port_id, .. } | Self::Deny { port_id, .. } => port_id,
+        }
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        match self {
+            Self::Allow { channel_id, .. } | Self::Deny { channel_id, .. } => channel_id,
+        }
+    }
+
+    /// Apply this update to the given [`PacketFilter`].
+    ///
+    /// Returns true if the filter was modified, false otherwise,
+    /// and an error if the channel is matched by a wildcard pattern.
+    pub fn apply(&self, filter: &mut PacketFilter) -> Result<bool, Error> {
+        match self.clone() {
+            Self::Allow {
+                port_id,
+                channel_id,
+            } => filter.allow_channel(port_id, channel_id),
+            Self::Deny {
+                port_id,
+                channel_id,
+            } => filter.deny_channel(port_id, channel_id),
+        }
+    }
 }
 
 /// The internal representation of channel filter policies.
@@ -73,6 +198,17 @@ impl ChannelFilters {
         })
     }
 
+    /// Indicates whether the given [`PortId`]-[`ChannelId`] pair is matched by an
+    /// entry of the filter policy which contains a wildcard.
+    pub fn matches_wildcard(&self, channel_port: (&PortId, &ChannelId)) -> bool {
+        let (port_id, channel_id) = channel_port;
+        self.0.iter().any(|(port_filter, chan_filter)| {
+            !(port_filter.is_exact() && chan_filter.is_exact())
+                && port_filter.matches(port_id)
+                && chan_filter.matches(channel_id)
+        })
+    }
+
     /// Indicates whether this filter policy contains only exact patterns.
     #[inline]
     pub fn is_exact(&self) -> bool {
@@ -81,6 +217,41 @@ impl ChannelFilters {
         })
     }
 
+    /// Add an exact match for the given [`PortId`]-[`ChannelId`] pair, unless
+    /// such an exact match is already present.
+    ///
+    /// Returns true if the pair was added, false otherwise.
+    pub fn insert_exact(&mut self, port_id: PortId, channel_id: ChannelId) -> bool {
+        if self
+            .iter_exact()
+            .any(|(p, c)| p == &port_id && c == &channel_id)
+        {
+            return false;
+        }
+
+        self.0.push((
+            FilterPattern::Exact(port_id),
+            FilterPattern::Exact(channel_id),
+        ));
+
+        true
+    }
+
+    /// Remove the exact match for the given [`PortId`]-[`ChannelId`] pair.
+    /// Wildcard patterns are left untouched.
+    ///
+    /// Returns true if a matching entry was removed, false otherwise.
+    pub fn remove_exact(&mut self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        let len = self.0.len();
+
+        self.0.retain(|(port_filter, chan_filter)| {
+            port_filter.exact_value() != Some(port_id)
+                || chan_filter.exact_value() != Some(channel_id)
+        });
+
+        self.0.len() != len
+    }
+
     /// An iterator over the [`PortId`]-[`ChannelId`] pairs that don't contain wildcards.
     pub fn iter_exact(&self) -> impl Iterator<Item = (&PortId, &ChannelId)> {
         self.0.iter().filter_map(|port_chan_filter| {
@@ -194,6 +365,8 @@ impl PartialEq for Wildcard {
     }
 }
 
+impl Eq for Wildcard {}
+
 /// Represents a single channel to be filtered in a [`ChannelFilters`] list.
 #[derive(Clone, Debug, PartialEq)]
 pub enum FilterPattern<T> {
@@ -508,4 +681,85 @@ mod tests {
         let wildcard = "ica*".parse::<Wildcard>().unwrap();
         assert_eq!(wildcard.to_string(), "ica*".to_string());
     }
+
+    #[test]
+    fn packet_filter_allow_deny_channel() {
+        let allow_policy = r#"
+            policy = 'allow'
+            list = [
+              ['transfer', 'channel-0'],
+            ]
+            "#;
+
+        let mut pf: PacketFilter =
+            toml::from_str(allow_policy).expect("could not parse filter policy");
+
+        let port = PortId::from_str("transfer").unwrap();
+        let channel = ChannelId::from_str("channel-1").unwrap();
+
+        assert!(!pf.is_allowed(&port, &channel));
+        assert!(pf.allow_channel(port.clone(), channel.clone()).unwrap());
+        assert!(pf.is_allowed(&port, &channel));
+        assert!(!pf.allow_channel(port.clone(), channel.clone()).unwrap());
+
+        assert!(pf.deny_channel(port.clone(), channel.clone()).unwrap());
+        assert!(!pf.is_allowed(&port, &channel));
+        assert!(pf.is_allowed(&port, &ChannelId::from_str("channel-0").unwrap()));
+    }
+
+    #[test]
+    fn packet_filter_update_allow_all() {
+        let mut pf = PacketFilter::AllowAll;
+
+        let update = PacketFilterUpdate::Deny {
+            port_id: PortId::from_str("transfer").unwrap(),
+            channel_id: ChannelId::from_str("channel-1").unwrap(),
+        };
+
+        assert!(update.apply(&mut pf).unwrap());
+        assert!(matches!(pf, PacketFilter::Deny(_)));
+        assert!(!pf.is_allowed(update.port_id(), update.channel_id()));
+
+        let update = PacketFilterUpdate::Allow {
+            port_id: PortId::from_str("transfer").unwrap(),
+            channel_id: ChannelId::from_str("channel-1").unwrap(),
+        };
+
+        assert!(update.apply(&mut pf).unwrap());
+        assert!(pf.is_allowed(update.port_id(), update.channel_id()));
+    }
+
+    #[test]
+    fn packet_filter_update_wildcard() {
+        let deny_policy = r#"
+            policy = 'deny'
+            list = [
+              ['ica*', '*'],
+            ]
+            "#;
+
+        let mut pf: PacketFilter =
+            toml::from_str(deny_policy).expect("could not parse filter policy");
+
+        let port = PortId::from_str("icahost").unwrap();
+        let channel = ChannelId::from_str("channel-1").unwrap();
+
+        assert!(pf.allow_channel(port.clone(), channel.clone()).is_err());
+        assert!(!pf.is_allowed(&port, &channel));
+
+        let allow_policy = r#"
+            policy = 'allow'
+            list = [
+              ['transfer', 'channel-*'],
+            ]
+            "#;
+
+        let mut pf: PacketFilter =
+            toml::from_str(allow_policy).expect("could not parse filter policy");
+
+        let port = PortId::from_str("transfer").unwrap();
+
+        assert!(pf.deny_channel(port.clone(), channel.clone()).is_err());
+        assert!(pf.is_allowed(&port, &channel));
+    }
 }
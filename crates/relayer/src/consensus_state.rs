@@ -50,6 +50,17 @@ impl AnyConsensusState {
     }
 }
 
+impl core::fmt::Display for AnyConsensusState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            AnyConsensusState::Tendermint(cs_state) => write!(f, "{}", cs_state),
+
+            #[cfg(test)]
+            AnyConsensusState::Mock(mock_state) => write!(f, "{:?}", mock_state),
+        }
+    }
+}
+
 impl Protobuf<Any> for AnyConsensusState {}
 
 impl TryFrom<Any> for AnyConsensusState {
@@ -121,7 +132,7 @@ impl From<&dyn ConsensusState> for AnyConsensusState {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AnyConsensusStateWithHeight {
     pub height: Height,
     pub consensus_state: AnyConsensusState,
@@ -0,0 +1,507 @@
+//! Coordination between redundant Hermes instances relaying on the same channels.
+//!
+//! When several instances of Hermes are deployed in an active-active setup,
+//! they would all submit the same packet messages, with all but one of the
+//! transactions failing while still paying for fees. When coordination is enabled,
+//! each instance first claims the range of sequences a packet belongs to, and only
+//! relays the packet if the claim succeeded.
+//!
+//! Claims are leases: an instance keeps the claim on a range for as long as it keeps
+//! relaying packets in that range, and the claim expires once the lease elapses
+//! without being renewed, at which point any other instance can take over.
+//!
+//! The leases are tracked through the modification times of the claim files, as set
+//! by the shared filesystem, and compared against the clock of each instance. The clocks
+//! of the hosts running the instances and of the filesystem server must therefore be
+//! kept in sync, eg. with NTP, with a skew well below the lease. Likewise, an instance
+//! must not stall for longer than the lease between checking its claim and relaying.
+
+use core::fmt::{Debug, Display, Error as FmtError, Formatter};
+use core::time::Duration;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime};
+
+use flex_error::{define_error, TraceError};
+use tracing::{debug, trace};
+
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::events::IbcEventType;
+
+use crate::config::CoordinationConfig;
+use crate::util::lock::{LockExt, RwArc};
+
+define_error! {
+    CoordinationError {
+        Io
+            { path: PathBuf }
+            [ TraceError<std::io::Error> ]
+            |e| { format_args!("I/O error while accessing claim file {}", e.path.display()) },
+
+        MissingSetting
+            { setting: String }
+            |e| { format_args!("coordination is enabled but `coordination.{}` is not set", e.setting) },
+    }
+}
+
+/// Identifies the packet that an instance wants to relay a message for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketClaim {
+    /// The chain on which the event was emitted.
+    pub chain_id: ChainId,
+    /// The port on the chain emitting the event.
+    pub port_id: PortId,
+    /// The channel on the chain emitting the event.
+    pub channel_id: ChannelId,
+    /// The type of event for which a message is relayed,
+    /// ie. `send_packet` or `write_acknowledgement`.
+    pub event_type: IbcEventType,
+    /// The sequence of the packet.
+    pub sequence: Sequence,
+}
+
+impl Display for PacketClaim {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "{}/{}/{}/{}/{}",
+            self.chain_id,
+            self.port_id,
+            self.channel_id,
+            self.event_type.as_str(),
+            self.sequence
+        )
+    }
+}
+
+/// A lock service shared between the Hermes instances relaying on the same channels.
+pub trait Coordinator: Debug + Send + Sync {
+    /// Try to claim the range of sequences the given packet belongs to.
+    ///
+    /// Returns `true` if this instance owns the claim, either because it was
+    /// just acquired or because it was already held and has been renewed,
+    /// and `false` if the range is currently claimed by another instance.
+    fn try_claim(&self, claim: &PacketClaim) -> Result<bool, CoordinationError>;
+}
+
+/// A [`Coordinator`] which stores claims as files in a directory shared between
+/// all instances, eg. on a common volume or network filesystem.
+///
+/// Each claim on a range is a sequence of generation files, `<event>-<range>.<generation>`,
+/// the latest of which holds the identifier of the instance owning the claim, while the
+/// lease is tracked through its modification time. Acquiring a claim, including taking over
+/// an expired one, only ever happens through the exclusive creation of the next generation
+/// file, so that when several instances race for the same range exactly one of them wins.
+/// Renewing a claim atomically replaces the generation file, and only if it is still the
+/// latest one and owned by this instance.
+///
+/// Claims which were superseded are removed by the instance taking over, and claims left
+/// expired for longer than twice the lease, eg. once the channel is no longer relayed on,
+/// are removed by the instances relaying on the same channel.
+#[derive(Clone, Debug)]
+pub struct DirectoryCoordinator {
+    instance_id: String,
+    claims_dir: PathBuf,
+    lease: Duration,
+    range_size: u64,
+    last_collection: RwArc<Instant>,
+}
+
+impl DirectoryCoordinator {
+    pub fn new(instance_id: String, claims_dir: PathBuf, lease: Duration, range_size: u64) -> Self {
+        Self {
+            instance_id,
+            claims_dir,
+            lease,
+            range_size: range_size.max(1),
+            last_collection: <RwArc<_>>::new_lock(Instant::now()),
+        }
+    }
+
+    /// The coordinator configured by the given settings, which must name this instance
+    /// and the directory shared with the other instances.
+    pub fn from_config(config: &CoordinationConfig) -> Result<Self, CoordinationError> {
+        let instance_id = config
+            .instance_id
+            .clone()
+            .filter(|id| !id.trim().is_empty())
+            .ok_or_else(|| CoordinationError::missing_setting("instance_id".to_string()))?;
+
+        let claims_dir = config
+            .claims_dir
+            .clone()
+            .ok_or_else(|| CoordinationError::missing_setting("claims_dir".to_string()))?;
+
+        Ok(Self::new(
+            instance_id,
+            claims_dir,
+            config.lease,
+            config.range_size,
+        ))
+    }
+
+    /// The directory holding the claims on the channel the given packet was sent on.
+    fn channel_dir(&self, claim: &PacketClaim) -> PathBuf {
+        self.claims_dir
+            .join(claim.chain_id.as_str())
+            .join(claim.port_id.as_str())
+            .join(claim.channel_id.as_str())
+    }
+
+    /// The prefix of the generation files of the claim on the range the given packet belongs to.
+    fn claim_prefix(&self, claim: &PacketClaim) -> String {
+        let range = u64::from(claim.sequence) / self.range_size;
+
+        format!("{}-{}.", claim.event_type.as_str(), range)
+    }
+
+    /// Returns the latest generation of the claim with the given prefix, if any.
+    fn latest_generation(
+        &self,
+        dir: &Path,
+        prefix: &str,
+    ) -> Result<Option<u64>, CoordinationError> {
+        let entries = fs::read_dir(dir).map_err(|e| CoordinationError::io(dir.to_path_buf(), e))?;
+
+        let mut latest = None;
+
+        for entry in entries {
+            let entry = entry.map_err(|e| CoordinationError::io(dir.to_path_buf(), e))?;
+
+            let generation = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix(prefix))
+                .and_then(|generation| generation.parse::<u64>().ok());
+
+            latest = latest.max(generation);
+        }
+
+        Ok(latest)
+    }
+
+    /// Attempts to create the given generation of a claim, returning `false`
+    /// if it was created by another instance first.
+    fn create_claim(&self, path: &Path) -> Result<bool, CoordinationError> {
+        match OpenOptions::new().write(true).create_new(true).open(path) {
+            Ok(mut file) => {
+                file.write_all(self.instance_id.as_bytes())
+                    .map_err(|e| CoordinationError::io(path.to_path_buf(), e))?;
+
+                Ok(true)
+            }
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(false),
+            Err(e) => Err(CoordinationError::io(path.to_path_buf(), e)),
+        }
+    }
+
+    /// Takes over the claim with the given prefix by creating the generation following
+    /// the expired one, and removes the generations it supersedes on success.
+    fn take_over(&self, dir: &Path, prefix: &str, expired: u64) -> Result<bool, CoordinationError> {
+        let path = dir.join(format!("{}{}", prefix, expired + 1));
+
+        if !self.create_claim(&path)? {
+            return Ok(false);
+        }
+
+        for generation in (0..=expired).rev() {
+            match fs::remove_file(dir.join(format!("{}{}", prefix, generation))) {
+                Ok(()) => {}
+                // Older generations have already been cleaned up by a previous owner
+                Err(e) if e.kind() == ErrorKind::NotFound => break,
+                Err(e) => return Err(CoordinationError::io(dir.to_path_buf(), e)),
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Renews the given generation of the claim with the given prefix, owned by this instance,
+    /// by atomically replacing it, returning `false` if it was taken over in the meantime.
+    fn renew_claim(
+        &self,
+        dir: &Path,
+        prefix: &str,
+        generation: u64,
+    ) -> Result<bool, CoordinationError> {
+        let path = dir.join(format!("{}{}", prefix, generation));
+        let renewed = dir.join(format!(".{}{}.{}", prefix, generation, self.instance_id));
+
+        fs::write(&renewed, self.instance_id.as_bytes())
+            .map_err(|e| CoordinationError::io(renewed.clone(), e))?;
+
+        // Compare: the claim must still be the latest generation, and owned by this instance.
+        let owned = self.latest_generation(dir, prefix)? == Some(generation)
+            && self.owner(&path)?.as_deref() == Some(self.instance_id.as_str());
+
+        if !owned {
+            let _ = fs::remove_file(&renewed);
+            return Ok(false);
+        }
+
+        // Swap
+        fs::rename(&renewed, &path).map_err(|e| CoordinationError::io(path.clone(), e))?;
+
+        // The claim may have been taken over between the comparison and the swap,
+        // in which case the superseded generation must not be left behind.
+        if self.latest_generation(dir, prefix)? != Some(generation) {
+            let _ = fs::remove_file(&path);
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    /// The instance owning the given claim file, if it still exists.
+    fn owner(&self, path: &Path) -> Result<Option<String>, CoordinationError> {
+        match fs::read_to_string(path) {
+            Ok(owner) => Ok(Some(owner)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(CoordinationError::io(path.to_path_buf(), e)),
+        }
+    }
+
+    /// How long ago the lease of the given claim file was last renewed.
+    fn elapsed(&self, path: &Path) -> Result<Duration, CoordinationError> {
+        let modified = fs::metadata(path)
+            .and_then(|m| m.modified())
+            .map_err(|e| CoordinationError::io(path.to_path_buf(), e))?;
+
+        Ok(SystemTime::now()
+            .duration_since(modified)
+            .unwrap_or_default())
+    }
+
+    fn is_expired(&self, path: &Path) -> Result<bool, CoordinationError> {
+        Ok(self.elapsed(path)? > self.lease)
+    }
+
+    /// Removes the claims in the given channel directory which have been left expired for
+    /// longer than twice the lease, at most once per lease.
+    fn collect_garbage(&self, dir: &Path) -> Result<(), CoordinationError> {
+        {
+            let mut last_collection = self.last_collection.acquire_write();
+
+            if last_collection.elapsed() < self.lease {
+                return Ok(());
+            }
+
+            *last_collection = Instant::now();
+        }
+
+        let entries = fs::read_dir(dir).map_err(|e| CoordinationError::io(dir.to_path_buf(), e))?;
+
+        for entry in entries {
+            let path = entry
+                .map_err(|e| CoordinationError::io(dir.to_path_buf(), e))?
+                .path();
+
+            match self.elapsed(&path) {
+                Ok(elapsed) if elapsed > self.lease * 2 => {
+                    trace!(path = %path.display(), "removing stale claim");
+
+                    match fs::remove_file(&path) {
+                        Ok(()) => {}
+                        Err(e) if e.kind() == ErrorKind::NotFound => {}
+                        Err(e) => return Err(CoordinationError::io(path, e)),
+                    }
+                }
+                Ok(_) => {}
+                // Removed by another instance in the meantime
+                Err(_) if !path.exists() => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Coordinator for DirectoryCoordinator {
+    fn try_claim(&self, claim: &PacketClaim) -> Result<bool, CoordinationError> {
+        let dir = self.channel_dir(claim);
+        let prefix = self.claim_prefix(claim);
+
+        fs::create_dir_all(&dir).map_err(|e| CoordinationError::io(dir.clone(), e))?;
+
+        self.collect_garbage(&dir)?;
+
+        let generation = match self.latest_generation(&dir, &prefix)? {
+            Some(generation) => generation,
+            None => {
+                let acquired = self.create_claim(&dir.join(format!("{}0", prefix)))?;
+
+                if acquired {
+                    trace!(%claim, "acquired claim");
+                }
+
+                return Ok(acquired);
+            }
+        };
+
+        let path = dir.join(format!("{}{}", prefix, generation));
+
+        let owner = match self.owner(&path)? {
+            Some(owner) => owner,
+            // The claim was superseded by a newer generation in the meantime,
+            // which was therefore acquired by another instance.
+            None => return Ok(false),
+        };
+
+        let expired = self.is_expired(&path)?;
+
+        if owner == self.instance_id && !expired {
+            trace!(%claim, "renewing claim");
+            return self.renew_claim(&dir, &prefix, generation);
+        }
+
+        if expired {
+            debug!(%claim, %owner, "claim has expired, taking over");
+
+            // Only one of the instances taking over the expired claim concurrently
+            // will manage to create the next generation of the claim.
+            return self.take_over(&dir, &prefix, generation);
+        }
+
+        debug!(%claim, %owner, "range is claimed by another instance");
+
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    fn claim(sequence: u64) -> PacketClaim {
+        PacketClaim {
+            chain_id: ChainId::from_string("ibc-0"),
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::from_str("channel-0").unwrap(),
+            event_type: IbcEventType::SendPacket,
+            sequence: Sequence::from(sequence),
+        }
+    }
+
+    fn claims_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("hermes-claims-{}-{}", name, std::process::id()));
+
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn claims_are_exclusive_within_lease() {
+        let dir = claims_dir("exclusive");
+
+        let a = DirectoryCoordinator::new("a".into(), dir.clone(), Duration::from_secs(60), 10);
+        let b = DirectoryCoordinator::new("b".into(), dir.clone(), Duration::from_secs(60), 10);
+
+        assert!(a.try_claim(&claim(1)).unwrap());
+        assert!(a.try_claim(&claim(9)).unwrap());
+        assert!(!b.try_claim(&claim(5)).unwrap());
+
+        // Sequence 10 belongs to the next range
+        assert!(b.try_claim(&claim(10)).unwrap());
+        assert!(!a.try_claim(&claim(11)).unwrap());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn expired_claims_are_taken_over() {
+        let dir = claims_dir("expired");
+
+        let a = DirectoryCoordinator::new("a".into(), dir.clone(), Duration::ZERO, 10);
+        let b = DirectoryCoordinator::new("b".into(), dir.clone(), Duration::ZERO, 10);
+
+        assert!(a.try_claim(&claim(1)).unwrap());
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert!(b.try_claim(&claim(1)).unwrap());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn concurrent_take_overs_have_a_single_winner() {
+        let dir = claims_dir("take-over");
+
+        let a = DirectoryCoordinator::new("a".into(), dir.clone(), Duration::ZERO, 10);
+        let b = DirectoryCoordinator::new("b".into(), dir.clone(), Duration::ZERO, 10);
+        let c = DirectoryCoordinator::new("c".into(), dir.clone(), Duration::ZERO, 10);
+
+        assert!(a.try_claim(&claim(1)).unwrap());
+
+        let channel_dir = a.channel_dir(&claim(1));
+        let prefix = a.claim_prefix(&claim(1));
+
+        // Both `b` and `c` observed the expired claim of `a`
+        assert!(b.take_over(&channel_dir, &prefix, 0).unwrap());
+        assert!(!c.take_over(&channel_dir, &prefix, 0).unwrap());
+
+        assert_eq!(a.latest_generation(&channel_dir, &prefix).unwrap(), Some(1));
+        assert!(!channel_dir.join(format!("{}0", prefix)).exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn claims_taken_over_are_not_renewed() {
+        let dir = claims_dir("renew");
+
+        let a = DirectoryCoordinator::new("a".into(), dir.clone(), Duration::from_secs(60), 10);
+        let b = DirectoryCoordinator::new("b".into(), dir.clone(), Duration::from_secs(60), 10);
+
+        assert!(a.try_claim(&claim(1)).unwrap());
+
+        let channel_dir = a.channel_dir(&claim(1));
+        let prefix = a.claim_prefix(&claim(1));
+
+        // `b` took over the claim of `a`, which `a` then tries to renew
+        assert!(b.take_over(&channel_dir, &prefix, 0).unwrap());
+        assert!(!a.renew_claim(&channel_dir, &prefix, 0).unwrap());
+        assert!(!channel_dir.join(format!("{}0", prefix)).exists());
+
+        assert!(!a.try_claim(&claim(1)).unwrap());
+        assert!(b.try_claim(&claim(1)).unwrap());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn stale_claims_are_removed() {
+        let dir = claims_dir("stale");
+
+        let a = DirectoryCoordinator::new("a".into(), dir.clone(), Duration::ZERO, 10);
+
+        assert!(a.try_claim(&claim(1)).unwrap());
+
+        let channel_dir = a.channel_dir(&claim(1));
+
+        std::thread::sleep(Duration::from_millis(20));
+        a.collect_garbage(&channel_dir).unwrap();
+
+        assert_eq!(fs::read_dir(&channel_dir).unwrap().count(), 0);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn coordination_requires_instance_id() {
+        let config = CoordinationConfig {
+            enabled: true,
+            instance_id: None,
+            claims_dir: Some(claims_dir("config")),
+            ..Default::default()
+        };
+
+        assert!(DirectoryCoordinator::from_config(&config).is_err());
+    }
+}
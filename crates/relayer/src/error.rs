@@ -33,6 +33,7 @@ use ibc_relayer_types::{
     },
     proofs::ProofError,
     relayer::ics18_relayer::error as relayer_error,
+    Height,
 };
 
 use crate::chain::cosmos::version;
@@ -350,6 +351,18 @@ define_error! {
                     e.chain_id, e.estimated_gas, e.max_gas)
             },
 
+        FeeBudgetExhausted
+            {
+                chain_id: ChainId,
+                spent: u128,
+                max_daily_spend: u128,
+                denom: String,
+            }
+            |e| {
+                format!("the daily fee budget of chain {0} is exhausted, {1}{3} out of {2}{3} were spent today",
+                    e.chain_id, e.spent, e.max_daily_spend, e.denom)
+            },
+
         HealthCheckJsonRpc
             {
                 chain_id: ChainId,
@@ -541,6 +554,72 @@ define_error! {
             |e| {
                 format_args!("message with length {} is too large for a transaction", e.len)
             },
+
+        MultisigSigners
+            {
+                signers: Vec<usize>,
+                threshold: u32,
+                keys: usize,
+            }
+            |e| {
+                format_args!(
+                    "invalid signers {:?} for a {}-of-{} multisig account",
+                    e.signers, e.threshold, e.keys)
+            },
+
+        MultisigNotASigner
+            { account: String }
+            |e| {
+                format_args!(
+                    "account {} is not among the expected signers of the multisig transaction",
+                    e.account)
+            },
+
+        MultisigMissingSignature
+            { public_key: String }
+            |e| {
+                format_args!(
+                    "missing the signature of the multisig member with public key {}",
+                    e.public_key)
+            },
+
+        InvalidQueryProof
+            {
+                chain_id: ChainId,
+                path: String,
+                height: u64,
+            }
+            [ commitment_error::Error ]
+            |e| {
+                format_args!(
+                    "the proof returned by chain {} for path {} at height {} does not verify against the app hash of the next block",
+                    e.chain_id, e.path, e.height)
+            },
+
+        StaleGrpcNode
+            {
+                chain_id: ChainId,
+                address: String,
+                height: Height,
+                highest: Height,
+            }
+            |e| {
+                format_args!(
+                    "gRPC endpoint {} of chain {} keeps serving queries at height {}, behind the highest observed height {}",
+                    e.address, e.chain_id, e.height, e.highest)
+            },
+
+        DivergedNode
+            {
+                chain_id: ChainId,
+                address: String,
+                divergence: String,
+            }
+            |e| {
+                format_args!(
+                    "node {} of chain {} diverged from the blocks seen earlier and is quarantined until it serves them again: {}",
+                    e.address, e.chain_id, e.divergence)
+            },
     }
 }
 
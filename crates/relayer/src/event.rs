@@ -382,6 +382,9 @@ fn channel_extract_attributes_from_tx(
             channel_events::COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY => {
                 attr.counterparty_channel_id = value.parse().ok();
             }
+            channel_events::VERSION_ATTRIBUTE_KEY => {
+                attr.version = value.parse().ok();
+            }
             _ => {}
         }
     }
@@ -392,8 +395,19 @@ fn channel_extract_attributes_from_tx(
 fn extract_packet_and_write_ack_from_tx(
     event: &AbciEvent,
 ) -> Result<(Packet, Vec<u8>), ChannelError> {
+    let decode_hex = |value: &str| {
+        hex::decode(value)
+            .map_err(|_| ChannelError::abci_conversion_failed(event.type_str.to_owned()))
+    };
+
     let mut packet = Packet::default();
     let mut write_ack: Vec<u8> = Vec::new();
+
+    // The hex-encoded data and acknowledgement take precedence,
+    // as they are the only lossless encodings of binary payloads.
+    let mut data_hex = None;
+    let mut ack_hex = None;
+
     for tag in &event.attributes {
         let key = tag.key.as_ref();
         let value = tag.value.as_ref();
@@ -420,18 +434,34 @@ fn extract_packet_and_write_ack_from_tx(
                 packet.timeout_height = parse_timeout_height(value)?;
             }
             channel_events::PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY => {
-                packet.timeout_timestamp = value.parse().unwrap();
+                packet.timeout_timestamp = value
+                    .parse()
+                    .map_err(ChannelError::invalid_packet_timestamp)?;
             }
             channel_events::PKT_DATA_ATTRIBUTE_KEY => {
                 packet.data = Vec::from(value.as_bytes());
             }
+            channel_events::PKT_DATA_HEX_ATTRIBUTE_KEY => {
+                data_hex = Some(decode_hex(value)?);
+            }
             channel_events::PKT_ACK_ATTRIBUTE_KEY => {
                 write_ack = Vec::from(value.as_bytes());
             }
+            channel_events::PKT_ACK_HEX_ATTRIBUTE_KEY => {
+                ack_hex = Some(decode_hex(value)?);
+            }
             _ => {}
         }
     }
 
+    if let Some(data) = data_hex {
+        packet.data = data;
+    }
+
+    if let Some(ack) = ack_hex {
+        write_ack = ack;
+    }
+
     Ok((packet, write_ack))
 }
 
@@ -513,6 +543,7 @@ mod tests {
             connection_id: "test_connection".parse().unwrap(),
             counterparty_port_id: "counterparty_test_port".parse().unwrap(),
             counterparty_channel_id: Some("channel-1".parse().unwrap()),
+            version: Some("ics20-1".parse().unwrap()),
         };
         let mut abci_events = vec![];
         let open_init = channel_events::OpenInit::try_from(attributes.clone()).unwrap();
@@ -601,4 +632,33 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn binary_packet_event_to_abci_event() {
+        let packet = Packet {
+            sequence: Sequence::from(10),
+            source_port: "a_test_port".parse().unwrap(),
+            source_channel: "channel-0".parse().unwrap(),
+            destination_port: "b_test_port".parse().unwrap(),
+            destination_channel: "channel-1".parse().unwrap(),
+            data: vec![0x00, 0xff, 0xfe, 0x80],
+            timeout_height: Height::new(1, 10).unwrap().into(),
+            timeout_timestamp: Timestamp::now(),
+        };
+        let write_ack = channel_events::WriteAcknowledgement {
+            packet,
+            ack: vec![0x01, 0xc3],
+        };
+
+        let abci_event = AbciEvent::try_from(write_ack.clone()).unwrap();
+        assert!(abci_event
+            .attributes
+            .iter()
+            .all(|tag| tag.key.as_ref() != channel_events::PKT_DATA_ATTRIBUTE_KEY));
+
+        match ibc_event_try_from_abci_event(&abci_event).unwrap() {
+            IbcEvent::WriteAcknowledgement(e) => assert_eq!(e, write_ack),
+            _ => panic!("unexpected event type"),
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use alloc::sync::Arc;
 use core::cmp::Ordering;
+use core::time::Duration;
 
 use crossbeam_channel as channel;
 use futures::{
@@ -9,10 +10,10 @@ use futures::{
 };
 use tokio::task::JoinHandle;
 use tokio::{runtime::Runtime as TokioRuntime, sync::mpsc};
-use tracing::{debug, error, info, instrument, trace};
+use tracing::{debug, error, info, instrument, trace, warn};
 
 use tendermint_rpc::{
-    event::Event as RpcEvent, query::Query, Error as RpcError, SubscriptionClient, Url,
+    event::Event as RpcEvent, query::Query, Client, Error as RpcError, SubscriptionClient, Url,
     WebSocketClient, WebSocketClientDriver,
 };
 
@@ -22,10 +23,11 @@ use ibc_relayer_types::{
 
 use crate::{
     chain::tracking::TrackingId,
+    config::{filter::ChannelFilters, ChainConfig},
     telemetry,
     util::{
         retry::{retry_with_index, RetryResult},
-        stream::try_group_while,
+        stream::try_group_while_or_flush,
     },
 };
 
@@ -36,6 +38,10 @@ use super::IbcEventWithHeight;
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// Maximum number of missed blocks for which the event monitor backfills the events.
+/// Larger gaps are left for the supervisor to recover from by clearing pending packets.
+const MAX_BACKFILL_BLOCKS: u64 = 100;
+
 mod retry_strategy {
     use crate::util::retry::clamp_total;
     use core::time::Duration;
@@ -60,6 +66,38 @@ pub struct EventBatch {
     pub events: Vec<IbcEventWithHeight>,
 }
 
+/// How the event monitor groups the events it receives into batches.
+#[derive(Clone, Debug, Default)]
+pub struct BatchConfig {
+    /// Send the current batch once no event was received for this long.
+    /// If not set, a batch is only sent once the events of the next block come in.
+    pub delay: Option<Duration>,
+
+    /// Send the current batch as soon as a `SendPacket` event
+    /// is observed on any of these channels.
+    pub priority_channels: ChannelFilters,
+}
+
+impl BatchConfig {
+    pub fn from_chain_config(config: &ChainConfig) -> Self {
+        Self {
+            delay: config.event_batch_delay,
+            priority_channels: config.priority_channels.clone(),
+        }
+    }
+
+    /// Whether the batch containing the given event must be sent right away.
+    fn is_priority(&self, event: &IbcEventWithHeight) -> bool {
+        match &event.event {
+            IbcEvent::SendPacket(send_packet) => self.priority_channels.matches((
+                &send_packet.packet.source_port,
+                &send_packet.packet.source_channel,
+            )),
+            _ => false,
+        }
+    }
+}
+
 type SubscriptionResult = core::result::Result<RpcEvent, RpcError>;
 type SubscriptionStream = dyn Stream<Item = SubscriptionResult> + Send + Sync + Unpin;
 
@@ -79,6 +117,10 @@ pub enum MonitorCmd {
 /// The default events that are queried are:
 /// - [`EventType::NewBlock`](tendermint_rpc::query::EventType::NewBlock)
 /// - [`EventType::Tx`](tendermint_rpc::query::EventType::Tx)
+///
+/// A single monitor runs per chain, over a single WebSocket connection,
+/// and its events are shared by all the workers subscribed to the chain
+/// through the event bus of the chain runtime.
 pub struct EventMonitor {
     chain_id: ChainId,
     /// WebSocket to collect events from
@@ -101,19 +143,30 @@ pub struct EventMonitor {
     subscriptions: Box<SubscriptionStream>,
     /// Tokio runtime
     rt: Arc<TokioRuntime>,
+    /// Height of the latest batch of events received, used to detect missed blocks
+    last_height: Option<Height>,
+    /// How to group the events into batches
+    batch_config: BatchConfig,
 }
 
 // TODO: These are SDK specific, should be eventually moved.
 pub mod queries {
     use tendermint_rpc::query::{EventType, Query};
 
+    /// The queries subscribed to by the event monitor.
+    ///
+    /// Rather than one subscription per IBC module, the transactions of all IBC
+    /// modules are received over a single subscription and their IBC events are
+    /// then demultiplexed by [`get_all_events`](crate::event::rpc::get_all_events),
+    /// since node providers often limit the number of subscriptions per client.
+    ///
+    /// New blocks are still received over a separate subscription, since the query
+    /// language cannot match both new blocks and transactions in a single query.
     pub fn all() -> Vec<Query> {
         // Note: Tendermint-go supports max 5 query specifiers!
         vec![
             new_block(),
-            ibc_client(),
-            ibc_connection(),
-            ibc_channel(),
+            ibc_tx(),
             // This will be needed when we send misbehavior evidence to full node
             // Query::eq("message.module", "evidence"),
         ]
@@ -123,16 +176,11 @@ pub mod queries {
         Query::from(EventType::NewBlock)
     }
 
-    pub fn ibc_client() -> Query {
-        Query::eq("message.module", "ibc_client")
-    }
-
-    pub fn ibc_connection() -> Query {
-        Query::eq("message.module", "ibc_connection")
-    }
-
-    pub fn ibc_channel() -> Query {
-        Query::eq("message.module", "ibc_channel")
+    /// Transactions with a message handled by any of the `ibc_client`, `ibc_connection`
+    /// or `ibc_channel` modules, which are still filtered by the node, since the query
+    /// language has no disjunction to match the three modules with equality.
+    pub fn ibc_tx() -> Query {
+        Query::from(EventType::Tx).and_contains("message.module", "ibc")
     }
 }
 
@@ -147,6 +195,7 @@ impl EventMonitor {
     pub fn new(
         chain_id: ChainId,
         node_addr: Url,
+        batch_config: BatchConfig,
         rt: Arc<TokioRuntime>,
     ) -> Result<(Self, EventReceiver, TxMonitorCmd)> {
         let (tx_batch, rx_batch) = channel::unbounded();
@@ -175,6 +224,8 @@ impl EventMonitor {
             rx_cmd,
             node_addr,
             subscriptions: Box::new(futures::stream::empty()),
+            last_height: None,
+            batch_config,
         };
 
         Ok((monitor, rx_batch, tx_cmd))
@@ -339,7 +390,11 @@ impl EventMonitor {
             core::mem::replace(&mut self.subscriptions, Box::new(futures::stream::empty()));
 
         // Convert the stream of RPC events into a stream of event batches.
-        let batches = stream_batches(subscriptions, self.chain_id.clone());
+        let batches = stream_batches(
+            subscriptions,
+            self.chain_id.clone(),
+            self.batch_config.clone(),
+        );
 
         // Needed to be able to poll the stream
         pin_mut!(batches);
@@ -422,7 +477,9 @@ impl EventMonitor {
     }
 
     /// Collect the IBC events from the subscriptions
-    fn process_batch(&self, batch: EventBatch) -> Result<()> {
+    fn process_batch(&mut self, batch: EventBatch) -> Result<()> {
+        self.check_gap(batch.height)?;
+
         telemetry!(ws_events, &batch.chain_id, batch.events.len() as u64);
 
         self.tx_batch
@@ -431,6 +488,98 @@ impl EventMonitor {
 
         Ok(())
     }
+
+    /// Detect whether blocks were missed before the given height, eg. while
+    /// reconnecting to the WebSocket endpoint, and if so, backfill their events.
+    ///
+    /// If the gap is too large or the backfilling fails, an error is propagated
+    /// to the supervisor, which then clears the pending packets.
+    fn check_gap(&mut self, height: Height) -> Result<()> {
+        let last_height = match self.last_height {
+            Some(last_height) if last_height >= height => return Ok(()),
+            last_height => {
+                self.last_height = Some(height);
+                last_height
+            }
+        };
+
+        let from = match last_height {
+            // A change of revision resets the heights, so gaps cannot be detected
+            Some(last_height) if last_height.revision_number() == height.revision_number() => {
+                last_height.increment()
+            }
+            _ => return Ok(()),
+        };
+
+        if from == height {
+            return Ok(());
+        }
+
+        let to = height
+            .decrement()
+            .expect("height is greater than the last height");
+        let missed = to.revision_height() - from.revision_height() + 1;
+
+        warn!(%from, %to, "missed the events of {} blocks, backfilling them", missed);
+
+        telemetry!(ws_event_gaps, &self.chain_id);
+
+        if missed > MAX_BACKFILL_BLOCKS {
+            warn!(
+                "too many missed blocks to backfill (max: {}), clearing pending packets instead",
+                MAX_BACKFILL_BLOCKS
+            );
+
+            return self.propagate_error(Error::missed_blocks(self.chain_id.clone(), from, to));
+        }
+
+        for revision_height in from.revision_height()..=to.revision_height() {
+            let height = Height::new(from.revision_number(), revision_height)
+                .expect("revision height is greater than zero");
+
+            match self.backfill(height) {
+                Ok(batch) => {
+                    telemetry!(ws_events, &batch.chain_id, batch.events.len() as u64);
+
+                    self.tx_batch
+                        .send(Ok(batch))
+                        .map_err(|_| Error::channel_send_failed())?;
+                }
+                Err(e) => {
+                    error!(%height, "failed to backfill the events of block: {}", e);
+
+                    return self.propagate_error(Error::missed_blocks(
+                        self.chain_id.clone(),
+                        height,
+                        to,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Query the events of the block at the given height.
+    fn backfill(&self, height: Height) -> Result<EventBatch> {
+        let tm_height = tendermint::block::Height::try_from(height.revision_height())
+            .expect("revision height fits in a Tendermint height");
+
+        let response = self
+            .rt
+            .block_on(self.client.block_results(tm_height))
+            .map_err(Error::rpc)?;
+
+        let mut events = crate::event::rpc::get_events_from_block_results(height, response);
+        sort_events(&mut events);
+
+        Ok(EventBatch {
+            chain_id: self.chain_id.clone(),
+            tracking_id: TrackingId::new_uuid(),
+            height,
+            events,
+        })
+    }
 }
 
 /// Collect the IBC events from an RPC event
@@ -446,6 +595,7 @@ fn collect_events(
 fn stream_batches(
     subscriptions: Box<SubscriptionStream>,
     chain_id: ChainId,
+    batch_config: BatchConfig,
 ) -> impl Stream<Item = Result<EventBatch>> {
     let id = chain_id.clone();
 
@@ -455,8 +605,14 @@ fn stream_batches(
         .map_err(Error::canceled_or_generic)
         .try_flatten();
 
-    // Group events by height
-    let grouped = try_group_while(events, |ev0, ev1| ev0.height == ev1.height);
+    // Group events by height, unless the batch config says to send them earlier
+    let delay = batch_config.delay;
+    let grouped = try_group_while_or_flush(
+        events,
+        |ev0, ev1| ev0.height == ev1.height,
+        move |ev| batch_config.is_priority(ev),
+        delay,
+    );
 
     // Convert each group to a batch
     grouped.map_ok(move |mut events_with_heights| {
@@ -2,6 +2,7 @@ use flex_error::{define_error, TraceError};
 
 use tendermint_rpc::{Error as RpcError, Url};
 
+use ibc_relayer_types::core::ics02_client::height::Height;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
 define_error! {
@@ -45,6 +46,10 @@ define_error! {
         Rpc
             [ TraceError<RpcError> ]
             |_| { "RPC error" },
+
+        MissedBlocks
+            { chain_id: ChainId, from: Height, to: Height }
+            |e| { format!("missed the events of blocks {0} to {1} on chain {2}", e.from, e.to, e.chain_id) },
     }
 }
 
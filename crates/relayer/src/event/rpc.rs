@@ -1,6 +1,8 @@
 use alloc::collections::BTreeMap as HashMap;
 use core::convert::TryFrom;
 
+use tendermint::abci::Event as AbciEvent;
+use tendermint_rpc::endpoint::block_results::Response as BlockResultsResponse;
 use tendermint_rpc::{event::Event as RpcEvent, event::EventData as RpcEventData};
 
 use ibc_relayer_types::core::ics02_client::{events as ClientEvents, height::Height};
@@ -74,8 +76,13 @@ use super::{ibc_event_try_from_abci_event, IbcEventWithHeight};
 /// ]
 /// ```
 ///
-/// Events originating from `BeginBlock` and `EndBlock` methods are extracted via the
-/// `RpcEvent::events` field. Here's an example of what these events look like ->
+/// Events originating from `BeginBlock` and `EndBlock` methods are extracted from the
+/// `result_begin_block` and `result_end_block` fields of the `EventData::NewBlock` variant,
+/// in the order in which they were emitted. This way, the packets sent from `EndBlock`,
+/// eg. by the interchain accounts controllers or by CCV, are relayed as soon as they are sent.
+///
+/// If the node does not report these fields, the events are extracted via the
+/// `RpcEvent::events` field instead. Here's an example of what these events look like ->
 /// ```json
 /// {
 ///     "channel_open_init.channel_id": [
@@ -126,7 +133,11 @@ pub fn get_all_events(
     let events = events.ok_or("missing events")?;
 
     match data {
-        RpcEventData::NewBlock { block, .. } if query == queries::new_block().to_string() => {
+        RpcEventData::NewBlock {
+            block,
+            result_begin_block,
+            result_end_block,
+        } if query == queries::new_block().to_string() => {
             let height = Height::new(
                 ChainId::chain_version(chain_id.to_string().as_str()),
                 u64::from(block.as_ref().ok_or("tx.height")?.header.height),
@@ -137,9 +148,22 @@ pub fn get_all_events(
                 ClientEvents::NewBlock::new(height).into(),
                 height,
             ));
-            events_with_height.append(&mut extract_block_events(height, &events));
+
+            match (result_begin_block, result_end_block) {
+                (None, None) => {
+                    events_with_height.append(&mut extract_block_events(height, &events));
+                }
+                (begin_block, end_block) => {
+                    let abci_events = begin_block
+                        .into_iter()
+                        .flat_map(|begin_block| begin_block.events)
+                        .chain(end_block.into_iter().flat_map(|end_block| end_block.events));
+
+                    events_with_height.extend(ibc_events_from_abci_events(height, abci_events));
+                }
+            }
         }
-        RpcEventData::Tx { tx_result } => {
+        RpcEventData::Tx { tx_result } if query == queries::ibc_tx().to_string() => {
             let height = Height::new(
                 ChainId::chain_version(chain_id.to_string().as_str()),
                 tx_result.height as u64,
@@ -148,19 +172,13 @@ pub fn get_all_events(
 
             for abci_event in &tx_result.result.events {
                 if let Ok(ibc_event) = ibc_event_try_from_abci_event(abci_event) {
-                    if query == queries::ibc_client().to_string()
-                        && event_is_type_client(&ibc_event)
-                    {
+                    if event_is_type_client(&ibc_event) {
                         tracing::trace!("extracted ibc_client event {}", ibc_event);
                         events_with_height.push(IbcEventWithHeight::new(ibc_event, height));
-                    } else if query == queries::ibc_connection().to_string()
-                        && event_is_type_connection(&ibc_event)
-                    {
+                    } else if event_is_type_connection(&ibc_event) {
                         tracing::trace!("extracted ibc_connection event {}", ibc_event);
                         events_with_height.push(IbcEventWithHeight::new(ibc_event, height));
-                    } else if query == queries::ibc_channel().to_string()
-                        && event_is_type_channel(&ibc_event)
-                    {
+                    } else if event_is_type_channel(&ibc_event) {
                         let _span = tracing::trace_span!("ibc_channel event").entered();
                         tracing::trace!("extracted {}", ibc_event);
                         if matches!(ibc_event, IbcEvent::SendPacket(_)) {
@@ -183,6 +201,53 @@ pub fn get_all_events(
     Ok(events_with_height)
 }
 
+/// Extract the IBC events of a block from the response to a `/block_results` query,
+/// ie. the events which would have been received over the WebSocket subscriptions
+/// for this block, starting with the `NewBlock` event.
+///
+/// This is used to backfill the events of the blocks missed by the event monitor.
+pub fn get_events_from_block_results(
+    height: Height,
+    response: BlockResultsResponse,
+) -> Vec<IbcEventWithHeight> {
+    let begin_block_events = response.begin_block_events.unwrap_or_default();
+    let end_block_events = response.end_block_events.unwrap_or_default();
+    let tx_events = response
+        .txs_results
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|tx_result| tx_result.events);
+
+    let abci_events = begin_block_events
+        .into_iter()
+        .chain(tx_events)
+        .chain(end_block_events);
+
+    core::iter::once(IbcEventWithHeight::new(
+        ClientEvents::NewBlock::new(height).into(),
+        height,
+    ))
+    .chain(ibc_events_from_abci_events(height, abci_events))
+    .collect()
+}
+
+/// Extract the client, connection and channel events among the given ABCI events,
+/// which were emitted in the block at the given height.
+fn ibc_events_from_abci_events(
+    height: Height,
+    abci_events: impl IntoIterator<Item = AbciEvent>,
+) -> impl Iterator<Item = IbcEventWithHeight> {
+    abci_events
+        .into_iter()
+        .filter_map(|abci_event| ibc_event_try_from_abci_event(&abci_event).ok())
+        .filter(|ibc_event| {
+            event_is_type_client(ibc_event)
+                || event_is_type_connection(ibc_event)
+                || event_is_type_channel(ibc_event)
+        })
+        .map(move |ibc_event| IbcEventWithHeight::new(ibc_event, height))
+}
+
 fn event_is_type_client(ev: &IbcEvent) -> bool {
     matches!(
         ev,
@@ -22,6 +22,7 @@ use ibc_relayer_types::core::ics02_client::msgs::misbehaviour::MsgSubmitMisbehav
 use ibc_relayer_types::core::ics02_client::msgs::update_client::MsgUpdateClient;
 use ibc_relayer_types::core::ics02_client::msgs::upgrade_client::MsgUpgradeClient;
 use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
+use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 use ibc_relayer_types::downcast;
 use ibc_relayer_types::events::{IbcEvent, IbcEventType, WithBlockDataType};
@@ -33,8 +34,9 @@ use crate::chain::client::ClientSettings;
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::{
     IncludeProof, PageRequest, QueryClientEventRequest, QueryClientStateRequest,
-    QueryConsensusStateRequest, QueryConsensusStatesRequest, QueryHeight, QueryTxRequest,
-    QueryUpgradedClientStateRequest, QueryUpgradedConsensusStateRequest,
+    QueryConsensusStateRequest, QueryConsensusStatesRequest, QueryHeight,
+    QueryHostConsensusStateRequest, QueryTxRequest, QueryUpgradedClientStateRequest,
+    QueryUpgradedConsensusStateRequest,
 };
 use crate::chain::tracking::TrackedMsgs;
 use crate::client_state::AnyClientState;
@@ -63,6 +65,17 @@ define_error! {
                     e.chain_id, e.description)
             },
 
+        ClientCreateHeight
+            {
+                chain_id: ChainId,
+                height: Height,
+                latest_height: Height,
+            }
+            |e| {
+                format_args!("cannot create client for chain {0} at height {1}, which is higher than its latest height {2}",
+                    e.chain_id, e.height, e.latest_height)
+            },
+
         Client
             [ ClientError ]
             |_| { "ICS02 client error" },
@@ -313,6 +326,12 @@ pub struct CreateOptions {
     pub max_clock_drift: Option<Duration>,
     pub trusting_period: Option<Duration>,
     pub trust_threshold: Option<TrustThreshold>,
+    /// The height of the source chain at which to anchor the client,
+    /// the latest height of the source chain if unspecified.
+    pub height: Option<Height>,
+    /// The ICS-23 proof specs against which the proofs of the source chain
+    /// are verified, the ones of its configuration if unspecified.
+    pub proof_specs: Option<ProofSpecs>,
 }
 
 /// Captures the diagnostic of verifying whether a certain
@@ -570,7 +589,8 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             )
         })?;
 
-        // Build client create message with the data from source chain at latest height.
+        // Build client create message with the data from source chain at the given height,
+        // or at latest height if unspecified.
         let latest_height = self.src_chain.query_latest_height().map_err(|e| {
             ForeignClientError::client_create(
                 self.src_chain.id(),
@@ -579,6 +599,18 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             )
         })?;
 
+        let height = match options.height {
+            None => latest_height,
+            Some(height) if height > latest_height => {
+                return Err(ForeignClientError::client_create_height(
+                    self.src_chain.id(),
+                    height,
+                    latest_height,
+                ))
+            }
+            Some(height) => height,
+        };
+
         // Calculate client state settings from the chain configurations and
         // optional user overrides.
         let src_config = self.src_chain.config().map_err(|e| {
@@ -599,7 +631,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         let client_state: AnyClientState = self
             .src_chain
-            .build_client_state(latest_height, settings)
+            .build_client_state(height, settings)
             .map_err(|e| {
                 ForeignClientError::client_create(
                     self.src_chain.id(),
@@ -608,13 +640,15 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 )
             })?;
 
+        if height != latest_height {
+            self.check_create_height_trusted(&client_state, height)?;
+        }
+
+        // The light block at the client height is trusted as-is, so that the consensus
+        // state is built from the header and next validator set hash at that height.
         let consensus_state = self
             .src_chain
-            .build_consensus_state(
-                client_state.latest_height(),
-                latest_height,
-                client_state.clone(),
-            )
+            .build_consensus_state(height, height, client_state.clone())
             .map_err(|e| {
                 ForeignClientError::client_create(
                     self.src_chain.id(),
@@ -630,6 +664,54 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         Ok(msg)
     }
 
+    /// Checks that the header of the source chain at the given historical height is still
+    /// within the trusting period of the new client, as a client whose consensus state is
+    /// outside of its trusting period would be expired as soon as it is created.
+    fn check_create_height_trusted(
+        &self,
+        client_state: &AnyClientState,
+        height: Height,
+    ) -> Result<(), ForeignClientError> {
+        let header_time = self
+            .src_chain
+            .query_host_consensus_state(QueryHostConsensusStateRequest {
+                height: QueryHeight::Specific(height),
+            })
+            .map_err(|e| {
+                ForeignClientError::client_create(
+                    self.src_chain.id(),
+                    format!(
+                        "failed while querying the src chain header at height {}",
+                        height
+                    ),
+                    e,
+                )
+            })?
+            .timestamp();
+
+        let latest_time = self
+            .src_chain
+            .query_application_status()
+            .map_err(|e| {
+                ForeignClientError::client_create(
+                    self.src_chain.id(),
+                    "failed while querying the src chain status".to_string(),
+                    e,
+                )
+            })?
+            .timestamp;
+
+        let elapsed = latest_time.duration_since(&header_time).unwrap_or_default();
+
+        if client_state.expired(elapsed) {
+            return Err(ForeignClientError::consensus_state_not_trusted(
+                height, elapsed,
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Returns the identifier of the newly created client.
     pub fn build_create_client_and_send(
         &self,
@@ -836,6 +918,8 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
     fn try_refresh(&mut self) -> Result<Option<Vec<IbcEvent>>, ForeignClientError> {
         let (client_state, elapsed) = self.validated_client_state()?;
 
+        telemetry!(self.client_height_lag_metrics(&client_state));
+
         // The refresh_window is the maximum duration
         // we can backoff between subsequent client updates.
         let refresh_window = client_state.refresh_period();
@@ -855,6 +939,42 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         }
     }
 
+    /// Record by how many blocks the latest height of the client
+    /// lags behind the latest height of its source chain.
+    #[cfg(feature = "telemetry")]
+    fn client_height_lag_metrics(&self, client_state: &AnyClientState) {
+        let src_height = match self.src_chain.query_latest_height() {
+            Ok(height) => height,
+            Err(e) => {
+                debug!(
+                    "failed to query the latest height of the source chain: {}",
+                    e
+                );
+                return;
+            }
+        };
+
+        let client_height = client_state.latest_height();
+
+        // The heights of different revisions cannot be compared,
+        // and the client is upgraded to the new revision anyway
+        let lag = if src_height.revision_number() == client_height.revision_number() {
+            src_height
+                .revision_height()
+                .saturating_sub(client_height.revision_height())
+        } else {
+            0
+        };
+
+        telemetry!(
+            client_height_lag,
+            &self.src_chain.id(),
+            &self.dst_chain.id(),
+            &self.id,
+            lag
+        );
+    }
+
     /// Wrapper for build_update_client_with_trusted.
     pub fn wait_and_build_update_client(
         &self,
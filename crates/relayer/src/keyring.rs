@@ -1,4 +1,5 @@
 use alloc::collections::btree_map::BTreeMap as HashMap;
+use core::fmt::{Debug, Formatter, Result as FmtResult};
 use std::ffi::OsStr;
 use std::fs::{self, File};
 use std::path::{Path, PathBuf};
@@ -16,34 +17,42 @@ use k256::ecdsa::{signature::Signer as _, Signature, SigningKey};
 use ripemd::Ripemd160;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
 use tiny_keccak::{Hasher, Keccak};
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::config::AddressType;
 
 use errors::Error;
+pub use multisig::MultisigKey;
 pub use pub_key::EncodedPubKey;
+pub use secret::SecretBytes;
 
 pub mod errors;
+pub mod multisig;
 mod pub_key;
+mod secret;
 
 pub type HDPath = StandardHDPath;
 
 pub const KEYSTORE_DEFAULT_FOLDER: &str = ".hermes/keys/";
 pub const KEYSTORE_DISK_BACKEND: &str = "keyring-test";
 pub const KEYSTORE_FILE_EXTENSION: &str = "json";
+pub const KEYSTORE_MULTISIG_FILE_EXTENSION: &str = "multisig";
 
 // /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\
 // WARNING: Changing this struct in backward incompatible way
 //          will force users to re-import their keys.
 // /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\
 /// Key entry stores the Private Key and Public Key as well the address
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct KeyEntry {
     /// Public key
     pub public_key: ExtendedPubKey,
 
-    /// Private key
-    pub private_key: ExtendedPrivKey,
+    /// Private key, in its BIP-32 encoding, wiped from memory once dropped
+    #[serde(with = "encoded_private_key")]
+    private_key: Zeroizing<[u8; ENCODED_PRIVATE_KEY_LEN]>,
 
     /// Account Bech32 format - TODO allow hrp
     pub account: String,
@@ -52,8 +61,105 @@ pub struct KeyEntry {
     pub address: Vec<u8>,
 }
 
+impl KeyEntry {
+    pub fn new(
+        public_key: ExtendedPubKey,
+        private_key: &ExtendedPrivKey,
+        account: String,
+        address: Vec<u8>,
+    ) -> Self {
+        Self {
+            public_key,
+            private_key: Zeroizing::new(private_key.encode()),
+            account,
+            address,
+        }
+    }
+
+    /// The raw bytes of the private key, wiped from memory once dropped.
+    pub fn secret_key_bytes(&self) -> SecretBytes {
+        SecretBytes::new(self.private_key[SECRET_KEY_OFFSET..].to_vec())
+    }
+}
+
+impl PartialEq for KeyEntry {
+    fn eq(&self, other: &Self) -> bool {
+        // Always compare the private keys, in constant time,
+        // to not leak which fields differ through timing.
+        let same_private_key: bool = self
+            .private_key
+            .as_slice()
+            .ct_eq(other.private_key.as_slice())
+            .into();
+
+        same_private_key
+            & (self.public_key == other.public_key)
+            & (self.account == other.account)
+            & (self.address == other.address)
+    }
+}
+
+impl Eq for KeyEntry {}
+
+impl Debug for KeyEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("KeyEntry")
+            .field("public_key", &self.public_key)
+            .field("private_key", &"[REDACTED]")
+            .field("account", &self.account)
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+/// The length of the BIP-32 encoding of an extended private key.
+const ENCODED_PRIVATE_KEY_LEN: usize = 78;
+
+/// The offset of the secret key in the BIP-32 encoding of an extended private key,
+/// after the version, depth, parent fingerprint, child number, chain code and a zero byte.
+const SECRET_KEY_OFFSET: usize = 46;
+
+/// Serializes the BIP-32 encoding of an extended private key as its Base58 form,
+/// as [`ExtendedPrivKey`] does, so that the stored key entries remain readable.
+mod encoded_private_key {
+    use bitcoin::util::bip32::ExtendedPrivKey;
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+    use zeroize::Zeroizing;
+
+    use super::ENCODED_PRIVATE_KEY_LEN;
+
+    pub fn serialize<S>(
+        encoded: &Zeroizing<[u8; ENCODED_PRIVATE_KEY_LEN]>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let private_key =
+            ExtendedPrivKey::decode(encoded.as_slice()).map_err(ser::Error::custom)?;
+
+        Zeroizing::new(private_key.to_string())
+            .as_str()
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> Result<Zeroizing<[u8; ENCODED_PRIVATE_KEY_LEN]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let encoded = Zeroizing::new(String::deserialize(deserializer)?);
+        let private_key: ExtendedPrivKey = encoded.parse().map_err(de::Error::custom)?;
+
+        Ok(Zeroizing::new(private_key.encode()))
+    }
+}
+
 /// JSON key seed file
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// The mnemonic is wiped from memory when the key file is dropped.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct KeyFile {
     pub name: String,
     pub r#type: String,
@@ -62,6 +168,24 @@ pub struct KeyFile {
     pub mnemonic: String,
 }
 
+impl Drop for KeyFile {
+    fn drop(&mut self) {
+        self.mnemonic.zeroize();
+    }
+}
+
+impl Debug for KeyFile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("KeyFile")
+            .field("name", &self.name)
+            .field("type", &self.r#type)
+            .field("address", &self.address)
+            .field("pubkey", &self.pubkey)
+            .field("mnemonic", &"[REDACTED]")
+            .finish()
+    }
+}
+
 impl KeyEntry {
     pub fn from_key_file(key_file: KeyFile, hd_path: &HDPath) -> Result<Self, Error> {
         // Decode the Bech32-encoded address from the key file
@@ -90,12 +214,12 @@ impl KeyEntry {
                 derived_pubkey_bytes,
             ))
         } else {
-            Ok(Self {
-                public_key: derived_pubkey,
-                private_key,
-                account: key_file.address,
-                address: keyfile_address_bytes,
-            })
+            Ok(Self::new(
+                derived_pubkey,
+                &private_key,
+                key_file.address.clone(),
+                keyfile_address_bytes,
+            ))
         }
     }
 }
@@ -111,6 +235,7 @@ pub trait KeyStore {
 pub struct Memory {
     account_prefix: String,
     keys: HashMap<String, KeyEntry>,
+    multisig_keys: HashMap<String, MultisigKey>,
 }
 
 impl Memory {
@@ -118,6 +243,24 @@ impl Memory {
         Self {
             account_prefix,
             keys: HashMap::new(),
+            multisig_keys: HashMap::new(),
+        }
+    }
+
+    pub fn get_multisig_key(&self, key_name: &str) -> Result<MultisigKey, Error> {
+        self.multisig_keys
+            .get(key_name)
+            .cloned()
+            .ok_or_else(Error::key_not_found)
+    }
+
+    pub fn add_multisig_key(&mut self, key_name: &str, key: MultisigKey) -> Result<(), Error> {
+        if self.multisig_keys.contains_key(key_name) {
+            Err(Error::key_already_exist())
+        } else {
+            self.multisig_keys.insert(key_name.to_string(), key);
+
+            Ok(())
         }
     }
 }
@@ -170,6 +313,40 @@ impl Test {
             store,
         }
     }
+
+    /// Multisig keys are stored next to the regular keys, under a different
+    /// file extension so that they are not mistaken for regular keys.
+    pub fn get_multisig_key(&self, key_name: &str) -> Result<MultisigKey, Error> {
+        let mut key_file = self.store.join(key_name);
+        key_file.set_extension(KEYSTORE_MULTISIG_FILE_EXTENSION);
+
+        if !key_file.as_path().exists() {
+            return Err(Error::key_file_not_found(format!("{}", key_file.display())));
+        }
+
+        let file = File::open(&key_file).map_err(|e| {
+            Error::key_file_io(
+                key_file.display().to_string(),
+                "failed to open file".to_string(),
+                e,
+            )
+        })?;
+
+        serde_json::from_reader(file)
+            .map_err(|e| Error::key_file_decode(format!("{}", key_file.display()), e))
+    }
+
+    pub fn add_multisig_key(&mut self, key_name: &str, key: MultisigKey) -> Result<(), Error> {
+        let mut filename = self.store.join(key_name);
+        filename.set_extension(KEYSTORE_MULTISIG_FILE_EXTENSION);
+        let file_path = filename.display().to_string();
+
+        let file = File::create(filename).map_err(|e| {
+            Error::key_file_io(file_path.clone(), "failed to create file".to_string(), e)
+        })?;
+
+        serde_json::to_writer_pretty(file, &key).map_err(|e| Error::key_file_encode(file_path, e))
+    }
 }
 
 impl KeyStore for Test {
@@ -313,6 +490,20 @@ impl KeyRing {
         }
     }
 
+    pub fn get_multisig_key(&self, key_name: &str) -> Result<MultisigKey, Error> {
+        match self {
+            KeyRing::Memory(m) => m.get_multisig_key(key_name),
+            KeyRing::Test(d) => d.get_multisig_key(key_name),
+        }
+    }
+
+    pub fn add_multisig_key(&mut self, key_name: &str, key: MultisigKey) -> Result<(), Error> {
+        match self {
+            KeyRing::Memory(m) => m.add_multisig_key(key_name, key),
+            KeyRing::Test(d) => d.add_multisig_key(key_name, key),
+        }
+    }
+
     /// Get key from seed file
     pub fn key_from_seed_file(
         &self,
@@ -344,12 +535,7 @@ impl KeyRing {
         let account = bech32::encode(self.account_prefix(), address.to_base32(), Variant::Bech32)
             .map_err(Error::bech32)?;
 
-        Ok(KeyEntry {
-            public_key,
-            private_key,
-            account,
-            address,
-        })
+        Ok(KeyEntry::new(public_key, &private_key, account, address))
     }
 
     /// Sign a message
@@ -378,14 +564,14 @@ pub fn sign_message(
     msg: Vec<u8>,
     address_type: &AddressType,
 ) -> Result<Vec<u8>, Error> {
-    let private_key_bytes = key.private_key.to_priv().to_bytes();
+    let private_key_bytes = key.secret_key_bytes();
     match address_type {
         AddressType::Ethermint { ref pk_type } if pk_type.ends_with(".ethsecp256k1.PubKey") => {
             let hash = keccak256_hash(msg.as_slice());
             let s = Secp256k1::signing_only();
             // SAFETY: hash is 32 bytes, as expected in `Message::from_slice` -- see `keccak256_hash`, hence `unwrap`
             let sign_msg = Message::from_slice(hash.as_slice()).unwrap();
-            let key = SecretKey::from_slice(private_key_bytes.as_slice())
+            let key = SecretKey::from_slice(private_key_bytes.expose_secret())
                 .map_err(Error::invalid_key_raw)?;
             let (_, sig_bytes) = s
                 .sign_ecdsa_recoverable(&sign_msg, &key)
@@ -393,8 +579,8 @@ pub fn sign_message(
             Ok(sig_bytes.to_vec())
         }
         AddressType::Cosmos | AddressType::Ethermint { .. } => {
-            let signing_key =
-                SigningKey::from_bytes(private_key_bytes.as_slice()).map_err(Error::invalid_key)?;
+            let signing_key = SigningKey::from_bytes(private_key_bytes.expose_secret())
+                .map_err(Error::invalid_key)?;
             let signature: Signature = signing_key.sign(&msg);
             Ok(signature.as_ref().to_vec())
         }
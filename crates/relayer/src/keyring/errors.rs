@@ -112,5 +112,25 @@ define_error! {
             |e| {
                 format!("invalid HD path: {0}", e.path)
             },
+
+        InvalidMultisigThreshold
+            {
+                threshold: u32,
+                keys: usize,
+            }
+            |e| {
+                format!("invalid multisig threshold {} for {} public keys, must be between 1 and the number of public keys",
+                    e.threshold, e.keys)
+            },
+
+        InvalidMultisigPublicKey
+            {
+                index: usize,
+                len: usize,
+            }
+            |e| {
+                format!("invalid multisig public key #{}: expected a 33 bytes compressed secp256k1 public key, got {} bytes",
+                    e.index, e.len)
+            },
     }
 }
@@ -0,0 +1,204 @@
+//! Multisig public keys, as used by the Cosmos SDK `LegacyAminoPubKey`.
+//!
+//! Hermes only ever knows the public keys of the members of a multisig account,
+//! the corresponding private keys being held by the co-signers. A multisig key can
+//! therefore not be used to sign transactions by itself, but only to build the
+//! transactions which the co-signers then sign, see [`crate::chain::cosmos::multisig`].
+
+use bech32::{ToBase32, Variant};
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::errors::Error;
+
+pub const MULTISIG_PUB_KEY_TYPE_URL: &str = "/cosmos.crypto.multisig.LegacyAminoPubKey";
+pub const SECP256K1_PUB_KEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+
+/// Length of a compressed secp256k1 public key.
+const SECP256K1_PUB_KEY_LEN: usize = 33;
+
+/// Amino prefix of the `tendermint/PubKeyMultisigThreshold` type.
+const AMINO_MULTISIG_PREFIX: [u8; 4] = [0x22, 0xc1, 0xf7, 0xe2];
+
+/// Amino prefix of the `tendermint/PubKeySecp256k1` type.
+const AMINO_SECP256K1_PREFIX: [u8; 4] = [0xeb, 0x5a, 0xe9, 0x87];
+
+/// Protobuf encoding of `cosmos.crypto.multisig.LegacyAminoPubKey`.
+#[derive(Clone, PartialEq, Message)]
+struct LegacyAminoPubKey {
+    #[prost(uint32, tag = "1")]
+    threshold: u32,
+    #[prost(message, repeated, tag = "2")]
+    public_keys: Vec<Any>,
+}
+
+/// Protobuf encoding of `cosmos.crypto.secp256k1.PubKey`.
+#[derive(Clone, PartialEq, Message)]
+struct Secp256k1PubKey {
+    #[prost(bytes = "vec", tag = "1")]
+    key: Vec<u8>,
+}
+
+/// A `threshold`-of-`n` multisig key, made of the compressed
+/// secp256k1 public keys of its `n` members.
+///
+/// The order of the public keys matters, as it determines the address of the
+/// multisig account. Note that `<chain-binary> keys add --multisig` sorts the keys
+/// by address unless `--nosort` is given, in which case the keys must be given
+/// to Hermes in that same order.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigKey {
+    /// Number of signatures required to sign a transaction
+    pub threshold: u32,
+
+    /// Compressed public keys of the members of the multisig account
+    pub public_keys: Vec<Vec<u8>>,
+
+    /// Account Bech32 format
+    pub account: String,
+
+    /// Address
+    pub address: Vec<u8>,
+}
+
+impl MultisigKey {
+    pub fn new(
+        threshold: u32,
+        public_keys: Vec<Vec<u8>>,
+        account_prefix: &str,
+    ) -> Result<Self, Error> {
+        if threshold == 0 || threshold as usize > public_keys.len() {
+            return Err(Error::invalid_multisig_threshold(
+                threshold,
+                public_keys.len(),
+            ));
+        }
+
+        if let Some((index, key)) = public_keys
+            .iter()
+            .enumerate()
+            .find(|(_, key)| key.len() != SECP256K1_PUB_KEY_LEN)
+        {
+            return Err(Error::invalid_multisig_public_key(index, key.len()));
+        }
+
+        let address = multisig_address(threshold, &public_keys);
+
+        let account = bech32::encode(account_prefix, address.to_base32(), Variant::Bech32)
+            .map_err(Error::bech32)?;
+
+        Ok(Self {
+            threshold,
+            public_keys,
+            account,
+            address,
+        })
+    }
+
+    /// The position of the given public key among the members of the multisig account.
+    pub fn signer_index(&self, public_key: &[u8]) -> Option<usize> {
+        self.public_keys.iter().position(|key| key == public_key)
+    }
+
+    /// The multisig public key, as a `LegacyAminoPubKey` protobuf `Any`.
+    pub fn to_any(&self) -> Any {
+        let pub_key = LegacyAminoPubKey {
+            threshold: self.threshold,
+            public_keys: self
+                .public_keys
+                .iter()
+                .map(|key| Any {
+                    type_url: SECP256K1_PUB_KEY_TYPE_URL.to_string(),
+                    value: Secp256k1PubKey { key: key.clone() }.encode_to_vec(),
+                })
+                .collect(),
+        };
+
+        Any {
+            type_url: MULTISIG_PUB_KEY_TYPE_URL.to_string(),
+            value: pub_key.encode_to_vec(),
+        }
+    }
+}
+
+/// The address of a multisig account is the truncated SHA-256 hash
+/// of the Amino encoding of its `LegacyAminoPubKey`.
+fn multisig_address(threshold: u32, public_keys: &[Vec<u8>]) -> Vec<u8> {
+    let mut amino = AMINO_MULTISIG_PREFIX.to_vec();
+
+    amino.push(0x08);
+    prost::encoding::encode_varint(u64::from(threshold), &mut amino);
+
+    for key in public_keys {
+        let mut encoded_key = AMINO_SECP256K1_PREFIX.to_vec();
+        prost::encoding::encode_varint(key.len() as u64, &mut encoded_key);
+        encoded_key.extend_from_slice(key);
+
+        amino.push(0x12);
+        prost::encoding::encode_varint(encoded_key.len() as u64, &mut amino);
+        amino.extend(encoded_key);
+    }
+
+    Sha256::digest(&amino)[..20].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::keyring::errors::ErrorDetail;
+
+    fn public_key(n: u8) -> Vec<u8> {
+        let mut key = vec![0x02];
+        key.extend([n; 32]);
+        key
+    }
+
+    #[test]
+    fn multisig_key_validation() {
+        let keys = vec![public_key(1), public_key(2), public_key(3)];
+
+        let err = MultisigKey::new(0, keys.clone(), "cosmos").unwrap_err();
+        assert!(matches!(
+            err.detail(),
+            ErrorDetail::InvalidMultisigThreshold(_)
+        ));
+
+        let err = MultisigKey::new(4, keys.clone(), "cosmos").unwrap_err();
+        assert!(matches!(
+            err.detail(),
+            ErrorDetail::InvalidMultisigThreshold(_)
+        ));
+
+        let err = MultisigKey::new(2, vec![public_key(1), vec![0x02; 20]], "cosmos").unwrap_err();
+        assert!(matches!(
+            err.detail(),
+            ErrorDetail::InvalidMultisigPublicKey(_)
+        ));
+
+        let key = MultisigKey::new(2, keys, "cosmos").unwrap();
+        assert!(key.account.starts_with("cosmos1"));
+        assert_eq!(key.address.len(), 20);
+        assert_eq!(key.signer_index(&public_key(3)), Some(2));
+        assert_eq!(key.signer_index(&public_key(4)), None);
+    }
+
+    #[test]
+    fn multisig_address_depends_on_threshold_and_key_order() {
+        let keys = vec![public_key(1), public_key(2)];
+        let reversed = vec![public_key(2), public_key(1)];
+
+        let key = MultisigKey::new(1, keys.clone(), "cosmos").unwrap();
+
+        assert_ne!(
+            key.address,
+            MultisigKey::new(2, keys, "cosmos").unwrap().address
+        );
+        assert_ne!(
+            key.address,
+            MultisigKey::new(1, reversed, "cosmos").unwrap().address
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+/// Raw secret key material.
+///
+/// The bytes are wiped from memory when the value is dropped,
+/// are never printed by the [`Debug`] implementation, and
+/// are compared in constant time.
+#[derive(Clone)]
+pub struct SecretBytes(Zeroizing<Vec<u8>>);
+
+impl SecretBytes {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(Zeroizing::new(bytes))
+    }
+
+    /// Access the raw secret bytes.
+    ///
+    /// The returned slice must not be copied into
+    /// values which are not themselves zeroized on drop.
+    pub fn expose_secret(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for SecretBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl ConstantTimeEq for SecretBytes {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.expose_secret().ct_eq(other.expose_secret())
+    }
+}
+
+impl PartialEq for SecretBytes {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for SecretBytes {}
+
+impl Debug for SecretBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "SecretBytes([REDACTED])")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SecretBytes;
+
+    #[test]
+    fn secret_bytes_eq() {
+        let a = SecretBytes::new(vec![1, 2, 3]);
+        let b = SecretBytes::new(vec![1, 2, 3]);
+        let c = SecretBytes::new(vec![1, 2, 4]);
+        let d = SecretBytes::new(vec![1, 2]);
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a, d);
+    }
+
+    #[test]
+    fn secret_bytes_debug_is_redacted() {
+        let secret = SecretBytes::new(vec![42; 32]);
+
+        assert_eq!(format!("{:?}", secret), "SecretBytes([REDACTED])");
+    }
+}
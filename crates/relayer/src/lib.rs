@@ -28,6 +28,7 @@ pub mod client_state;
 pub mod config;
 pub mod connection;
 pub mod consensus_state;
+pub mod coordination;
 pub mod denom;
 pub mod error;
 pub mod event;
@@ -44,9 +45,11 @@ pub mod registry;
 pub mod rest;
 pub mod sdk_error;
 pub mod spawn;
+pub mod state_store;
 pub mod supervisor;
 pub mod telemetry;
 pub mod transfer;
 pub mod upgrade_chain;
 pub mod util;
+pub mod webhook;
 pub mod worker;
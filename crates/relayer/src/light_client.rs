@@ -101,6 +101,14 @@ impl Header for AnyHeader {
     }
 }
 
+impl core::fmt::Display for AnyHeader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Self::Tendermint(header) => write!(f, "{}", header),
+        }
+    }
+}
+
 impl ErasedProtobuf<Any> for AnyHeader {}
 
 impl TryFrom<Any> for AnyHeader {
@@ -1,5 +1,6 @@
 use itertools::Itertools;
 
+use tendermint::Hash;
 use tendermint_light_client::{
     components::{self, io::AtHeight},
     light_client::LightClient as TmLightClient,
@@ -177,12 +178,42 @@ impl LightClient {
         })
     }
 
-    fn prepare_client(&self, client_state: &AnyClientState) -> Result<TmLightClient, Error> {
-        let clock = components::clock::SystemClock;
-        let hasher = operations::hasher::ProdHasher;
-        let verifier = ProdVerifier::default();
-        let scheduler = components::scheduler::basic_bisecting_schedule;
+    /// Fetch the hash of the header at the given height from the node,
+    /// eg. to compare it with the hash of a block seen earlier.
+    pub fn fetch_header_hash(&self, height: ICSHeight) -> Result<Hash, Error> {
+        let height = TMHeight::try_from(height.revision_height()).map_err(Error::invalid_height)?;
+        let light_block = self.fetch_light_block(AtHeight::At(height))?;
+
+        Ok(light_block.signed_header.header.hash())
+    }
+
+    /// Fetch the latest header of the chain from the node, without verifying it.
+    pub fn fetch_latest(&self) -> Result<LightBlock, Error> {
+        self.fetch_light_block(AtHeight::Highest)
+    }
+
+    /// Verify the header at the given height starting from the given trusted header,
+    /// rather than from the header served by the node at some trusted height.
+    pub fn verify_from(
+        &self,
+        trusted: LightBlock,
+        target: TMHeight,
+        options: TmOptions,
+    ) -> Result<LightBlock, Error> {
+        trace!(trusted = %trusted.height(), %target, "light client verification");
+
+        let client = self.client_with_options(options);
 
+        let mut store = MemoryStore::new();
+        store.insert(trusted, Status::Trusted);
+        let mut state = LightClientState::new(store);
+
+        client
+            .verify_to_target(target, &mut state)
+            .map_err(|e| Error::light_client_verification(self.chain_id.to_string(), e))
+    }
+
+    fn prepare_client(&self, client_state: &AnyClientState) -> Result<TmLightClient, Error> {
         let client_state =
             downcast!(client_state => AnyClientState::Tendermint).ok_or_else(|| {
                 Error::client_type_mismatch(ClientType::Tendermint, client_state.client_type())
@@ -197,7 +228,16 @@ impl LightClient {
             clock_drift: client_state.max_clock_drift,
         };
 
-        Ok(TmLightClient::new(
+        Ok(self.client_with_options(params))
+    }
+
+    fn client_with_options(&self, params: TmOptions) -> TmLightClient {
+        let clock = components::clock::SystemClock;
+        let hasher = operations::hasher::ProdHasher;
+        let verifier = ProdVerifier::default();
+        let scheduler = components::scheduler::basic_bisecting_schedule;
+
+        TmLightClient::new(
             self.peer_id,
             params,
             clock,
@@ -205,7 +245,7 @@ impl LightClient {
             verifier,
             hasher,
             self.io.clone(),
-        ))
+        )
     }
 
     fn prepare_state(&self, trusted: ICSHeight) -> Result<LightClientState, Error> {
@@ -1,3 +1,4 @@
+use alloc::sync::Arc;
 use ibc_relayer_types::core::{
     ics03_connection::connection::State as ConnectionState,
     ics04_channel::channel::State as ChannelState,
@@ -9,6 +10,8 @@ use crate::chain::requests::{QueryChannelRequest, QueryHeight};
 use crate::chain::{counterparty::check_channel_counterparty, requests::QueryConnectionRequest};
 use crate::chain::{handle::ChainHandle, requests::IncludeProof};
 use crate::channel::{Channel, ChannelSide};
+use crate::config::PathRelayModes;
+use crate::coordination::Coordinator;
 use crate::link::error::LinkError;
 
 pub mod cli;
@@ -172,6 +175,23 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
         Link::new(channel, with_tx_confirmation)
     }
 
+    /// Coordinate with other Hermes instances through the given [`Coordinator`],
+    /// so that only packets claimed by this instance get relayed.
+    pub fn set_coordinator(&mut self, coordinator: Arc<dyn Coordinator>) {
+        self.a_to_b.set_coordinator(coordinator);
+    }
+
+    /// Only relay the packet messages of the given [`PathRelayModes`] on this link.
+    pub fn set_relay_modes(&mut self, relay_modes: PathRelayModes) {
+        self.a_to_b.set_relay_modes(relay_modes);
+    }
+
+    /// Whether to skip the acknowledgements of the packets already acknowledged,
+    /// before relaying them on this link.
+    pub fn set_prune_acked_packets(&mut self, prune_acked_packets: bool) {
+        self.a_to_b.set_prune_acked_packets(prune_acked_packets);
+    }
+
     /// Constructs a link around the channel that is reverse to the channel
     /// in this link.
     pub fn reverse(
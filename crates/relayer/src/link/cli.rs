@@ -37,6 +37,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 odata,
                 &|| self.src_time_latest(),
                 &|| self.src_max_block_time(),
+                &|| self.src_estimated_block_time(),
                 &|| self.src_latest_height(),
             )?))
         } else if let Some(odata) = self.dst_operational_data.pop_front() {
@@ -44,6 +45,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 odata,
                 &|| self.dst_time_latest(),
                 &|| self.dst_max_block_time(),
+                &|| self.dst_estimated_block_time(),
                 &|| self.dst_latest_height(),
             )?))
         } else {
@@ -53,14 +55,17 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
     /// Given a vector of [`OperationalData`], this method proceeds to relaying
     /// all the messages therein. It accumulates all events generated in the
-    /// mutable vector of [`IbcEvent`]s.
+    /// mutable vector of [`IbcEvent`]s, after handing over the events of each
+    /// batch to `on_batch` as soon as the batch is committed.
     pub fn relay_and_accumulate_results(
         &self,
         from: Vec<OperationalData>,
         results: &mut Vec<IbcEvent>,
+        on_batch: &mut dyn FnMut(&[IbcEvent]),
     ) -> Result<(), LinkError> {
         for od in from {
             let mut last_res = self.relay_from_operational_data::<SyncSender>(od)?;
+            on_batch(&last_res.events);
             results.append(&mut last_res.events);
         }
 
@@ -72,10 +77,28 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
     pub fn relay_recv_packet_and_timeout_messages(&self) -> Result<Vec<IbcEvent>, LinkError> {
         self.relay_recv_packet_and_timeout_messages_with_packet_data_query_height(None)
     }
+
+    /// Same as [`Link::relay_recv_packet_and_timeout_messages`], handing over the events
+    /// of each batch of messages to `on_batch` as soon as the batch is committed.
+    pub fn relay_recv_packet_and_timeout_messages_with_progress(
+        &self,
+        on_batch: &mut dyn FnMut(&[IbcEvent]),
+    ) -> Result<Vec<IbcEvent>, LinkError> {
+        self.relay_recv_packets_and_timeouts(None, on_batch)
+    }
+
     /// Implements the `packet-recv` CLI
     pub fn relay_recv_packet_and_timeout_messages_with_packet_data_query_height(
         &self,
         packet_data_query_height: Option<Height>,
+    ) -> Result<Vec<IbcEvent>, LinkError> {
+        self.relay_recv_packets_and_timeouts(packet_data_query_height, &mut |_| {})
+    }
+
+    fn relay_recv_packets_and_timeouts(
+        &self,
+        packet_data_query_height: Option<Height>,
+        on_batch: &mut dyn FnMut(&[IbcEvent]),
     ) -> Result<Vec<IbcEvent>, LinkError> {
         let _span = error_span!(
             "relay_recv_packet_and_timeout_messages",
@@ -114,6 +137,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
             query_height,
             query_send_packet_events,
             TrackingId::new_static("packet-recv"),
+            on_batch,
         )
     }
 
@@ -121,10 +145,27 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
         self.relay_ack_packet_messages_with_packet_data_query_height(None)
     }
 
+    /// Same as [`Link::relay_ack_packet_messages`], handing over the events
+    /// of each batch of messages to `on_batch` as soon as the batch is committed.
+    pub fn relay_ack_packet_messages_with_progress(
+        &self,
+        on_batch: &mut dyn FnMut(&[IbcEvent]),
+    ) -> Result<Vec<IbcEvent>, LinkError> {
+        self.relay_acks(None, on_batch)
+    }
+
     /// Implements the `packet-ack` CLI
     pub fn relay_ack_packet_messages_with_packet_data_query_height(
         &self,
         packet_data_query_height: Option<Height>,
+    ) -> Result<Vec<IbcEvent>, LinkError> {
+        self.relay_acks(packet_data_query_height, &mut |_| {})
+    }
+
+    fn relay_acks(
+        &self,
+        packet_data_query_height: Option<Height>,
+        on_batch: &mut dyn FnMut(&[IbcEvent]),
     ) -> Result<Vec<IbcEvent>, LinkError> {
         let _span = error_span!(
             "relay_ack_packet_messages",
@@ -163,6 +204,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
             query_height,
             query_write_ack_events,
             TrackingId::new_static("packet-ack"),
+            on_batch,
         )
     }
 
@@ -172,6 +214,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
         query_height: Qualified<Height>,
         query_fn: QueryFn,
         tracking_id: TrackingId,
+        on_batch: &mut dyn FnMut(&[IbcEvent]),
     ) -> Result<Vec<IbcEvent>, LinkError>
     where
         QueryFn: Fn(
@@ -198,31 +241,38 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
             // In case of zero connection delay, the op. data will already be ready
             let (src_ods, dst_ods) = self.a_to_b.try_fetch_scheduled_operational_data()?;
             self.a_to_b
-                .relay_and_accumulate_results(Vec::from(src_ods), &mut results)?;
+                .relay_and_accumulate_results(Vec::from(src_ods), &mut results, on_batch)?;
             self.a_to_b
-                .relay_and_accumulate_results(Vec::from(dst_ods), &mut results)?;
+                .relay_and_accumulate_results(Vec::from(dst_ods), &mut results, on_batch)?;
         }
 
         // In case of non-zero connection delay, we block here waiting for all op.data
         // until the connection delay elapses
         while let Some(odata) = self.a_to_b.fetch_scheduled_operational_data()? {
             self.a_to_b
-                .relay_and_accumulate_results(vec![odata], &mut results)?;
+                .relay_and_accumulate_results(vec![odata], &mut results, on_batch)?;
         }
 
         Ok(results)
     }
 }
 
-fn wait_for_conn_delay<ChainTime, MaxBlockTime, LatestHeight>(
+/// Waits for the connection delay of the given operational data to elapse.
+///
+/// The connection block delay is derived from the `max_expected_time_per_block`, as
+/// on chain, whereas the time to wait for the remaining blocks is derived from the
+/// `estimated_time_per_block`, after which the remaining delay is checked again.
+fn wait_for_conn_delay<ChainTime, MaxBlockTime, EstimatedBlockTime, LatestHeight>(
     odata: OperationalData,
     chain_time: &ChainTime,
     max_expected_time_per_block: &MaxBlockTime,
+    estimated_time_per_block: &EstimatedBlockTime,
     latest_height: &LatestHeight,
 ) -> Result<OperationalData, LinkError>
 where
     ChainTime: Fn() -> Result<Instant, LinkError>,
     MaxBlockTime: Fn() -> Result<Duration, LinkError>,
+    EstimatedBlockTime: Fn() -> Result<Duration, LinkError>,
     LatestHeight: Fn() -> Result<Height, LinkError>,
 {
     let (time_left, blocks_left) =
@@ -247,10 +297,17 @@ where
 
             let blocks_left: u32 = blocks_left.try_into().expect("blocks_left > u32::MAX");
 
-            // Wait until the delay period passes
-            thread::sleep(blocks_left * max_expected_time_per_block()?);
+            // Wait until the remaining blocks are likely produced
+            thread::sleep(blocks_left * estimated_time_per_block()?);
 
-            Ok(odata)
+            // The estimate may be off, so recurse to recheck that all delays are handled.
+            wait_for_conn_delay(
+                odata,
+                chain_time,
+                max_expected_time_per_block,
+                estimated_time_per_block,
+                latest_height,
+            )
         }
         (time_left, _) => {
             info!(
@@ -268,6 +325,7 @@ where
                 odata,
                 chain_time,
                 max_expected_time_per_block,
+                estimated_time_per_block,
                 latest_height,
             )
         }
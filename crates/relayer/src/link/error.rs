@@ -1,4 +1,7 @@
 use flex_error::define_error;
+use tendermint::abci::Code;
+use tonic::Code as GrpcCode;
+
 use ibc_relayer_types::core::ics02_client::error::Error as Ics02Error;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
@@ -6,8 +9,11 @@ use ibc_relayer_types::Height;
 
 use crate::channel::ChannelError;
 use crate::connection::ConnectionError;
-use crate::error::Error;
+use crate::error::{Error, ErrorDetail};
 use crate::foreign_client::{ForeignClientError, HasExpiredOrFrozenError};
+use crate::sdk_error::{
+    sdk_error_from_tx_sync_error_code, ClientErrorDetail, SdkError, SdkErrorDetail,
+};
 use crate::supervisor::Error as SupervisorError;
 use crate::transfer::TransferError;
 
@@ -32,6 +38,17 @@ define_error! {
                 format!("failed to construct packet proofs for chain {0}", e.chain_id)
             },
 
+        PacketProofsCount
+            {
+                chain_id: ChainId,
+                expected: usize,
+                actual: usize,
+            }
+            |e| {
+                format!("chain {0} built {1} packet proofs while {2} were requested",
+                    e.chain_id, e.actual, e.expected)
+            },
+
         Query
             { chain_id: ChainId }
             [ Error ]
@@ -165,3 +182,157 @@ impl HasExpiredOrFrozenError for LinkError {
         self.detail().is_expired_or_frozen_error()
     }
 }
+
+/// The codes of the errors of the ibc-go channel module which are returned when
+/// another relayer already submitted the same message, eg. a packet was already
+/// received, acknowledged or timed out.
+///
+/// Cf: <https://github.com/cosmos/ibc-go/blob/v5.0.0/modules/core/04-channel/types/errors.go>
+const CHANNEL_RACE_ERROR_CODES: &[u32] = &[
+    17, // ErrAcknowledgementExists
+    19, // ErrPacketReceived
+    20, // ErrPacketCommitmentNotFound
+    22, // ErrNoOpMsg
+];
+
+/// How the link and its workers should react to an error.
+///
+/// The class of an error is derived from its typed detail, eg. the gRPC status code
+/// or the ABCI error code and codespace, rather than from its message, so that the
+/// reaction does not depend on the wording of the errors across node versions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// A transient failure, eg. a node which cannot be reached, worth retrying.
+    Retryable,
+
+    /// A failure which relaying cannot recover from, eg. an expired or frozen client.
+    Fatal,
+
+    /// A failure caused by the configuration of Hermes, eg. a missing key or
+    /// a maximum gas too low, which retrying does not fix.
+    Misconfiguration,
+
+    /// A failure caused by another relayer having submitted the same messages first,
+    /// eg. a packet which was already received, which is safe to ignore.
+    ExpectedRace,
+}
+
+impl LinkError {
+    pub fn class(&self) -> ErrorClass {
+        match self.detail() {
+            LinkErrorDetail::Client(e) if e.source.is_expired_or_frozen_error() => {
+                ErrorClass::Fatal
+            }
+            LinkErrorDetail::Relayer(e) => classify(&e.source),
+            LinkErrorDetail::Query(e) => classify(&e.source),
+            LinkErrorDetail::PacketProofsConstructor(e) => classify(&e.source),
+            LinkErrorDetail::ChannelNotFound(_) | LinkErrorDetail::Signer(_) => {
+                ErrorClass::Misconfiguration
+            }
+            _ => ErrorClass::Retryable,
+        }
+    }
+}
+
+/// The class of an error of the relayer, see [`ErrorClass`].
+pub fn classify(e: &Error) -> ErrorClass {
+    if e.is_trusted_state_outside_trusting_period_error() {
+        return ErrorClass::Fatal;
+    }
+
+    match e.detail() {
+        ErrorDetail::GrpcStatus(e) => classify_grpc_code(e.status.code()),
+        ErrorDetail::DeliverTx(e) => classify_sdk_error(&e.detail),
+        ErrorDetail::CheckTx(e) => match e.response.code {
+            Code::Ok => ErrorClass::Retryable,
+            Code::Err(code) => classify_sdk_error(&sdk_error_from_tx_sync_error_code(code)),
+        },
+        ErrorDetail::KeyBase(_)
+        | ErrorDetail::KeyNotFound(_)
+        | ErrorDetail::InvalidKeyAddress(_)
+        | ErrorDetail::InvalidUri(_)
+        | ErrorDetail::TxIndexingDisabled(_)
+        | ErrorDetail::NoHistoricalEntries(_)
+        | ErrorDetail::SdkModuleVersion(_)
+        | ErrorDetail::MessageTooBigForTx(_)
+        | ErrorDetail::ConfigValidationTxSizeOutOfBounds(_)
+        | ErrorDetail::ConfigValidationMaxGasTooHigh(_)
+        | ErrorDetail::ConfigValidationTrustingPeriodSmallerThanZero(_)
+        | ErrorDetail::ConfigValidationTrustingPeriodGreaterThanUnbondingPeriod(_)
+        | ErrorDetail::ConfigValidationDefaultGasTooHigh(_)
+        | ErrorDetail::ConfigValidationGasMultiplierLow(_)
+        | ErrorDetail::TxSimulateGasEstimateExceeded(_) => ErrorClass::Misconfiguration,
+        _ => ErrorClass::Retryable,
+    }
+}
+
+fn classify_grpc_code(code: GrpcCode) -> ErrorClass {
+    match code {
+        GrpcCode::Unauthenticated | GrpcCode::PermissionDenied | GrpcCode::Unimplemented => {
+            ErrorClass::Misconfiguration
+        }
+        _ => ErrorClass::Retryable,
+    }
+}
+
+fn classify_sdk_error(e: &SdkError) -> ErrorClass {
+    match e.detail() {
+        SdkErrorDetail::OutOfGas(_) | SdkErrorDetail::InsufficientFee(_) => {
+            ErrorClass::Misconfiguration
+        }
+        SdkErrorDetail::Client(e) => match e.source {
+            ClientErrorDetail::FrozenLightClient(_) => ErrorClass::Fatal,
+            _ => ErrorClass::Retryable,
+        },
+        SdkErrorDetail::UnknownSdk(e)
+            if e.codespace == "channel" && CHANNEL_RACE_ERROR_CODES.contains(&e.code) =>
+        {
+            ErrorClass::ExpectedRace
+        }
+        _ => ErrorClass::Retryable,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_sdk_errors() {
+        assert_eq!(
+            classify_sdk_error(&SdkError::out_of_gas(11)),
+            ErrorClass::Misconfiguration
+        );
+        assert_eq!(
+            classify_sdk_error(&SdkError::unknown_sdk("channel".to_string(), 19)),
+            ErrorClass::ExpectedRace
+        );
+        assert_eq!(
+            classify_sdk_error(&SdkError::unknown_sdk("sdk".to_string(), 19)),
+            ErrorClass::Retryable
+        );
+    }
+
+    #[test]
+    fn classify_link_errors() {
+        assert_eq!(
+            LinkError::relayer(Error::key_not_found(
+                "relayer".to_string(),
+                crate::keyring::errors::Error::key_not_found()
+            ))
+            .class(),
+            ErrorClass::Misconfiguration
+        );
+        assert_eq!(
+            LinkError::relayer(Error::grpc_status(tonic::Status::unavailable(
+                "node is down"
+            )))
+            .class(),
+            ErrorClass::Retryable
+        );
+        assert_eq!(
+            LinkError::update_client_failed().class(),
+            ErrorClass::Retryable
+        );
+    }
+}
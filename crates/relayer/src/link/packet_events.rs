@@ -86,12 +86,25 @@ pub fn query_send_packet_events<ChainA: ChainHandle>(
     path: &PathIdentifiers,
     sequences: &[Sequence],
     src_query_height: Qualified<Height>,
+) -> Result<Vec<IbcEventWithHeight>, Error> {
+    query_send_packet_events_since(src_chain, path, sequences, src_query_height, None)
+}
+
+/// Returns the same packet events as [`query_send_packet_events`], restricted
+/// to the ones which occurred at or after `min_height`, if set.
+pub fn query_send_packet_events_since<ChainA: ChainHandle>(
+    src_chain: &ChainA,
+    path: &PathIdentifiers,
+    sequences: &[Sequence],
+    src_query_height: Qualified<Height>,
+    min_height: Option<Height>,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
     let _span = span!(
         Level::DEBUG,
         "query_send_packet_events",
         chain = %src_chain.id(),
         height = %src_query_height,
+        ?min_height,
         ?sequences,
     )
     .entered();
@@ -104,6 +117,8 @@ pub fn query_send_packet_events<ChainA: ChainHandle>(
         destination_channel_id: path.channel_id.clone(),
         sequences: sequences.to_vec(),
         height: src_query_height.map(QueryHeight::Specific),
+        min_height,
+        pagination: None,
     };
 
     query_packet_events(src_chain, query)
@@ -116,12 +131,25 @@ pub fn query_write_ack_events<ChainA: ChainHandle>(
     path: &PathIdentifiers,
     sequences: &[Sequence],
     src_query_height: Qualified<Height>,
+) -> Result<Vec<IbcEventWithHeight>, Error> {
+    query_write_ack_events_since(src_chain, path, sequences, src_query_height, None)
+}
+
+/// Returns the same packet events as [`query_write_ack_events`], restricted
+/// to the ones which occurred at or after `min_height`, if set.
+pub fn query_write_ack_events_since<ChainA: ChainHandle>(
+    src_chain: &ChainA,
+    path: &PathIdentifiers,
+    sequences: &[Sequence],
+    src_query_height: Qualified<Height>,
+    min_height: Option<Height>,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
     let _span = span!(
         Level::DEBUG,
         "query_write_ack_packet_events",
         chain = %src_chain.id(),
         height = %src_query_height,
+        ?min_height,
         ?sequences,
     )
     .entered();
@@ -134,6 +162,8 @@ pub fn query_write_ack_events<ChainA: ChainHandle>(
         destination_channel_id: path.counterparty_channel_id.clone(),
         sequences: sequences.to_vec(),
         height: src_query_height.map(QueryHeight::Specific),
+        min_height,
+        pagination: None,
     };
 
     query_packet_events(src_chain, query)
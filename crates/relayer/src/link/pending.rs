@@ -18,7 +18,22 @@ use crate::{
     link::{operational_data::OperationalData, relay_sender::AsyncReply, RelaySummary, TxHashes},
 };
 
-pub const TIMEOUT: Duration = Duration::from_secs(300);
+/// The number of blocks after which a pending transaction which
+/// was not committed yet is considered to have timed out.
+pub const TIMEOUT_BLOCKS: u32 = 50;
+
+/// The minimum time after which a pending transaction which was not committed yet
+/// is considered to have timed out, regardless of how fast the chain produces blocks.
+pub const MIN_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// The time after which a pending transaction which was not committed yet is considered
+/// to have timed out, given the estimated time it takes the chain to produce a block.
+///
+/// The timeout is never shorter than [`MIN_TIMEOUT`], so that transactions which are
+/// slow to be included in a block on fast chains are not resubmitted needlessly.
+pub fn timeout(block_time: Duration) -> Duration {
+    (block_time * TIMEOUT_BLOCKS).max(MIN_TIMEOUT)
+}
 
 /// A wrapper over an [`OperationalData`] that is pending.
 /// Additionally holds all the necessary information
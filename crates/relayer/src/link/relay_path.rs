@@ -1,5 +1,7 @@
 use alloc::collections::BTreeMap as HashMap;
 use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use std::collections::HashSet;
 use std::ops::Sub;
 use std::time::{Duration, Instant};
 
@@ -11,6 +13,7 @@ use crate::chain::counterparty::unreceived_acknowledgements;
 use crate::chain::counterparty::unreceived_packets;
 use crate::chain::endpoint::ChainStatus;
 use crate::chain::handle::ChainHandle;
+use crate::chain::requests::BuildPacketProofsRequest;
 use crate::chain::requests::QueryChannelRequest;
 use crate::chain::requests::QueryClientEventRequest;
 use crate::chain::requests::QueryHeight;
@@ -25,6 +28,8 @@ use crate::chain::tracking::TrackedMsgs;
 use crate::chain::tracking::TrackingId;
 use crate::channel::error::ChannelError;
 use crate::channel::Channel;
+use crate::config::{default, PathRelayModes};
+use crate::coordination::{Coordinator, PacketClaim};
 use crate::event::monitor::EventBatch;
 use crate::event::IbcEventWithHeight;
 use crate::foreign_client::{ForeignClient, ForeignClientError};
@@ -34,19 +39,25 @@ use crate::link::operational_data::{
 };
 use crate::link::packet_events::query_packet_events_with;
 use crate::link::packet_events::query_send_packet_events;
+use crate::link::packet_events::query_send_packet_events_since;
 use crate::link::packet_events::query_write_ack_events;
+use crate::link::packet_events::query_write_ack_events_since;
 use crate::link::pending::PendingTxs;
 use crate::link::relay_sender::{AsyncReply, SubmitReply};
 use crate::link::relay_summary::RelaySummary;
 use crate::link::{pending, relay_sender};
-use crate::path::PathIdentifiers;
+use crate::path::{Path, PathEnd, PathIdentifiers};
 use crate::telemetry;
+use crate::util::block_time::BlockTimeEstimator;
 use crate::util::pretty::PrettyEvents;
 use crate::util::queue::Queue;
 use ibc_relayer_types::{
+    applications::transfer::limits::TransferLimits,
+    applications::wasm::acknowledgement::ContractError,
     core::{
         ics02_client::events::ClientMisbehaviour as ClientMisbehaviourEvent,
         ics04_channel::{
+            ack_status::AckStatus,
             channel::{ChannelEnd, Order, State as ChannelState},
             events::{SendPacket, WriteAcknowledgement},
             msgs::{
@@ -54,11 +65,12 @@ use ibc_relayer_types::{
                 recv_packet::MsgRecvPacket, timeout::MsgTimeout,
                 timeout_on_close::MsgTimeoutOnClose,
             },
-            packet::{Packet, PacketMsgType},
+            packet::{Packet, PacketMsgType, Sequence},
         },
         ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId},
     },
-    events::{IbcEvent, WithBlockDataType},
+    events::{IbcEvent, IbcEventType, WithBlockDataType},
+    proofs::Proofs,
     signer::Signer,
     timestamp::Timestamp,
     tx_msg::Msg,
@@ -67,6 +79,10 @@ use ibc_relayer_types::{
 
 const MAX_RETRIES: usize = 5;
 
+/// Maximum number of blocks searched for packet events when resuming relaying from the
+/// last scanned height. Larger ranges are handled by clearing the pending packets instead.
+pub const MAX_RESUME_BLOCKS: u64 = 1000;
+
 /// Whether or not to resubmit packets when pending transactions
 /// fail to process within the given timeout duration.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -75,6 +91,17 @@ pub enum Resubmit {
     No,
 }
 
+/// A message to the destination chain collected out of an event,
+/// whose packet proofs may still have to be built.
+///
+/// The proofs of the packets relayed out of a set of events are built
+/// in a single batch, once the messages of all the events are collected.
+enum DstMsg<'a> {
+    Built(Any),
+    RecvPacket(&'a Packet, Height),
+    Ack(&'a WriteAcknowledgement, Height),
+}
+
 impl Resubmit {
     /// Packet resubmission is enabled when the clear interval for packets is 0. Otherwise,
     /// when the packet clear interval is > 0, the relayer will periodically clear unsent packets
@@ -111,6 +138,26 @@ pub struct RelayPath<ChainA: ChainHandle, ChainB: ChainHandle> {
     // transactions if [`confirm_txes`] is true.
     pending_txs_src: PendingTxs<ChainA>,
     pending_txs_dst: PendingTxs<ChainB>,
+
+    // Claims packets before relaying them, to avoid relaying the same
+    // packets as other Hermes instances, if coordination is enabled.
+    coordinator: Option<Arc<dyn Coordinator>>,
+
+    // Estimate the time it takes the source and destination chains to
+    // produce a block, based on the blocks observed while relaying.
+    src_block_time: BlockTimeEstimator,
+    dst_block_time: BlockTimeEstimator,
+
+    // The limits on the ICS-20 transfers accepted by the destination chain,
+    // beyond which packets are not relayed since they would fail anyway.
+    dst_transfer_limits: TransferLimits,
+
+    // Which of the packet messages are relayed on this path.
+    relay_modes: PathRelayModes,
+
+    // Whether to skip the acknowledgements of the packets already acknowledged
+    // on the source chain, checking all of them at once before relaying them.
+    prune_acked_packets: bool,
 }
 
 impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
@@ -137,6 +184,16 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         let src_port_id = channel.src_port_id().clone();
         let dst_port_id = channel.dst_port_id().clone();
 
+        let src_block_time = BlockTimeEstimator::new(
+            src_chain
+                .config()
+                .map_err(LinkError::relayer)?
+                .max_block_time,
+        );
+        let dst_config = dst_chain.config().map_err(LinkError::relayer)?;
+        let dst_block_time = BlockTimeEstimator::new(dst_config.max_block_time);
+        let dst_transfer_limits = dst_config.transfer_limits;
+
         let path = PathIdentifiers {
             port_id: dst_port_id.clone(),
             channel_id: dst_channel_id.clone(),
@@ -155,9 +212,41 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             confirm_txes: with_tx_confirmation,
             pending_txs_src: PendingTxs::new(src_chain, src_channel_id, src_port_id, dst_chain_id),
             pending_txs_dst: PendingTxs::new(dst_chain, dst_channel_id, dst_port_id, src_chain_id),
+
+            coordinator: None,
+
+            src_block_time,
+            dst_block_time,
+
+            dst_transfer_limits,
+
+            relay_modes: PathRelayModes::default(),
+
+            prune_acked_packets: default::prune_acked_packets(),
         })
     }
 
+    /// Coordinate with other Hermes instances through the given [`Coordinator`],
+    /// so that only packets claimed by this instance get relayed.
+    pub fn set_coordinator(&mut self, coordinator: Arc<dyn Coordinator>) {
+        self.coordinator = Some(coordinator);
+    }
+
+    /// Only relay the packet messages of the given [`PathRelayModes`] on this path.
+    pub fn set_relay_modes(&mut self, relay_modes: PathRelayModes) {
+        self.relay_modes = relay_modes;
+    }
+
+    pub fn relay_modes(&self) -> PathRelayModes {
+        self.relay_modes
+    }
+
+    /// Whether to skip the acknowledgements of the packets already acknowledged
+    /// on the source chain, before relaying them.
+    pub fn set_prune_acked_packets(&mut self, prune_acked_packets: bool) {
+        self.prune_acked_packets = prune_acked_packets;
+    }
+
     pub fn src_chain(&self) -> &ChainA {
         self.channel.src_chain()
     }
@@ -202,6 +291,27 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self.channel
     }
 
+    /// The path this relay path relays packets over, with the source
+    /// chain on the `a_side` and the destination chain on the `b_side`.
+    pub fn path(&self) -> Path {
+        Path::new(
+            PathEnd {
+                chain_id: self.src_chain().id(),
+                client_id: self.src_client_id().clone(),
+                connection_id: self.src_connection_id().clone(),
+                port_id: self.src_port_id().clone(),
+                channel_id: self.src_channel_id().clone(),
+            },
+            PathEnd {
+                chain_id: self.dst_chain().id(),
+                client_id: self.dst_client_id().clone(),
+                connection_id: self.dst_connection_id().clone(),
+                port_id: self.dst_port_id().clone(),
+                channel_id: self.dst_channel_id().clone(),
+            },
+        )
+    }
+
     fn src_channel(&self, height_query: QueryHeight) -> Result<ChannelEnd, LinkError> {
         self.src_chain()
             .query_channel(
@@ -263,28 +373,22 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     }
 
     pub(crate) fn src_time_latest(&self) -> Result<Instant, LinkError> {
+        let status = self.src_chain().query_application_status().unwrap();
+        self.src_block_time.observe(status.height, status.timestamp);
+
         let elapsed = Timestamp::now()
-            .duration_since(
-                &self
-                    .src_chain()
-                    .query_application_status()
-                    .unwrap()
-                    .timestamp,
-            )
+            .duration_since(&status.timestamp)
             .unwrap_or_default();
 
         Ok(Instant::now().sub(elapsed))
     }
 
     pub(crate) fn dst_time_latest(&self) -> Result<Instant, LinkError> {
+        let status = self.dst_chain().query_application_status().unwrap();
+        self.dst_block_time.observe(status.height, status.timestamp);
+
         let elapsed = Timestamp::now()
-            .duration_since(
-                &self
-                    .dst_chain()
-                    .query_application_status()
-                    .unwrap()
-                    .timestamp,
-            )
+            .duration_since(&status.timestamp)
             .unwrap_or_default();
 
         Ok(Instant::now().sub(elapsed))
@@ -308,6 +412,36 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .max_block_time)
     }
 
+    /// The estimated time it takes the source chain to produce a block,
+    /// refined with the latest status of the chain if a new block is likely.
+    pub(crate) fn src_estimated_block_time(&self) -> Result<Duration, LinkError> {
+        if self.src_block_time.needs_sample() {
+            let status = self
+                .src_chain()
+                .query_application_status()
+                .map_err(|e| LinkError::query(self.src_chain().id(), e))?;
+
+            self.src_block_time.observe(status.height, status.timestamp);
+        }
+
+        Ok(self.src_block_time.estimate())
+    }
+
+    /// The estimated time it takes the destination chain to produce a block,
+    /// refined with the latest status of the chain if a new block is likely.
+    pub(crate) fn dst_estimated_block_time(&self) -> Result<Duration, LinkError> {
+        if self.dst_block_time.needs_sample() {
+            let status = self
+                .dst_chain()
+                .query_application_status()
+                .map_err(|e| LinkError::query(self.dst_chain().id(), e))?;
+
+            self.dst_block_time.observe(status.height, status.timestamp);
+        }
+
+        Ok(self.dst_block_time.estimate())
+    }
+
     fn unordered_channel(&self) -> bool {
         self.channel.ordering == Order::Unordered
     }
@@ -439,6 +573,80 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(())
     }
 
+    /// Schedules the pending packets which were sent or acknowledged on the source chain in
+    /// the blocks from `from` to `to`, eg. while Hermes was not running, by querying the events
+    /// of these packets within this height window rather than in the whole history of the
+    /// source chain.
+    ///
+    /// Pending packets which were sent or acknowledged before `from` are left to be cleared
+    /// at the next clear interval. If the range spans more than [`MAX_RESUME_BLOCKS`] blocks
+    /// or different revisions of the source chain, the pending packets are cleared instead.
+    pub fn schedule_packets_since(&self, from: Height, to: Height) -> Result<(), LinkError> {
+        let _span = span!(Level::ERROR, "schedule_packets_since", %from, %to).entered();
+
+        if to < from {
+            return Ok(());
+        }
+
+        if from.revision_number() != to.revision_number()
+            || to.revision_height() - from.revision_height() >= MAX_RESUME_BLOCKS
+        {
+            warn!(
+                "too many blocks to scan (max: {}), clearing pending packets instead",
+                MAX_RESUME_BLOCKS
+            );
+
+            return self.relay_pending_packets(Some(to));
+        }
+
+        let tracking_id = TrackingId::new_cleared_uuid();
+        telemetry!(received_event_batch, tracking_id);
+
+        let (sequences, _) = unreceived_packets(self.dst_chain(), self.src_chain(), &self.path_id)
+            .map_err(LinkError::supervisor)?;
+
+        for events_chunk in query_packet_events_with(
+            &sequences,
+            Qualified::SmallerEqual(to),
+            self.src_chain(),
+            &self.path_id,
+            move |chain, path, sequences, height| {
+                query_send_packet_events_since(chain, path, sequences, height, Some(from))
+            },
+        ) {
+            self.events_to_operational_data(TrackedEvents::new(events_chunk, tracking_id))?;
+        }
+
+        let (sequences, _) =
+            unreceived_acknowledgements(self.dst_chain(), self.src_chain(), &self.path_id)
+                .map_err(LinkError::supervisor)?;
+
+        for events_chunk in query_packet_events_with(
+            &sequences,
+            Qualified::SmallerEqual(to),
+            self.src_chain(),
+            &self.path_id,
+            move |chain, path, sequences, height| {
+                query_write_ack_events_since(chain, path, sequences, height, Some(from))
+            },
+        ) {
+            self.events_to_operational_data(TrackedEvents::new(events_chunk, tracking_id))?;
+        }
+
+        debug!("done scheduling pending packets since last scanned height");
+
+        Ok(())
+    }
+
+    /// Whether all the operational data scheduled so far has been submitted and, unless
+    /// transaction confirmation is disabled, its transactions confirmed.
+    pub fn is_idle(&self) -> bool {
+        self.src_operational_data.is_empty()
+            && self.dst_operational_data.is_empty()
+            && self.pending_txs_src.pending_queue.is_empty()
+            && self.pending_txs_dst.pending_queue.is_empty()
+    }
+
     /// Generate & schedule operational data from the input `batch` of IBC events.
     pub fn update_schedule(&self, batch: EventBatch) -> Result<(), LinkError> {
         let _span = span!(
@@ -512,6 +720,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .query_application_status()
             .map_err(|e| LinkError::query(self.src_chain().id(), e))?;
 
+        self.dst_block_time
+            .observe(dst_latest_info.height, dst_latest_info.timestamp);
+
         let dst_latest_height = dst_latest_info.height;
 
         // Operational data targeting the source chain (e.g., Timeout packets)
@@ -530,12 +741,19 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             self.channel.connection_delay,
         );
 
+        // Messages to be sent to the destination chain, in the order of their events
+        let mut dst_msgs = Vec::new();
+
+        let unreceived_acks = self.unreceived_acks_on_src(input.iter())?;
+
         for event_with_height in input {
             trace!(event = %event_with_height, "processing event");
 
             let (dst_msg, src_msg) = match &event_with_height.event {
                 IbcEvent::CloseInitChannel(_) => (
-                    Some(self.build_chan_close_confirm_from_event(event_with_height)?),
+                    Some(DstMsg::Built(
+                        self.build_chan_close_confirm_from_event(event_with_height)?,
+                    )),
                     None,
                 ),
                 IbcEvent::TimeoutPacket(_) => {
@@ -550,7 +768,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                             .state_matches(&ChannelState::Closed)
                     {
                         (
-                            Some(self.build_chan_close_confirm_from_event(event_with_height)?),
+                            Some(DstMsg::Built(
+                                self.build_chan_close_confirm_from_event(event_with_height)?,
+                            )),
                             None,
                         )
                     } else {
@@ -561,6 +781,17 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                     if self.send_packet_event_handled(event)? {
                         debug!(?event, "SendPacket event has already been handled");
 
+                        (None, None)
+                    } else if self.relay_modes.relays_recv_packets()
+                        // Packets are not claimed when only relaying timeouts, so as not
+                        // to hold off the instances relaying them before they time out.
+                        && !self.claim_packet(IbcEventType::SendPacket, event.packet.sequence)
+                    {
+                        debug!(
+                            ?event,
+                            "SendPacket event is claimed by another relayer instance"
+                        );
+
                         (None, None)
                     } else {
                         self.build_recv_or_timeout_from_send_packet_event(
@@ -571,23 +802,35 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                     }
                 }
                 IbcEvent::WriteAcknowledgement(ref event) => {
-                    if self
+                    if !self.relay_modes.relays_acks() {
+                        trace!(
+                            ?event,
+                            "not relaying acknowledgement in {} mode",
+                            self.relay_modes.dst
+                        );
+
+                        (None, None)
+                    } else if self
                         .dst_channel(QueryHeight::Latest)?
                         .state_matches(&ChannelState::Closed)
                     {
                         (None, None)
-                    } else if self.write_ack_event_handled(event)? {
+                    } else if self.write_ack_event_handled(event, &unreceived_acks)? {
                         debug!(
                             ?event,
                             "WriteAcknowledgement event has already been handled"
                         );
 
+                        (None, None)
+                    } else if !self.claim_packet(IbcEventType::WriteAck, event.packet.sequence) {
+                        debug!(
+                            ?event,
+                            "WriteAcknowledgement event is claimed by another relayer instance"
+                        );
+
                         (None, None)
                     } else {
-                        (
-                            self.build_ack_from_recv_event(event, event_with_height.height)?,
-                            None,
-                        )
+                        (Some(DstMsg::Ack(event, event_with_height.height)), None)
                     }
                 }
                 _ => (None, None),
@@ -595,12 +838,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
             // Collect messages to be sent to the destination chain (e.g., RecvPacket)
             if let Some(msg) = dst_msg {
-                trace!(%msg.type_url, event = %event_with_height, "collected event");
-
-                dst_od.batch.push(TransitMessage {
-                    event_with_height: event_with_height.clone(),
-                    msg,
-                });
+                dst_msgs.push((event_with_height.clone(), msg));
             }
 
             // Collect timeout messages, to be sent to the source chain
@@ -618,12 +856,90 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             }
         }
 
+        dst_od.batch = self.build_dst_msgs(dst_msgs)?;
+
         let src_od = Some(src_od).filter(|s| !s.batch.is_empty());
         let dst_od = Some(dst_od).filter(|s| !s.batch.is_empty());
 
         Ok((src_od, dst_od))
     }
 
+    /// Builds the messages to the destination chain collected out of a set of events,
+    /// requesting the proofs of all their packets from the source chain at once.
+    fn build_dst_msgs(
+        &self,
+        msgs: Vec<(IbcEventWithHeight, DstMsg<'_>)>,
+    ) -> Result<Vec<TransitMessage>, LinkError> {
+        let requests: Vec<_> = msgs
+            .iter()
+            .filter_map(|(_, msg)| match msg {
+                DstMsg::Built(_) => None,
+                DstMsg::RecvPacket(packet, height) => Some(BuildPacketProofsRequest {
+                    packet_type: PacketMsgType::Recv,
+                    port_id: packet.source_port.clone(),
+                    channel_id: packet.source_channel.clone(),
+                    sequence: packet.sequence,
+                    height: *height,
+                }),
+                DstMsg::Ack(event, height) => Some(BuildPacketProofsRequest {
+                    packet_type: PacketMsgType::Ack,
+                    port_id: event.packet.destination_port.clone(),
+                    channel_id: event.packet.destination_channel.clone(),
+                    sequence: event.packet.sequence,
+                    height: *height,
+                }),
+            })
+            .collect();
+
+        let request_count = requests.len();
+
+        let proofs = if requests.is_empty() {
+            Vec::new()
+        } else {
+            self.src_chain()
+                .build_packet_proofs_batch(requests)
+                .map_err(|e| LinkError::packet_proofs_constructor(self.src_chain().id(), e))?
+        };
+
+        // The proofs are matched with the messages in the order of the requests,
+        // which is only sound if there is exactly one for each request.
+        if proofs.len() != request_count {
+            return Err(LinkError::packet_proofs_count(
+                self.src_chain().id(),
+                request_count,
+                proofs.len(),
+            ));
+        }
+
+        let mut proofs = proofs.into_iter();
+        let mut next_proofs = || {
+            proofs
+                .next()
+                .expect("there is one proof for each request, as checked above")
+        };
+
+        msgs.into_iter()
+            .map(|(event_with_height, msg)| {
+                let msg = match msg {
+                    DstMsg::Built(msg) => msg,
+                    DstMsg::RecvPacket(packet, _) => {
+                        self.build_recv_packet(packet, next_proofs())?
+                    }
+                    DstMsg::Ack(event, _) => {
+                        self.build_ack_from_recv_event(event, next_proofs())?
+                    }
+                };
+
+                trace!(%msg.type_url, event = %event_with_height, "collected event");
+
+                Ok(TransitMessage {
+                    event_with_height,
+                    msg,
+                })
+            })
+            .collect()
+    }
+
     /// Relays an [`OperationalData`] using a specific
     /// sender, which implements [`relay_sender::Submit`].
     pub(crate) fn relay_from_operational_data<S: relay_sender::Submit>(
@@ -840,6 +1156,46 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             || self.send_packet_commitment_cleared_on_src(&sp.packet)?)
     }
 
+    /// Returns the sequences of the packets of the given `WriteAcknowledgement` events
+    /// whose acknowledgement was not received yet on the source chain of the packets,
+    /// ie. the destination chain of the relay path that sends the acknowledgments.
+    ///
+    /// The commitments of all the packets are checked with a single query, so that
+    /// the acknowledgements of large batches of packets already acknowledged, eg. by
+    /// another relayer or by transactions submitted before a restart, are skipped at
+    /// once. Returns `None` if the acknowledged packets are not pruned, in which case
+    /// each packet is checked on its own by [`Self::write_ack_event_handled`].
+    fn unreceived_acks_on_src<'a>(
+        &self,
+        events: impl Iterator<Item = &'a IbcEventWithHeight>,
+    ) -> Result<Option<HashSet<Sequence>>, LinkError> {
+        if !self.prune_acked_packets {
+            return Ok(None);
+        }
+
+        let sequences: Vec<Sequence> = events
+            .filter_map(|event_with_height| match &event_with_height.event {
+                IbcEvent::WriteAcknowledgement(event) => Some(event.packet.sequence),
+                _ => None,
+            })
+            .collect();
+
+        if sequences.is_empty() {
+            return Ok(Some(HashSet::new()));
+        }
+
+        let unreceived_acks = self
+            .dst_chain()
+            .query_unreceived_acknowledgements(QueryUnreceivedAcksRequest {
+                port_id: self.dst_port_id().clone(),
+                channel_id: self.dst_channel_id().clone(),
+                packet_ack_sequences: sequences,
+            })
+            .map_err(LinkError::relayer)?;
+
+        Ok(Some(unreceived_acks.into_iter().collect()))
+    }
+
     /// Checks if an acknowledgement for the given packet has been received on
     /// source chain of the packet, ie. the destination chain of the relay path
     /// that sends the acknowledgment.
@@ -856,9 +1212,42 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(unreceived_ack.is_empty())
     }
 
-    /// Checks if a receive packet event has already been handled (e.g. by another relayer).
-    fn write_ack_event_handled(&self, rp: &WriteAcknowledgement) -> Result<bool, LinkError> {
-        self.recv_packet_acknowledged_on_src(&rp.packet)
+    /// Checks if a receive packet event has already been handled (e.g. by another relayer),
+    /// given the unreceived acknowledgements returned by [`Self::unreceived_acks_on_src`].
+    fn write_ack_event_handled(
+        &self,
+        rp: &WriteAcknowledgement,
+        unreceived_acks: &Option<HashSet<Sequence>>,
+    ) -> Result<bool, LinkError> {
+        match unreceived_acks {
+            Some(unreceived) => Ok(!unreceived.contains(&rp.packet.sequence)),
+            None => self.recv_packet_acknowledged_on_src(&rp.packet),
+        }
+    }
+
+    /// Whether this instance may relay the message for the packet with the given
+    /// sequence, in case coordination with other instances is enabled.
+    ///
+    /// If the claim cannot be checked, the packet is relayed anyway, since
+    /// relaying a packet twice is preferable to not relaying it at all.
+    fn claim_packet(&self, event_type: IbcEventType, sequence: Sequence) -> bool {
+        let coordinator = match &self.coordinator {
+            Some(coordinator) => coordinator,
+            None => return true,
+        };
+
+        let claim = PacketClaim {
+            chain_id: self.src_chain().id(),
+            port_id: self.src_port_id().clone(),
+            channel_id: self.src_channel_id().clone(),
+            event_type,
+            sequence,
+        };
+
+        coordinator.try_claim(&claim).unwrap_or_else(|e| {
+            warn!(%claim, "failed to claim packet, relaying it anyway: {}", e);
+            true
+        })
     }
 
     /// Returns the `processed_height` for the consensus state at specified height
@@ -1151,6 +1540,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         )
         .entered();
 
+        if !self.relay_modes.relays_acks() {
+            return Ok(());
+        }
+
         let (sequences, src_response_height) =
             unreceived_acknowledgements(self.dst_chain(), self.src_chain(), &self.path_id)
                 .map_err(LinkError::supervisor)?;
@@ -1185,53 +1578,46 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(())
     }
 
-    fn build_recv_packet(&self, packet: &Packet, height: Height) -> Result<Option<Any>, LinkError> {
-        let proofs = self
-            .src_chain()
-            .build_packet_proofs(
-                PacketMsgType::Recv,
-                &packet.source_port,
-                &packet.source_channel,
-                packet.sequence,
-                height,
-            )
-            .map_err(|e| LinkError::packet_proofs_constructor(self.src_chain().id(), e))?;
-
-        let msg = MsgRecvPacket::new(packet.clone(), proofs.clone(), self.dst_signer()?);
-
+    fn build_recv_packet(&self, packet: &Packet, proofs: Proofs) -> Result<Any, LinkError> {
         trace!(packet = %packet, height = %proofs.height(), "built recv_packet msg");
 
-        Ok(Some(msg.to_any()))
+        let msg = MsgRecvPacket::new(packet.clone(), proofs, self.dst_signer()?);
+
+        Ok(msg.to_any())
     }
 
     fn build_ack_from_recv_event(
         &self,
         event: &WriteAcknowledgement,
-        height: Height,
-    ) -> Result<Option<Any>, LinkError> {
+        proofs: Proofs,
+    ) -> Result<Any, LinkError> {
         let packet = event.packet.clone();
 
-        let proofs = self
-            .src_chain()
-            .build_packet_proofs(
-                PacketMsgType::Ack,
-                &packet.destination_port,
-                &packet.destination_channel,
-                packet.sequence,
-                height,
-            )
-            .map_err(|e| LinkError::packet_proofs_constructor(self.src_chain().id(), e))?;
+        match AckStatus::from_bytes(&event.ack) {
+            AckStatus::Success => {}
+            AckStatus::Error(err) if packet.destination_port.is_wasm() => {
+                warn!(
+                    %packet,
+                    contract = packet.destination_port.wasm_contract_address(),
+                    "contract failed to handle packet: {}",
+                    ContractError::parse(&err)
+                );
+            }
+            AckStatus::Error(err) => {
+                warn!(
+                    %packet,
+                    "application failed to handle packet, relaying error acknowledgement: {}",
+                    err
+                );
+            }
+        }
 
-        let msg = MsgAcknowledgement::new(
-            packet,
-            event.ack.clone().into(),
-            proofs.clone(),
-            self.dst_signer()?,
-        );
+        trace!(packet = %packet, height = %proofs.height(), "built acknowledgment msg");
 
-        trace!(packet = %msg.packet, height = %proofs.height(), "built acknowledgment msg");
+        let msg =
+            MsgAcknowledgement::new(packet, event.ack.clone().into(), proofs, self.dst_signer()?);
 
-        Ok(Some(msg.to_any()))
+        Ok(msg.to_any())
     }
 
     fn build_timeout_packet(
@@ -1330,17 +1716,39 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 
-    fn build_recv_or_timeout_from_send_packet_event(
+    fn build_recv_or_timeout_from_send_packet_event<'a>(
         &self,
-        event: &SendPacket,
+        event: &'a SendPacket,
         dst_info: &ChainStatus,
         height: Height,
-    ) -> Result<(Option<Any>, Option<Any>), LinkError> {
+    ) -> Result<(Option<DstMsg<'a>>, Option<Any>), LinkError> {
         let timeout = self.build_timeout_from_send_packet_event(event, dst_info)?;
         if timeout.is_some() {
-            Ok((None, timeout))
+            if self.relay_modes.relays_timeouts() {
+                Ok((None, timeout))
+            } else {
+                trace!(packet = %event.packet, "not relaying timeout in {} mode", self.relay_modes.src);
+
+                Ok((None, None))
+            }
+        } else if !self.relay_modes.relays_recv_packets() {
+            trace!(packet = %event.packet, "not relaying packet in {} mode", self.relay_modes.src);
+
+            Ok((None, None))
+        } else if let Err(e) = self
+            .dst_transfer_limits
+            .check_packet_data(&event.packet.data)
+        {
+            warn!(
+                packet = %event.packet,
+                "not relaying packet which would be rejected by the destination chain, \
+                it will be timed out instead: {}",
+                e
+            );
+
+            Ok((None, None))
         } else {
-            Ok((self.build_recv_packet(&event.packet, height)?, None))
+            Ok((Some(DstMsg::RecvPacket(&event.packet, height)), None))
         }
     }
 
@@ -1490,6 +1898,12 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     }
 
     fn process_pending_txs_src(&self, resubmit: Resubmit) -> Result<RelaySummary, LinkError> {
+        if self.pending_txs_src.pending_queue.is_empty() {
+            return Ok(RelaySummary::empty());
+        }
+
+        let timeout = pending::timeout(self.src_estimated_block_time()?);
+
         let do_resubmit = match resubmit {
             Resubmit::Yes => {
                 Some(|odata| self.relay_from_operational_data::<relay_sender::AsyncSender>(odata))
@@ -1499,13 +1913,19 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         let res = self
             .pending_txs_src
-            .process_pending(pending::TIMEOUT, self, do_resubmit)?
+            .process_pending(timeout, self, do_resubmit)?
             .unwrap_or_else(RelaySummary::empty);
 
         Ok(res)
     }
 
     fn process_pending_txs_dst(&self, resubmit: Resubmit) -> Result<RelaySummary, LinkError> {
+        if self.pending_txs_dst.pending_queue.is_empty() {
+            return Ok(RelaySummary::empty());
+        }
+
+        let timeout = pending::timeout(self.dst_estimated_block_time()?);
+
         let do_resubmit = match resubmit {
             Resubmit::Yes => {
                 Some(|odata| self.relay_from_operational_data::<relay_sender::AsyncSender>(odata))
@@ -1515,7 +1935,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         let res = self
             .pending_txs_dst
-            .process_pending(pending::TIMEOUT, self, do_resubmit)?
+            .process_pending(timeout, self, do_resubmit)?
             .unwrap_or_else(RelaySummary::empty);
 
         Ok(res)
@@ -1537,6 +1957,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .query_application_status()
             .map_err(|e| LinkError::query(self.src_chain().id(), e))?;
 
+        self.dst_block_time
+            .observe(dst_status.height, dst_status.timestamp);
+
         let dst_current_height = dst_status.height;
 
         // Intermediary data struct to help better manage the transfer from dst. operational data
@@ -1545,6 +1968,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         let mut timed_out: HashMap<usize, OperationalData> = HashMap::default();
 
+        let unreceived_acks = self.unreceived_acks_on_src(
+            all_dst_odata
+                .iter()
+                .flat_map(|odata| odata.batch.iter())
+                .map(|msg| &msg.event_with_height),
+        )?;
+
         // For each operational data targeting the destination chain...
         for (odata_pos, odata) in all_dst_odata.iter_mut().enumerate() {
             // ... check each `SendPacket` event, whether it should generate a timeout message
@@ -1588,7 +2018,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                         }
                     }
                     IbcEvent::WriteAcknowledgement(event) => {
-                        if self.write_ack_event_handled(event)? {
+                        if self.write_ack_event_handled(event, &unreceived_acks)? {
                             debug!(?event, "WriteAcknowledgement has already been handled");
                         } else {
                             retain_batch.push(gm.clone());
@@ -1,5 +1,16 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use serde::Serialize;
+
+use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics04_channel::channel::IdentifiedChannelEnd;
-use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+
+use crate::chain::counterparty::ChannelConnectionClient;
+use crate::chain::handle::ChainHandle;
+use crate::channel::Channel;
 
 /// Defines the channel & port identifiers which comprise
 /// the two ends of a relayer path.
@@ -25,3 +36,165 @@ impl PathIdentifiers {
         })
     }
 }
+
+/// The identifiers of one end of a path: a channel on a chain,
+/// along with the connection and client it is built upon.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+pub struct PathEnd {
+    pub chain_id: ChainId,
+    pub client_id: ClientId,
+    pub connection_id: ConnectionId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
+impl Display for PathEnd {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "{}:{}/{} ({}, {})",
+            self.chain_id, self.port_id, self.channel_id, self.connection_id, self.client_id
+        )
+    }
+}
+
+/// A path between two chains, fully identified by both of its ends.
+///
+/// Unlike [`PathIdentifiers`], which only holds the channel and port
+/// identifiers, a `Path` tells apart channels bound to the same port
+/// identifiers on different chains or over different connections.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+pub struct Path {
+    pub a_side: PathEnd,
+    pub b_side: PathEnd,
+}
+
+impl Path {
+    pub fn new(a_side: PathEnd, b_side: PathEnd) -> Self {
+        Self { a_side, b_side }
+    }
+
+    /// Build the path of an open channel discovered on the given chain,
+    /// see [`channel_connection_client`](crate::chain::counterparty::channel_connection_client).
+    ///
+    /// Returns `None` if the handshake has not yet progressed far enough
+    /// for the identifiers of the counterparty channel and connection to be known.
+    pub fn from_channel_connection_client(
+        chain_id: ChainId,
+        ccc: &ChannelConnectionClient,
+    ) -> Option<Self> {
+        let channel_counterparty = ccc.channel.channel_end.counterparty();
+        let connection_counterparty = ccc.connection.connection_end.counterparty();
+
+        let a_side = PathEnd {
+            chain_id,
+            client_id: ccc.client.client_id.clone(),
+            connection_id: ccc.connection.connection_id.clone(),
+            port_id: ccc.channel.port_id.clone(),
+            channel_id: ccc.channel.channel_id.clone(),
+        };
+
+        let b_side = PathEnd {
+            chain_id: ccc.client.client_state.chain_id(),
+            client_id: connection_counterparty.client_id().clone(),
+            connection_id: connection_counterparty.connection_id.clone()?,
+            port_id: channel_counterparty.port_id().clone(),
+            channel_id: channel_counterparty.channel_id.clone()?,
+        };
+
+        Some(Self::new(a_side, b_side))
+    }
+
+    /// Build the path of the given channel, as configured for relaying.
+    ///
+    /// Returns `None` if the identifiers of either channel end are not yet known.
+    pub fn from_channel<ChainA: ChainHandle, ChainB: ChainHandle>(
+        channel: &Channel<ChainA, ChainB>,
+    ) -> Option<Self> {
+        let a_side = PathEnd {
+            chain_id: channel.a_side.chain_id(),
+            client_id: channel.a_side.client_id().clone(),
+            connection_id: channel.a_side.connection_id().clone(),
+            port_id: channel.a_side.port_id().clone(),
+            channel_id: channel.a_side.channel_id()?.clone(),
+        };
+
+        let b_side = PathEnd {
+            chain_id: channel.b_side.chain_id(),
+            client_id: channel.b_side.client_id().clone(),
+            connection_id: channel.b_side.connection_id().clone(),
+            port_id: channel.b_side.port_id().clone(),
+            channel_id: channel.b_side.channel_id()?.clone(),
+        };
+
+        Some(Self::new(a_side, b_side))
+    }
+
+    /// The same path, seen from the other end.
+    pub fn flipped(self) -> Self {
+        Self {
+            a_side: self.b_side,
+            b_side: self.a_side,
+        }
+    }
+
+    /// The channel and port identifiers of this path, with the `b_side`
+    /// as the destination and the `a_side` as the counterparty.
+    pub fn identifiers(&self) -> PathIdentifiers {
+        PathIdentifiers {
+            port_id: self.b_side.port_id.clone(),
+            channel_id: self.b_side.channel_id.clone(),
+            counterparty_port_id: self.a_side.port_id.clone(),
+            counterparty_channel_id: self.a_side.channel_id.clone(),
+        }
+    }
+}
+
+impl Display for Path {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{} <-> {}", self.a_side, self.b_side)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::core::ics02_client::client_type::ClientType;
+
+    fn path_end(chain: &str, client: u64, connection: u64, channel: u64) -> PathEnd {
+        PathEnd {
+            chain_id: ChainId::from_string(chain),
+            client_id: ClientId::new(ClientType::Tendermint, client).unwrap(),
+            connection_id: ConnectionId::new(connection),
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(channel),
+        }
+    }
+
+    #[test]
+    fn paths_on_the_same_port_differ() {
+        let path = Path::new(path_end("ibc-0", 0, 0, 0), path_end("ibc-1", 0, 0, 0));
+        let other = Path::new(path_end("ibc-0", 0, 0, 0), path_end("ibc-2", 0, 0, 0));
+
+        assert_ne!(path, other);
+        assert_eq!(
+            path.to_string(),
+            "ibc-0:transfer/channel-0 (connection-0, 07-tendermint-0) <-> \
+             ibc-1:transfer/channel-0 (connection-0, 07-tendermint-0)"
+        );
+    }
+
+    #[test]
+    fn flipped_path_identifiers() {
+        let path = Path::new(path_end("ibc-0", 0, 1, 2), path_end("ibc-1", 3, 4, 5));
+
+        let ids = path.identifiers();
+        assert_eq!(ids.channel_id, ChannelId::new(5));
+        assert_eq!(ids.counterparty_channel_id, ChannelId::new(2));
+
+        let flipped = path.clone().flipped();
+        assert_eq!(flipped.a_side, path.b_side);
+        assert_eq!(flipped.identifiers().channel_id, ChannelId::new(2));
+    }
+}
@@ -12,6 +12,7 @@ use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use crate::{
     chain::handle::ChainHandle,
     config::Config,
+    object::Object,
     spawn::{spawn_chain_runtime, SpawnError},
     util::lock::RwArc,
 };
@@ -19,10 +20,15 @@ use crate::{
 /// Registry for keeping track of [`ChainHandle`]s indexed by a `ChainId`.
 ///
 /// The purpose of this type is to avoid spawning multiple runtimes for a single `ChainId`.
+///
+/// When enabled in the configuration, the connection and channel handshake workers are
+/// given handles to a second runtime for each chain, so that a slow handshake does not
+/// hold up the requests of the client and packet workers, and vice versa.
 #[derive(Debug)]
 pub struct Registry<Chain: ChainHandle> {
     config: Config,
     handles: HashMap<ChainId, Chain>,
+    handshake_handles: HashMap<ChainId, Chain>,
     rt: Arc<TokioRuntime>,
 }
 
@@ -37,6 +43,7 @@ impl<Chain: ChainHandle> Registry<Chain> {
         Self {
             config,
             handles: HashMap::new(),
+            handshake_handles: HashMap::new(),
             rt: Arc::new(TokioRuntime::new().unwrap()),
         }
     }
@@ -66,6 +73,66 @@ impl<Chain: ChainHandle> Registry<Chain> {
         Ok(handle.clone())
     }
 
+    /// Get the [`ChainHandle`] to be used by the worker for the given [`Object`]
+    /// on the chain with the given [`ChainId`].
+    ///
+    /// This is a handle to the dedicated handshake runtime of that chain for
+    /// connection and channel workers, if enabled in the configuration and if
+    /// the chain has a `handshake_key_name` to sign with, and the same handle
+    /// as returned by [`Registry::get_or_spawn`] otherwise.
+    pub fn get_or_spawn_for(
+        &mut self,
+        chain_id: &ChainId,
+        object: &Object,
+    ) -> Result<Chain, SpawnError> {
+        let dedicated_runtime = match object {
+            Object::Connection(_) => self.config.mode.connections.dedicated_runtime,
+            Object::Channel(_) => self.config.mode.channels.dedicated_runtime,
+            _ => false,
+        };
+
+        if !dedicated_runtime {
+            return self.get_or_spawn(chain_id);
+        }
+
+        if let Some(handle) = self.handshake_handles.get(chain_id) {
+            return Ok(handle.clone());
+        }
+
+        // The dedicated runtime must sign with a key of its own, otherwise both
+        // runtimes would track the sequence of the same account separately.
+        let handshake_key_name = self
+            .config
+            .find_chain(chain_id)
+            .and_then(|chain_config| chain_config.handshake_key_name.clone());
+
+        let handshake_key_name = match handshake_key_name {
+            Some(key_name) => key_name,
+            None => {
+                warn!(
+                    chain = %chain_id,
+                    "no `handshake_key_name` configured for the dedicated handshake runtime, \
+                     sharing the runtime of the other workers instead"
+                );
+
+                return self.get_or_spawn(chain_id);
+            }
+        };
+
+        let mut config = self.config.clone();
+        if let Some(chain_config) = config.find_chain_mut(chain_id) {
+            chain_config.key_name = handshake_key_name;
+        }
+
+        let handle: Chain = spawn_chain_runtime(&config, chain_id, self.rt.clone())?;
+        self.handshake_handles
+            .insert(chain_id.clone(), handle.clone());
+
+        trace!(chain = %chain_id, "spawned handshake chain runtime");
+
+        Ok(handle)
+    }
+
     /// Spawn a chain runtime for the chain with the given [`ChainId`],
     /// only if the registry does not contain a handle for that runtime already.
     ///
@@ -81,9 +148,14 @@ impl<Chain: ChainHandle> Registry<Chain> {
         }
     }
 
-    /// Shutdown the runtime associated with the given chain identifier.
+    /// Shutdown the runtimes associated with the given chain identifier.
     pub fn shutdown(&mut self, chain_id: &ChainId) {
-        if let Some(handle) = self.handles.remove(chain_id) {
+        let handles = [
+            self.handles.remove(chain_id),
+            self.handshake_handles.remove(chain_id),
+        ];
+
+        for handle in handles.into_iter().flatten() {
             if let Err(e) = handle.shutdown() {
                 warn!(chain = %chain_id, "chain runtime might have failed to shutdown properly: {}", e);
             }
@@ -1,8 +1,11 @@
 use crossbeam_channel::TryRecvError;
 use tracing::{error, trace};
 
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
 use crate::{
-    config::Config,
+    chain::handle::Subscription,
+    config::{Config, PacketFilter, PacketFilterUpdate},
     rest::request::ReplySender,
     rest::request::{Request, VersionInfo},
     supervisor::dump_state::SupervisorState,
@@ -31,6 +34,19 @@ pub type Receiver = crossbeam_channel::Receiver<Request>;
 //  e.g., adjusting chain config, removing chains, etc.
 pub enum Command {
     DumpState(ReplySender<SupervisorState>),
+    UpdatePacketFilter {
+        chain_id: ChainId,
+        update: PacketFilterUpdate,
+        reply_to: ReplySender<PacketFilter>,
+    },
+    ClearPackets {
+        chain_id: ChainId,
+        reply_to: ReplySender<()>,
+    },
+    SubscribeEvents {
+        chain_id: ChainId,
+        reply_to: ReplySender<Subscription>,
+    },
 }
 
 /// Process incoming REST requests.
@@ -82,6 +98,32 @@ pub fn process_incoming_requests(config: &Config, channel: &Receiver) -> Option<
 
                 return Some(Command::DumpState(reply_to));
             }
+
+            Request::UpdatePacketFilter {
+                chain_id,
+                update,
+                reply_to,
+            } => {
+                trace!("UpdatePacketFilter {} {:?}", chain_id, update);
+
+                return Some(Command::UpdatePacketFilter {
+                    chain_id,
+                    update,
+                    reply_to,
+                });
+            }
+
+            Request::ClearPackets { chain_id, reply_to } => {
+                trace!("ClearPackets {}", chain_id);
+
+                return Some(Command::ClearPackets { chain_id, reply_to });
+            }
+
+            Request::SubscribeEvents { chain_id, reply_to } => {
+                trace!("SubscribeEvents {}", chain_id);
+
+                return Some(Command::SubscribeEvents { chain_id, reply_to });
+            }
         },
         Err(e) => {
             if !matches!(e, TryRecvError::Empty) {
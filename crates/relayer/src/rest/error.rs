@@ -20,9 +20,21 @@ pub enum RestApiError {
     #[error("failed to parse the string {0} into a valid chain identifier: {1}")]
     InvalidChainId(String, ValidationErrorDetail),
 
+    #[error("failed to parse the string {0} into a valid port identifier: {1}")]
+    InvalidPortId(String, ValidationErrorDetail),
+
+    #[error("failed to parse the string {0} into a valid channel identifier: {1}")]
+    InvalidChannelId(String, ValidationErrorDetail),
+
     #[error("failed while parsing the request body into a chain configuration: {0}")]
     InvalidChainConfig(String),
 
+    #[error("failed to subscribe to the events of chain {0}: {1}")]
+    Subscription(ChainId, String),
+
+    #[error("failed to update the packet filter of chain {0}: {1}")]
+    PacketFilterUpdate(ChainId, String),
+
     #[error("not implemented")]
     Unimplemented,
 }
@@ -35,7 +47,11 @@ impl RestApiError {
             RestApiError::Serialization(_) => "Serialization",
             RestApiError::ChainConfigNotFound(_) => "ChainConfigNotFound",
             RestApiError::InvalidChainId(_, _) => "InvalidChainId",
+            RestApiError::InvalidPortId(_, _) => "InvalidPortId",
+            RestApiError::InvalidChannelId(_, _) => "InvalidChannelId",
             RestApiError::InvalidChainConfig(_) => "InvalidChainConfig",
+            RestApiError::Subscription(_, _) => "Subscription",
+            RestApiError::PacketFilterUpdate(_, _) => "PacketFilterUpdate",
             RestApiError::Unimplemented => "Unimplemented",
         }
     }
@@ -2,7 +2,12 @@ use serde::Serialize;
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
-use crate::{config::ChainConfig, rest::RestApiError, supervisor::dump_state::SupervisorState};
+use crate::{
+    chain::handle::Subscription,
+    config::{ChainConfig, PacketFilter, PacketFilterUpdate},
+    rest::RestApiError,
+    supervisor::dump_state::SupervisorState,
+};
 
 pub type ReplySender<T> = crossbeam_channel::Sender<Result<T, RestApiError>>;
 pub type ReplyReceiver<T> = crossbeam_channel::Receiver<Result<T, RestApiError>>;
@@ -36,4 +41,20 @@ pub enum Request {
         chain_id: ChainId,
         reply_to: ReplySender<ChainConfig>,
     },
+
+    UpdatePacketFilter {
+        chain_id: ChainId,
+        update: PacketFilterUpdate,
+        reply_to: ReplySender<PacketFilter>,
+    },
+
+    ClearPackets {
+        chain_id: ChainId,
+        reply_to: ReplySender<()>,
+    },
+
+    SubscribeEvents {
+        chain_id: ChainId,
+        reply_to: ReplySender<Subscription>,
+    },
 }
@@ -0,0 +1,163 @@
+//! Persistence of the relaying progress of the workers across restarts.
+//!
+//! Packet workers record the height of the latest block of the source chain
+//! whose packets they have relayed, so that upon restart they can query the
+//! events of the blocks they have missed in the meantime, instead of either
+//! starting from the latest height and skipping the events of these blocks, or
+//! clearing the pending packets by searching the whole history of the chain.
+//!
+//! This height is only recorded once the packets scheduled up to it have been
+//! relayed, and at most every few seconds rather than at every block.
+
+use core::fmt::Debug;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use flex_error::{define_error, TraceError};
+
+use ibc_relayer_types::core::ics02_client::height::HeightError;
+use ibc_relayer_types::Height;
+
+use crate::config::StateStoreConfig;
+use crate::object::Packet;
+
+define_error! {
+    StateStoreError {
+        Io
+            { path: PathBuf }
+            [ TraceError<std::io::Error> ]
+            |e| { format_args!("I/O error while accessing state file {}", e.path.display()) },
+
+        InvalidHeight
+            { path: PathBuf }
+            [ HeightError ]
+            |e| { format_args!("invalid height in state file {}", e.path.display()) },
+    }
+}
+
+/// Stores the relaying progress of the workers.
+pub trait StateStore: Debug + Send + Sync {
+    /// The height of the latest block of the source chain whose packets
+    /// have been relayed by the worker for the given packet path, if any.
+    fn last_scanned_height(&self, path: &Packet) -> Result<Option<Height>, StateStoreError>;
+
+    /// Record that the packets up to the given height of the source chain
+    /// have been relayed by the worker for the given packet path.
+    fn set_last_scanned_height(&self, path: &Packet, height: Height)
+        -> Result<(), StateStoreError>;
+}
+
+/// A [`StateStore`] which stores the state of each worker as files in a directory.
+#[derive(Clone, Debug)]
+pub struct DirectoryStateStore {
+    dir: PathBuf,
+}
+
+impl DirectoryStateStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub fn from_config(config: &StateStoreConfig) -> Self {
+        Self::new(config.dir.clone())
+    }
+
+    /// The path of the file holding the last scanned height of the given packet path.
+    fn last_scanned_height_path(&self, path: &Packet) -> PathBuf {
+        self.dir
+            .join(path.src_chain_id.as_str())
+            .join(path.src_port_id.as_str())
+            .join(path.src_channel_id.as_str())
+            .join(path.dst_chain_id.as_str())
+            .join("last_scanned_height")
+    }
+}
+
+impl StateStore for DirectoryStateStore {
+    fn last_scanned_height(&self, path: &Packet) -> Result<Option<Height>, StateStoreError> {
+        let file = self.last_scanned_height_path(path);
+
+        let contents = match fs::read_to_string(&file) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(StateStoreError::io(file, e)),
+        };
+
+        contents
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|e| StateStoreError::invalid_height(file, e))
+    }
+
+    fn set_last_scanned_height(
+        &self,
+        path: &Packet,
+        height: Height,
+    ) -> Result<(), StateStoreError> {
+        let file = self.last_scanned_height_path(path);
+
+        if let Some(parent) = file.parent() {
+            fs::create_dir_all(parent).map_err(|e| StateStoreError::io(parent.to_path_buf(), e))?;
+        }
+
+        // Write to a temporary file first and rename it, so that
+        // the state file is never left partially written.
+        let tmp_file = file.with_extension("tmp");
+
+        fs::write(&tmp_file, height.to_string())
+            .map_err(|e| StateStoreError::io(tmp_file.clone(), e))?;
+
+        fs::rename(&tmp_file, &file).map_err(|e| StateStoreError::io(file, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+    fn packet_path(channel_id: &str) -> Packet {
+        Packet {
+            dst_chain_id: ChainId::from_string("ibc-1"),
+            src_chain_id: ChainId::from_string("ibc-0"),
+            src_channel_id: ChannelId::from_str(channel_id).unwrap(),
+            src_port_id: PortId::transfer(),
+        }
+    }
+
+    #[test]
+    fn last_scanned_height_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("hermes-state-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let store = DirectoryStateStore::new(dir.clone());
+        let path = packet_path("channel-0");
+
+        assert_eq!(store.last_scanned_height(&path).unwrap(), None);
+
+        store
+            .set_last_scanned_height(&path, Height::new(1, 42).unwrap())
+            .unwrap();
+        store
+            .set_last_scanned_height(&path, Height::new(1, 43).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            store.last_scanned_height(&path).unwrap(),
+            Some(Height::new(1, 43).unwrap())
+        );
+        assert_eq!(
+            store
+                .last_scanned_height(&packet_path("channel-1"))
+                .unwrap(),
+            None
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -16,21 +16,22 @@ use ibc_relayer_types::{
 };
 
 use crate::{
-    chain::{endpoint::HealthCheck, handle::ChainHandle, tracking::TrackingId},
-    config::Config,
+    chain::{cosmos::fee_budget, endpoint::HealthCheck, handle::ChainHandle, tracking::TrackingId},
+    config::{Config, PacketFilter, PacketFilterUpdate},
     event::{
         monitor::{self, Error as EventError, ErrorDetail as EventErrorDetail, EventBatch},
         IbcEventWithHeight,
     },
     object::Object,
     registry::{Registry, SharedRegistry},
-    rest,
+    rest::{self, RestApiError},
     supervisor::scan::ScanMode,
     telemetry,
     util::{
         lock::LockExt,
         task::{spawn_background_task, Next, TaskError, TaskHandle},
     },
+    webhook,
     worker::WorkerMap,
 };
 
@@ -49,7 +50,12 @@ pub mod spawn;
 pub mod cmd;
 use cmd::SupervisorCmd;
 
-use self::{scan::ChainScanner, spawn::SpawnContext};
+pub mod standby;
+
+use self::{
+    scan::{ChainScanner, ChainsScan},
+    spawn::SpawnContext,
+};
 
 type ArcBatch = Arc<monitor::Result<EventBatch>>;
 type Subscription = Receiver<ArcBatch>;
@@ -75,6 +81,10 @@ pub struct SupervisorOptions {
     /// even when an allow list is configured for a chain and the full scan could
     /// be omitted.
     pub force_full_scan: bool,
+
+    /// Relay on the chains running unsupported versions of their modules,
+    /// instead of failing the health check.
+    pub allow_incompatible_versions: bool,
 }
 
 /**
@@ -128,7 +138,11 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
     options: SupervisorOptions,
 ) -> Result<Vec<TaskHandle>, Error> {
     if options.health_check {
-        health_check(&config, &mut registry.write());
+        health_check(
+            &config,
+            &mut registry.write(),
+            options.allow_incompatible_versions,
+        )?;
     }
 
     let workers = Arc::new(RwLock::new(WorkerMap::new()));
@@ -149,25 +163,61 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
     info!("scanned chains:");
     info!("{}", scan);
 
+    // With warm standby enabled, the chains which could not be scanned are left out
+    // until they recover, rather than being relayed on without any workers.
+    let mut standby_chains = if config.standby.enabled {
+        unscanned_chains(&config, &scan)
+    } else {
+        Vec::new()
+    };
+
     spawn_context(&config, &mut registry.write(), &mut workers.acquire_write()).spawn_workers(scan);
 
-    let subscriptions = init_subscriptions(&config, &mut registry.write())?;
+    let subscriptions = init_subscriptions(&config, &mut registry.write(), &mut standby_chains)?;
+
+    // The configuration is shared between the tasks so that
+    // it can be updated at runtime, e.g. through the REST API.
+    let config = Arc::new(RwLock::new(config));
 
     let batch_tasks = spawn_batch_workers(
-        &config,
+        config.clone(),
         registry.clone(),
-        client_state_filter,
+        client_state_filter.clone(),
         workers.clone(),
         subscriptions,
     );
 
     let cmd_task = spawn_cmd_worker(registry.clone(), workers.clone(), cmd_rx);
 
-    let mut tasks = vec![cmd_task];
+    let respawn_task = spawn_respawn_worker(config.clone(), registry.clone(), workers.clone());
+
+    let mut tasks = vec![cmd_task, respawn_task];
     tasks.extend(batch_tasks);
 
+    if !standby_chains.is_empty() {
+        warn!(
+            "chains on standby until they recover: {}",
+            standby_chains.iter().join(", ")
+        );
+
+        let standby_task = standby::spawn_standby_worker(
+            config.clone(),
+            registry.clone(),
+            client_state_filter.clone(),
+            workers.clone(),
+            standby_chains,
+        );
+
+        tasks.push(standby_task);
+    }
+
+    if config.acquire_read().webhook.enabled {
+        let webhook_task = webhook::spawn_webhook_exporter(&config.acquire_read().webhook);
+        tasks.push(webhook_task);
+    }
+
     if let Some(rest_rx) = rest_rx {
-        let rest_task = spawn_rest_worker(config, registry, workers, rest_rx);
+        let rest_task = spawn_rest_worker(config, registry, client_state_filter, workers, rest_rx);
         tasks.push(rest_task);
     }
 
@@ -175,43 +225,55 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
 }
 
 fn spawn_batch_workers<Chain: ChainHandle>(
-    config: &Config,
+    config: Arc<RwLock<Config>>,
     registry: SharedRegistry<Chain>,
     client_state_filter: Arc<RwLock<FilterPolicy>>,
     workers: Arc<RwLock<WorkerMap>>,
     subscriptions: Vec<(Chain, Subscription)>,
 ) -> Vec<TaskHandle> {
-    let mut handles = Vec::with_capacity(subscriptions.len());
-
-    for (chain, subscription) in subscriptions {
-        let config = config.clone();
-        let registry = registry.clone();
-        let client_state_filter = client_state_filter.clone();
-        let workers = workers.clone();
-
-        let handle = spawn_background_task(
-            error_span!("worker.batch", chain = %chain.id()),
-            Some(Duration::from_millis(5)),
-            move || -> Result<Next, TaskError<Infallible>> {
-                if let Ok(batch) = subscription.try_recv() {
-                    handle_batch(
-                        &config,
-                        &mut registry.write(),
-                        &mut client_state_filter.acquire_write(),
-                        &mut workers.acquire_write(),
-                        chain.clone(),
-                        batch,
-                    );
-                }
-
-                Ok(Next::Continue)
-            },
-        );
+    subscriptions
+        .into_iter()
+        .map(|(chain, subscription)| {
+            spawn_batch_worker(
+                config.clone(),
+                registry.clone(),
+                client_state_filter.clone(),
+                workers.clone(),
+                chain,
+                subscription,
+            )
+        })
+        .collect()
+}
 
-        handles.push(handle);
-    }
+/// Spawn a worker processing the batches of events received
+/// from the given chain through the given subscription.
+fn spawn_batch_worker<Chain: ChainHandle>(
+    config: Arc<RwLock<Config>>,
+    registry: SharedRegistry<Chain>,
+    client_state_filter: Arc<RwLock<FilterPolicy>>,
+    workers: Arc<RwLock<WorkerMap>>,
+    chain: Chain,
+    subscription: Subscription,
+) -> TaskHandle {
+    spawn_background_task(
+        error_span!("worker.batch", chain = %chain.id()),
+        Some(Duration::from_millis(5)),
+        move || -> Result<Next, TaskError<Infallible>> {
+            if let Ok(batch) = subscription.try_recv() {
+                handle_batch(
+                    &config.acquire_read(),
+                    &mut registry.write(),
+                    &mut client_state_filter.acquire_write(),
+                    &mut workers.acquire_write(),
+                    chain.clone(),
+                    batch,
+                );
+            }
 
-    handles
+            Ok(Next::Continue)
+        },
+    )
 }
 
 pub fn spawn_cmd_worker<Chain: ChainHandle>(
@@ -228,6 +290,26 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
                     SupervisorCmd::DumpState(reply_to) => {
                         dump_state(&registry.read(), &workers.acquire_read(), reply_to);
                     }
+                    SupervisorCmd::PauseWorker(object, reply_to) => {
+                        let found = match workers.acquire_read().get(&object) {
+                            Some(handle) => {
+                                handle.pause();
+                                true
+                            }
+                            None => false,
+                        };
+                        let _ = reply_to.try_send(found);
+                    }
+                    SupervisorCmd::ResumeWorker(object, reply_to) => {
+                        let found = match workers.acquire_read().get(&object) {
+                            Some(handle) => {
+                                handle.resume();
+                                true
+                            }
+                            None => false,
+                        };
+                        let _ = reply_to.try_send(found);
+                    }
                 }
             }
 
@@ -236,9 +318,34 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
     )
 }
 
+/// Spawn a task which periodically respawns the workers whose tasks panicked,
+/// see [`WorkerMap::respawn_panicked`].
+fn spawn_respawn_worker<Chain: ChainHandle>(
+    config: Arc<RwLock<Config>>,
+    registry: SharedRegistry<Chain>,
+    workers: Arc<RwLock<WorkerMap>>,
+) -> TaskHandle {
+    spawn_background_task(
+        error_span!("worker.respawn"),
+        Some(Duration::from_secs(5)),
+        move || -> Result<Next, TaskError<Infallible>> {
+            // Acquire the locks in the same order as the batch workers
+            let config = config.acquire_read();
+            let mut registry = registry.write();
+
+            workers
+                .acquire_write()
+                .respawn_panicked(&mut registry, &config);
+
+            Ok(Next::Continue)
+        },
+    )
+}
+
 pub fn spawn_rest_worker<Chain: ChainHandle>(
-    config: Config,
+    config: Arc<RwLock<Config>>,
     registry: SharedRegistry<Chain>,
+    client_state_filter: Arc<RwLock<FilterPolicy>>,
     workers: Arc<RwLock<WorkerMap>>,
     rest_rx: rest::Receiver,
 ) -> TaskHandle {
@@ -246,7 +353,7 @@ pub fn spawn_rest_worker<Chain: ChainHandle>(
         error_span!("rest"),
         Some(Duration::from_millis(500)),
         move || -> Result<Next, TaskError<Infallible>> {
-            handle_rest_requests(&config, &registry.read(), &workers.acquire_read(), &rest_rx);
+            handle_rest_requests(&config, &registry, &client_state_filter, &workers, &rest_rx);
 
             Ok(Next::Continue)
         },
@@ -525,8 +632,15 @@ fn chain_scanner<'a, Chain: ChainHandle>(
     ChainScanner::new(config, registry, client_state_filter, full_scan)
 }
 
-/// Perform a health check on all connected chains
-fn health_check<Chain: ChainHandle>(config: &Config, registry: &mut Registry<Chain>) {
+/// Perform a health check on all connected chains.
+///
+/// Fails if a chain runs unsupported versions of its modules,
+/// unless `allow_incompatible_versions` is set.
+fn health_check<Chain: ChainHandle>(
+    config: &Config,
+    registry: &mut Registry<Chain>,
+    allow_incompatible_versions: bool,
+) -> Result<(), Error> {
     use HealthCheck::*;
 
     let chains = &config.chains;
@@ -541,6 +655,12 @@ fn health_check<Chain: ChainHandle>(config: &Config, registry: &mut Registry<Cha
             Ok(chain) => match chain.health_check() {
                 Ok(Healthy) => info!("chain is healthy"),
                 Ok(Unhealthy(e)) => warn!("chain is not healthy: {}", e),
+                Ok(Incompatible(e)) if allow_incompatible_versions => {
+                    warn!("chain runs unsupported versions, relaying anyway: {}", e)
+                }
+                Ok(Incompatible(e)) => {
+                    return Err(Error::incompatible_chain(id.clone(), *e));
+                }
                 Err(e) => error!("failed to perform health check: {}", e),
             },
             Err(e) => {
@@ -551,19 +671,42 @@ fn health_check<Chain: ChainHandle>(config: &Config, registry: &mut Registry<Cha
             }
         }
     }
+
+    Ok(())
+}
+
+/// The chains in the configuration which could not be scanned.
+fn unscanned_chains(config: &Config, scan: &ChainsScan) -> Vec<ChainId> {
+    // The chains are scanned in the order in which they appear in the configuration
+    config
+        .chains
+        .iter()
+        .zip(scan.chains.iter())
+        .filter(|(_, chain_scan)| chain_scan.is_err())
+        .map(|(chain_config, _)| chain_config.id.clone())
+        .collect()
 }
 
-/// Subscribe to the events emitted by the chains the supervisor is connected to.
+/// Subscribe to the events emitted by the chains the supervisor is connected to,
+/// except for the given chains on standby.
+///
+/// With warm standby enabled, the chains which cannot be subscribed to are
+/// added to the chains on standby.
 #[instrument(name = "supervisor.init_subscriptions", level = "error", skip_all)]
 fn init_subscriptions<Chain: ChainHandle>(
     config: &Config,
     registry: &mut Registry<Chain>,
+    standby_chains: &mut Vec<ChainId>,
 ) -> Result<Vec<(Chain, Subscription)>, Error> {
     let chains = &config.chains;
 
     let mut subscriptions = Vec::with_capacity(chains.len());
 
     for chain_config in chains {
+        if standby_chains.contains(&chain_config.id) {
+            continue;
+        }
+
         let chain = match registry.get_or_spawn(&chain_config.id) {
             Ok(chain) => chain,
             Err(e) => {
@@ -572,22 +715,33 @@ fn init_subscriptions<Chain: ChainHandle>(
                     chain_config.id, e
                 );
 
+                if config.standby.enabled {
+                    standby_chains.push(chain_config.id.clone());
+                }
+
                 continue;
             }
         };
 
         match chain.subscribe() {
             Ok(subscription) => subscriptions.push((chain, subscription)),
-            Err(e) => error!(
-                "failed to subscribe to events of {}: {}",
-                chain_config.id, e
-            ),
+            Err(e) => {
+                error!(
+                    "failed to subscribe to events of {}: {}",
+                    chain_config.id, e
+                );
+
+                if config.standby.enabled {
+                    standby_chains.push(chain_config.id.clone());
+                }
+            }
         }
     }
 
     // At least one chain runtime should be available, otherwise the supervisor
-    // cannot do anything and will hang indefinitely.
-    if registry.size() == 0 {
+    // cannot do anything and will hang indefinitely, unless some chains are on
+    // standby and may become available later on.
+    if registry.size() == 0 && standby_chains.is_empty() {
         return Err(Error::no_chains_available());
     }
 
@@ -609,36 +763,160 @@ fn dump_state<Chain: ChainHandle>(
 /// as a [`SupervisorState`].
 fn state<Chain: ChainHandle>(registry: &Registry<Chain>, workers: &WorkerMap) -> SupervisorState {
     let chains = registry.chains().map(|c| c.id()).collect_vec();
-    SupervisorState::new(chains, workers.handles())
+
+    SupervisorState {
+        fee_budgets: fee_budget::fee_budgets(),
+        ..SupervisorState::new(chains, workers.handles(), workers.dead_objects().list())
+    }
 }
 
 fn handle_rest_requests<Chain: ChainHandle>(
-    config: &Config,
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
+    config: &Arc<RwLock<Config>>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &Arc<RwLock<FilterPolicy>>,
+    workers: &Arc<RwLock<WorkerMap>>,
     rest_rx: &rest::Receiver,
 ) {
-    if let Some(cmd) = rest::process_incoming_requests(config, rest_rx) {
-        handle_rest_cmd(registry, workers, cmd);
+    let cmd = rest::process_incoming_requests(&config.acquire_read(), rest_rx);
+
+    if let Some(cmd) = cmd {
+        handle_rest_cmd(config, registry, client_state_filter, workers, cmd);
     }
 }
 
 #[instrument(name = "supervisor.handle_rest_cmd", level = "error", skip_all)]
 fn handle_rest_cmd<Chain: ChainHandle>(
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
+    config: &Arc<RwLock<Config>>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &Arc<RwLock<FilterPolicy>>,
+    workers: &Arc<RwLock<WorkerMap>>,
     m: rest::Command,
 ) {
     match m {
         rest::Command::DumpState(reply) => {
-            let state = state(registry, workers);
+            let state = state(&registry.read(), &workers.acquire_read());
             reply
                 .send(Ok(state))
                 .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
         }
+        rest::Command::UpdatePacketFilter {
+            chain_id,
+            update,
+            reply_to,
+        } => {
+            let result = update_packet_filter(
+                &mut config.acquire_write(),
+                &mut registry.write(),
+                &mut client_state_filter.acquire_write(),
+                &mut workers.acquire_write(),
+                &chain_id,
+                update,
+            );
+
+            reply_to
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+        }
+        rest::Command::ClearPackets { chain_id, reply_to } => {
+            let result = if config.acquire_read().has_chain(&chain_id) {
+                // `clear_pending_packets` never fails
+                let _ = clear_pending_packets(&mut workers.acquire_write(), &chain_id);
+                Ok(())
+            } else {
+                Err(RestApiError::ChainConfigNotFound(chain_id))
+            };
+
+            reply_to
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+        }
+        rest::Command::SubscribeEvents { chain_id, reply_to } => {
+            let result = subscribe_events(&config.acquire_read(), &mut registry.write(), &chain_id);
+
+            reply_to
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+        }
     }
 }
 
+/// Subscribe to the events of the given chain, on behalf of a client of the supervisor.
+fn subscribe_events<Chain: ChainHandle>(
+    config: &Config,
+    registry: &mut Registry<Chain>,
+    chain_id: &ChainId,
+) -> Result<Subscription, RestApiError> {
+    if !config.has_chain(chain_id) {
+        return Err(RestApiError::ChainConfigNotFound(chain_id.clone()));
+    }
+
+    registry
+        .get_or_spawn(chain_id)
+        .map_err(|e| RestApiError::Subscription(chain_id.clone(), e.to_string()))?
+        .subscribe()
+        .map_err(|e| RestApiError::Subscription(chain_id.clone(), e.to_string()))
+}
+
+/// Apply the given [`PacketFilterUpdate`] to the packet filter of the given chain,
+/// and spawn or shut down the workers for the affected channel accordingly.
+///
+/// Returns the updated packet filter.
+#[instrument(
+    name = "supervisor.update_packet_filter",
+    level = "error",
+    skip_all,
+    fields(
+        chain = %chain_id,
+        port = %update.port_id(),
+        channel = %update.channel_id(),
+    )
+)]
+fn update_packet_filter<Chain: ChainHandle>(
+    config: &mut Config,
+    registry: &mut Registry<Chain>,
+    client_state_filter: &mut FilterPolicy,
+    workers: &mut WorkerMap,
+    chain_id: &ChainId,
+    update: PacketFilterUpdate,
+) -> Result<PacketFilter, RestApiError> {
+    let chain_config = config
+        .find_chain_mut(chain_id)
+        .ok_or_else(|| RestApiError::ChainConfigNotFound(chain_id.clone()))?;
+
+    let updated = update
+        .apply(&mut chain_config.packet_filter)
+        .map_err(|e| RestApiError::PacketFilterUpdate(chain_id.clone(), e.to_string()))?;
+    let packet_filter = chain_config.packet_filter.clone();
+
+    if !updated {
+        debug!("packet filter is unchanged, nothing to do");
+        return Ok(packet_filter);
+    }
+
+    let (port_id, channel_id) = (update.port_id(), update.channel_id());
+
+    if packet_filter.is_allowed(port_id, channel_id) {
+        info!("channel is now allowed, spawning its workers");
+
+        let scan = chain_scanner(config, registry, client_state_filter, ScanMode::Auto)
+            .scan_channel(chain_id, port_id, channel_id);
+
+        match scan {
+            Ok(scan) => spawn_context(config, registry, workers).spawn_workers_for_chain(scan),
+            Err(e) => error!("failed to scan channel, no workers were spawned: {}", e),
+        }
+    } else {
+        info!("channel is now denied, shutting down its workers");
+
+        for object in workers.objects_for_channel(chain_id, port_id, channel_id) {
+            info!("shutting down worker: {}", object.short_name());
+            workers.shutdown_worker(&object);
+        }
+    }
+
+    Ok(packet_filter)
+}
+
 #[instrument(
     name = "supervisor.clear_pending_packets",
     level = "error",
@@ -672,6 +950,8 @@ fn process_batch<Chain: ChainHandle>(
 
     telemetry!(received_event_batch, batch.tracking_id);
 
+    webhook::export_events(&batch.chain_id, &batch.events);
+
     let collected = collect_events(config, workers, &src_chain, batch);
 
     // If there is a NewBlock event, forward this event first to any workers affected by it.
@@ -702,11 +982,11 @@ fn process_batch<Chain: ChainHandle>(
         }
 
         let src = registry
-            .get_or_spawn(object.src_chain_id())
+            .get_or_spawn_for(object.src_chain_id(), &object)
             .map_err(Error::spawn)?;
 
         let dst = registry
-            .get_or_spawn(object.dst_chain_id())
+            .get_or_spawn_for(object.dst_chain_id(), &object)
             .map_err(Error::spawn)?;
 
         if let Object::Packet(ref _path) = object {
@@ -731,6 +1011,8 @@ fn process_batch<Chain: ChainHandle>(
 /// the corresponding event:
 /// * send_packet_events: The number of SendPacket events received
 /// * acknowledgement_events: The number of WriteAcknowledgment events received.
+/// * error_acknowledgement_events: The number of WriteAcknowledgment events received
+///   with an acknowledgement reporting that the application failed to handle the packet.
 /// * timeout_events: The number of TimeoutPacket events received.
 ///
 /// The labels `chain_id` represents the chain sending the event, and `counterparty_chain_id` represents
@@ -748,6 +1030,8 @@ fn send_telemetry<Src, Dst>(
     Src: ChainHandle,
     Dst: ChainHandle,
 {
+    use ibc_relayer_types::core::ics04_channel::ack_status::AckStatus;
+
     telemetry! {
         for e in events {
             match e.event.clone() {
@@ -770,6 +1054,15 @@ fn send_telemetry<Src, Dst>(
                         &path.src_port_id,
                         &dst.id(),
                     );
+
+                    if !AckStatus::from_bytes(&write_ack_ev.ack).is_success() {
+                        ibc_telemetry::global().error_acknowledgement_events(
+                            &src.id(),
+                            &path.src_channel_id,
+                            &path.src_port_id,
+                            &dst.id(),
+                        );
+                    }
                 }
                 IbcEvent::TimeoutPacket(_) => {
                     ibc_telemetry::global().timeout_events(
@@ -817,6 +1110,12 @@ fn handle_batch<Chain: ChainHandle>(
             let _ = clear_pending_packets(workers, &chain_id)
                 .map_err(|e| error!("error during clearing pending packets: {}", e));
         }
+        Err(EventError(EventErrorDetail::MissedBlocks(_), _)) => {
+            warn!("could not backfill the events of missed blocks, clearing pending packets");
+
+            let _ = clear_pending_packets(workers, &chain_id)
+                .map_err(|e| error!("error during clearing pending packets: {}", e));
+        }
         Err(e) => {
             error!("error when receiving event batch: {}", e)
         }
@@ -1,8 +1,18 @@
 use crossbeam_channel::Sender;
 
+use crate::object::Object;
+
 use super::dump_state::SupervisorState;
 
 #[derive(Clone, Debug)]
 pub enum SupervisorCmd {
     DumpState(Sender<SupervisorState>),
+
+    /// Pause the worker for the given object, replying
+    /// with whether such a worker exists.
+    PauseWorker(Object, Sender<bool>),
+
+    /// Resume the worker for the given object, replying
+    /// with whether such a worker exists.
+    ResumeWorker(Object, Sender<bool>),
 }
@@ -7,8 +7,9 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use crate::{
+    chain::cosmos::fee_budget::FeeBudgetStatus,
     object::{Object, ObjectType},
-    worker::{WorkerData, WorkerHandle, WorkerId},
+    worker::{DeadObject, WorkerData, WorkerHandle, WorkerId, WorkerMetrics},
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -16,11 +17,31 @@ pub struct WorkerDesc {
     pub id: WorkerId,
     pub object: Object,
     pub data: Option<WorkerData>,
+    #[serde(default)]
+    pub paused: bool,
+    #[serde(default)]
+    pub metrics: WorkerMetrics,
 }
 
 impl WorkerDesc {
     pub fn new(id: WorkerId, object: Object, data: Option<WorkerData>) -> Self {
-        Self { id, object, data }
+        Self {
+            id,
+            object,
+            data,
+            paused: false,
+            metrics: WorkerMetrics::default(),
+        }
+    }
+
+    pub fn from_handle(handle: &WorkerHandle) -> Self {
+        Self {
+            id: handle.id(),
+            object: handle.object().clone(),
+            data: handle.data().cloned(),
+            paused: handle.is_paused(),
+            metrics: handle.metrics(),
+        }
     }
 }
 
@@ -28,23 +49,33 @@ impl WorkerDesc {
 pub struct SupervisorState {
     pub chains: Vec<ChainId>,
     pub workers: BTreeMap<ObjectType, Vec<WorkerDesc>>,
+    #[serde(default)]
+    pub dead_objects: Vec<DeadObject>,
+    #[serde(default)]
+    pub fee_budgets: Vec<FeeBudgetStatus>,
 }
 
 impl SupervisorState {
     pub fn new<'a>(
         mut chains: Vec<ChainId>,
         workers: impl Iterator<Item = &'a WorkerHandle>,
+        dead_objects: Vec<DeadObject>,
     ) -> Self {
         chains.sort();
 
         let workers = workers
-            .map(|h| WorkerDesc::new(h.id(), h.object().clone(), h.data().cloned()))
+            .map(WorkerDesc::from_handle)
             .into_group_map_by(|desc| desc.object.object_type())
             .into_iter()
             .update(|(_, os)| os.sort_by_key(|desc| desc.object.short_name()))
             .collect::<BTreeMap<_, _>>();
 
-        Self { chains, workers }
+        Self {
+            chains,
+            workers,
+            dead_objects,
+            fee_budgets: Vec::new(),
+        }
     }
 
     pub fn print_info(&self) {
@@ -61,7 +92,11 @@ impl Display for SupervisorState {
         for (tpe, objects) in &self.workers {
             writeln!(f, "* {tpe:?} workers:")?;
             for desc in objects {
-                writeln!(f, "  - {} (id: {})", desc.object.short_name(), desc.id)?;
+                write!(f, "  - {} (id: {})", desc.object.short_name(), desc.id)?;
+                if desc.paused {
+                    write!(f, " [paused]")?;
+                }
+                writeln!(f)?;
                 if let Some(WorkerData::Client {
                     misbehaviour,
                     refresh,
@@ -72,6 +107,41 @@ impl Display for SupervisorState {
             }
         }
 
+        if !self.dead_objects.is_empty() {
+            writeln!(f, "* Dead objects:")?;
+            for dead in &self.dead_objects {
+                writeln!(
+                    f,
+                    "  - {} (stuck in {} for {})",
+                    dead.object.short_name(),
+                    dead.state,
+                    humantime::format_duration(dead.stuck_for)
+                )?;
+                if let Some(reason) = &dead.reason {
+                    writeln!(f, "    | {reason}")?;
+                }
+            }
+        }
+
+        if !self.fee_budgets.is_empty() {
+            writeln!(f, "* Daily fee budgets:")?;
+            for budget in &self.fee_budgets {
+                write!(
+                    f,
+                    "  - {}: {}{} out of {}{}",
+                    budget.chain_id,
+                    budget.spent,
+                    budget.denom,
+                    budget.max_daily_spend,
+                    budget.denom
+                )?;
+                if budget.exhausted {
+                    write!(f, " [exhausted]")?;
+                }
+                writeln!(f)?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -62,6 +62,14 @@ define_error! {
         NoChainsAvailable
             |_| { "supervisor was not able to connect to any chains" },
 
+        IncompatibleChain
+            { chain_id: ChainId }
+            [ RelayerError ]
+            |e| {
+                format_args!("chain {0} runs unsupported versions of its modules, \
+                    which must be explicitly allowed to relay on it", e.chain_id)
+            },
+
         Spawn
             [ SpawnError ]
             |_| { "supervisor was not able to spawn chain runtime" },
@@ -25,7 +25,10 @@ use crate::{
         },
     },
     client_state::IdentifiedAnyClientState,
-    config::{filter::ChannelFilters, ChainConfig, Config, PacketFilter},
+    config::{
+        filter::{ChannelFilters, FilterPattern},
+        ChainConfig, Config, PacketFilter,
+    },
     path::PathIdentifiers,
     registry::Registry,
     supervisor::client_state_filter::{FilterPolicy, Permission},
@@ -336,6 +339,28 @@ impl<'a, Chain: ChainHandle> ChainScanner<'a, Chain> {
         Ok(scan)
     }
 
+    /// Scan a single channel of the given chain, regardless of the chain's
+    /// packet filter, e.g. after the channel has been allowed at runtime.
+    pub fn scan_channel(
+        &mut self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ChainScan, Error> {
+        let chain = self.registry.get_or_spawn(chain_id).map_err(Error::spawn)?;
+
+        let mut scan = ChainScan::new(chain_id.clone());
+
+        let filters = ChannelFilters::new(vec![(
+            FilterPattern::Exact(port_id.clone()),
+            FilterPattern::Exact(channel_id.clone()),
+        )]);
+
+        self.query_allowed_channels(&chain, &filters, &mut scan)?;
+
+        Ok(scan)
+    }
+
     pub fn query_allowed_channels(
         &mut self,
         chain: &Chain,
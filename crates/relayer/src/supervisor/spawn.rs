@@ -197,8 +197,18 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
                 src_connection_id: connection.connection_id,
             });
 
+            let src = self
+                .registry
+                .get_or_spawn_for(&chain.id(), &connection_object)
+                .map_err(Error::spawn)?;
+
+            let dst = self
+                .registry
+                .get_or_spawn_for(&counterparty_chain.id(), &connection_object)
+                .map_err(Error::spawn)?;
+
             self.workers
-                .spawn(chain, counterparty_chain, &connection_object, self.config)
+                .spawn(src, dst, &connection_object, self.config)
                 .then(|| {
                     info!(
                         "spawning Connection worker: {}",
@@ -315,8 +325,18 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
                 src_port_id: channel_scan.channel.port_id,
             });
 
+            let src = self
+                .registry
+                .get_or_spawn_for(&chain.id(), &channel_object)
+                .map_err(SupervisorError::spawn)?;
+
+            let dst = self
+                .registry
+                .get_or_spawn_for(&counterparty_chain.id(), &channel_object)
+                .map_err(SupervisorError::spawn)?;
+
             self.workers
-                .spawn(chain, counterparty_chain, &channel_object, self.config)
+                .spawn(src, dst, &channel_object, self.config)
                 .then(|| info!("spawned channel worker: {}", channel_object.short_name()));
 
             Ok(true)
@@ -0,0 +1,143 @@
+//! Warm standby of the chains which are unavailable when the supervisor starts.
+//!
+//! Instead of being left out until Hermes is restarted, such chains are probed
+//! periodically, and reintegrated into the supervisor once they are healthy:
+//! their workers are spawned and their events are processed from then on.
+
+use alloc::sync::Arc;
+use core::convert::Infallible;
+use core::time::Duration;
+use std::sync::RwLock;
+use std::time::Instant;
+
+use tracing::{debug, error_span, info, warn};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::chain::endpoint::HealthCheck;
+use crate::chain::handle::ChainHandle;
+use crate::config::Config;
+use crate::registry::SharedRegistry;
+use crate::util::lock::LockExt;
+use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
+use crate::worker::WorkerMap;
+
+use super::client_state_filter::FilterPolicy;
+use super::scan::ScanMode;
+use super::{chain_scanner, spawn_batch_worker, spawn_context, Error};
+
+/// Spawn a task probing the given chains on standby every `probe_interval`
+/// of the standby configuration, until they have all been reintegrated.
+///
+/// The batch workers of the reintegrated chains are owned by this task,
+/// and are thus shut down along with it.
+pub fn spawn_standby_worker<Chain: ChainHandle>(
+    config: Arc<RwLock<Config>>,
+    registry: SharedRegistry<Chain>,
+    client_state_filter: Arc<RwLock<FilterPolicy>>,
+    workers: Arc<RwLock<WorkerMap>>,
+    mut chains: Vec<ChainId>,
+) -> TaskHandle {
+    let probe_interval = config.acquire_read().standby.probe_interval;
+    let mut last_probe = Instant::now();
+    let mut batch_tasks = Vec::with_capacity(chains.len());
+
+    // The task wakes up often so that it shuts down promptly,
+    // but only probes the chains once the interval has elapsed.
+    spawn_background_task(
+        error_span!("worker.standby"),
+        Some(Duration::from_millis(500)),
+        move || -> Result<Next, TaskError<Infallible>> {
+            if chains.is_empty() || last_probe.elapsed() < probe_interval {
+                return Ok(Next::Continue);
+            }
+
+            last_probe = Instant::now();
+
+            chains.retain(|chain_id| {
+                let _span = error_span!("standby", chain = %chain_id).entered();
+
+                match reintegrate_chain(
+                    &config,
+                    &registry,
+                    &client_state_filter,
+                    &workers,
+                    chain_id,
+                ) {
+                    Ok(Some(batch_task)) => {
+                        info!("chain has recovered and is now relayed on");
+                        batch_tasks.push(batch_task);
+                        false
+                    }
+                    Ok(None) => {
+                        warn!(
+                            "chain was removed from the configuration, it is no longer on standby"
+                        );
+                        false
+                    }
+                    Err(e) => {
+                        debug!("chain is still unavailable: {}", e);
+                        true
+                    }
+                }
+            });
+
+            Ok(Next::Continue)
+        },
+    )
+}
+
+/// Scan the given chain and spawn its workers, along with
+/// a batch worker for its events, if it is healthy.
+///
+/// Returns `None` if the chain is no longer in the configuration,
+/// which is shared with the rest of the supervisor and may change.
+fn reintegrate_chain<Chain: ChainHandle>(
+    config: &Arc<RwLock<Config>>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &Arc<RwLock<FilterPolicy>>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    chain_id: &ChainId,
+) -> Result<Option<TaskHandle>, Error> {
+    if config.acquire_read().find_chain(chain_id).is_none() {
+        return Ok(None);
+    }
+
+    let chain = registry.get_or_spawn(chain_id).map_err(Error::spawn)?;
+
+    if let HealthCheck::Unhealthy(e) = chain.health_check().map_err(Error::relayer)? {
+        return Err(Error::relayer(*e));
+    }
+
+    let subscription = chain.subscribe().map_err(Error::relayer)?;
+
+    {
+        let config = config.acquire_read();
+
+        let chain_config = match config.find_chain(chain_id) {
+            Some(chain_config) => chain_config,
+            None => return Ok(None),
+        };
+
+        let scan = chain_scanner(
+            &config,
+            &mut registry.write(),
+            &mut client_state_filter.acquire_write(),
+            ScanMode::Auto,
+        )
+        .scan_chain(chain_config)
+        .map_err(Error::scan)?;
+
+        spawn_context(&config, &mut registry.write(), &mut workers.acquire_write())
+            .spawn_workers_for_chain(scan);
+    }
+
+    Ok(Some(spawn_batch_worker(
+        config.clone(),
+        registry.clone(),
+        client_state_filter.clone(),
+        workers.clone(),
+        chain,
+        subscription,
+    )))
+}
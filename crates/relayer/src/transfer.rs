@@ -15,6 +15,7 @@ use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::timestamp::{Timestamp, TimestampOverflowError};
 use ibc_relayer_types::tx_msg::Msg;
+use ibc_relayer_types::Height;
 
 use crate::chain::endpoint::ChainStatus;
 use crate::chain::handle::ChainHandle;
@@ -67,6 +68,26 @@ define_error! {
 
         ZeroTimeout
             | _ | { "packet timeout height and packet timeout timestamp cannot both be 0" },
+
+        ExpiredTimeoutHeight
+            {
+                timeout_height: Height,
+                latest_height: Height,
+            }
+            |e| {
+                format!("timeout height {} is not after the latest height {} of the destination chain",
+                    e.timeout_height, e.latest_height)
+            },
+
+        ExpiredTimeoutTimestamp
+            {
+                timeout_timestamp: Timestamp,
+                latest_timestamp: Timestamp,
+            }
+            |e| {
+                format!("timeout timestamp {} is not after the latest timestamp {} of the destination chain",
+                    e.timeout_timestamp, e.latest_timestamp)
+            },
     }
 }
 
@@ -113,6 +134,54 @@ impl TransferTimeout {
             timeout_timestamp,
         })
     }
+
+    /**
+       Construct the transfer timeout parameters from the given transfer
+       options and the latest chain status of the destination chain.
+
+       The absolute timeout height and timestamp of the options, if any,
+       take precedence over the height offset and duration respectively,
+       and must be strictly after the latest height and time of the
+       destination chain, since the packet would otherwise be timed out
+       right away.
+    */
+    pub fn from_options(
+        opts: &TransferOptions,
+        destination_chain_status: &ChainStatus,
+    ) -> Result<Self, TransferError> {
+        let offsets = Self::new(
+            opts.timeout_height_offset,
+            opts.timeout_duration,
+            destination_chain_status,
+        )?;
+
+        let timeout_height = match opts.timeout_height {
+            Some(height) if height <= destination_chain_status.height => {
+                return Err(TransferError::expired_timeout_height(
+                    height,
+                    destination_chain_status.height,
+                ));
+            }
+            Some(height) => height.into(),
+            None => offsets.timeout_height,
+        };
+
+        let timeout_timestamp = match opts.timeout_timestamp {
+            Some(timestamp) if !timestamp.after(&destination_chain_status.timestamp) => {
+                return Err(TransferError::expired_timeout_timestamp(
+                    timestamp,
+                    destination_chain_status.timestamp,
+                ));
+            }
+            Some(timestamp) => timestamp,
+            None => offsets.timeout_timestamp,
+        };
+
+        Ok(TransferTimeout {
+            timeout_height,
+            timeout_timestamp,
+        })
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -124,6 +193,15 @@ pub struct TransferOptions {
     pub receiver: Option<String>,
     pub timeout_height_offset: u64,
     pub timeout_duration: Duration,
+    /// Absolute timeout height on the destination chain,
+    /// which takes precedence over `timeout_height_offset`.
+    pub timeout_height: Option<Height>,
+    /// Absolute timeout timestamp on the destination chain,
+    /// which takes precedence over `timeout_duration`.
+    pub timeout_timestamp: Option<Timestamp>,
+    /// Memo of the transfers, eg. the instructions of the middlewares of the
+    /// destination chain. Only understood by the chains running ibc-go v6 or later.
+    pub memo: Option<String>,
     pub number_msgs: usize,
 }
 
@@ -136,7 +214,8 @@ pub fn build_transfer_message(
     receiver: Signer,
     timeout_height: TimeoutHeight,
     timeout_timestamp: Timestamp,
-) -> Any {
+    memo: String,
+) -> Result<Any, TransferError> {
     let msg = MsgTransfer {
         source_port: src_port_id,
         source_channel: src_channel_id,
@@ -148,9 +227,13 @@ pub fn build_transfer_message(
         receiver,
         timeout_height,
         timeout_timestamp,
+        memo,
     };
 
-    msg.to_any()
+    msg.validate_basic()
+        .map_err(TransferError::token_transfer)?;
+
+    Ok(msg.to_any())
 }
 
 pub fn build_transfer_messages<SrcChain: ChainHandle, DstChain: ChainHandle>(
@@ -163,17 +246,29 @@ pub fn build_transfer_messages<SrcChain: ChainHandle, DstChain: ChainHandle>(
         None => dst_chain.get_signer().map_err(TransferError::key)?,
     };
 
+    let memo = opts.memo.clone().unwrap_or_default();
+
+    // Reject transfers whose packets the destination chain would fail to receive
+    let transfer_limits = dst_chain
+        .config()
+        .map_err(TransferError::relayer)?
+        .transfer_limits;
+
+    transfer_limits
+        .check_receiver(receiver.as_ref())
+        .map_err(TransferError::token_transfer)?;
+
+    transfer_limits
+        .check_memo(&memo)
+        .map_err(TransferError::token_transfer)?;
+
     let sender = src_chain.get_signer().map_err(TransferError::key)?;
 
     let destination_chain_status = dst_chain
         .query_application_status()
         .map_err(TransferError::relayer)?;
 
-    let timeout = TransferTimeout::new(
-        opts.timeout_height_offset,
-        opts.timeout_duration,
-        &destination_chain_status,
-    )?;
+    let timeout = TransferTimeout::from_options(opts, &destination_chain_status)?;
 
     let message = build_transfer_message(
         opts.src_port_id.clone(),
@@ -184,7 +279,8 @@ pub fn build_transfer_messages<SrcChain: ChainHandle, DstChain: ChainHandle>(
         receiver,
         timeout.timeout_height,
         timeout.timeout_timestamp,
-    );
+        memo,
+    )?;
 
     let msgs = vec![message; opts.number_msgs];
 
@@ -230,3 +326,62 @@ pub fn build_and_send_transfer_messages<SrcChain: ChainHandle, DstChain: ChainHa
     let msgs = build_transfer_messages(src_chain, dst_chain, opts)?;
     send_messages(src_chain, msgs)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::applications::transfer::Amount;
+
+    fn options(
+        timeout_height: Option<Height>,
+        timeout_timestamp: Option<Timestamp>,
+    ) -> TransferOptions {
+        TransferOptions {
+            src_port_id: PortId::transfer(),
+            src_channel_id: ChannelId::default(),
+            amount: Amount::from(42u64),
+            denom: "samoleans".to_string(),
+            receiver: None,
+            timeout_height_offset: 10,
+            timeout_duration: Duration::from_secs(60),
+            timeout_height,
+            timeout_timestamp,
+            memo: None,
+            number_msgs: 1,
+        }
+    }
+
+    fn status() -> ChainStatus {
+        ChainStatus {
+            height: Height::new(1, 100).unwrap(),
+            timestamp: Timestamp::from_nanoseconds(1_000_000_000_000).unwrap(),
+        }
+    }
+
+    #[test]
+    fn absolute_timeouts_take_precedence() {
+        let height = Height::new(1, 150).unwrap();
+        let timestamp = Timestamp::from_nanoseconds(2_000_000_000_000).unwrap();
+
+        let timeout =
+            TransferTimeout::from_options(&options(Some(height), Some(timestamp)), &status())
+                .unwrap();
+
+        assert_eq!(timeout.timeout_height, height.into());
+        assert_eq!(timeout.timeout_timestamp, timestamp);
+
+        let timeout = TransferTimeout::from_options(&options(None, None), &status()).unwrap();
+
+        assert_eq!(timeout.timeout_height, Height::new(1, 110).unwrap().into());
+    }
+
+    #[test]
+    fn expired_absolute_timeouts_are_rejected() {
+        let height = Height::new(1, 100).unwrap();
+        assert!(TransferTimeout::from_options(&options(Some(height), None), &status()).is_err());
+
+        let timestamp = Timestamp::from_nanoseconds(1_000_000_000_000).unwrap();
+        assert!(TransferTimeout::from_options(&options(None, Some(timestamp)), &status()).is_err());
+    }
+}
@@ -1,10 +1,12 @@
 mod block_on;
 pub use block_on::block_on;
 
+pub mod block_time;
 pub mod diff;
 pub mod iter;
 pub mod lock;
 pub mod pretty;
+pub mod profile;
 pub mod queue;
 pub mod retry;
 pub mod stream;
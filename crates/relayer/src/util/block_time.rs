@@ -0,0 +1,140 @@
+use core::time::Duration;
+use std::time::Instant;
+
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height;
+
+use crate::util::lock::{LockExt, RwArc};
+
+/// The weight given to each newly observed block interval, as a fraction `1 / N`,
+/// in the exponential moving average of the block time.
+const SMOOTHING_FACTOR: u32 = 5;
+
+/// A lower bound on the estimate, so that waiting for a number of blocks never busy-loops.
+const MIN_BLOCK_TIME: Duration = Duration::from_millis(100);
+
+/// Estimates the time it takes a chain to produce a block,
+/// based on the block intervals observed on that chain.
+///
+/// The estimate is an exponential moving average of the observed block intervals,
+/// bounded by the `max_block_time` configured for the chain, which is also the
+/// estimate until at least two blocks of the same revision have been observed.
+#[derive(Clone, Debug)]
+pub struct BlockTimeEstimator {
+    max_block_time: Duration,
+    state: RwArc<EstimatorState>,
+}
+
+#[derive(Debug, Default)]
+struct EstimatorState {
+    /// The latest block observed, and when it was observed
+    latest: Option<(Height, Timestamp, Instant)>,
+    estimate: Option<Duration>,
+}
+
+impl BlockTimeEstimator {
+    pub fn new(max_block_time: Duration) -> Self {
+        Self {
+            max_block_time,
+            state: <RwArc<_>>::new_lock(EstimatorState::default()),
+        }
+    }
+
+    pub fn max_block_time(&self) -> Duration {
+        self.max_block_time
+    }
+
+    /// The estimated time it takes the chain to produce a block.
+    pub fn estimate(&self) -> Duration {
+        self.state
+            .acquire_read()
+            .estimate
+            .unwrap_or(self.max_block_time)
+    }
+
+    /// Whether a new block has likely been produced since the latest one observed,
+    /// ie. whether observing the chain status again is likely to refine the estimate.
+    pub fn needs_sample(&self) -> bool {
+        let state = self.state.acquire_read();
+
+        match state.latest {
+            Some((_, _, observed_at)) => {
+                observed_at.elapsed() >= state.estimate.unwrap_or(self.max_block_time)
+            }
+            None => true,
+        }
+    }
+
+    /// Record that the block at the given height has the given timestamp.
+    pub fn observe(&self, height: Height, timestamp: Timestamp) {
+        let mut state = self.state.acquire_write();
+
+        if let Some((latest_height, latest_timestamp, _)) = state.latest {
+            if height.revision_number() == latest_height.revision_number() {
+                if height <= latest_height {
+                    return;
+                }
+
+                let blocks = height.revision_height() - latest_height.revision_height();
+
+                if let Some(elapsed) = timestamp.duration_since(&latest_timestamp) {
+                    let interval = elapsed / u32::try_from(blocks).unwrap_or(u32::MAX);
+
+                    let estimate = match state.estimate {
+                        Some(estimate) => {
+                            (estimate * (SMOOTHING_FACTOR - 1) + interval) / SMOOTHING_FACTOR
+                        }
+                        None => interval,
+                    };
+
+                    state.estimate = Some(estimate.max(MIN_BLOCK_TIME).min(self.max_block_time));
+                }
+            }
+        }
+
+        state.latest = Some((height, timestamp, Instant::now()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observe(estimator: &BlockTimeEstimator, height: u64, secs: u64) {
+        estimator.observe(
+            Height::new(1, height).unwrap(),
+            Timestamp::from_nanoseconds(secs * 1_000_000_000).unwrap(),
+        );
+    }
+
+    #[test]
+    fn estimate_from_observed_blocks() {
+        let estimator = BlockTimeEstimator::new(Duration::from_secs(30));
+        assert_eq!(estimator.estimate(), Duration::from_secs(30));
+        assert!(estimator.needs_sample());
+
+        observe(&estimator, 10, 1000);
+        assert_eq!(estimator.estimate(), Duration::from_secs(30));
+
+        // 5 blocks in 30 seconds
+        observe(&estimator, 15, 1030);
+        assert_eq!(estimator.estimate(), Duration::from_secs(6));
+
+        // Older blocks are ignored
+        observe(&estimator, 12, 1012);
+        assert_eq!(estimator.estimate(), Duration::from_secs(6));
+
+        // A single block in 11 seconds moves the estimate by a fifth of the difference
+        observe(&estimator, 16, 1041);
+        assert_eq!(estimator.estimate(), Duration::from_secs(7));
+    }
+
+    #[test]
+    fn estimate_is_bounded_by_max_block_time() {
+        let estimator = BlockTimeEstimator::new(Duration::from_secs(10));
+
+        observe(&estimator, 10, 1000);
+        observe(&estimator, 11, 1100);
+        assert_eq!(estimator.estimate(), Duration::from_secs(10));
+    }
+}
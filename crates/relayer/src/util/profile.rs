@@ -0,0 +1,133 @@
+//! Timings of the phases of a command, such as queries or broadcasting transactions,
+//! accumulated across all chains and threads when enabled with `hermes --profile`.
+//!
+//! Unlike the `time!` macro, which logs every operation when the `profiling` feature
+//! is enabled at compile time, these timings are enabled at runtime and only summed
+//! per phase, so that they can be reported by users of the released binaries.
+
+use core::fmt;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+use crate::util::lock::{LockExt, RwArc};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static TIMINGS: Lazy<RwArc<BTreeMap<Phase, PhaseTiming>>> = Lazy::new(Default::default);
+
+/// A phase of the execution of a command.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    /// Spawning the runtimes of the chains
+    SpawnChains,
+    /// Querying the chains, excluding the queries for proofs
+    Query,
+    /// Querying the chains for proofs and building the proofs of the messages
+    ProofQuery,
+    /// Simulating transactions to estimate their gas
+    Simulation,
+    /// Broadcasting transactions
+    Broadcast,
+    /// Waiting for the transactions to be committed
+    Confirmation,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SpawnChains => write!(f, "spawn chains"),
+            Self::Query => write!(f, "queries"),
+            Self::ProofQuery => write!(f, "proof queries"),
+            Self::Simulation => write!(f, "simulation"),
+            Self::Broadcast => write!(f, "broadcast"),
+            Self::Confirmation => write!(f, "confirmation"),
+        }
+    }
+}
+
+/// The time spent in a phase, over all of its occurrences.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct PhaseTiming {
+    pub count: u64,
+    #[serde(with = "humantime_serde")]
+    pub total: Duration,
+}
+
+/// Enable the recording of the timings of the phases for the rest of the process.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Measure the given phase until the returned guard is dropped.
+pub fn measure(phase: Phase) -> PhaseGuard {
+    PhaseGuard {
+        phase,
+        start: is_enabled().then(Instant::now),
+    }
+}
+
+/// The timings recorded so far, sorted by phase.
+pub fn report() -> Vec<(Phase, PhaseTiming)> {
+    TIMINGS
+        .acquire_read()
+        .iter()
+        .map(|(phase, timing)| (*phase, *timing))
+        .collect()
+}
+
+fn record(phase: Phase, elapsed: Duration) {
+    let mut timings = TIMINGS.acquire_write();
+    let timing = timings.entry(phase).or_default();
+
+    timing.count += 1;
+    timing.total += elapsed;
+}
+
+/// Records the time elapsed since its creation in its phase when dropped,
+/// if recording was enabled at the time it was created.
+#[must_use]
+pub struct PhaseGuard {
+    phase: Phase,
+    start: Option<Instant>,
+}
+
+impl Drop for PhaseGuard {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            record(self.phase, start.elapsed());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulate_phase_timings() {
+        drop(measure(Phase::Broadcast));
+        assert!(report().iter().all(|(phase, _)| *phase != Phase::Broadcast));
+
+        enable();
+
+        drop(measure(Phase::Broadcast));
+        drop(measure(Phase::Broadcast));
+
+        let (_, timing) = report()
+            .into_iter()
+            .find(|(phase, _)| *phase == Phase::Broadcast)
+            .unwrap();
+
+        assert_eq!(timing.count, 2);
+    }
+}
@@ -1,6 +1,8 @@
 use async_stream::stream;
 use core::mem;
-use futures::stream::Stream;
+use core::time::Duration;
+use futures::pin_mut;
+use futures::stream::{Stream, StreamExt};
 
 /// ## Example
 ///
@@ -90,6 +92,75 @@ where
     }
 }
 
+/// Like [`try_group_while`], but the current group is also yielded as soon as
+/// an element for which `flush_after` holds is added to it, and, if a `delay` is
+/// given, once no element was received for that long while the group is not empty.
+///
+/// Unlike with [`try_group_while`], the current group is yielded as a whole
+/// before an error. The `delay` relies on the Tokio timer, and therefore
+/// requires the stream to be polled from within a Tokio runtime.
+pub fn try_group_while_or_flush<A, E, S, F, G>(
+    input: S,
+    group_these: F,
+    flush_after: G,
+    delay: Option<Duration>,
+) -> impl Stream<Item = Result<Vec<A>, E>>
+where
+    S: Stream<Item = Result<A, E>>,
+    F: Fn(&A, &A) -> bool + 'static,
+    G: Fn(&A) -> bool + 'static,
+{
+    stream! {
+        pin_mut!(input);
+
+        let mut group: Vec<A> = vec![];
+
+        loop {
+            let next = match delay {
+                Some(delay) if !group.is_empty() => {
+                    match tokio::time::timeout(delay, input.next()).await {
+                        Ok(next) => next,
+                        Err(_) => {
+                            yield Ok(mem::take(&mut group));
+                            continue;
+                        }
+                    }
+                }
+                _ => input.next().await,
+            };
+
+            match next {
+                Some(Ok(x)) => {
+                    if let Some(last) = group.last() {
+                        if !group_these(last, &x) {
+                            yield Ok(mem::take(&mut group));
+                        }
+                    }
+
+                    let flush = flush_after(&x);
+                    group.push(x);
+
+                    if flush {
+                        yield Ok(mem::take(&mut group));
+                    }
+                }
+                Some(Err(e)) => {
+                    if !group.is_empty() {
+                        yield Ok(mem::take(&mut group));
+                    }
+
+                    yield Err(e);
+                }
+                None => break,
+            }
+        }
+
+        if !group.is_empty() {
+            yield Ok(group);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,4 +245,63 @@ mod tests {
 
         assert_eq!(result, Vec::<Result<Vec<i32>, ()>>::new());
     }
+
+    #[test]
+    fn try_group_while_or_flush_after() {
+        let input = stream::iter(vec![
+            ok((1, 1)),
+            Ok((1, 2)),
+            Ok((1, 3)),
+            Ok((2, 1)),
+            Err(()),
+            Ok((2, 2)),
+            Ok((3, 1)),
+        ]);
+
+        let output = try_group_while_or_flush(input, |a, b| a.0 == b.0, |a| a.1 == 2, None)
+            .collect::<Vec<_>>();
+        let result = block_on(output);
+
+        assert_eq!(
+            result,
+            vec![
+                Ok(vec![(1, 1), (1, 2)]),
+                Ok(vec![(1, 3)]),
+                Ok(vec![(2, 1)]),
+                Err(()),
+                Ok(vec![(2, 2)]),
+                Ok(vec![(3, 1)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn try_group_while_or_flush_delay() {
+        let input = stream::iter(vec![
+            (ok((1, 1)), 0),
+            (Ok((1, 2)), 0),
+            (Ok((1, 3)), 500),
+            (Ok((2, 1)), 0),
+        ])
+        .then(|(x, wait)| async move {
+            tokio::time::sleep(core::time::Duration::from_millis(wait)).await;
+            x
+        });
+
+        let output = try_group_while_or_flush(
+            input,
+            |a, b| a.0 == b.0,
+            |_| false,
+            Some(core::time::Duration::from_millis(100)),
+        )
+        .collect::<Vec<_>>();
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let result = rt.block_on(output);
+
+        assert_eq!(
+            result,
+            vec![Ok(vec![(1, 1), (1, 2)]), Ok(vec![(1, 3)]), Ok(vec![(2, 1)]),]
+        );
+    }
 }
@@ -2,12 +2,20 @@ use core::fmt::Display;
 use core::mem;
 use core::time::Duration;
 use crossbeam_channel::{bounded, Sender};
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 use std::thread;
 use tracing::{debug, error, warn};
 
+use crate::telemetry;
 use crate::util::lock::LockExt;
 
+/// How long a suspended task without an interval pause waits before checking again
+/// whether it has been resumed.
+const PAUSED_INTERVAL: Duration = Duration::from_millis(100);
+
 /**
    A task handle holds the endpoints for stopping or waiting for a
    background task to terminate.
@@ -18,10 +26,17 @@ use crate::util::lock::LockExt;
 
    Otherwise, when the `TaskHandle` is dropped, it will stop the background
    task and wait for the background task to terminate before returning.
+
+   The background task can also be temporarily suspended by calling
+   [`pause`](TaskHandle::pause), until [`resume`](TaskHandle::resume)
+   is called.
 */
 pub struct TaskHandle {
     shutdown_sender: Sender<()>,
     stopped: Arc<RwLock<bool>>,
+    paused: Arc<AtomicBool>,
+    ignored_errors: Arc<AtomicU64>,
+    panicked: Arc<AtomicBool>,
     join_handle: DropJoinHandle,
 }
 
@@ -73,6 +88,13 @@ pub enum Next {
    argument is also given for the task runner to sleep for the given
    duration before calling the step runner again.
 
+   A panic of the step runner is caught and recorded rather than silently
+   unwinding the task thread, and the task is then stopped as if it
+   encountered a fatal error. The step runner is not called again, since
+   the panic may have left its state inconsistent: workers whose tasks
+   panicked are instead respawned with fresh state by the supervisor,
+   see [`WorkerMap::respawn_panicked`](crate::worker::WorkerMap::respawn_panicked).
+
    The function returns a [`TaskHandle`] that can be used to shutdown the
    background task. If the [`TaskHandle`] is dropped or if explicit shutdown
    instruction is sent, the task runner will stop calling the step runner
@@ -95,25 +117,48 @@ pub fn spawn_background_task<E: Display>(
     let stopped = Arc::new(RwLock::new(false));
     let write_stopped = stopped.clone();
 
+    let paused = Arc::new(AtomicBool::new(false));
+    let read_paused = paused.clone();
+
+    let ignored_errors = Arc::new(AtomicU64::new(0));
+    let write_ignored_errors = ignored_errors.clone();
+
+    let panicked = Arc::new(AtomicBool::new(false));
+    let write_panicked = panicked.clone();
+
     let (shutdown_sender, receiver) = bounded(1);
 
     let join_handle = thread::spawn(move || {
         let _entered = span.enter();
+
         loop {
             match receiver.try_recv() {
                 Ok(()) => {
                     break;
                 }
-                _ => match step_runner() {
-                    Ok(Next::Continue) => {}
-                    Ok(Next::Abort) => {
+                _ if read_paused.load(Ordering::SeqCst) => {}
+                _ => match catch_unwind(AssertUnwindSafe(&mut step_runner)) {
+                    Err(panic) => {
+                        write_panicked.store(true, Ordering::SeqCst);
+                        telemetry!(
+                            worker_panic,
+                            span.metadata()
+                                .map_or("unknown", |metadata| metadata.name())
+                        );
+
+                        error!("task aborting after panicking: {}", panic_message(&*panic));
+                        break;
+                    }
+                    Ok(Ok(Next::Continue)) => {}
+                    Ok(Ok(Next::Abort)) => {
                         debug!("aborting task");
                         break;
                     }
-                    Err(TaskError::Ignore(e)) => {
+                    Ok(Err(TaskError::Ignore(e))) => {
                         warn!("task encountered ignorable error: {}", e);
+                        write_ignored_errors.fetch_add(1, Ordering::SeqCst);
                     }
-                    Err(TaskError::Fatal(e)) => {
+                    Ok(Err(TaskError::Fatal(e))) => {
                         error!("task aborting after encountering fatal error: {}", e);
                         break;
                     }
@@ -121,6 +166,8 @@ pub fn spawn_background_task<E: Display>(
             }
             if let Some(interval) = interval_pause {
                 thread::sleep(interval);
+            } else if read_paused.load(Ordering::SeqCst) {
+                thread::sleep(PAUSED_INTERVAL);
             }
         }
 
@@ -132,10 +179,24 @@ pub fn spawn_background_task<E: Display>(
     TaskHandle {
         shutdown_sender,
         stopped,
+        paused,
+        ignored_errors,
+        panicked,
         join_handle: DropJoinHandle(Some(join_handle)),
     }
 }
 
+/// The message a panic was raised with, if it was raised with a string.
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    if let Some(message) = panic.downcast_ref::<&'static str>() {
+        message
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
 impl TaskHandle {
     /**
        Wait for the background task to terminate.
@@ -179,6 +240,45 @@ impl TaskHandle {
     pub fn is_stopped(&self) -> bool {
         *self.stopped.acquire_read()
     }
+
+    /**
+       Suspend the background task: the step runner is not called
+       anymore until the task is resumed, once its current step is done.
+    */
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+    }
+
+    /**
+       Resume the background task after it was suspended with
+       [`pause`](TaskHandle::pause).
+    */
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+    }
+
+    /**
+       Check whether the background task is currently suspended.
+    */
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /**
+       The number of ignorable errors the step runner has returned so far,
+       after each of which the step was retried.
+    */
+    pub fn ignored_errors(&self) -> u64 {
+        self.ignored_errors.load(Ordering::SeqCst)
+    }
+
+    /**
+       Check whether the background task was stopped because its
+       step runner panicked.
+    */
+    pub fn has_panicked(&self) -> bool {
+        self.panicked.load(Ordering::SeqCst)
+    }
 }
 
 impl Drop for DropJoinHandle {
@@ -194,3 +294,29 @@ impl Drop for TaskHandle {
         let _ = self.shutdown_sender.send(());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stop_task_after_panic() {
+        let steps = Arc::new(AtomicU64::new(0));
+        let write_steps = steps.clone();
+
+        let handle = spawn_background_task(tracing::Span::none(), None, move || {
+            if write_steps.fetch_add(1, Ordering::SeqCst) == 0 {
+                panic!("step panicked");
+            }
+
+            Ok::<_, TaskError<String>>(Next::Continue)
+        });
+
+        while !handle.is_stopped() {
+            thread::sleep(Duration::from_millis(100));
+        }
+
+        assert!(handle.has_panicked());
+        assert_eq!(steps.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,279 @@
+//! Export of the IBC events observed by Hermes to an HTTP endpoint.
+//!
+//! When the webhook is enabled, the events of the configured types, eg. the
+//! relayed packets, client misbehaviour and handshake completion events, are
+//! posted as [CloudEvents](https://cloudevents.io) to the configured endpoint,
+//! so that operators can plug Hermes into their existing incident pipelines.
+//!
+//! Only the events observed through the event subscriptions of the chains
+//! are exported, whether they result from the transactions of Hermes or of
+//! another relayer. The events of the transactions submitted by the CLI, or of
+//! the blocks missed while the subscription of a chain is down, are not.
+//!
+//! The events are posted in batches, using the batched content mode of the
+//! CloudEvents HTTP binding, and each batch is retried with an exponential
+//! backoff before being dropped if the endpoint keeps failing. The events are
+//! queued in between, and dropped as well once the queue is full.
+
+use core::convert::Infallible;
+use core::str::FromStr;
+use core::time::Duration;
+use std::thread;
+
+use crossbeam_channel as channel;
+use once_cell::sync::OnceCell;
+use serde::Serialize;
+use tracing::{debug, error, error_span, warn};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::events::IbcEventType;
+
+use crate::config::WebhookConfig;
+use crate::event::IbcEventWithHeight;
+use crate::telemetry;
+use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
+
+/// The content type of a batch of CloudEvents, as per the HTTP binding.
+const CLOUDEVENTS_BATCH_CONTENT_TYPE: &str = "application/cloudevents-batch+json";
+
+/// How long to wait before retrying to post a batch for the first time,
+/// the delay doubling with each retry.
+const INITIAL_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// How long to wait for the endpoint to respond to a post.
+const POST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How many events may be queued for export, waiting to be posted,
+/// before the newly observed events are dropped.
+const QUEUE_CAPACITY: usize = 10_000;
+
+/// The exporter the events are handed over to, once spawned.
+static EXPORTER: OnceCell<Exporter> = OnceCell::new();
+
+#[derive(Debug)]
+struct Exporter {
+    event_types: Vec<String>,
+    sender: channel::Sender<CloudEvent>,
+}
+
+impl Exporter {
+    fn exports(&self, event: &IbcEventWithHeight) -> bool {
+        let event_type = event.event.event_type();
+
+        self.event_types
+            .iter()
+            .any(|exported| exported == event_type.as_str())
+    }
+}
+
+/// An IBC event observed on a chain, in the structured JSON format of CloudEvents.
+#[derive(Clone, Debug, Serialize)]
+pub struct CloudEvent {
+    pub specversion: &'static str,
+    pub id: String,
+    /// The chain on which the event was observed, eg. `/hermes/ibc-0`.
+    pub source: String,
+    /// The type of the IBC event, eg. `hermes.ibc.receive_packet`.
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub time: String,
+    pub datacontenttype: &'static str,
+    pub data: IbcEventWithHeight,
+    #[serde(skip)]
+    chain_id: ChainId,
+}
+
+impl CloudEvent {
+    pub fn new(chain_id: &ChainId, event: IbcEventWithHeight) -> Self {
+        Self {
+            specversion: "1.0",
+            id: uuid::Uuid::new_v4().to_string(),
+            source: format!("/hermes/{}", chain_id),
+            event_type: format!("hermes.ibc.{}", event.event.event_type().as_str()),
+            time: tendermint::Time::now().to_rfc3339(),
+            datacontenttype: "application/json",
+            data: event,
+            chain_id: chain_id.clone(),
+        }
+    }
+}
+
+/// Spawn a task posting the events handed over through [`export_events`]
+/// to the endpoint of the given configuration, every `batch_interval`.
+pub fn spawn_webhook_exporter(config: &WebhookConfig) -> TaskHandle {
+    for event_type in &config.events {
+        if IbcEventType::from_str(event_type).is_err() {
+            warn!(
+                "unknown event type '{}' in the webhook configuration, no such event will be exported",
+                event_type
+            );
+        }
+    }
+
+    let (sender, receiver) = channel::bounded(QUEUE_CAPACITY);
+
+    let exporter = Exporter {
+        event_types: config.events.clone(),
+        sender,
+    };
+
+    if EXPORTER.set(exporter).is_err() {
+        warn!("the webhook exporter was already spawned, the events are exported by the first one");
+    }
+
+    let agent = ureq::AgentBuilder::new().timeout(POST_TIMEOUT).build();
+    let config = config.clone();
+
+    spawn_background_task(
+        error_span!("webhook"),
+        Some(config.batch_interval),
+        move || -> Result<Next, TaskError<Infallible>> {
+            let events: Vec<CloudEvent> = receiver.try_iter().collect();
+
+            for batch in events.chunks(config.batch_size.max(1)) {
+                post_batch(&agent, &config, batch);
+            }
+
+            Ok(Next::Continue)
+        },
+    )
+}
+
+/// Hand over the events of the configured types among the given events,
+/// observed on the given chain, to the webhook exporter, if it was spawned.
+///
+/// The events are dropped if the queue of the exporter is full, eg. because
+/// the endpoint has been failing for a while, so as not to hold up relaying.
+pub fn export_events(chain_id: &ChainId, events: &[IbcEventWithHeight]) {
+    let exporter = match EXPORTER.get() {
+        Some(exporter) => exporter,
+        None => return,
+    };
+
+    let mut dropped = 0;
+
+    for event in events.iter().filter(|event| exporter.exports(event)) {
+        let cloud_event = CloudEvent::new(chain_id, event.clone());
+
+        if exporter.sender.try_send(cloud_event).is_err() {
+            dropped += 1;
+        }
+    }
+
+    if dropped > 0 {
+        warn!(
+            chain = %chain_id,
+            "the webhook queue is full, dropping {} events",
+            dropped
+        );
+
+        telemetry!(webhook_events_dropped, chain_id, dropped as u64);
+    }
+}
+
+/// Post the given batch of events to the endpoint, retrying up to `max_retries`
+/// times before dropping the batch.
+fn post_batch(agent: &ureq::Agent, config: &WebhookConfig, batch: &[CloudEvent]) {
+    let body = match serde_json::to_string(batch) {
+        Ok(body) => body,
+        Err(e) => {
+            error!(
+                "failed to serialize a batch of {} events: {}",
+                batch.len(),
+                e
+            );
+            return;
+        }
+    };
+
+    let mut delay = INITIAL_RETRY_DELAY;
+
+    for attempt in 0..=config.max_retries {
+        if attempt > 0 {
+            thread::sleep(delay);
+            delay *= 2;
+        }
+
+        match agent
+            .post(&config.url)
+            .set("Content-Type", CLOUDEVENTS_BATCH_CONTENT_TYPE)
+            .send_string(&body)
+        {
+            Ok(_) => {
+                debug!("posted {} events to {}", batch.len(), config.url);
+                return;
+            }
+            Err(e) => warn!(
+                "failed to post {} events to {} (attempt {}/{}): {}",
+                batch.len(),
+                config.url,
+                attempt + 1,
+                config.max_retries + 1,
+                e
+            ),
+        }
+    }
+
+    error!(
+        "dropping {} events which could not be posted to {}",
+        batch.len(),
+        config.url
+    );
+
+    for event in batch {
+        telemetry!(webhook_events_dropped, &event.chain_id, 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::core::ics04_channel::events::{ReceivePacket, SendPacket};
+    use ibc_relayer_types::core::ics04_channel::packet::Packet;
+    use ibc_relayer_types::events::IbcEvent;
+    use ibc_relayer_types::Height;
+
+    fn event(event: IbcEvent) -> IbcEventWithHeight {
+        IbcEventWithHeight::new(event, Height::new(0, 10).unwrap())
+    }
+
+    #[test]
+    fn export_configured_event_types() {
+        let (sender, _receiver) = channel::bounded(QUEUE_CAPACITY);
+
+        let exporter = Exporter {
+            event_types: vec!["receive_packet".to_string()],
+            sender,
+        };
+
+        assert!(
+            exporter.exports(&event(IbcEvent::ReceivePacket(ReceivePacket {
+                packet: Packet::default(),
+            })))
+        );
+
+        assert!(!exporter.exports(&event(IbcEvent::SendPacket(SendPacket {
+            packet: Packet::default(),
+        }))));
+    }
+
+    #[test]
+    fn cloud_event_json() {
+        let cloud_event = CloudEvent::new(
+            &ChainId::from_string("ibc-0"),
+            event(IbcEvent::ReceivePacket(ReceivePacket {
+                packet: Packet::default(),
+            })),
+        );
+
+        let json = serde_json::to_value(&cloud_event).unwrap();
+
+        assert_eq!(json["specversion"], "1.0");
+        assert_eq!(json["source"], "/hermes/ibc-0");
+        assert_eq!(json["type"], "hermes.ibc.receive_packet");
+        assert_eq!(json["datacontenttype"], "application/json");
+        assert!(json["data"]["event"]["ReceivePacket"].is_object());
+        assert!(json.get("chain_id").is_none());
+    }
+}
@@ -2,14 +2,17 @@ use alloc::sync::Arc;
 use core::fmt::{Display, Error as FmtError, Formatter};
 use ibc_relayer_types::core::ics04_channel::channel::Order;
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::AtomicU64;
 use std::sync::Mutex;
-use tracing::error;
+use tracing::{debug, error, info};
 
+use crate::coordination::DirectoryCoordinator;
 use crate::foreign_client::ForeignClient;
 use crate::link::{Link, LinkParameters, Resubmit};
+use crate::state_store::{DirectoryStateStore, StateStore};
 use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
-    config::Config,
+    config::{Config, PathRelayModes},
     object::Object,
 };
 
@@ -19,7 +22,7 @@ mod error;
 pub use error::RunError;
 
 mod handle;
-pub use handle::{WorkerData, WorkerHandle};
+pub use handle::{WorkerData, WorkerHandle, WorkerMetrics};
 
 mod cmd;
 pub use cmd::WorkerCmd;
@@ -27,6 +30,9 @@ pub use cmd::WorkerCmd;
 mod map;
 pub use map::WorkerMap;
 
+mod dead_objects;
+pub use dead_objects::{DeadObject, DeadObjects};
+
 pub mod channel;
 pub mod client;
 pub mod connection;
@@ -45,6 +51,10 @@ impl WorkerId {
     pub fn next(self) -> Self {
         Self(self.0 + 1)
     }
+
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
 }
 
 impl Display for WorkerId {
@@ -58,8 +68,10 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
     id: WorkerId,
     object: Object,
     config: &Config,
+    dead_objects: &DeadObjects,
 ) -> WorkerHandle {
     let mut task_handles = Vec::new();
+    let packets_relayed = Arc::new(AtomicU64::new(0));
 
     let (cmd_tx, data) = match &object {
         Object::Client(client) => {
@@ -93,10 +105,23 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
 
             (cmd_tx, Some(data))
         }
+        Object::Connection(_) if dead_objects.contains(&object) => {
+            debug!(
+                "not spawning worker for connection {} whose handshake was given up on",
+                object.short_name()
+            );
+
+            (None, None)
+        }
         Object::Connection(connection) => {
             let (cmd_tx, cmd_rx) = crossbeam_channel::unbounded();
-            let connection_task =
-                connection::spawn_connection_worker(connection.clone(), chains, cmd_rx);
+            let connection_task = connection::spawn_connection_worker(
+                connection.clone(),
+                chains,
+                cmd_rx,
+                config.mode.connections,
+                dead_objects.clone(),
+            );
             task_handles.push(connection_task);
 
             (Some(cmd_tx), None)
@@ -122,7 +147,37 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
             );
 
             match link_res {
-                Ok(link) => {
+                Ok(mut link) => {
+                    if config.coordination.enabled {
+                        match DirectoryCoordinator::from_config(&config.coordination) {
+                            Ok(coordinator) => link.set_coordinator(Arc::new(coordinator)),
+                            Err(e) => error!(
+                                "not coordinating with other instances on path {}: {}",
+                                path.short_name(),
+                                e
+                            ),
+                        }
+                    }
+
+                    // The packets sent from each chain are relayed as per the mode of its
+                    // channel end, so that the packets sent from a chain and their
+                    // acknowledgements and timeouts can be left to different relayers.
+                    let relay_modes = PathRelayModes::new(
+                        config.find_chain(&path.src_chain_id),
+                        &path.src_port_id,
+                        &path.src_channel_id,
+                        config.find_chain(&path.dst_chain_id),
+                        link.a_to_b.dst_port_id(),
+                        link.a_to_b.dst_channel_id(),
+                    );
+
+                    if relay_modes != PathRelayModes::default() {
+                        info!("relaying {} in {} mode", path.short_name(), relay_modes);
+                    }
+
+                    link.set_relay_modes(relay_modes);
+                    link.set_prune_acked_packets(packets_config.prune_acked_packets);
+
                     let channel_ordering = link.a_to_b.channel().ordering;
                     let should_clear_on_start =
                         packets_config.clear_on_start || channel_ordering == Order::Ordered;
@@ -131,16 +186,28 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                     let link = Arc::new(Mutex::new(link));
                     let resubmit = Resubmit::from_clear_interval(packets_config.clear_interval);
 
+                    let state_store = config.state_store.enabled.then(|| {
+                        Arc::new(DirectoryStateStore::from_config(&config.state_store))
+                            as Arc<dyn StateStore>
+                    });
+
                     let packet_task = packet::spawn_packet_cmd_worker(
                         cmd_rx,
                         link.clone(),
                         should_clear_on_start,
                         packets_config.clear_interval,
                         path.clone(),
+                        state_store,
+                        packets_relayed.clone(),
                     );
                     task_handles.push(packet_task);
 
-                    let link_task = packet::spawn_packet_worker(path.clone(), link, resubmit);
+                    let link_task = packet::spawn_packet_worker(
+                        path.clone(),
+                        link,
+                        resubmit,
+                        packets_relayed.clone(),
+                    );
                     task_handles.push(link_task);
 
                     (Some(cmd_tx), None)
@@ -162,5 +229,5 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
         }
     };
 
-    WorkerHandle::new(id, object, data, cmd_tx, task_handles)
+    WorkerHandle::new(id, object, data, cmd_tx, task_handles, packets_relayed)
 }
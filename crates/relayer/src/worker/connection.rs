@@ -1,32 +1,66 @@
 use core::time::Duration;
+use std::time::Instant;
+
 use crossbeam_channel::Receiver;
-use tracing::{debug, error_span};
+use ibc_relayer_types::core::ics03_connection::connection::State;
+use ibc_relayer_types::Height;
+use tracing::{debug, error_span, info, warn};
 
+use crate::config::Connections as ConnectionsConfig;
 use crate::connection::Connection as RelayConnection;
 use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
 use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
-    object::Connection,
-    util::retry::retry_with_index,
+    object::{Connection, Object},
+    util::retry::{retry_with_index, RetryResult},
     worker::retry_strategy,
 };
 
 use super::error::RunError;
-use super::WorkerCmd;
+use super::{DeadObject, DeadObjects, WorkerCmd};
 
 pub fn spawn_connection_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     connection: Connection,
     chains: ChainHandlePair<ChainA, ChainB>,
     cmd_rx: Receiver<WorkerCmd>,
+    config: ConnectionsConfig,
+    dead_objects: DeadObjects,
 ) -> TaskHandle {
     let mut complete_handshake_on_new_block = true;
+
+    // The last time the handshake made progress, as far as the worker can tell,
+    // ie. the last time the worker received a handshake event for the connection.
+    let mut last_progress = Instant::now();
+
     spawn_background_task(
         error_span!("worker.connection", connection = %connection.short_name()),
         Some(Duration::from_millis(200)),
         move || {
             if let Ok(cmd) = cmd_rx.try_recv() {
                 match cmd {
+                    WorkerCmd::NewBlock {
+                        height: current_height,
+                        new_block: _,
+                    } if config
+                        .handshake_timeout
+                        .map_or(false, |timeout| last_progress.elapsed() >= timeout) =>
+                    {
+                        let stuck_for = last_progress.elapsed();
+                        last_progress = Instant::now();
+
+                        handle_stuck_handshake(
+                            &chains,
+                            &connection,
+                            current_height,
+                            stuck_for,
+                            config.cleanup_stuck_handshakes,
+                            &dead_objects,
+                        )
+                    }
+
                     WorkerCmd::IbcEvents { batch } => {
+                        last_progress = Instant::now();
+
                         // there can be up to two event for this connection, e.g. init and try.
                         // process the last event, the one with highest "rank".
                         let last_event_with_height = batch.events.last();
@@ -87,3 +121,59 @@ pub fn spawn_connection_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
         },
     )
 }
+
+/// Give up on the handshake of the given connection, which has not made any
+/// progress for `stuck_for`, unless it turns out to be complete or, if `cleanup`
+/// is enabled, its next step can be submitted successfully.
+fn handle_stuck_handshake<ChainA: ChainHandle, ChainB: ChainHandle>(
+    chains: &ChainHandlePair<ChainA, ChainB>,
+    connection: &Connection,
+    current_height: Height,
+    stuck_for: Duration,
+    cleanup: bool,
+    dead_objects: &DeadObjects,
+) -> Result<Next, TaskError<RunError>> {
+    let height = current_height
+        .decrement()
+        .map_err(|e| TaskError::Fatal(RunError::ics02(e)))?;
+
+    let (mut handshake_connection, state) = RelayConnection::restore_from_state(
+        chains.a.clone(),
+        chains.b.clone(),
+        connection.clone(),
+        height,
+    )
+    .map_err(|e| TaskError::Fatal(RunError::connection(e)))?;
+
+    // Only handshakes which have not been opened on the source chain
+    // yet are given up on, since they cannot be used in the meantime.
+    if !matches!(state, State::Init | State::TryOpen) {
+        return Ok(Next::Continue);
+    }
+
+    if cleanup {
+        info!(
+            "connection handshake stuck in {} for {:?}, attempting its next step one last time",
+            state, stuck_for
+        );
+
+        match handshake_connection.step_state(state, 0) {
+            RetryResult::Ok(next) => return Ok(next),
+            RetryResult::Retry(_) | RetryResult::Err(_) => {}
+        }
+    }
+
+    warn!(
+        "giving up on connection handshake stuck in {} for {:?}",
+        state, stuck_for
+    );
+
+    dead_objects.insert(DeadObject {
+        object: Object::Connection(connection.clone()),
+        state: state.to_string(),
+        stuck_for,
+        reason: cleanup.then(|| "failed to submit the next handshake step".to_string()),
+    });
+
+    Ok(Next::Abort)
+}
@@ -0,0 +1,59 @@
+use alloc::collections::BTreeMap;
+use core::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::object::Object;
+use crate::util::lock::{LockExt, RwArc};
+
+/// An object whose handshake the relayer gave up on completing,
+/// after it remained stuck for longer than the configured timeout.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeadObject {
+    pub object: Object,
+
+    /// The state in which the handshake was stuck on the source chain of the object.
+    pub state: String,
+
+    /// For how long the handshake had not made any progress when it was given up on.
+    #[serde(with = "humantime_serde")]
+    pub stuck_for: Duration,
+
+    /// Why the handshake could not be completed, if known.
+    pub reason: Option<String>,
+}
+
+/// The registry of the dead objects, shared between the workers
+/// which give up on objects and the supervisor, which reports them.
+///
+/// No worker is spawned for an object once it is recorded as dead,
+/// until the relayer is restarted.
+#[derive(Clone, Debug, Default)]
+pub struct DeadObjects {
+    objects: RwArc<BTreeMap<Object, DeadObject>>,
+}
+
+impl DeadObjects {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&self, dead_object: DeadObject) {
+        self.objects
+            .acquire_write()
+            .insert(dead_object.object.clone(), dead_object);
+    }
+
+    pub fn contains(&self, object: &Object) -> bool {
+        self.objects.acquire_read().contains_key(object)
+    }
+
+    /// Forget about the given object, so that a worker can be spawned for it again.
+    pub fn remove(&self, object: &Object) -> Option<DeadObject> {
+        self.objects.acquire_write().remove(object)
+    }
+
+    pub fn list(&self) -> Vec<DeadObject> {
+        self.objects.acquire_read().values().cloned().collect()
+    }
+}
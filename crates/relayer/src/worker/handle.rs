@@ -1,5 +1,7 @@
 use core::fmt;
 use core::mem;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 
 use crossbeam_channel::Sender;
 use serde::Deserialize;
@@ -25,12 +27,30 @@ pub enum WorkerData {
     Client { misbehaviour: bool, refresh: bool },
 }
 
+/// Metrics about the activity of a worker, aggregated over its tasks.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkerMetrics {
+    /// The number of packets relayed by the worker, counting received,
+    /// acknowledged and timed out packets.
+    pub packets_relayed: u64,
+
+    /// The number of failed steps of the worker tasks which were retried.
+    pub retries: u64,
+
+    /// The number of times the worker tasks panicked, including the panics
+    /// after which the worker was respawned.
+    #[serde(default)]
+    pub panics: u64,
+}
+
 pub struct WorkerHandle {
     id: WorkerId,
     object: Object,
     data: Option<WorkerData>,
     tx: RwArc<Option<Sender<WorkerCmd>>>,
     task_handles: Vec<TaskHandle>,
+    packets_relayed: Arc<AtomicU64>,
+    respawns: u64,
 }
 
 impl WorkerHandle {
@@ -40,6 +60,7 @@ impl WorkerHandle {
         data: Option<WorkerData>,
         tx: Option<Sender<WorkerCmd>>,
         task_handles: Vec<TaskHandle>,
+        packets_relayed: Arc<AtomicU64>,
     ) -> Self {
         Self {
             id,
@@ -47,9 +68,16 @@ impl WorkerHandle {
             data,
             tx: <RwArc<_>>::new_lock(tx),
             task_handles,
+            packets_relayed,
+            respawns: 0,
         }
     }
 
+    /// Record how many times the worker was respawned after one of its tasks panicked.
+    pub(super) fn set_respawns(&mut self, respawns: u64) {
+        self.respawns = respawns;
+    }
+
     pub fn try_send_command(&self, cmd: WorkerCmd) {
         let res = if let Some(tx) = self.tx.acquire_read().as_ref() {
             tx.send(cmd)
@@ -107,6 +135,49 @@ impl WorkerHandle {
         // Drop handle automatically handles the waiting for tasks to terminate.
     }
 
+    /// Pause all worker tasks. Commands sent to a paused worker
+    /// are queued until the worker is resumed.
+    pub fn pause(&self) {
+        for task in self.task_handles.iter() {
+            task.pause()
+        }
+    }
+
+    /// Resume all worker tasks.
+    pub fn resume(&self) {
+        for task in self.task_handles.iter() {
+            task.resume()
+        }
+    }
+
+    /// Whether the worker is paused, ie. it has tasks and all of them are paused.
+    pub fn is_paused(&self) -> bool {
+        !self.task_handles.is_empty() && self.task_handles.iter().all(TaskHandle::is_paused)
+    }
+
+    /// Get the metrics of the worker.
+    pub fn metrics(&self) -> WorkerMetrics {
+        WorkerMetrics {
+            packets_relayed: self.packets_relayed.load(Ordering::SeqCst),
+            retries: self
+                .task_handles
+                .iter()
+                .map(TaskHandle::ignored_errors)
+                .sum(),
+            panics: self.respawns
+                + self
+                    .task_handles
+                    .iter()
+                    .filter(|task| task.has_panicked())
+                    .count() as u64,
+        }
+    }
+
+    /// Whether any of the worker tasks was stopped because it panicked.
+    pub fn has_panicked(&self) -> bool {
+        self.task_handles.iter().any(TaskHandle::has_panicked)
+    }
+
     pub fn is_stopped(&self) -> bool {
         for task in self.task_handles.iter() {
             if !task.is_stopped() {
@@ -1,25 +1,50 @@
 use alloc::collections::btree_map::BTreeMap as HashMap;
 use core::mem;
+use core::time::Duration;
+use std::time::Instant;
 
 use ibc_relayer_types::core::ics02_client::events::NewBlock;
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::Height;
-use tracing::{debug, trace};
+use tracing::{debug, error, trace, warn};
 
 use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
     config::Config,
     object::Object,
+    registry::Registry,
     telemetry,
 };
 
-use super::{spawn_worker_tasks, WorkerHandle, WorkerId};
+use super::{spawn_worker_tasks, DeadObjects, WorkerHandle, WorkerId};
+
+/// How many times in a row a worker is respawned after one of its tasks panicked,
+/// before it is given up on.
+const MAX_RESPAWNS: u64 = 5;
+
+/// How long a worker has to run without being respawned for its
+/// budget of respawns to be reset.
+const RESPAWN_RESET_PERIOD: Duration = Duration::from_secs(10 * 60);
+
+/// The respawns of the worker associated with an [`Object`].
+#[derive(Debug)]
+struct Respawns {
+    /// The number of respawns since the budget was last reset,
+    /// or `MAX_RESPAWNS + 1` once the worker was given up on.
+    count: u64,
+    /// The total number of respawns of the worker.
+    total: u64,
+    /// When the worker was last respawned, or given up on.
+    last: Instant,
+}
 
 /// Manage the lifecycle of [`WorkerHandle`]s associated with [`Object`]s.
 #[derive(Debug)]
 pub struct WorkerMap {
     workers: HashMap<Object, WorkerHandle>,
     latest_worker_id: WorkerId,
+    dead_objects: DeadObjects,
+    respawns: HashMap<Object, Respawns>,
 }
 
 impl Default for WorkerMap {
@@ -27,6 +52,8 @@ impl Default for WorkerMap {
         Self {
             workers: HashMap::new(),
             latest_worker_id: WorkerId::new(0),
+            dead_objects: DeadObjects::new(),
+            respawns: HashMap::new(),
         }
     }
 }
@@ -151,6 +178,94 @@ impl WorkerMap {
         }
     }
 
+    /// Respawn the workers one of whose tasks panicked, with fresh state, since the
+    /// panic may have left the state of the panicked task inconsistent.
+    ///
+    /// A worker is respawned at most [`MAX_RESPAWNS`] times in a row, after which it
+    /// is given up on. This budget is reset once the worker ran for [`RESPAWN_RESET_PERIOD`]
+    /// without being respawned, and a worker which was given up on is respawned again
+    /// once that period elapsed.
+    pub fn respawn_panicked<Chain: ChainHandle>(
+        &mut self,
+        registry: &mut Registry<Chain>,
+        config: &Config,
+    ) {
+        let panicked = self
+            .workers
+            .iter()
+            .filter(|(_, worker)| worker.has_panicked())
+            .map(|(object, _)| object.clone())
+            .collect::<Vec<_>>();
+
+        for object in panicked {
+            let (count, total) = match self.respawns.get_mut(&object) {
+                Some(respawns) if respawns.last.elapsed() > RESPAWN_RESET_PERIOD => {
+                    respawns.count = 0;
+                    (0, respawns.total)
+                }
+                Some(respawns) => (respawns.count, respawns.total),
+                None => (0, 0),
+            };
+
+            if count > MAX_RESPAWNS {
+                continue;
+            }
+
+            if count == MAX_RESPAWNS {
+                error!(
+                    worker.object = %object.short_name(),
+                    "giving up on worker after it was respawned {} times in a row", MAX_RESPAWNS
+                );
+
+                self.record_respawn(&object, count + 1, total);
+                continue;
+            }
+
+            let chains = registry
+                .get_or_spawn_for(object.src_chain_id(), &object)
+                .and_then(|src| {
+                    registry
+                        .get_or_spawn_for(object.dst_chain_id(), &object)
+                        .map(|dst| (src, dst))
+                });
+
+            let (src, dst) = match chains {
+                Ok(chains) => chains,
+                Err(e) => {
+                    error!(
+                        worker.object = %object.short_name(),
+                        "failed to respawn worker whose task panicked: {}", e
+                    );
+                    continue;
+                }
+            };
+
+            warn!(
+                worker.object = %object.short_name(),
+                "respawning worker whose task panicked"
+            );
+
+            self.shutdown_worker(&object);
+
+            let mut worker = self.spawn_worker(src, dst, &object, config);
+            worker.set_respawns(total + 1);
+            self.workers.insert(object.clone(), worker);
+
+            self.record_respawn(&object, count + 1, total + 1);
+        }
+    }
+
+    fn record_respawn(&mut self, object: &Object, count: u64, total: u64) {
+        self.respawns.insert(
+            object.clone(),
+            Respawns {
+                count,
+                total,
+                last: Instant::now(),
+            },
+        );
+    }
+
     /// Force spawn a worker for the given [`Object`].
     fn spawn_worker<Chain: ChainHandle>(
         &mut self,
@@ -166,6 +281,7 @@ impl WorkerMap {
             self.next_worker_id(),
             object.clone(),
             config,
+            &self.dead_objects,
         )
     }
 
@@ -186,6 +302,33 @@ impl WorkerMap {
             .collect()
     }
 
+    /// List the [`Object`]s for which there is an associated packet or channel
+    /// worker for the given channel on the given source chain.
+    pub fn objects_for_channel(
+        &self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Vec<Object> {
+        self.workers
+            .keys()
+            .filter(|o| match o {
+                Object::Packet(p) => {
+                    &p.src_chain_id == chain_id
+                        && &p.src_port_id == port_id
+                        && &p.src_channel_id == channel_id
+                }
+                Object::Channel(c) => {
+                    &c.src_chain_id == chain_id
+                        && &c.src_port_id == port_id
+                        && &c.src_channel_id == channel_id
+                }
+                _ => false,
+            })
+            .cloned()
+            .collect()
+    }
+
     /// List the [`WorkerHandle`]s associated with the given chain.
     pub fn workers_for_chain(&self, chain_id: &ChainId) -> Vec<&WorkerHandle> {
         self.workers
@@ -194,6 +337,16 @@ impl WorkerMap {
             .collect()
     }
 
+    /// Return the handle to the worker associated with the given [`Object`], if any.
+    pub fn get(&self, object: &Object) -> Option<&WorkerHandle> {
+        self.workers.get(object)
+    }
+
+    /// The objects whose handshake the workers of this map gave up on.
+    pub fn dead_objects(&self) -> &DeadObjects {
+        &self.dead_objects
+    }
+
     /// Return all the handles to the workers tracked in this map.
     pub fn handles(&self) -> impl Iterator<Item = &WorkerHandle> {
         self.workers.values()
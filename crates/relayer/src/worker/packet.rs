@@ -1,17 +1,21 @@
 use core::time::Duration;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 use crossbeam_channel::Receiver;
-use tracing::{error, error_span, trace};
+use tracing::{debug, error, error_span, trace, warn};
 
+use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::Height;
 
 use crate::chain::handle::ChainHandle;
 use crate::event::monitor::EventBatch;
-use crate::foreign_client::HasExpiredOrFrozenError;
+use crate::link::error::{ErrorClass, LinkError};
+use crate::link::Link;
 use crate::link::Resubmit;
-use crate::link::{error::LinkError, Link};
 use crate::object::Packet;
+use crate::state_store::StateStore;
 use crate::telemetry;
 use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
 
@@ -19,12 +23,22 @@ use super::error::RunError;
 use super::WorkerCmd;
 
 fn handle_link_error_in_task(e: LinkError) -> TaskError<RunError> {
-    if e.is_expired_or_frozen_error() {
+    match e.class() {
         // If the client is expired or frozen, terminate the packet worker
         // as there is no point of relaying further packets.
-        TaskError::Fatal(RunError::link(e))
-    } else {
-        TaskError::Ignore(RunError::link(e))
+        ErrorClass::Fatal => TaskError::Fatal(RunError::link(e)),
+        ErrorClass::Misconfiguration => {
+            warn!(
+                "will retry, but the configuration of Hermes likely needs to be fixed: {}",
+                e
+            );
+            TaskError::Ignore(RunError::link(e))
+        }
+        ErrorClass::ExpectedRace => {
+            debug!("ignoring error caused by another relayer: {}", e);
+            TaskError::Ignore(RunError::link(e))
+        }
+        ErrorClass::Retryable => TaskError::Ignore(RunError::link(e)),
     }
 }
 
@@ -35,6 +49,7 @@ pub fn spawn_packet_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     // Mutex is used to prevent race condition between the packet workers
     link: Arc<Mutex<Link<ChainA, ChainB>>>,
     resubmit: Resubmit,
+    packets_relayed: Arc<AtomicU64>,
 ) -> TaskHandle {
     let span = {
         let relay_path = &link.lock().unwrap().a_to_b;
@@ -48,11 +63,74 @@ pub fn spawn_packet_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     };
 
     spawn_background_task(span, Some(Duration::from_millis(1000)), move || {
-        handle_execute_schedule(&mut link.lock().unwrap(), &path, resubmit)?;
+        handle_execute_schedule(&mut link.lock().unwrap(), &path, resubmit, &packets_relayed)?;
         Ok(Next::Continue)
     })
 }
 
+/// Minimum time between two writes of the last scanned height to the state store.
+const SCANNED_HEIGHT_SAVE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Tracks the scanning of the source chain by a packet command worker.
+struct ScanProgress {
+    /// The height scanned before the worker was last stopped,
+    /// until the blocks since that height have been scanned.
+    resume_from: Option<Height>,
+    /// The last height scanned, if not yet saved to the state store.
+    unsaved: Option<Height>,
+    /// When the last scanned height was last saved to the state store.
+    last_saved: Option<Instant>,
+}
+
+impl ScanProgress {
+    fn load(state_store: Option<&dyn StateStore>, path: &Packet) -> Self {
+        let resume_from = state_store.and_then(|store| {
+            store
+                .last_scanned_height(path)
+                .map_err(|e| warn!("failed to load the last scanned height: {}", e))
+                .ok()
+                .flatten()
+        });
+
+        Self {
+            resume_from,
+            unsaved: None,
+            last_saved: None,
+        }
+    }
+
+    /// Saves the last scanned height to the state store, only once the operational data
+    /// scheduled up to that height has been relayed, so that no packet is skipped when
+    /// resuming, and at most once per [`SCANNED_HEIGHT_SAVE_INTERVAL`].
+    fn save<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &mut self,
+        state_store: &dyn StateStore,
+        path: &Packet,
+        link: &Link<ChainA, ChainB>,
+    ) {
+        let height = match self.unsaved {
+            Some(height) => height,
+            None => return,
+        };
+
+        let due = self
+            .last_saved
+            .map_or(true, |at| at.elapsed() >= SCANNED_HEIGHT_SAVE_INTERVAL);
+
+        if !due || !link.a_to_b.is_idle() {
+            return;
+        }
+
+        match state_store.set_last_scanned_height(path, height) {
+            Ok(()) => {
+                self.unsaved = None;
+                self.last_saved = Some(Instant::now());
+            }
+            Err(e) => warn!("failed to store the last scanned height {}: {}", height, e),
+        }
+    }
+}
+
 pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     cmd_rx: Receiver<WorkerCmd>,
     // Mutex is used to prevent race condition between the packet workers
@@ -60,6 +138,8 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     mut should_clear_on_start: bool,
     clear_interval: u64,
     path: Packet,
+    state_store: Option<Arc<dyn StateStore>>,
+    packets_relayed: Arc<AtomicU64>,
 ) -> TaskHandle {
     let span = {
         let relay_path = &link.lock().unwrap().a_to_b;
@@ -72,6 +152,9 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
         )
     };
 
+    // Resume scanning from the last height scanned before the worker was last stopped, if known
+    let mut progress = ScanProgress::load(state_store.as_deref(), &path);
+
     spawn_background_task(span, Some(Duration::from_millis(200)), move || {
         if let Ok(cmd) = cmd_rx.try_recv() {
             // Try to clear pending packets. At different levels down in `handle_packet_cmd` there
@@ -82,8 +165,11 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
             handle_packet_cmd(
                 &mut link.lock().unwrap(),
                 &mut should_clear_on_start,
+                &mut progress,
                 clear_interval,
                 &path,
+                state_store.as_deref(),
+                &packets_relayed,
                 cmd,
             )?;
         }
@@ -102,13 +188,21 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
 ///
 /// Given a `ClearPendingPackets` command, clears pending packets.
 ///
+/// If the worker is not clearing packets on start but the height it last scanned
+/// before being stopped is known, the first `IbcEvent` or `NewBlock` command
+/// triggers the scanning of the blocks since that height. The scanned height is
+/// saved to the state store once the packets scheduled up to it have been relayed.
+///
 /// Regardless of the incoming command, this method also refreshes and
 /// and executes any scheduled operational data that is ready.
 fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: &mut Link<ChainA, ChainB>,
     should_clear_on_start: &mut bool,
+    progress: &mut ScanProgress,
     clear_interval: u64,
     path: &Packet,
+    state_store: Option<&dyn StateStore>,
+    packets_relayed: &AtomicU64,
     cmd: WorkerCmd,
 ) -> Result<(), TaskError<RunError>> {
     // Handle packet clearing which is triggered from a command
@@ -134,21 +228,48 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
         WorkerCmd::ClearPendingPackets => (true, None),
     };
 
+    let scanned_height = match &cmd {
+        WorkerCmd::IbcEvents { batch } => Some(batch.height),
+        WorkerCmd::NewBlock { height, .. } => Some(*height),
+        WorkerCmd::ClearPendingPackets => None,
+    };
+
     if do_clear {
         // Reset the `clear_on_start` flag and attempt packet clearing once now.
         // More clearing will be done at clear interval.
         if *should_clear_on_start {
             *should_clear_on_start = false;
         }
-        handle_clear_packet(link, clear_interval, path, maybe_height)?;
+
+        // Clearing the pending packets covers the blocks missed since the last scanned height
+        progress.resume_from = None;
+
+        handle_clear_packet(link, clear_interval, path, maybe_height, packets_relayed)?;
+    }
+
+    if let (Some(from), Some(height)) = (progress.resume_from, scanned_height) {
+        // The events at the current height are handled by the command itself
+        if let Ok(to) = height.decrement() {
+            handle_resume_packets(link, clear_interval, path, from, to, packets_relayed)?;
+        }
+
+        progress.resume_from = None;
     }
 
     // Handle command-specific task
     if let WorkerCmd::IbcEvents { batch } = cmd {
-        handle_update_schedule(link, clear_interval, path, batch)
-    } else {
-        Ok(())
+        handle_update_schedule(link, clear_interval, path, batch, packets_relayed)?;
     }
+
+    if let Some(store) = state_store {
+        if scanned_height.is_some() {
+            progress.unsaved = scanned_height;
+        }
+
+        progress.save(store, path, link);
+    }
+
+    Ok(())
 }
 
 /// Whether or not to clear pending packets at this `step` for some height.
@@ -166,12 +287,18 @@ fn handle_update_schedule<ChainA: ChainHandle, ChainB: ChainHandle>(
     clear_interval: u64,
     path: &Packet,
     batch: EventBatch,
+    packets_relayed: &AtomicU64,
 ) -> Result<(), TaskError<RunError>> {
     link.a_to_b
         .update_schedule(batch)
         .map_err(handle_link_error_in_task)?;
 
-    handle_execute_schedule(link, path, Resubmit::from_clear_interval(clear_interval))
+    handle_execute_schedule(
+        link,
+        path,
+        Resubmit::from_clear_interval(clear_interval),
+        packets_relayed,
+    )
 }
 
 fn handle_clear_packet<ChainA: ChainHandle, ChainB: ChainHandle>(
@@ -179,36 +306,80 @@ fn handle_clear_packet<ChainA: ChainHandle, ChainB: ChainHandle>(
     clear_interval: u64,
     path: &Packet,
     height: Option<Height>,
+    packets_relayed: &AtomicU64,
 ) -> Result<(), TaskError<RunError>> {
     link.a_to_b
         .schedule_packet_clearing(height)
         .map_err(handle_link_error_in_task)?;
 
-    handle_execute_schedule(link, path, Resubmit::from_clear_interval(clear_interval))
+    handle_execute_schedule(
+        link,
+        path,
+        Resubmit::from_clear_interval(clear_interval),
+        packets_relayed,
+    )
+}
+
+fn handle_resume_packets<ChainA: ChainHandle, ChainB: ChainHandle>(
+    link: &mut Link<ChainA, ChainB>,
+    clear_interval: u64,
+    path: &Packet,
+    from: Height,
+    to: Height,
+    packets_relayed: &AtomicU64,
+) -> Result<(), TaskError<RunError>> {
+    debug!(%from, %to, "resuming from the last scanned height");
+
+    link.a_to_b
+        .schedule_packets_since(from, to)
+        .map_err(handle_link_error_in_task)?;
+
+    handle_execute_schedule(
+        link,
+        path,
+        Resubmit::from_clear_interval(clear_interval),
+        packets_relayed,
+    )
 }
 
 fn handle_execute_schedule<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: &mut Link<ChainA, ChainB>,
     _path: &Packet,
     resubmit: Resubmit,
+    packets_relayed: &AtomicU64,
 ) -> Result<(), TaskError<RunError>> {
     link.a_to_b
         .refresh_schedule()
         .map_err(handle_link_error_in_task)?;
 
     link.a_to_b.execute_schedule().map_err(|e| {
-        if e.is_expired_or_frozen_error() {
-            TaskError::Fatal(RunError::link(e))
-        } else {
+        if e.class() == ErrorClass::Retryable {
             error!("will retry: schedule execution encountered error: {}", e,);
-            TaskError::Ignore(RunError::link(e))
         }
+        handle_link_error_in_task(e)
     })?;
 
     let summary = link.a_to_b.process_pending_txs(resubmit);
 
     if !summary.is_empty() {
         trace!("produced relay summary: {:?}", summary);
+
+        let relayed = summary
+            .events
+            .iter()
+            .filter(|event| {
+                matches!(
+                    event,
+                    IbcEvent::ReceivePacket(_)
+                        | IbcEvent::AcknowledgePacket(_)
+                        | IbcEvent::TimeoutPacket(_)
+                        | IbcEvent::TimeoutOnClosePacket(_)
+                )
+            })
+            .count();
+
+        packets_relayed.fetch_add(relayed as u64, Ordering::SeqCst);
+
         telemetry!(packet_metrics(_path, &summary));
     }
 
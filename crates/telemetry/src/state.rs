@@ -83,12 +83,18 @@ pub struct TelemetryState {
     /// Number of workers per type
     workers: UpDownCounter<i64>,
 
+    /// Number of times a worker task panicked, per task
+    worker_panics: Counter<u64>,
+
     /// Number of client update messages submitted per client
     client_updates_submitted: Counter<u64>,
 
     /// Number of misbehaviours detected and submitted per client
     client_misbehaviours_submitted: Counter<u64>,
 
+    /// Number of blocks by which the latest height of each client lags behind its source chain
+    client_height_lag: ObservableGauge<u64>,
+
     /// Number of confirmed receive packets per channel
     receive_packets_confirmed: Counter<u64>,
 
@@ -110,12 +116,30 @@ pub struct TelemetryState {
     /// How many IBC events did Hermes receive via the WebSocket subscription, per chain
     ws_events: Counter<u64>,
 
+    /// Number of gaps in the heights of the blocks received via the WebSocket subscription, per chain
+    ws_event_gaps: Counter<u64>,
+
+    /// Number of blocks by which the gRPC endpoint lags behind the highest height observed, per chain
+    grpc_node_lag: ObservableGauge<u64>,
+
+    /// Number of times the node of a chain forked away from the blocks seen earlier
+    node_divergences: Counter<u64>,
+
     /// Number of messages submitted to a specific chain
     total_messages_submitted: Counter<u64>,
 
     /// The balance of each wallet Hermes uses per chain
     wallet_balance: ObservableGauge<f64>,
 
+    /// The fees spent by Hermes on each chain with a daily fee budget, over the current day
+    fee_budget_spent: ObservableGauge<f64>,
+
+    /// Number of transactions withheld because the daily fee budget of the chain was exhausted
+    fee_budget_exhausted: Counter<u64>,
+
+    /// Number of events dropped by the webhook exporter instead of being posted, per chain
+    webhook_events_dropped: Counter<u64>,
+
     /// Indicates the latency for all transactions submitted to a specific chain,
     /// i.e. the difference between the moment when Hermes received a batch of events
     /// until the corresponding transaction(s) were submitted. Milliseconds.
@@ -136,6 +160,9 @@ pub struct TelemetryState {
     /// Number of WriteAcknowledgement events received
     acknowledgement_events: Counter<u64>,
 
+    /// Number of WriteAcknowledgement events received with an error acknowledgement
+    error_acknowledgement_events: Counter<u64>,
+
     /// Number of Timeout events received
     timeout_events: Counter<u64>,
 
@@ -185,6 +212,7 @@ impl TelemetryState {
 
         self.ws_reconnect.add(&cx, 0, labels);
         self.ws_events.add(&cx, 0, labels);
+        self.ws_event_gaps.add(&cx, 0, labels);
         self.total_messages_submitted.add(&cx, 0, labels);
 
         self.init_queries(chain_id);
@@ -228,6 +256,7 @@ impl TelemetryState {
 
         self.send_packet_events.add(&cx, 0, labels);
         self.acknowledgement_events.add(&cx, 0, labels);
+        self.error_acknowledgement_events.add(&cx, 0, labels);
         self.timeout_events.add(&cx, 0, labels);
 
         if clear_packets {
@@ -291,6 +320,13 @@ impl TelemetryState {
         self.workers.add(&cx, count, labels);
     }
 
+    /// Number of times a worker task panicked, per task
+    pub fn worker_panic(&self, task: &str) {
+        let cx = Context::current();
+        let labels = &[KeyValue::new("task", task.to_string())];
+        self.worker_panics.add(&cx, 1, labels);
+    }
+
     /// Update the number of client updates per client
     pub fn client_updates_submitted(
         &self,
@@ -329,6 +365,26 @@ impl TelemetryState {
         self.client_misbehaviours_submitted.add(&cx, count, labels);
     }
 
+    /// Number of blocks by which the latest height of the client lags
+    /// behind the latest height of its source chain.
+    pub fn client_height_lag(
+        &self,
+        src_chain: &ChainId,
+        dst_chain: &ChainId,
+        client: &ClientId,
+        lag: u64,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("src_chain", src_chain.to_string()),
+            KeyValue::new("dst_chain", dst_chain.to_string()),
+            KeyValue::new("client", client.to_string()),
+        ];
+
+        self.client_height_lag.observe(&cx, lag, labels);
+    }
+
     /// Number of receive packets relayed, per channel
     pub fn receive_packets_confirmed(
         &self,
@@ -435,6 +491,33 @@ impl TelemetryState {
         self.ws_events.add(&cx, count, labels);
     }
 
+    /// Number of gaps in the heights of the blocks received via the WebSocket subscription, per chain
+    pub fn ws_event_gaps(&self, chain_id: &ChainId) {
+        let cx = Context::current();
+
+        let labels = &[KeyValue::new("chain", chain_id.to_string())];
+
+        self.ws_event_gaps.add(&cx, 1, labels);
+    }
+
+    /// Number of blocks by which the gRPC endpoint lags behind the highest height observed, per chain
+    pub fn grpc_node_lag(&self, chain_id: &ChainId, lag: u64) {
+        let cx = Context::current();
+
+        let labels = &[KeyValue::new("chain", chain_id.to_string())];
+
+        self.grpc_node_lag.observe(&cx, lag, labels);
+    }
+
+    /// The node of the chain forked away from the blocks seen earlier
+    pub fn node_divergence(&self, chain_id: &ChainId) {
+        let cx = Context::current();
+
+        let labels = &[KeyValue::new("chain", chain_id.to_string())];
+
+        self.node_divergences.add(&cx, 1, labels);
+    }
+
     /// How many messages Hermes submitted to the chain
     pub fn total_messages_submitted(&self, chain_id: &ChainId, count: u64) {
         let cx = Context::current();
@@ -458,6 +541,37 @@ impl TelemetryState {
         self.wallet_balance.observe(&cx, amount, labels);
     }
 
+    /// The fees spent by Hermes on the chain over the current day, in the given denom,
+    /// for the chains with a daily fee budget.
+    pub fn fee_budget_spent(&self, chain_id: &ChainId, amount: f64, denom: &str) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("denom", denom.to_string()),
+        ];
+
+        self.fee_budget_spent.observe(&cx, amount, labels);
+    }
+
+    /// A transaction was withheld because the daily fee budget of the chain was exhausted
+    pub fn fee_budget_exhausted(&self, chain_id: &ChainId) {
+        let cx = Context::current();
+
+        let labels = &[KeyValue::new("chain", chain_id.to_string())];
+
+        self.fee_budget_exhausted.add(&cx, 1, labels);
+    }
+
+    /// Number of events dropped by the webhook exporter instead of being posted, per chain
+    pub fn webhook_events_dropped(&self, chain_id: &ChainId, count: u64) {
+        let cx = Context::current();
+
+        let labels = &[KeyValue::new("chain", chain_id.to_string())];
+
+        self.webhook_events_dropped.add(&cx, count, labels);
+    }
+
     pub fn received_event_batch(&self, tracking_id: impl ToString) {
         self.in_flight_events
             .insert(tracking_id.to_string(), Instant::now());
@@ -565,6 +679,27 @@ impl TelemetryState {
         self.acknowledgement_events.add(&cx, 1, labels);
     }
 
+    /// Number of WriteAcknowledgement events whose acknowledgement reports
+    /// that the application failed to handle the packet
+    pub fn error_acknowledgement_events(
+        &self,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+        ];
+
+        self.error_acknowledgement_events.add(&cx, 1, labels);
+    }
+
     pub fn timeout_events(
         &self,
         chain_id: &ChainId,
@@ -775,6 +910,8 @@ impl AggregatorSelector for CustomAggregatorSelector {
     fn aggregator_for(&self, descriptor: &Descriptor) -> Option<Arc<dyn Aggregator + Send + Sync>> {
         match descriptor.name() {
             "wallet_balance" => Some(Arc::new(last_value())),
+            "fee_budget_spent" => Some(Arc::new(last_value())),
+            "client_height_lag" => Some(Arc::new(last_value())),
             "backlog_oldest_sequence" => Some(Arc::new(last_value())),
             "backlog_oldest_timestamp" => Some(Arc::new(last_value())),
             "backlog_size" => Some(Arc::new(last_value())),
@@ -818,6 +955,11 @@ impl Default for TelemetryState {
                 .with_description("Number of workers")
                 .init(),
 
+            worker_panics: meter
+                .u64_counter("worker_panics")
+                .with_description("Number of times a worker task panicked")
+                .init(),
+
             client_updates_submitted: meter
                 .u64_counter("client_updates_submitted")
                 .with_description("Number of client update messages submitted")
@@ -828,6 +970,11 @@ impl Default for TelemetryState {
                 .with_description("Number of misbehaviours detected and submitted")
                 .init(),
 
+            client_height_lag: meter
+                .u64_observable_gauge("client_height_lag")
+                .with_description("Number of blocks by which the latest height of each client lags behind its source chain")
+                .init(),
+
             receive_packets_confirmed: meter
                 .u64_counter("receive_packets_confirmed")
                 .with_description("Number of confirmed receive packets. Available if relayer runs with Tx confirmation enabled")
@@ -865,6 +1012,21 @@ impl Default for TelemetryState {
                 .with_description("How many IBC events did Hermes receive via the websocket subscription")
                 .init(),
 
+            ws_event_gaps: meter
+                .u64_counter("ws_event_gaps")
+                .with_description("Number of gaps in the heights of the blocks received via the websocket subscription")
+                .init(),
+
+            grpc_node_lag: meter
+                .u64_observable_gauge("grpc_node_lag")
+                .with_description("Number of blocks by which the gRPC endpoint lags behind the highest height observed by Hermes")
+                .init(),
+
+            node_divergences: meter
+                .u64_counter("node_divergences")
+                .with_description("Number of times the node of a chain forked away from the blocks seen earlier")
+                .init(),
+
             total_messages_submitted: meter
                 .u64_counter("total_messages_submitted")
                 .with_description("Number of messages submitted to a specific chain")
@@ -875,6 +1037,21 @@ impl Default for TelemetryState {
                 .with_description("The balance of each wallet Hermes uses per chain. Please note that when converting the balance to f64 a loss in precision might be introduced in the displayed value")
                 .init(),
 
+            fee_budget_spent: meter
+                .f64_observable_gauge("fee_budget_spent")
+                .with_description("The fees spent by Hermes on each chain with a daily fee budget, over the current day")
+                .init(),
+
+            fee_budget_exhausted: meter
+                .u64_counter("fee_budget_exhausted")
+                .with_description("Number of transactions withheld because the daily fee budget of the chain was exhausted")
+                .init(),
+
+            webhook_events_dropped: meter
+                .u64_counter("webhook_events_dropped")
+                .with_description("Number of events dropped by the webhook exporter, either because its queue was full or because the endpoint kept failing")
+                .init(),
+
             send_packet_events: meter
                 .u64_counter("send_packet_events")
                 .with_description("Number of SendPacket events received")
@@ -885,6 +1062,13 @@ impl Default for TelemetryState {
                 .with_description("Number of WriteAcknowledgement events received")
                 .init(),
 
+            error_acknowledgement_events: meter
+                .u64_counter("error_acknowledgement_events")
+                .with_description(
+                    "Number of WriteAcknowledgement events received with an error acknowledgement",
+                )
+                .init(),
+
             timeout_events: meter
                 .u64_counter("timeout_events")
                 .with_description("Number of TimeoutPacket events received")
@@ -146,8 +146,16 @@ impl TestOverrides for IbcTransferMBT {
                 refresh: true,
                 misbehaviour: true,
             },
-            connections: ConfigConnections { enabled: true },
-            channels: ConfigChannels { enabled: true },
+            connections: ConfigConnections {
+                enabled: true,
+                dedicated_runtime: false,
+                handshake_timeout: None,
+                cleanup_stuck_handshakes: false,
+            },
+            channels: ConfigChannels {
+                enabled: true,
+                dedicated_runtime: false,
+            },
             packets: ConfigPackets {
                 enabled: true,
                 clear_interval: 10,
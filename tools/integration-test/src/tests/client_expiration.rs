@@ -104,8 +104,16 @@ impl TestOverrides for ExpirationTestOverrides {
                 refresh: true,
                 misbehaviour: true,
             },
-            connections: config::Connections { enabled: true },
-            channels: config::Channels { enabled: true },
+            connections: config::Connections {
+                enabled: true,
+                dedicated_runtime: false,
+                handshake_timeout: None,
+                cleanup_stuck_handshakes: false,
+            },
+            channels: config::Channels {
+                enabled: true,
+                dedicated_runtime: false,
+            },
             packets: config::Packets {
                 enabled: true,
                 clear_interval: 10,
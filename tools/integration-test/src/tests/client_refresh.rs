@@ -32,6 +32,8 @@ impl TestOverrides for ClientDefaultsTest {
             max_clock_drift: Some(Duration::from_secs(3)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::new(13, 23).unwrap()),
+            height: None,
+            proof_specs: None,
         }
     }
 
@@ -40,6 +42,8 @@ impl TestOverrides for ClientDefaultsTest {
             max_clock_drift: Some(Duration::from_secs(6)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::TWO_THIRDS),
+            height: None,
+            proof_specs: None,
         }
     }
 }
@@ -108,6 +112,8 @@ impl TestOverrides for ClientFailsTest {
             max_clock_drift: Some(Duration::from_secs(3)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::new(13, 23).unwrap()),
+            height: None,
+            proof_specs: None,
         }
     }
 
@@ -116,6 +122,8 @@ impl TestOverrides for ClientFailsTest {
             max_clock_drift: Some(Duration::from_secs(6)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::TWO_THIRDS),
+            height: None,
+            proof_specs: None,
         }
     }
 }
@@ -67,6 +67,8 @@ impl TestOverrides for ClientOptionsTest {
             max_clock_drift: Some(Duration::from_secs(3)),
             trusting_period: Some(Duration::from_secs(120_000)),
             trust_threshold: Some(TrustThreshold::new(13, 23).unwrap()),
+            height: None,
+            proof_specs: None,
         }
     }
 
@@ -75,6 +77,8 @@ impl TestOverrides for ClientOptionsTest {
             max_clock_drift: Some(Duration::from_secs(6)),
             trusting_period: Some(Duration::from_secs(340_000)),
             trust_threshold: Some(TrustThreshold::TWO_THIRDS),
+            height: None,
+            proof_specs: None,
         }
     }
 }
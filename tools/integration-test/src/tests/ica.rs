@@ -9,9 +9,12 @@ use ibc_relayer::config::{
 use ibc_relayer_types::core::ics04_channel::channel::State;
 
 use ibc_test_framework::{
+    bootstrap::binary::ica::{
+        bootstrap_interchain_account_channel, register_interchain_account_channel,
+    },
     ibc::denom::Denom,
     prelude::*,
-    relayer::channel::{assert_eventually_channel_established, query_channel_end},
+    relayer::channel::query_channel_end,
 };
 
 #[test]
@@ -78,25 +81,19 @@ impl BinaryConnectionTest for IcaFilterTestAllow {
         chains: ConnectedChains<Controller, Host>,
         connection: ConnectedConnection<Controller, Host>,
     ) -> Result<(), Error> {
-        // Register an interchain account on behalf of
-        // controller wallet `user1` where the counterparty chain is the interchain accounts host.
-        // Then spawn the supervisor.
-        let (_handle, wallet, channel_id, port_id) =
-            register_interchain_account(&relayer, &chains, &connection)?;
-
-        // Check that the corresponding ICA channel is eventually established.
-        let _counterparty_channel_id = assert_eventually_channel_established(
-            chains.handle_a(),
-            chains.handle_b(),
-            &channel_id.as_ref(),
-            &port_id.as_ref(),
+        let _handle = relayer.spawn_supervisor()?;
+
+        // Register an interchain account on behalf of controller wallet `user1`
+        // where the counterparty chain is the interchain accounts host, and
+        // check that the corresponding ICA channel is eventually established.
+        let ica_channel = bootstrap_interchain_account_channel(
+            &chains,
+            &connection,
+            &chains.node_a.wallets().user1(),
         )?;
 
-        // Query the controller chain for the address of the ICA wallet on the host chain.
-        let ica_address = chains
-            .node_a
-            .chain_driver()
-            .query_interchain_account(&wallet.address(), &connection.connection_id_a.as_ref())?;
+        let wallet = ica_channel.owner;
+        let ica_address = ica_channel.interchain_account;
 
         let stake_denom: MonoTagged<Host, Denom> = MonoTagged::new(Denom::base("stake"));
 
@@ -195,11 +192,15 @@ impl BinaryConnectionTest for IcaFilterTestDeny {
         chains: ConnectedChains<Controller, Host>,
         connection: ConnectedConnection<Controller, Host>,
     ) -> Result<(), Error> {
+        let _handle = relayer.spawn_supervisor()?;
+
         // Register an interchain account on behalf of controller wallet `user1`
         // where the counterparty chain is the interchain accounts host.
-        // Then spawn the supervisor.
-        let (_handle, _, channel_id, port_id) =
-            register_interchain_account(&relayer, &chains, &connection)?;
+        let (channel_id, port_id) = register_interchain_account_channel(
+            &chains,
+            &connection,
+            &chains.node_a.wallets().user1(),
+        )?;
 
         // Wait a bit, the relayer will refuse to complete the channel handshake
         // because the port is explicitly disallowed by the filter.
@@ -217,35 +218,82 @@ impl BinaryConnectionTest for IcaFilterTestDeny {
     }
 }
 
-#[allow(clippy::type_complexity)]
-fn register_interchain_account<ChainA: ChainHandle, ChainB: ChainHandle>(
-    relayer: &RelayerDriver,
-    chains: &ConnectedChains<ChainA, ChainB>,
-    connection: &ConnectedConnection<ChainA, ChainB>,
-) -> Result<
-    (
-        SupervisorHandle,
-        MonoTagged<ChainA, Wallet>,
-        TaggedChannelId<ChainA, ChainB>,
-        TaggedPortId<ChainA, ChainB>,
-    ),
-    Error,
-> {
-    let wallet = chains.node_a.wallets().user1().cloned();
-    let handle = relayer.spawn_supervisor()?;
-
-    chains
-        .node_a
-        .chain_driver()
-        .register_interchain_account(&wallet.address(), &connection.connection_id_a.as_ref())?;
-
-    let channel_id: TaggedChannelId<ChainA, ChainB> =
-        TaggedChannelId::new("channel-0".parse().unwrap());
-
-    let icacontroller =
-        PortId::from_str(&format!("icacontroller-{}", wallet.address().value())).unwrap();
-
-    let port_id: TaggedPortId<ChainA, ChainB> = TaggedPortId::new(icacontroller);
-
-    Ok((handle, wallet, channel_id, port_id))
+#[test]
+fn test_ica_channel_ports() -> Result<(), Error> {
+    run_binary_connection_test(&IcaChannelPortsTest)
+}
+
+/// Test that the channel handshake completes over the interchain
+/// accounts ports, and that the channel ends on both chains are
+/// bound to these ports rather than to the `transfer` port.
+pub struct IcaChannelPortsTest;
+
+impl TestOverrides for IcaChannelPortsTest {
+    fn modify_relayer_config(&self, config: &mut Config) {
+        config.mode.channels.enabled = true;
+    }
+}
+
+impl BinaryConnectionTest for IcaChannelPortsTest {
+    fn run<Controller: ChainHandle, Host: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        relayer: RelayerDriver,
+        chains: ConnectedChains<Controller, Host>,
+        connection: ConnectedConnection<Controller, Host>,
+    ) -> Result<(), Error> {
+        let _handle = relayer.spawn_supervisor()?;
+
+        let ica_channel = bootstrap_interchain_account_channel(
+            &chains,
+            &connection,
+            &chains.node_a.wallets().user1(),
+        )?;
+
+        let channel = ica_channel.channel;
+
+        assert_eq(
+            "host port should be the interchain accounts host port",
+            channel.port_b.value(),
+            &PortId::from_str("icahost").unwrap(),
+        )?;
+
+        let channel_end_a = query_channel_end(
+            chains.handle_a(),
+            &channel.channel_id_a.as_ref(),
+            &channel.port_a.as_ref(),
+        )?;
+
+        let channel_end_b = query_channel_end(
+            chains.handle_b(),
+            &channel.channel_id_b.as_ref(),
+            &channel.port_b.as_ref(),
+        )?;
+
+        assert_eq(
+            "controller channel end should be ordered",
+            channel_end_a.value().ordering(),
+            &Order::Ordered,
+        )?;
+
+        assert_eq(
+            "controller channel end should point to the host port",
+            &channel_end_a.value().counterparty().port_id,
+            channel.port_b.value(),
+        )?;
+
+        assert_eq(
+            "host channel end should point to the controller port",
+            &channel_end_b.value().counterparty().port_id,
+            channel.port_a.value(),
+        )?;
+
+        assert_eq(
+            "relayer channel should be restored over the interchain accounts ports",
+            channel.channel.src_port_id(),
+            channel.port_a.value(),
+        )?;
+
+        Ok(())
+    }
 }
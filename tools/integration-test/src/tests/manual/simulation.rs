@@ -89,6 +89,9 @@ fn tx_raw_ft_transfer<SrcChain: ChainHandle, DstChain: ChainHandle>(
         receiver: Some(recipient.value().0.clone()),
         timeout_height_offset,
         timeout_duration,
+        timeout_height: None,
+        timeout_timestamp: None,
+        memo: None,
         number_msgs: number_messages,
     };
 
@@ -18,6 +18,7 @@ pub mod memo;
 pub mod python;
 pub mod query_packet;
 pub mod supervisor;
+pub mod supervisor_harness;
 pub mod tendermint;
 pub mod ternary_transfer;
 pub mod transfer;
@@ -39,3 +40,6 @@ pub mod manual;
 
 #[cfg(any(doc, feature = "example"))]
 pub mod example;
+
+#[cfg(any(doc, feature = "load"))]
+pub mod transfer_load;
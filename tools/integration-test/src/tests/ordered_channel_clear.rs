@@ -218,6 +218,9 @@ impl BinaryChannelTest for OrderedChannelClearEqualCLITest {
             receiver: Some(chains.node_b.wallets().user1().address().value().0.clone()),
             timeout_height_offset: 1000,
             timeout_duration: Duration::from_secs(0),
+            timeout_height: None,
+            timeout_timestamp: None,
+            memo: None,
             number_msgs: num_msgs,
         };
 
@@ -0,0 +1,103 @@
+//! Tests the pausing and resuming of workers of an in-process supervisor,
+//! using the [`SupervisorHarness`] to assert on the metrics of the workers.
+
+use ibc_test_framework::prelude::*;
+use ibc_test_framework::util::random::random_u128_range;
+
+#[test]
+fn test_pause_packet_worker() -> Result<(), Error> {
+    run_binary_channel_test(&PausePacketWorkerTest)
+}
+
+pub struct PausePacketWorkerTest;
+
+impl TestOverrides for PausePacketWorkerTest {
+    fn modify_relayer_config(&self, config: &mut Config) {
+        config.mode.packets.clear_interval = 0;
+    }
+
+    fn should_spawn_supervisor(&self) -> bool {
+        false
+    }
+}
+
+impl BinaryChannelTest for PausePacketWorkerTest {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+        channel: ConnectedChannel<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        let denom_a = chains.node_a.denom();
+
+        let wallet_a = chains.node_a.wallets().user1().cloned();
+        let wallet_b = chains.node_b.wallets().user1().cloned();
+
+        let denom_b = derive_ibc_denom(
+            &channel.port_b.as_ref(),
+            &channel.channel_id_b.as_ref(),
+            &denom_a,
+        )?;
+
+        let balance_b = chains
+            .node_b
+            .chain_driver()
+            .query_balance(&wallet_b.address(), &denom_b.as_ref())?;
+
+        let packet_worker = packet_worker_object(&channel);
+
+        relayer.with_supervisor_harness(|harness| {
+            let transfer = |amount: u128| {
+                chains.node_a.chain_driver().ibc_transfer_token(
+                    &channel.port_a.as_ref(),
+                    &channel.channel_id_a.as_ref(),
+                    &wallet_a.as_ref(),
+                    &wallet_b.address(),
+                    &denom_a.with_amount(amount).as_ref(),
+                )
+            };
+
+            let amount1 = random_u128_range(1000, 5000);
+
+            info!("Performing IBC transfer with amount {}", amount1);
+
+            transfer(amount1)?;
+
+            harness.assert_eventual_packets_relayed(&packet_worker, 1)?;
+
+            harness.pause_worker(&packet_worker)?;
+
+            let amount2 = random_u128_range(1000, 5000);
+
+            info!(
+                "Performing IBC transfer with amount {}, which should not be relayed while the worker is paused",
+                amount2
+            );
+
+            transfer(amount2)?;
+
+            sleep(Duration::from_secs(5));
+
+            harness.assert_packets_relayed(&packet_worker, 1)?;
+
+            chains.node_b.chain_driver().assert_eventual_wallet_amount(
+                &wallet_b.address(),
+                &(balance_b.clone() + amount1).as_ref(),
+            )?;
+
+            harness.resume_worker(&packet_worker)?;
+
+            harness.assert_eventual_packets_relayed(&packet_worker, 2)?;
+
+            chains.node_b.chain_driver().assert_eventual_wallet_amount(
+                &wallet_b.address(),
+                &(balance_b + amount1 + amount2).as_ref(),
+            )?;
+
+            harness.assert_retries_at_most(&packet_worker, 0)?;
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,49 @@
+//! Soak tests firing randomized ICS-20 transfers at a configurable rate,
+//! and checking that all of them are eventually relayed.
+//!
+//! These tests are only enabled with the `"load"` feature, as they take
+//! a while to run. The duration of the load can be set with the
+//! `LOAD_DURATION_SECS` environment variable.
+
+use std::env;
+
+use ibc_test_framework::prelude::*;
+use ibc_test_framework::util::load::{generate_transfer_load, LoadConfig};
+
+#[test]
+fn test_transfer_load() -> Result<(), Error> {
+    run_binary_channel_test(&TransferLoadTest)
+}
+
+pub struct TransferLoadTest;
+
+impl TestOverrides for TransferLoadTest {}
+
+impl BinaryChannelTest for TransferLoadTest {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        _relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+        channel: ConnectedChannel<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        let mut config = LoadConfig::default();
+
+        if let Ok(duration) = env::var("LOAD_DURATION_SECS") {
+            config.duration = Duration::from_secs(duration.parse().map_err(handle_generic_error)?);
+        }
+
+        let load = generate_transfer_load(&chains, &channel, &config)?;
+
+        info!(
+            "waiting for {} packets carrying a total of {} {} to be relayed",
+            load.packets,
+            load.total_amount(),
+            load.denom_a
+        );
+
+        load.assert_eventual_conservation(&chains)?;
+
+        Ok(())
+    }
+}
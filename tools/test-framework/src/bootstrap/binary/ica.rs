@@ -0,0 +1,193 @@
+/*!
+   Helper functions for bootstrapping a channel between two chains over the
+   interchain accounts ports, instead of the `transfer` port.
+
+   Interchain account channels are opened by the controller chain upon the
+   registration of an interchain account, with a port derived from the address
+   of the owner of the account, so they cannot be created with
+   [`bootstrap_channel`](super::channel::bootstrap_channel). They exercise
+   the handshake and the relaying of packets over ordered channels between
+   ports other than `transfer`, bound to distinct capabilities on each chain.
+*/
+
+use core::str::FromStr;
+use core::time::Duration;
+use eyre::eyre;
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::QueryConnectionChannelsRequest;
+use ibc_relayer::channel::Channel;
+use ibc_relayer::object::Channel as ChannelObject;
+use ibc_relayer_types::core::ics24_host::identifier::PortId;
+use tracing::info;
+
+use crate::chain::ext::ica::InterchainAccountMethodsExt;
+use crate::chain::tagged::TaggedChainDriverExt;
+use crate::error::{handle_generic_error, Error};
+use crate::relayer::channel::{
+    assert_eventually_channel_established, query_channel_end, TaggedChannelEndExt,
+};
+use crate::types::binary::chains::ConnectedChains;
+use crate::types::binary::channel::ConnectedChannel;
+use crate::types::binary::connection::ConnectedConnection;
+use crate::types::id::{TaggedChannelId, TaggedPortId};
+use crate::types::tagged::*;
+use crate::types::wallet::{TaggedWallet, Wallet, WalletAddress};
+use crate::util::retry::assert_eventually_succeed;
+
+/**
+   A channel between the interchain accounts controller port of an owner
+   on the controller chain, and the interchain accounts host port on the
+   host chain.
+*/
+#[derive(Debug, Clone)]
+pub struct InterchainAccountChannel<Controller: ChainHandle, Host: ChainHandle> {
+    /**
+       The owner of the interchain account on the controller chain.
+    */
+    pub owner: MonoTagged<Controller, Wallet>,
+
+    /**
+       The address of the interchain account on the host chain.
+    */
+    pub interchain_account: MonoTagged<Host, WalletAddress>,
+
+    /**
+       The established channel, with the controller chain as chain A.
+    */
+    pub channel: ConnectedChannel<Controller, Host>,
+}
+
+/**
+   The interchain accounts controller port of the given owner.
+*/
+pub fn controller_port_id<Controller, Host>(
+    owner: &MonoTagged<Controller, &WalletAddress>,
+) -> Result<TaggedPortId<Controller, Host>, Error> {
+    let port_id = PortId::from_str(&format!("icacontroller-{}", owner.value()))
+        .map_err(handle_generic_error)?;
+
+    Ok(DualTagged::new(port_id))
+}
+
+/**
+   Register an interchain account on the host chain on behalf of the given
+   owner over the given connection, and return the ID of the channel which
+   the controller chain initializes as a result.
+
+   The channel handshake is only completed if a relayer with channel
+   workers enabled is running.
+*/
+pub fn register_interchain_account_channel<Controller: ChainHandle, Host: ChainHandle>(
+    chains: &ConnectedChains<Controller, Host>,
+    connection: &ConnectedConnection<Controller, Host>,
+    owner: &MonoTagged<Controller, &Wallet>,
+) -> Result<
+    (
+        TaggedChannelId<Controller, Host>,
+        TaggedPortId<Controller, Host>,
+    ),
+    Error,
+> {
+    chains
+        .node_a
+        .chain_driver()
+        .register_interchain_account(&owner.address(), &connection.connection_id_a.as_ref())?;
+
+    let port_id = controller_port_id(&owner.address())?;
+
+    let channel_id = assert_eventually_succeed(
+        "interchain account channel should eventually be initialized",
+        20,
+        Duration::from_secs(1),
+        || {
+            let channels =
+                chains
+                    .handle_a()
+                    .query_connection_channels(QueryConnectionChannelsRequest {
+                        connection_id: connection.connection_id_a.value().clone(),
+                        pagination: None,
+                    })?;
+
+            channels
+                .into_iter()
+                .find(|channel| &channel.port_id == port_id.value())
+                .map(|channel| DualTagged::new(channel.channel_id))
+                .ok_or_else(|| {
+                    Error::generic(eyre!(
+                        "expected a channel on port {} of connection {}",
+                        port_id,
+                        connection.connection_id_a
+                    ))
+                })
+        },
+    )?;
+
+    Ok((channel_id, port_id))
+}
+
+/**
+   Register an interchain account on the host chain on behalf of the given
+   owner, and wait for the channel between the controller and host ports to
+   be established, which requires a relayer with channel workers enabled
+   to be running.
+*/
+pub fn bootstrap_interchain_account_channel<Controller: ChainHandle, Host: ChainHandle>(
+    chains: &ConnectedChains<Controller, Host>,
+    connection: &ConnectedConnection<Controller, Host>,
+    owner: &MonoTagged<Controller, &Wallet>,
+) -> Result<InterchainAccountChannel<Controller, Host>, Error> {
+    let (channel_id_a, port_a) = register_interchain_account_channel(chains, connection, owner)?;
+
+    let channel_id_b = assert_eventually_channel_established(
+        chains.handle_a(),
+        chains.handle_b(),
+        &channel_id_a.as_ref(),
+        &port_a.as_ref(),
+    )?;
+
+    let port_b = query_channel_end(chains.handle_a(), &channel_id_a.as_ref(), &port_a.as_ref())?
+        .tagged_counterparty_port_id();
+
+    let height = chains.handle_a().query_latest_height()?;
+
+    let (channel, _) = Channel::restore_from_state(
+        chains.handle_a().clone(),
+        chains.handle_b().clone(),
+        ChannelObject {
+            dst_chain_id: chains.chain_id_b().cloned_value(),
+            src_chain_id: chains.chain_id_a().cloned_value(),
+            src_channel_id: channel_id_a.value().clone(),
+            src_port_id: port_a.value().clone(),
+        },
+        height,
+    )?;
+
+    let interchain_account = chains
+        .node_a
+        .chain_driver()
+        .query_interchain_account(&owner.address(), &connection.connection_id_a.as_ref())?;
+
+    info!(
+        "established interchain account channel from {}/{}/{} to {}/{}/{} for account {}",
+        chains.chain_id_a(),
+        port_a,
+        channel_id_a,
+        chains.chain_id_b(),
+        port_b,
+        channel_id_b,
+        interchain_account,
+    );
+
+    Ok(InterchainAccountChannel {
+        owner: owner.cloned(),
+        interchain_account,
+        channel: ConnectedChannel {
+            connection: connection.clone(),
+            channel,
+            channel_id_a,
+            channel_id_b,
+            port_a,
+            port_b,
+        },
+    })
+}
@@ -5,3 +5,4 @@
 pub mod chain;
 pub mod channel;
 pub mod connection;
+pub mod ica;
@@ -57,6 +57,7 @@ pub use crate::relayer::channel::TaggedChannelEndExt;
 pub use crate::relayer::connection::{TaggedConnectionEndExt, TaggedConnectionExt};
 pub use crate::relayer::driver::RelayerDriver;
 pub use crate::relayer::foreign_client::TaggedForeignClientExt;
+pub use crate::relayer::harness::{packet_worker_object, SupervisorHarness};
 pub use crate::types::binary::chains::ConnectedChains;
 pub use crate::types::binary::channel::ConnectedChannel;
 pub use crate::types::binary::connection::ConnectedConnection;
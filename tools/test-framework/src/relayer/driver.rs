@@ -9,6 +9,7 @@ use ibc_relayer::supervisor::{spawn_supervisor, SupervisorHandle, SupervisorOpti
 use std::path::PathBuf;
 
 use crate::error::Error;
+use crate::relayer::harness::SupervisorHarness;
 use crate::types::env::{EnvWriter, ExportEnv};
 use crate::util::suspend::hang_on_error;
 
@@ -62,6 +63,7 @@ impl RelayerDriver {
             SupervisorOptions {
                 health_check: false,
                 force_full_scan: false,
+                allow_incompatible_versions: false,
             },
         )
         .map_err(Error::supervisor)
@@ -80,6 +82,27 @@ impl RelayerDriver {
 
         hang_on_error(self.hang_on_fail, cont)
     }
+
+    /**
+       Spawns the relayer supervisor and return a [`SupervisorHarness`]
+       to control its workers and assert on their metrics.
+    */
+    pub fn spawn_supervisor_harness(&self) -> Result<SupervisorHarness, Error> {
+        Ok(SupervisorHarness::new(self.spawn_supervisor()?))
+    }
+
+    /**
+       Same as [`with_supervisor`](Self::with_supervisor), but the continuation
+       is given a [`SupervisorHarness`] for the running supervisor.
+    */
+    pub fn with_supervisor_harness<R>(
+        &self,
+        cont: impl FnOnce(&SupervisorHarness) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        let harness = self.spawn_supervisor_harness()?;
+
+        hang_on_error(self.hang_on_fail, || cont(&harness))
+    }
 }
 
 impl ExportEnv for RelayerDriver {
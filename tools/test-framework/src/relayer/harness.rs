@@ -0,0 +1,219 @@
+/*!
+   A harness around an in-process relayer supervisor, which allows tests
+   to control the workers spawned by the supervisor and to assert on their
+   internal metrics.
+*/
+
+use core::time::Duration;
+use crossbeam_channel::bounded;
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::object::{Object, Packet};
+use ibc_relayer::supervisor::cmd::SupervisorCmd;
+use ibc_relayer::supervisor::dump_state::{SupervisorState, WorkerDesc};
+use ibc_relayer::supervisor::SupervisorHandle;
+use ibc_relayer::worker::WorkerMetrics;
+
+use crate::error::{handle_generic_error, Error};
+use crate::types::binary::channel::ConnectedChannel;
+use crate::util::retry::assert_eventually_succeed;
+
+/**
+   How long to wait for the supervisor to reply to a command.
+   The supervisor polls for commands every 500 milliseconds.
+*/
+const REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/**
+   Wraps a running [`SupervisorHandle`] and provides hooks to pause and
+   resume specific workers, as well as assertions on the metrics of the
+   workers, such as the number of packets relayed and of retried steps.
+
+   The supervisor is stopped when the harness is dropped.
+*/
+pub struct SupervisorHarness {
+    pub handle: SupervisorHandle,
+}
+
+impl SupervisorHarness {
+    pub fn new(handle: SupervisorHandle) -> Self {
+        Self { handle }
+    }
+
+    /**
+       Query the internal state of the supervisor, including the
+       state and metrics of all its workers.
+    */
+    pub fn state(&self) -> Result<SupervisorState, Error> {
+        let (reply_to, reply) = bounded(1);
+
+        self.send(SupervisorCmd::DumpState(reply_to))?;
+
+        reply
+            .recv_timeout(REPLY_TIMEOUT)
+            .map_err(handle_generic_error)
+    }
+
+    /**
+       Query the description of the worker for the given object.
+    */
+    pub fn worker(&self, object: &Object) -> Result<WorkerDesc, Error> {
+        self.state()?
+            .workers
+            .into_values()
+            .flatten()
+            .find(|desc| &desc.object == object)
+            .ok_or_else(|| {
+                Error::assertion(format!(
+                    "expected supervisor to have a worker for {}",
+                    object.short_name()
+                ))
+            })
+    }
+
+    /**
+       Query the metrics of the worker for the given object.
+    */
+    pub fn worker_metrics(&self, object: &Object) -> Result<WorkerMetrics, Error> {
+        Ok(self.worker(object)?.metrics)
+    }
+
+    /**
+       Pause the worker for the given object. The events received
+       by the worker while paused are handled once it is resumed.
+    */
+    pub fn pause_worker(&self, object: &Object) -> Result<(), Error> {
+        let (reply_to, reply) = bounded(1);
+
+        self.send(SupervisorCmd::PauseWorker(object.clone(), reply_to))?;
+
+        let found = reply
+            .recv_timeout(REPLY_TIMEOUT)
+            .map_err(handle_generic_error)?;
+
+        expect_worker(object, found)
+    }
+
+    /**
+       Resume the worker for the given object after it was paused
+       with [`pause_worker`](Self::pause_worker).
+    */
+    pub fn resume_worker(&self, object: &Object) -> Result<(), Error> {
+        let (reply_to, reply) = bounded(1);
+
+        self.send(SupervisorCmd::ResumeWorker(object.clone(), reply_to))?;
+
+        let found = reply
+            .recv_timeout(REPLY_TIMEOUT)
+            .map_err(handle_generic_error)?;
+
+        expect_worker(object, found)
+    }
+
+    /**
+       Assert that the worker for the given object has relayed exactly
+       the given number of packets so far.
+    */
+    pub fn assert_packets_relayed(&self, object: &Object, expected: u64) -> Result<(), Error> {
+        let metrics = self.worker_metrics(object)?;
+
+        if metrics.packets_relayed == expected {
+            Ok(())
+        } else {
+            Err(Error::assertion(format!(
+                "expected worker for {} to have relayed {} packets, but it relayed {}",
+                object.short_name(),
+                expected,
+                metrics.packets_relayed
+            )))
+        }
+    }
+
+    /**
+       Assert that the worker for the given object eventually relays
+       at least the given number of packets.
+    */
+    pub fn assert_eventual_packets_relayed(
+        &self,
+        object: &Object,
+        at_least: u64,
+    ) -> Result<WorkerMetrics, Error> {
+        assert_eventually_succeed(
+            &format!(
+                "worker for {} should relay at least {} packets",
+                object.short_name(),
+                at_least
+            ),
+            20,
+            Duration::from_secs(1),
+            || {
+                let metrics = self.worker_metrics(object)?;
+
+                if metrics.packets_relayed >= at_least {
+                    Ok(metrics)
+                } else {
+                    Err(Error::assertion(format!(
+                        "worker for {} relayed {} packets",
+                        object.short_name(),
+                        metrics.packets_relayed
+                    )))
+                }
+            },
+        )
+    }
+
+    /**
+       Assert that the tasks of the worker for the given object
+       have retried at most the given number of failed steps.
+    */
+    pub fn assert_retries_at_most(&self, object: &Object, max_retries: u64) -> Result<(), Error> {
+        let metrics = self.worker_metrics(object)?;
+
+        if metrics.retries <= max_retries {
+            Ok(())
+        } else {
+            Err(Error::assertion(format!(
+                "expected worker for {} to have retried at most {} times, but it retried {} times",
+                object.short_name(),
+                max_retries,
+                metrics.retries
+            )))
+        }
+    }
+
+    /**
+       Explicitly stop the supervisor.
+    */
+    pub fn shutdown(self) {
+        self.handle.shutdown()
+    }
+
+    fn send(&self, cmd: SupervisorCmd) -> Result<(), Error> {
+        self.handle.sender.send(cmd).map_err(handle_generic_error)
+    }
+}
+
+fn expect_worker(object: &Object, found: bool) -> Result<(), Error> {
+    if found {
+        Ok(())
+    } else {
+        Err(Error::assertion(format!(
+            "expected supervisor to have a worker for {}",
+            object.short_name()
+        )))
+    }
+}
+
+/**
+   The [`Object`] identifying the worker relaying packets
+   from chain A to chain B over the given channel.
+*/
+pub fn packet_worker_object<ChainA: ChainHandle, ChainB: ChainHandle>(
+    channel: &ConnectedChannel<ChainA, ChainB>,
+) -> Object {
+    Object::Packet(Packet {
+        dst_chain_id: channel.channel.b_chain().id(),
+        src_chain_id: channel.channel.a_chain().id(),
+        src_channel_id: channel.channel_id_a.value().clone(),
+        src_port_id: channel.port_a.value().clone(),
+    })
+}
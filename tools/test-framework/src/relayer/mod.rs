@@ -24,6 +24,7 @@ pub mod connection;
 pub mod driver;
 pub mod fee;
 pub mod foreign_client;
+pub mod harness;
 pub mod refresh;
 pub mod transfer;
 pub mod tx;
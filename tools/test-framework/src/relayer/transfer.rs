@@ -56,7 +56,8 @@ pub fn build_transfer_message<SrcChain, DstChain>(
         receiver,
         TimeoutHeight::no_timeout(),
         timeout_timestamp,
-    ))
+        String::new(),
+    )?)
 }
 
 /**
@@ -65,5 +65,6 @@ pub fn new_tx_config_for_test(
         max_msg_num,
         max_tx_size,
         extension_options,
+        fee_budget: None,
     })
 }
@@ -132,6 +132,7 @@ impl FullNode {
             rpc_timeout: Duration::from_secs(10),
             account_prefix: self.chain_driver.account_prefix.clone(),
             key_name: self.wallets.relayer.id.0.clone(),
+            handshake_key_name: None,
 
             // By default we use in-memory key store to avoid polluting
             // ~/.hermes/keys. See
@@ -144,19 +145,29 @@ impl FullNode {
             gas_adjustment: None,
             gas_multiplier: Default::default(),
             fee_granter: None,
+            max_daily_fee_spend: None,
             max_msg_num: Default::default(),
             max_tx_size: Default::default(),
             max_block_time: Duration::from_secs(30),
             clock_drift: Duration::from_secs(5),
             trusting_period: Some(Duration::from_secs(14 * 24 * 3600)),
+            unbonding_period: None,
             trust_threshold: Default::default(),
             gas_price: config::GasPrice::new(0.001, "stake".to_string()),
             packet_filter: Default::default(),
             address_type: chain_type.address_type(),
             memo_prefix: Default::default(),
+            verify_query_proofs: false,
+            proof_query_concurrency: config::default::proof_query_concurrency(),
+            event_batch_delay: None,
+            priority_channels: Default::default(),
+            recv_only_channels: Default::default(),
+            ack_only_channels: Default::default(),
+            transfer_limits: Default::default(),
             proof_specs: Default::default(),
             extension_options: Default::default(),
             sequential_batch_tx: false,
+            compat_mode: None,
         })
     }
 
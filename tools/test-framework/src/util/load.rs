@@ -0,0 +1,374 @@
+/*!
+   Generation of randomized ICS-20 transfer load, for soak and performance
+   regression tests of the relayer.
+
+   The transfers are fired concurrently from the user wallets of a chain to
+   randomly picked user wallets of a counterparty chain, with random amounts,
+   at a configurable rate. The resulting [`TransferLoad`] keeps track of the
+   amounts sent and received by each wallet, so that the conservation of the
+   balances can be checked once the relayer has relayed all the packets.
+*/
+
+use core::time::Duration;
+use std::thread;
+use std::time::Instant;
+use tracing::{debug, info};
+
+use ibc_relayer::chain::handle::ChainHandle;
+
+use crate::chain::driver::ChainDriver;
+use crate::chain::ext::transfer::ChainTransferMethodsExt;
+use crate::chain::tagged::TaggedChainDriverExt;
+use crate::error::Error;
+use crate::ibc::denom::{derive_ibc_denom, Denom};
+use crate::ibc::token::{TaggedDenomExt, TaggedToken};
+use crate::types::binary::chains::ConnectedChains;
+use crate::types::binary::channel::ConnectedChannel;
+use crate::types::id::{TaggedChannelId, TaggedPortId};
+use crate::types::single::node::TaggedFullNodeExt;
+use crate::types::tagged::*;
+use crate::types::wallet::{TaggedTestWalletsExt, TaggedWallet, Wallet, WalletAddress};
+use crate::util::random::{random_u128_range, random_u64_range};
+
+/**
+   The parameters of the transfer load to generate.
+*/
+#[derive(Debug, Clone)]
+pub struct LoadConfig {
+    /**
+       The number of transfer transactions each sender attempts to submit
+       per second. The actual rate may be lower if submitting a transaction
+       takes longer than the interval between two transactions.
+    */
+    pub transactions_per_second: u32,
+
+    /**
+       For how long the transfers are fired.
+    */
+    pub duration: Duration,
+
+    /**
+       The maximum number of transfer messages included in a single
+       transaction. The number of messages of each transaction is
+       picked randomly between 1 and this value.
+    */
+    pub max_msgs_per_tx: usize,
+
+    /**
+       The range from which the amount of each transfer is picked randomly.
+    */
+    pub min_amount: u128,
+    pub max_amount: u128,
+}
+
+impl Default for LoadConfig {
+    fn default() -> Self {
+        Self {
+            transactions_per_second: 2,
+            duration: Duration::from_secs(30),
+            max_msgs_per_tx: 5,
+            min_amount: 1000,
+            max_amount: 5000,
+        }
+    }
+}
+
+/**
+   The record of the transfers fired by [`generate_transfer_load`]
+   from chain A to chain B.
+*/
+#[derive(Debug, Clone)]
+pub struct TransferLoad<ChainA, ChainB> {
+    /**
+       The denomination of the transferred tokens on chain A.
+    */
+    pub denom_a: MonoTagged<ChainA, Denom>,
+
+    /**
+       The IBC denomination of the transferred tokens on chain B.
+    */
+    pub denom_b: MonoTagged<ChainB, Denom>,
+
+    /**
+       The senders on chain A, together with their balance
+       before the load and the total amount they sent.
+    */
+    pub senders: Vec<(MonoTagged<ChainA, WalletAddress>, TaggedToken<ChainA>, u128)>,
+
+    /**
+       The recipients on chain B, together with their balance
+       before the load and the total amount they were sent.
+    */
+    pub recipients: Vec<(MonoTagged<ChainB, WalletAddress>, TaggedToken<ChainB>, u128)>,
+
+    /**
+       The number of transfer transactions submitted.
+    */
+    pub transactions: u64,
+
+    /**
+       The number of transfer messages submitted, ie. the
+       number of packets to be relayed.
+    */
+    pub packets: u64,
+}
+
+/**
+   A transfer from one of the senders to one of the recipients,
+   identified by their index.
+*/
+struct SentTransfer {
+    recipient: usize,
+    amount: u128,
+    num_msgs: usize,
+}
+
+/**
+   Fire transfers of random amounts from the user wallets of chain A to
+   randomly picked user wallets of chain B over the given channel, for
+   the duration and at the rate given in the [`LoadConfig`].
+
+   Each sender submits its transactions from its own thread, so that the
+   transfers of the different senders are submitted concurrently.
+
+   Returns once all the transfers have been submitted, without waiting
+   for them to be relayed.
+*/
+pub fn generate_transfer_load<ChainA: ChainHandle, ChainB: ChainHandle>(
+    chains: &ConnectedChains<ChainA, ChainB>,
+    channel: &ConnectedChannel<ChainA, ChainB>,
+    config: &LoadConfig,
+) -> Result<TransferLoad<ChainA, ChainB>, Error> {
+    let denom_a = chains.node_a.denom().cloned();
+
+    let denom_b = derive_ibc_denom(
+        &channel.port_b.as_ref(),
+        &channel.channel_id_b.as_ref(),
+        &denom_a.as_ref(),
+    )?;
+
+    let wallets_a = chains.node_a.wallets();
+    let wallets_b = chains.node_b.wallets();
+
+    let senders = [wallets_a.user1().cloned(), wallets_a.user2().cloned()];
+
+    let recipients = [
+        wallets_b.user1().address().cloned(),
+        wallets_b.user2().address().cloned(),
+    ];
+
+    let sender_balances = senders
+        .iter()
+        .map(|sender| {
+            chains
+                .node_a
+                .chain_driver()
+                .query_balance(&sender.address(), &denom_a.as_ref())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let recipient_balances = recipients
+        .iter()
+        .map(|recipient| {
+            chains
+                .node_b
+                .chain_driver()
+                .query_balance(&recipient.as_ref(), &denom_b.as_ref())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    info!(
+        "generating transfer load from {} to {} for {:?}: {:?}",
+        chains.chain_id_a(),
+        chains.chain_id_b(),
+        config.duration,
+        config
+    );
+
+    let threads = senders
+        .iter()
+        .map(|sender| {
+            let driver = chains.node_a.chain_driver().cloned();
+            let port_a = channel.port_a.clone();
+            let channel_id_a = channel.channel_id_a.clone();
+            let sender = sender.clone();
+            let recipients = recipients.clone();
+            let denom_a = denom_a.clone();
+            let config = config.clone();
+
+            thread::spawn(move || {
+                fire_transfers(
+                    driver,
+                    port_a,
+                    channel_id_a,
+                    sender,
+                    &recipients,
+                    denom_a,
+                    &config,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut sent = vec![0; senders.len()];
+    let mut received = vec![0; recipients.len()];
+    let mut transactions = 0;
+    let mut packets = 0;
+
+    // Wait for all the senders to be done before reporting any failure
+    let mut results = Vec::new();
+    for thread in threads {
+        results.push(thread.join());
+    }
+
+    for (i, result) in results.into_iter().enumerate() {
+        let transfers =
+            result.map_err(|_| Error::generic(eyre::eyre!("transfer load thread panicked")))??;
+
+        for transfer in transfers {
+            let total = transfer.amount * transfer.num_msgs as u128;
+
+            sent[i] += total;
+            received[transfer.recipient] += total;
+            transactions += 1;
+            packets += transfer.num_msgs as u64;
+        }
+    }
+
+    info!(
+        "submitted {} transfer transactions with {} packets from {} to {}",
+        transactions,
+        packets,
+        chains.chain_id_a(),
+        chains.chain_id_b()
+    );
+
+    Ok(TransferLoad {
+        denom_a,
+        denom_b,
+        senders: zip_balances(
+            senders.iter().map(|s| s.address().cloned()),
+            sender_balances,
+            sent,
+        ),
+        recipients: zip_balances(recipients, recipient_balances, received),
+        transactions,
+        packets,
+    })
+}
+
+impl<ChainA: ChainHandle, ChainB: ChainHandle> TransferLoad<ChainA, ChainB> {
+    /**
+       The total amount transferred from chain A to chain B.
+    */
+    pub fn total_amount(&self) -> u128 {
+        self.senders.iter().map(|(_, _, sent)| sent).sum()
+    }
+
+    /**
+       Assert that the balances of the senders and recipients eventually
+       reflect all the transfers, ie. that every token deducted from the
+       senders on chain A has been credited to the recipients on chain B.
+    */
+    pub fn assert_eventual_conservation(
+        &self,
+        chains: &ConnectedChains<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        let total_received: u128 = self
+            .recipients
+            .iter()
+            .map(|(_, _, received)| received)
+            .sum();
+
+        if total_received != self.total_amount() {
+            return Err(Error::assertion(format!(
+                "expected the amount sent ({}) to be equal to the amount received ({})",
+                self.total_amount(),
+                total_received
+            )));
+        }
+
+        for (sender, balance, sent) in self.senders.iter() {
+            chains.node_a.chain_driver().assert_eventual_wallet_amount(
+                &sender.as_ref(),
+                &(balance.clone() - *sent).as_ref(),
+            )?;
+        }
+
+        for (recipient, balance, received) in self.recipients.iter() {
+            chains.node_b.chain_driver().assert_eventual_wallet_amount(
+                &recipient.as_ref(),
+                &(balance.clone() + *received).as_ref(),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn fire_transfers<ChainA: ChainHandle, ChainB: ChainHandle>(
+    driver: MonoTagged<ChainA, ChainDriver>,
+    port_id: TaggedPortId<ChainA, ChainB>,
+    channel_id: TaggedChannelId<ChainA, ChainB>,
+    sender: MonoTagged<ChainA, Wallet>,
+    recipients: &[MonoTagged<ChainB, WalletAddress>],
+    denom: MonoTagged<ChainA, Denom>,
+    config: &LoadConfig,
+) -> Result<Vec<SentTransfer>, Error> {
+    let interval = Duration::from_secs(1) / config.transactions_per_second.max(1);
+    let start = Instant::now();
+
+    let mut transfers = Vec::new();
+    let mut next = start;
+
+    while start.elapsed() < config.duration {
+        let recipient = random_u64_range(0, recipients.len() as u64) as usize;
+        let amount = random_u128_range(config.min_amount, config.max_amount);
+        let num_msgs = random_u64_range(1, config.max_msgs_per_tx.max(1) as u64 + 1) as usize;
+
+        debug!(
+            "sending {} transfers of {} {} from {} to {}",
+            num_msgs,
+            amount,
+            denom,
+            sender.value().address,
+            recipients[recipient]
+        );
+
+        driver.as_ref().ibc_transfer_token_multiple(
+            &port_id.as_ref(),
+            &channel_id.as_ref(),
+            &sender.as_ref(),
+            &recipients[recipient].as_ref(),
+            &denom.with_amount(amount).as_ref(),
+            num_msgs,
+        )?;
+
+        transfers.push(SentTransfer {
+            recipient,
+            amount,
+            num_msgs,
+        });
+
+        next += interval;
+
+        if let Some(remaining) = next.checked_duration_since(Instant::now()) {
+            thread::sleep(remaining);
+        }
+    }
+
+    Ok(transfers)
+}
+
+fn zip_balances<Chain>(
+    wallets: impl IntoIterator<Item = MonoTagged<Chain, WalletAddress>>,
+    balances: Vec<TaggedToken<Chain>>,
+    amounts: Vec<u128>,
+) -> Vec<(MonoTagged<Chain, WalletAddress>, TaggedToken<Chain>, u128)> {
+    wallets
+        .into_iter()
+        .zip(balances)
+        .zip(amounts)
+        .map(|((wallet, balance), amount)| (wallet, balance, amount))
+        .collect()
+}